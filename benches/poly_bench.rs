@@ -1,5 +1,13 @@
-use criterion::{Criterion, black_box, criterion_group, criterion_main};
-use lumen_math::{DefaultFieldConfig, FieldElement, Polynomial};
+use std::hint::black_box;
+
+use criterion::{Criterion, criterion_group, criterion_main};
+#[allow(deprecated)]
+use lumen_math::DilithiumFieldConfig;
+use lumen_math::poly::ntt::{CyclicNttContext, intt, ntt};
+use lumen_math::{
+    DefaultFieldConfig, DilithiumFieldElement, FieldElement, Polynomial, U1024, dilithium_intt,
+    dilithium_ntt, dilithium_pointwise_mul, mul_negacyclic,
+};
 
 fn bench_poly_mul(c: &mut Criterion) {
     let size = 256;
@@ -21,5 +29,111 @@ fn bench_poly_mul(c: &mut Criterion) {
     group.finish();
 }
 
-criterion_group!(benches, bench_poly_mul);
+/// Cyclic product with the free `ntt`/`intt`, which derive every layer's root
+/// on each call.
+fn mul_uncached(
+    a: &[FieldElement<DefaultFieldConfig>],
+    b: &[FieldElement<DefaultFieldConfig>],
+) -> Vec<FieldElement<DefaultFieldConfig>> {
+    let mut x = a.to_vec();
+    let mut y = b.to_vec();
+    ntt(&mut x);
+    ntt(&mut y);
+    for (u, v) in x.iter_mut().zip(&y) {
+        *u = *u * *v;
+    }
+    intt(&mut x);
+    x
+}
+
+fn bench_cyclic_ntt(c: &mut Criterion) {
+    let mut group = c.benchmark_group("Cyclic NTT Multiplication");
+
+    for size in [64, 256, 1024] {
+        let coeffs: Vec<FieldElement<DefaultFieldConfig>> = (0..size)
+            .map(|i| FieldElement::new(U1024::from_u64(i as u64 * 7919 + 1)))
+            .collect();
+        let ctx = CyclicNttContext::<DefaultFieldConfig>::shared(size);
+
+        group.bench_function(format!("free ntt/intt (n = {size})"), |b| {
+            b.iter(|| mul_uncached(black_box(&coeffs), black_box(&coeffs)))
+        });
+
+        group.bench_function(format!("CyclicNttContext (n = {size})"), |b| {
+            b.iter(|| ctx.mul(black_box(&coeffs), black_box(&coeffs)))
+        });
+    }
+
+    group.finish();
+}
+
+fn bench_mul_fast(c: &mut Criterion) {
+    let mut group = c.benchmark_group("Non-Power-of-Two Products");
+
+    // Product lengths just above or between powers of two.
+    for len in [513, 640, 1100] {
+        let half = len / 2 + 1;
+        let make = |n: usize| {
+            Polynomial::new(
+                (0..n)
+                    .map(|i| FieldElement::<DefaultFieldConfig>::new(U1024::from_u64(i as u64 + 1)))
+                    .collect(),
+            )
+        };
+        let a = make(half);
+        let b = make(len + 1 - half);
+
+        group.bench_function(format!("mul_ntt (len = {len})"), |bench| {
+            bench.iter(|| black_box(&a).mul_ntt(black_box(&b)))
+        });
+
+        group.bench_function(format!("mul_fast (len = {len})"), |bench| {
+            bench.iter(|| black_box(&a).mul_fast(black_box(&b)))
+        });
+    }
+
+    group.finish();
+}
+
+#[allow(deprecated)]
+fn bench_dilithium_mul(c: &mut Criterion) {
+    let native: [DilithiumFieldElement; 256] =
+        std::array::from_fn(|i| DilithiumFieldElement::new((i as u32 * 7919) ^ 0x5a5a5));
+    let generic: Vec<FieldElement<DilithiumFieldConfig>> = native
+        .iter()
+        .map(|c| FieldElement::new(U1024::from_u64(c.value() as u64)))
+        .collect();
+
+    let mut group = c.benchmark_group("Dilithium Negacyclic Multiplication (n = 256)");
+
+    group.bench_function("mul_negacyclic (U1024)", |b| {
+        b.iter(|| {
+            let mut x = black_box(&generic).clone();
+            let mut y = black_box(&generic).clone();
+            mul_negacyclic(&mut x, &mut y)
+        })
+    });
+
+    group.bench_function("dilithium_ntt (u32)", |b| {
+        b.iter(|| {
+            let mut x = *black_box(&native);
+            let mut y = *black_box(&native);
+            dilithium_ntt(&mut x);
+            dilithium_ntt(&mut y);
+            let mut z = dilithium_pointwise_mul(&x, &y);
+            dilithium_intt(&mut z);
+            z
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    bench_poly_mul,
+    bench_cyclic_ntt,
+    bench_mul_fast,
+    bench_dilithium_mul
+);
 criterion_main!(benches);
@@ -50,6 +50,22 @@ fn compute_r2_bigint(modulus: &BigUint) -> BigUint {
     two.modpow(&BigUint::from(2048u32), modulus)
 }
 
+/// Compute log2 of the order of `root` modulo P, or `None` if that order is
+/// not a power of two.
+/// This is the TWO_ADICITY the cyclic NTT relies on.
+fn compute_two_adicity_bigint(root: &BigUint, modulus: &BigUint) -> Option<u32> {
+    // The order divides P - 1, so it is at most 2^v with v = v2(P - 1).
+    let max = (modulus - BigUint::one()).trailing_zeros().unwrap_or(0) as u32;
+    let mut x = root % modulus;
+    for k in 0..=max {
+        if x.is_one() {
+            return Some(k);
+        }
+        x = &x * &x % modulus;
+    }
+    None
+}
+
 #[proc_macro_derive(FieldConfig, attributes(modulus, root))]
 pub fn derive_field_config(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
@@ -99,6 +115,9 @@ pub fn derive_field_config(input: TokenStream) -> TokenStream {
         BigUint::one()
     };
 
+    let two_adicity = compute_two_adicity_bigint(&root, &modulus)
+        .expect("FieldConfig: root must have a power-of-two order modulo the modulus");
+
     let modulus_arr = biguint_to_u64_array(&modulus);
     let r2_arr = biguint_to_u64_array(&r2);
     let n_prime_arr = biguint_to_u64_array(&n_prime);
@@ -116,6 +135,7 @@ pub fn derive_field_config(input: TokenStream) -> TokenStream {
             const R2: lumen_math::U1024 = #r2_tokens;
             const N_PRIME: lumen_math::U1024 = #n_prime_tokens;
             const ROOT_OF_UNITY: lumen_math::U1024 = #root_tokens;
+            const TWO_ADICITY: u32 = #two_adicity;
 
             fn to_montgomery_context() -> lumen_math::field::montgomery::MontgomeryContext {
                  lumen_math::field::montgomery::MontgomeryContext {
@@ -257,6 +277,29 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_compute_two_adicity() {
+        // 7681 - 1 = 2^9 * 15; 7146 has order 512 and 2028 = 7146^2 has order 256.
+        let modulus = BigUint::from(7681u64);
+        assert_eq!(
+            compute_two_adicity_bigint(&BigUint::from(7146u64), &modulus),
+            Some(9)
+        );
+        assert_eq!(
+            compute_two_adicity_bigint(&BigUint::from(2028u64), &modulus),
+            Some(8)
+        );
+        assert_eq!(
+            compute_two_adicity_bigint(&BigUint::one(), &modulus),
+            Some(0)
+        );
+        // 17 generates the whole group, of order 2^9 * 15.
+        assert_eq!(
+            compute_two_adicity_bigint(&BigUint::from(17u64), &modulus),
+            None
+        );
+    }
+
     #[test]
     fn test_hex_parsing_with_0x_prefix() {
         // Test that both "0x11" and "11" parse correctly
@@ -1190,7 +1190,9 @@ impl Digest for U1024 {
 }
 
 /// Expands a message to the specified length using SHA256 (RFC 9380 style).
-fn expand_message_sha256(input: &[u8], len_in_bytes: usize) -> Vec<u8> {
+///
+/// `len_in_bytes` must not exceed 255 * 32 = 8160 bytes.
+pub(crate) fn expand_message_sha256(input: &[u8], len_in_bytes: usize) -> Vec<u8> {
     const DST: &[u8] = b"lumen_math_expand_v1";
     const HASH_LEN: usize = 32;
 
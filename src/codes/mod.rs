@@ -0,0 +1,8 @@
+//! Error-correcting codes over finite fields.
+//!
+//! This module provides:
+//! - `reed_solomon` - Reed–Solomon encoding, erasure decoding and error correction
+
+pub mod reed_solomon;
+
+pub use reed_solomon::{DecodedWord, Encoding, ReedSolomon, ReedSolomonError};
@@ -0,0 +1,402 @@
+//! Reed–Solomon codes over prime fields.
+//!
+//! A Reed–Solomon code RS[n, k] encodes a message of `k` field elements as the
+//! evaluations of a polynomial of degree < k over a multiplicative subgroup
+//! {1, ω, ω², ..., ω^(n-1)} of order n. Because the evaluation domain is a
+//! subgroup, encoding is a single cyclic NTT.
+//!
+//! # Encodings
+//!
+//! - **Non-systematic**: the message is the coefficient vector of the polynomial.
+//! - **Systematic**: the message appears verbatim in the codeword at positions
+//!   `i * (n / k)`. This requires `k` to be a power of two so that those positions
+//!   form a subgroup of order `k` and interpolation is an inverse NTT.
+//!
+//! # Decoding
+//!
+//! - [`ReedSolomon::decode_erasures`] recovers the message from any `k` known symbols.
+//! - [`ReedSolomon::decode`] corrects up to `(n - k) / 2` symbol errors using
+//!   Gao's algorithm (partial extended Euclid on `x^n - 1` and the interpolant
+//!   of the received word) and reports the positions it corrected.
+//!
+//! # Example
+//!
+//! ```
+//! use lumen_math::codes::ReedSolomon;
+//! use lumen_math::{DefaultFieldConfig, fp};
+//!
+//! let rs = ReedSolomon::<DefaultFieldConfig>::new(8, 4).unwrap();
+//! let message = vec![fp!(1u64), fp!(2u64), fp!(3u64), fp!(4u64)];
+//!
+//! let mut codeword = rs.encode(&message).unwrap();
+//! codeword[5] = fp!(99u64); // corrupt one symbol
+//!
+//! let decoded = rs.decode(&codeword).unwrap();
+//! assert_eq!(decoded.message, message);
+//! assert_eq!(decoded.error_positions, vec![5]);
+//! ```
+
+use crate::poly::ntt::{intt, ntt};
+use crate::{FieldConfig, FieldElement, Polynomial};
+
+/// Error type for Reed–Solomon encoding and decoding.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ReedSolomonError {
+    /// Code parameters are invalid (n not a power of two, k = 0, k > n, ...).
+    InvalidParameters,
+    /// Input length does not match the code parameters.
+    LengthMismatch,
+    /// Fewer than `k` symbols are available for erasure decoding.
+    TooManyErasures,
+    /// The known symbols are not consistent with any codeword.
+    InconsistentSymbols,
+    /// More than `(n - k) / 2` symbol errors are present.
+    TooManyErrors,
+}
+
+impl std::fmt::Display for ReedSolomonError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReedSolomonError::InvalidParameters => write!(f, "Invalid Reed-Solomon parameters"),
+            ReedSolomonError::LengthMismatch => {
+                write!(f, "Input length does not match code parameters")
+            }
+            ReedSolomonError::TooManyErasures => {
+                write!(f, "Not enough known symbols to recover the message")
+            }
+            ReedSolomonError::InconsistentSymbols => {
+                write!(f, "Known symbols do not form a valid codeword")
+            }
+            ReedSolomonError::TooManyErrors => {
+                write!(f, "Number of errors exceeds the correction capacity")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ReedSolomonError {}
+
+/// How messages are laid out inside codewords.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Encoding {
+    /// The message is the coefficient vector of the encoding polynomial.
+    NonSystematic,
+    /// The message occupies codeword positions `i * (n / k)`.
+    Systematic,
+}
+
+/// Result of a successful error-correcting decode.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DecodedWord<C: FieldConfig> {
+    /// The recovered message.
+    pub message: Vec<FieldElement<C>>,
+    /// The corrected codeword.
+    pub codeword: Vec<FieldElement<C>>,
+    /// Positions (in ascending order) where the received word was corrected.
+    pub error_positions: Vec<usize>,
+}
+
+/// A Reed–Solomon code RS[n, k] over the evaluation domain of the cyclic NTT.
+///
+/// # Type Parameters
+/// * `C` - Field configuration whose `ROOT_OF_UNITY` generates a subgroup of order ≥ n
+#[derive(Clone, Debug)]
+pub struct ReedSolomon<C: FieldConfig> {
+    n: usize,
+    k: usize,
+    encoding: Encoding,
+    /// Evaluation points [ω^0, ω^1, ..., ω^(n-1)]
+    domain: Vec<FieldElement<C>>,
+}
+
+impl<C: FieldConfig> ReedSolomon<C> {
+    /// Creates a non-systematic RS[n, k] code.
+    ///
+    /// `n` must be a power of two (at most 2^TWO_ADICITY) and `1 <= k <= n`.
+    pub fn new(n: usize, k: usize) -> Result<Self, ReedSolomonError> {
+        Self::with_encoding(n, k, Encoding::NonSystematic)
+    }
+
+    /// Creates a systematic RS[n, k] code.
+    ///
+    /// In addition to the requirements of [`new`](Self::new), `k` must be a power of two.
+    pub fn new_systematic(n: usize, k: usize) -> Result<Self, ReedSolomonError> {
+        Self::with_encoding(n, k, Encoding::Systematic)
+    }
+
+    /// Creates an RS[n, k] code with the given message layout.
+    pub fn with_encoding(n: usize, k: usize, encoding: Encoding) -> Result<Self, ReedSolomonError> {
+        if !n.is_power_of_two() || n.trailing_zeros() > C::TWO_ADICITY || k == 0 || k > n {
+            return Err(ReedSolomonError::InvalidParameters);
+        }
+        if encoding == Encoding::Systematic && !k.is_power_of_two() {
+            return Err(ReedSolomonError::InvalidParameters);
+        }
+
+        // ROOT_OF_UNITY has order 2^TWO_ADICITY; square it down to a primitive n-th root.
+        let mut omega = FieldElement::<C>::new(C::ROOT_OF_UNITY);
+        for _ in 0..(C::TWO_ADICITY - n.trailing_zeros()) {
+            omega = omega.square();
+        }
+
+        let mut domain = Vec::with_capacity(n);
+        let mut current = FieldElement::<C>::one();
+        for _ in 0..n {
+            domain.push(current);
+            current = current * omega;
+        }
+
+        Ok(Self {
+            n,
+            k,
+            encoding,
+            domain,
+        })
+    }
+
+    /// Returns the codeword length n.
+    #[inline]
+    pub fn n(&self) -> usize {
+        self.n
+    }
+
+    /// Returns the message length k.
+    #[inline]
+    pub fn k(&self) -> usize {
+        self.k
+    }
+
+    /// Returns the message layout of this code.
+    #[inline]
+    pub fn encoding(&self) -> Encoding {
+        self.encoding
+    }
+
+    /// Returns the maximum number of symbol errors `decode` can correct.
+    #[inline]
+    pub fn max_errors(&self) -> usize {
+        (self.n - self.k) / 2
+    }
+
+    /// Returns the evaluation domain [ω^0, ..., ω^(n-1)].
+    #[inline]
+    pub fn domain(&self) -> &[FieldElement<C>] {
+        &self.domain
+    }
+
+    /// Encodes a message of length k into a codeword of length n.
+    pub fn encode(
+        &self,
+        message: &[FieldElement<C>],
+    ) -> Result<Vec<FieldElement<C>>, ReedSolomonError> {
+        if message.len() != self.k {
+            return Err(ReedSolomonError::LengthMismatch);
+        }
+
+        let mut coeffs = message.to_vec();
+        if self.encoding == Encoding::Systematic {
+            // Interpolate over the order-k subgroup so that p(ω^(i·n/k)) = m_i.
+            intt(&mut coeffs);
+        }
+        Ok(self.evaluate_coefficients(coeffs))
+    }
+
+    /// Encodes the coefficients of `poly` (degree < k) into a codeword.
+    pub fn encode_polynomial(
+        &self,
+        poly: &Polynomial<C>,
+    ) -> Result<Vec<FieldElement<C>>, ReedSolomonError> {
+        if poly.coeffs.len() > self.k {
+            return Err(ReedSolomonError::LengthMismatch);
+        }
+        Ok(self.evaluate_coefficients(poly.coeffs.clone()))
+    }
+
+    /// Recovers the message from a codeword with missing symbols.
+    ///
+    /// `received[i]` is `None` for an erased symbol. Any `k` known symbols suffice;
+    /// the remaining known symbols are checked for consistency.
+    pub fn decode_erasures(
+        &self,
+        received: &[Option<FieldElement<C>>],
+    ) -> Result<Vec<FieldElement<C>>, ReedSolomonError> {
+        if received.len() != self.n {
+            return Err(ReedSolomonError::LengthMismatch);
+        }
+
+        let known: Vec<(usize, FieldElement<C>)> = received
+            .iter()
+            .enumerate()
+            .filter_map(|(i, s)| s.map(|v| (i, v)))
+            .collect();
+        if known.len() < self.k {
+            return Err(ReedSolomonError::TooManyErasures);
+        }
+
+        let points: Vec<_> = known[..self.k]
+            .iter()
+            .map(|(i, _)| self.domain[*i])
+            .collect();
+        let values: Vec<_> = known[..self.k].iter().map(|(_, v)| *v).collect();
+        let poly = Polynomial::interpolate(&points, &values);
+
+        let codeword = self.evaluate_coefficients(poly.coeffs.clone());
+        if known[self.k..].iter().any(|(i, v)| codeword[*i] != *v) {
+            return Err(ReedSolomonError::InconsistentSymbols);
+        }
+
+        Ok(self.extract_message(&poly, &codeword))
+    }
+
+    /// Corrects up to `(n - k) / 2` symbol errors using Gao's algorithm.
+    ///
+    /// Returns the recovered message, the corrected codeword and the positions
+    /// that were corrected, or [`ReedSolomonError::TooManyErrors`] when the
+    /// received word is further than the unique decoding radius from every codeword.
+    pub fn decode(&self, received: &[FieldElement<C>]) -> Result<DecodedWord<C>, ReedSolomonError> {
+        if received.len() != self.n {
+            return Err(ReedSolomonError::LengthMismatch);
+        }
+
+        // g0 = x^n - 1 vanishes on the whole domain; g1 interpolates the received word.
+        let g0 = Polynomial::monomial(FieldElement::one(), self.n) - Polynomial::one();
+        let mut g1 = received.to_vec();
+        intt(&mut g1);
+        let g1 = Polynomial::new(g1);
+
+        // Partial extended Euclid: stop once deg(g) < (n + k) / 2.
+        let (mut g_prev, mut g_cur) = (g0, g1);
+        let (mut v_prev, mut v_cur) = (Polynomial::zero(), Polynomial::one());
+        while g_cur.degree().is_some_and(|d| 2 * d >= self.n + self.k) {
+            let (q, r) = g_prev.divide_with_remainder(&g_cur);
+            let v_next = v_prev - q * &v_cur;
+            g_prev = std::mem::replace(&mut g_cur, r);
+            v_prev = std::mem::replace(&mut v_cur, v_next);
+        }
+
+        let (poly, remainder) = g_cur.divide_with_remainder(&v_cur);
+        if !remainder.is_zero() || poly.coeffs.len() > self.k {
+            return Err(ReedSolomonError::TooManyErrors);
+        }
+
+        let codeword = self.evaluate_coefficients(poly.coeffs.clone());
+        let error_positions: Vec<usize> = (0..self.n)
+            .filter(|&i| codeword[i] != received[i])
+            .collect();
+        if error_positions.len() > self.max_errors() {
+            return Err(ReedSolomonError::TooManyErrors);
+        }
+
+        Ok(DecodedWord {
+            message: self.extract_message(&poly, &codeword),
+            codeword,
+            error_positions,
+        })
+    }
+
+    /// Evaluates a coefficient vector (length ≤ n) over the domain via NTT.
+    fn evaluate_coefficients(&self, mut coeffs: Vec<FieldElement<C>>) -> Vec<FieldElement<C>> {
+        coeffs.resize(self.n, FieldElement::zero());
+        ntt(&mut coeffs);
+        coeffs
+    }
+
+    /// Reads the message back out of the encoding polynomial or codeword.
+    fn extract_message(
+        &self,
+        poly: &Polynomial<C>,
+        codeword: &[FieldElement<C>],
+    ) -> Vec<FieldElement<C>> {
+        match self.encoding {
+            Encoding::NonSystematic => {
+                let mut message = poly.coeffs.clone();
+                message.resize(self.k, FieldElement::zero());
+                message
+            }
+            Encoding::Systematic => {
+                let stride = self.n / self.k;
+                (0..self.k).map(|i| codeword[i * stride]).collect()
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::field::config::DefaultFieldConfig;
+    use crate::fp;
+
+    type RS = ReedSolomon<DefaultFieldConfig>;
+
+    fn message(k: usize) -> Vec<FieldElement<DefaultFieldConfig>> {
+        (0..k).map(|i| fp!((i * 7 + 3) as u64)).collect()
+    }
+
+    #[test]
+    fn test_encode_matches_polynomial_evaluation() {
+        let rs = RS::new(8, 3).unwrap();
+        let msg = message(3);
+        let codeword = rs.encode(&msg).unwrap();
+
+        let poly = Polynomial::new(msg);
+        for (point, symbol) in rs.domain().iter().zip(codeword.iter()) {
+            assert_eq!(poly.evaluate(point), *symbol);
+        }
+    }
+
+    #[test]
+    fn test_systematic_layout() {
+        let rs = RS::new_systematic(16, 4).unwrap();
+        let msg = message(4);
+        let codeword = rs.encode(&msg).unwrap();
+
+        for (i, m) in msg.iter().enumerate() {
+            assert_eq!(codeword[i * 4], *m);
+        }
+    }
+
+    #[test]
+    fn test_decode_without_errors() {
+        let rs = RS::new(8, 4).unwrap();
+        let msg = message(4);
+        let codeword = rs.encode(&msg).unwrap();
+
+        let decoded = rs.decode(&codeword).unwrap();
+        assert_eq!(decoded.message, msg);
+        assert!(decoded.error_positions.is_empty());
+    }
+
+    #[test]
+    fn test_invalid_parameters() {
+        assert_eq!(
+            RS::new(6, 3).unwrap_err(),
+            ReedSolomonError::InvalidParameters
+        );
+        assert_eq!(
+            RS::new(8, 0).unwrap_err(),
+            ReedSolomonError::InvalidParameters
+        );
+        assert_eq!(
+            RS::new(8, 9).unwrap_err(),
+            ReedSolomonError::InvalidParameters
+        );
+        assert_eq!(
+            RS::new_systematic(8, 3).unwrap_err(),
+            ReedSolomonError::InvalidParameters
+        );
+    }
+
+    #[test]
+    fn test_length_mismatch() {
+        let rs = RS::new(8, 4).unwrap();
+        assert_eq!(
+            rs.encode(&message(3)).unwrap_err(),
+            ReedSolomonError::LengthMismatch
+        );
+        assert_eq!(
+            rs.decode(&message(4)).unwrap_err(),
+            ReedSolomonError::LengthMismatch
+        );
+    }
+}
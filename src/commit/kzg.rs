@@ -0,0 +1,322 @@
+//! KZG polynomial commitments over BN254.
+//!
+//! A structured reference string holds [τ^i]G1 for i ≤ d and [τ^i]G2 for
+//! i ≤ t. A polynomial p of degree ≤ d is committed as C = [p(τ)]G1.
+//!
+//! - **Single opening** at z: the prover sends v = p(z) and π = [q(τ)]G1 with
+//!   q = (p - v) / (X - z). The verifier checks e(C - [v]G1, G2) = e(π, [τ - z]G2).
+//! - **Batched opening** at z_1, ..., z_k (k ≤ t): with I interpolating the
+//!   claimed values and Z = Π (X - z_i), the prover sends π = [q(τ)]G1 with
+//!   q = (p - I) / Z and the verifier checks e(C - [I(τ)]G1, G2) = e(π, [Z(τ)]G2).
+//!
+//! Both checks are evaluated as a single two-pairing product.
+//!
+//! # Security
+//!
+//! [`KzgParams::setup_insecure`] derives the reference string from a known τ.
+//! Anyone who knows τ can forge openings, so it is only suitable for tests
+//! and prototyping.
+//!
+//! # Examples
+//!
+//! ```
+//! use lumen_math::commit::KzgParams;
+//! use lumen_math::curve::bn254::Fr;
+//! use lumen_math::{Polynomial, U1024};
+//!
+//! let params = KzgParams::setup_insecure(4, 1, &Fr::new(U1024::from_u64(12345)));
+//! let poly = Polynomial::new(vec![Fr::new(U1024::from_u64(1)), Fr::new(U1024::from_u64(2))]);
+//!
+//! let commitment = params.commit(&poly).unwrap();
+//! let opening = params.open(&poly, &Fr::new(U1024::from_u64(3))).unwrap();
+//! assert_eq!(opening.value, Fr::new(U1024::from_u64(7)));
+//! assert!(params.verify(&commitment, &opening).is_ok());
+//! ```
+
+use crate::Polynomial;
+use crate::curve::bn254::{
+    Bn254FrConfig, Fr, G1Affine, G1Projective, G2Affine, G2Projective, multi_pairing,
+};
+use crate::curve::short_weierstrass::msm;
+
+/// Error type for KZG commitments and openings.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum KzgError {
+    /// The polynomial degree exceeds the supported maximum.
+    DegreeTooLarge { degree: usize, max_degree: usize },
+    /// More opening points than the reference string supports.
+    TooManyPoints { count: usize, max_points: usize },
+    /// A batched opening needs at least one point.
+    EmptyBatch,
+    /// Opening points must be distinct.
+    DuplicatePoints,
+    /// The number of claimed values does not match the number of points.
+    LengthMismatch,
+    /// The pairing check failed.
+    VerificationFailed,
+}
+
+impl std::fmt::Display for KzgError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            KzgError::DegreeTooLarge { degree, max_degree } => write!(
+                f,
+                "Polynomial degree {} exceeds maximum {}",
+                degree, max_degree
+            ),
+            KzgError::TooManyPoints { count, max_points } => write!(
+                f,
+                "Cannot open at {} points, maximum is {}",
+                count, max_points
+            ),
+            KzgError::EmptyBatch => write!(f, "Batched opening needs at least one point"),
+            KzgError::DuplicatePoints => write!(f, "Opening points must be distinct"),
+            KzgError::LengthMismatch => write!(f, "Number of values does not match points"),
+            KzgError::VerificationFailed => write!(f, "KZG verification failed"),
+        }
+    }
+}
+
+impl std::error::Error for KzgError {}
+
+/// A commitment [p(τ)]G1.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct KzgCommitment(pub G1Affine);
+
+/// An opening of a committed polynomial at a single point.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct KzgOpening {
+    /// The evaluation point z.
+    pub point: Fr,
+    /// The claimed value p(z).
+    pub value: Fr,
+    /// The witness [q(τ)]G1.
+    pub proof: G1Affine,
+}
+
+/// An opening of a committed polynomial at several points with one witness.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct KzgBatchOpening {
+    /// The evaluation points z_1, ..., z_k.
+    pub points: Vec<Fr>,
+    /// The claimed values p(z_1), ..., p(z_k).
+    pub values: Vec<Fr>,
+    /// The witness [q(τ)]G1.
+    pub proof: G1Affine,
+}
+
+/// A KZG structured reference string.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct KzgParams {
+    powers_g1: Vec<G1Affine>,
+    powers_g2: Vec<G2Affine>,
+}
+
+impl KzgParams {
+    /// Creates a reference string from a known secret τ.
+    ///
+    /// Supports polynomials of degree ≤ `max_degree` and batched openings at up
+    /// to `max_points` points. [τ]G2 is always included, since single-point
+    /// verification needs it, so `max_points` is raised to at least 1. Not
+    /// secure: see the module documentation.
+    pub fn setup_insecure(max_degree: usize, max_points: usize, tau: &Fr) -> Self {
+        let max_points = max_points.max(1);
+        let g1 = G1Projective::generator();
+        let g2 = G2Projective::generator();
+
+        let mut powers_g1 = Vec::with_capacity(max_degree + 1);
+        let mut powers_g2 = Vec::with_capacity(max_points + 1);
+        let mut t = Fr::one();
+        for i in 0..=max_degree.max(max_points) {
+            let scalar = t.to_u1024();
+            if i <= max_degree {
+                powers_g1.push(g1.mul_scalar(&scalar).to_affine());
+            }
+            if i <= max_points {
+                powers_g2.push(g2.mul_scalar(&scalar).to_affine());
+            }
+            t = t * *tau;
+        }
+
+        Self {
+            powers_g1,
+            powers_g2,
+        }
+    }
+
+    /// Returns the maximum supported polynomial degree.
+    pub fn max_degree(&self) -> usize {
+        self.powers_g1.len() - 1
+    }
+
+    /// Returns the maximum number of points in a batched opening.
+    pub fn max_points(&self) -> usize {
+        self.powers_g2.len() - 1
+    }
+
+    /// Returns [p(τ)]G1 for a polynomial within the degree bound.
+    fn eval_g1(&self, poly: &Polynomial<Bn254FrConfig>) -> Result<G1Projective, KzgError> {
+        if poly.coeffs.len() > self.powers_g1.len() {
+            return Err(KzgError::DegreeTooLarge {
+                degree: poly.coeffs.len() - 1,
+                max_degree: self.max_degree(),
+            });
+        }
+        let scalars: Vec<_> = poly.coeffs.iter().map(|c| c.to_u1024()).collect();
+        Ok(msm(&self.powers_g1[..scalars.len()], &scalars))
+    }
+
+    /// Commits to a polynomial.
+    pub fn commit(&self, poly: &Polynomial<Bn254FrConfig>) -> Result<KzgCommitment, KzgError> {
+        Ok(KzgCommitment(self.eval_g1(poly)?.to_affine()))
+    }
+
+    /// Opens a polynomial at `point`.
+    pub fn open(
+        &self,
+        poly: &Polynomial<Bn254FrConfig>,
+        point: &Fr,
+    ) -> Result<KzgOpening, KzgError> {
+        let value = poly.mod_linear(point);
+        let divisor = Polynomial::new(vec![-*point, Fr::one()]);
+        let (quotient, _) = poly.divide_with_remainder(&divisor);
+
+        Ok(KzgOpening {
+            point: *point,
+            value,
+            proof: self.eval_g1(&quotient)?.to_affine(),
+        })
+    }
+
+    /// Verifies a single-point opening against a commitment.
+    pub fn verify(&self, commitment: &KzgCommitment, opening: &KzgOpening) -> Result<(), KzgError> {
+        let g1 = G1Projective::generator();
+        let g2 = G2Projective::generator();
+
+        let lhs = commitment.0.to_projective() - g1.mul_scalar(&opening.value.to_u1024());
+        let rhs = self.powers_g2[1].to_projective() - g2.mul_scalar(&opening.point.to_u1024());
+
+        // e(C - [v]G1, -G2) · e(π, [τ - z]G2) = 1
+        let product = multi_pairing(&[
+            (lhs.to_affine(), -G2Affine::generator()),
+            (opening.proof, rhs.to_affine()),
+        ]);
+        if product.is_one() {
+            Ok(())
+        } else {
+            Err(KzgError::VerificationFailed)
+        }
+    }
+
+    fn check_points(&self, points: &[Fr]) -> Result<(), KzgError> {
+        if points.is_empty() {
+            return Err(KzgError::EmptyBatch);
+        }
+        if points.len() > self.max_points() {
+            return Err(KzgError::TooManyPoints {
+                count: points.len(),
+                max_points: self.max_points(),
+            });
+        }
+        for (i, a) in points.iter().enumerate() {
+            if points[i + 1..].contains(a) {
+                return Err(KzgError::DuplicatePoints);
+            }
+        }
+        Ok(())
+    }
+
+    /// Opens a polynomial at several distinct points with a single witness.
+    pub fn open_batch(
+        &self,
+        poly: &Polynomial<Bn254FrConfig>,
+        points: &[Fr],
+    ) -> Result<KzgBatchOpening, KzgError> {
+        self.check_points(points)?;
+
+        let values: Vec<Fr> = points.iter().map(|z| poly.mod_linear(z)).collect();
+        let interpolant = Polynomial::interpolate(points, &values);
+        let zerofier = Polynomial::zerofier(points);
+        let (quotient, _) = (poly.clone() - interpolant).divide_with_remainder(&zerofier);
+
+        Ok(KzgBatchOpening {
+            points: points.to_vec(),
+            values,
+            proof: self.eval_g1(&quotient)?.to_affine(),
+        })
+    }
+
+    /// Verifies a batched opening against a commitment.
+    pub fn verify_batch(
+        &self,
+        commitment: &KzgCommitment,
+        opening: &KzgBatchOpening,
+    ) -> Result<(), KzgError> {
+        self.check_points(&opening.points)?;
+        if opening.values.len() != opening.points.len() {
+            return Err(KzgError::LengthMismatch);
+        }
+
+        let interpolant = Polynomial::interpolate(&opening.points, &opening.values);
+        let zerofier = Polynomial::zerofier(&opening.points);
+
+        let lhs = commitment.0.to_projective() - self.eval_g1(&interpolant)?;
+        let z_scalars: Vec<_> = zerofier.coeffs.iter().map(|c| c.to_u1024()).collect();
+        let rhs = msm(&self.powers_g2[..z_scalars.len()], &z_scalars);
+
+        // e(C - [I(τ)]G1, -G2) · e(π, [Z(τ)]G2) = 1
+        let product = multi_pairing(&[
+            (lhs.to_affine(), -G2Affine::generator()),
+            (opening.proof, rhs.to_affine()),
+        ]);
+        if product.is_one() {
+            Ok(())
+        } else {
+            Err(KzgError::VerificationFailed)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::U1024;
+
+    fn fr(v: u64) -> Fr {
+        Fr::new(U1024::from_u64(v))
+    }
+
+    #[test]
+    fn test_commitment_matches_tau_evaluation() {
+        let tau = fr(77);
+        let params = KzgParams::setup_insecure(3, 1, &tau);
+        let poly = Polynomial::new(vec![fr(5), fr(0), fr(2)]);
+        let expected = G1Projective::generator()
+            .mul_scalar(&poly.evaluate(&tau).to_u1024())
+            .to_affine();
+        assert_eq!(params.commit(&poly).unwrap().0, expected);
+    }
+
+    #[test]
+    fn test_degree_bound() {
+        let params = KzgParams::setup_insecure(2, 1, &fr(3));
+        let poly = Polynomial::new(vec![fr(1), fr(1), fr(1), fr(1)]);
+        assert_eq!(
+            params.commit(&poly),
+            Err(KzgError::DegreeTooLarge {
+                degree: 3,
+                max_degree: 2
+            })
+        );
+    }
+
+    #[test]
+    fn test_zero_max_points_still_verifies() {
+        let params = KzgParams::setup_insecure(2, 0, &fr(9));
+        assert_eq!(params.max_points(), 1);
+        let poly = Polynomial::new(vec![fr(4), fr(1), fr(6)]);
+        let commitment = params.commit(&poly).unwrap();
+        let opening = params.open(&poly, &fr(5)).unwrap();
+        assert!(params.verify(&commitment, &opening).is_ok());
+    }
+}
@@ -0,0 +1,468 @@
+//! Binary Merkle trees over SHA-256.
+//!
+//! Leaves are arbitrary byte strings or field elements. Hashing is domain
+//! separated so that a leaf can never be confused with an internal node:
+//!
+//! ```text
+//! leaf(data)    = SHA256(0x00 || data)
+//! node(l, r)    = SHA256(0x01 || l || r)
+//! ```
+//!
+//! Field elements are encoded as their canonical 128-byte big-endian value.
+//! When the number of leaves is not a power of two, the bottom layer is padded
+//! with the all-zero digest, which is not the hash of any leaf.
+//!
+//! Openings come in two forms:
+//! - [`MerkleProof`] authenticates a single leaf with one sibling per level
+//! - [`MerkleMultiProof`] authenticates several leaves at once, omitting every
+//!   node the verifier can recompute from the opened leaves themselves
+//!
+//! # Examples
+//!
+//! ```
+//! use lumen_math::commit::MerkleTree;
+//! use lumen_math::fp;
+//!
+//! let values = vec![fp!(1u64), fp!(2u64), fp!(3u64), fp!(4u64)];
+//! let tree = MerkleTree::from_field_elements(&values);
+//! let root = tree.root();
+//!
+//! let proof = tree.open(2);
+//! assert!(proof.verify_field_element(&root, &values[2]).is_ok());
+//! assert!(proof.verify_field_element(&root, &values[3]).is_err());
+//! ```
+
+use sha2::{Digest as Sha2Digest, Sha256};
+
+use crate::{FieldConfig, FieldElement};
+
+/// A SHA-256 digest.
+pub type MerkleHash = [u8; 32];
+
+/// Domain tag for leaf hashes.
+const LEAF_TAG: u8 = 0x00;
+/// Domain tag for internal node hashes.
+const NODE_TAG: u8 = 0x01;
+/// Digest used to pad the leaf layer up to a power of two.
+const PADDING: MerkleHash = [0u8; 32];
+
+/// Error type for Merkle proof verification.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MerkleError {
+    /// A leaf index does not fit in a tree of the proof's depth.
+    IndexOutOfRange { index: usize },
+    /// The number of leaves supplied does not match the number of opened indices.
+    LeafCountMismatch,
+    /// The proof has too few or too many authentication nodes, or unsorted indices.
+    MalformedProof,
+    /// The recomputed root does not match the commitment.
+    RootMismatch,
+}
+
+impl std::fmt::Display for MerkleError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MerkleError::IndexOutOfRange { index } => {
+                write!(f, "Leaf index {} is out of range", index)
+            }
+            MerkleError::LeafCountMismatch => {
+                write!(f, "Number of leaves does not match the opened indices")
+            }
+            MerkleError::MalformedProof => write!(f, "Malformed Merkle proof"),
+            MerkleError::RootMismatch => write!(f, "Merkle root mismatch"),
+        }
+    }
+}
+
+impl std::error::Error for MerkleError {}
+
+/// Hashes a leaf with the leaf domain tag.
+pub fn hash_leaf(data: &[u8]) -> MerkleHash {
+    let mut hasher = Sha256::new();
+    hasher.update([LEAF_TAG]);
+    hasher.update(data);
+    hasher.finalize().into()
+}
+
+/// Hashes two children with the internal node domain tag.
+pub fn hash_node(left: &MerkleHash, right: &MerkleHash) -> MerkleHash {
+    let mut hasher = Sha256::new();
+    hasher.update([NODE_TAG]);
+    hasher.update(left);
+    hasher.update(right);
+    hasher.finalize().into()
+}
+
+/// Encodes field elements as concatenated 128-byte big-endian canonical values.
+pub fn field_elements_to_bytes<C: FieldConfig>(values: &[FieldElement<C>]) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(128 * values.len());
+    for v in values {
+        bytes.extend_from_slice(&v.to_u1024().to_be_bytes());
+    }
+    bytes
+}
+
+/// A binary Merkle tree storing every layer of digests.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MerkleTree {
+    /// `layers[0]` holds the (padded) leaf hashes, the last layer holds the root.
+    layers: Vec<Vec<MerkleHash>>,
+    num_leaves: usize,
+}
+
+impl MerkleTree {
+    /// Builds a tree over byte-string leaves.
+    ///
+    /// # Panics
+    /// Panics if `leaves` is empty.
+    pub fn new<T: AsRef<[u8]>>(leaves: &[T]) -> Self {
+        let hashes: Vec<MerkleHash> = leaves.iter().map(|l| hash_leaf(l.as_ref())).collect();
+        Self::from_leaf_hashes(hashes)
+    }
+
+    /// Builds a tree with one leaf per field element.
+    ///
+    /// # Panics
+    /// Panics if `values` is empty.
+    pub fn from_field_elements<C: FieldConfig>(values: &[FieldElement<C>]) -> Self {
+        let hashes: Vec<MerkleHash> = values
+            .iter()
+            .map(|v| hash_leaf(&v.to_u1024().to_be_bytes()))
+            .collect();
+        Self::from_leaf_hashes(hashes)
+    }
+
+    /// Builds a tree whose leaves are consecutive chunks of `chunk_size` field elements.
+    ///
+    /// Useful for committing to cosets that are always opened together.
+    ///
+    /// # Panics
+    /// Panics if `values` is empty or its length is not a multiple of `chunk_size`.
+    pub fn from_field_element_chunks<C: FieldConfig>(
+        values: &[FieldElement<C>],
+        chunk_size: usize,
+    ) -> Self {
+        assert!(
+            chunk_size > 0 && values.len().is_multiple_of(chunk_size),
+            "Length must be a positive multiple of the chunk size"
+        );
+        let hashes: Vec<MerkleHash> = values
+            .chunks(chunk_size)
+            .map(|chunk| hash_leaf(&field_elements_to_bytes(chunk)))
+            .collect();
+        Self::from_leaf_hashes(hashes)
+    }
+
+    /// Builds a tree from already hashed leaves.
+    ///
+    /// # Panics
+    /// Panics if `hashes` is empty.
+    pub fn from_leaf_hashes(mut hashes: Vec<MerkleHash>) -> Self {
+        assert!(!hashes.is_empty(), "Merkle tree needs at least one leaf");
+        let num_leaves = hashes.len();
+        hashes.resize(num_leaves.next_power_of_two(), PADDING);
+
+        let mut layers = vec![hashes];
+        while layers.last().unwrap().len() > 1 {
+            let next = layers
+                .last()
+                .unwrap()
+                .chunks(2)
+                .map(|pair| hash_node(&pair[0], &pair[1]))
+                .collect();
+            layers.push(next);
+        }
+
+        Self { layers, num_leaves }
+    }
+
+    /// Returns the root commitment.
+    pub fn root(&self) -> MerkleHash {
+        self.layers.last().unwrap()[0]
+    }
+
+    /// Returns the number of leaves (before padding).
+    pub fn num_leaves(&self) -> usize {
+        self.num_leaves
+    }
+
+    /// Returns the number of levels between the leaves and the root.
+    pub fn depth(&self) -> usize {
+        self.layers.len() - 1
+    }
+
+    /// Returns the hash of leaf `index`.
+    ///
+    /// # Panics
+    /// Panics if `index >= num_leaves()`.
+    pub fn leaf_hash(&self, index: usize) -> MerkleHash {
+        assert!(index < self.num_leaves, "Leaf index out of range");
+        self.layers[0][index]
+    }
+
+    /// Opens a single leaf.
+    ///
+    /// # Panics
+    /// Panics if `index >= num_leaves()`.
+    pub fn open(&self, index: usize) -> MerkleProof {
+        assert!(index < self.num_leaves, "Leaf index out of range");
+
+        let siblings = self.layers[..self.depth()]
+            .iter()
+            .enumerate()
+            .map(|(level, layer)| layer[(index >> level) ^ 1])
+            .collect();
+
+        MerkleProof {
+            leaf_index: index,
+            siblings,
+        }
+    }
+
+    /// Opens several leaves with a single batched proof.
+    ///
+    /// Indices are sorted and deduplicated; leaves must be supplied to
+    /// [`MerkleMultiProof::verify`] in the order of [`MerkleMultiProof::indices`].
+    ///
+    /// # Panics
+    /// Panics if `indices` is empty or any index is `>= num_leaves()`.
+    pub fn open_multi(&self, indices: &[usize]) -> MerkleMultiProof {
+        assert!(!indices.is_empty(), "At least one index is required");
+        let mut known: Vec<usize> = indices.to_vec();
+        known.sort_unstable();
+        known.dedup();
+        assert!(
+            *known.last().unwrap() < self.num_leaves,
+            "Leaf index out of range"
+        );
+
+        let leaf_indices = known.clone();
+        let mut nodes = Vec::new();
+
+        for layer in &self.layers[..self.depth()] {
+            let mut i = 0;
+            while i < known.len() {
+                let idx = known[i];
+                if idx.is_multiple_of(2) && i + 1 < known.len() && known[i + 1] == idx + 1 {
+                    i += 2;
+                } else {
+                    nodes.push(layer[idx ^ 1]);
+                    i += 1;
+                }
+            }
+            known = known.iter().map(|&idx| idx >> 1).collect();
+            known.dedup();
+        }
+
+        MerkleMultiProof {
+            indices: leaf_indices,
+            depth: self.depth(),
+            nodes,
+        }
+    }
+}
+
+/// An authentication path for a single leaf.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MerkleProof {
+    /// Position of the opened leaf.
+    pub leaf_index: usize,
+    /// Sibling digests from the leaf layer up to (excluding) the root.
+    pub siblings: Vec<MerkleHash>,
+}
+
+impl MerkleProof {
+    /// Verifies that `leaf` is at `leaf_index` in the tree committed to by `root`.
+    pub fn verify(&self, root: &MerkleHash, leaf: &[u8]) -> Result<(), MerkleError> {
+        self.verify_hash(root, &hash_leaf(leaf))
+    }
+
+    /// Verifies a field element leaf.
+    pub fn verify_field_element<C: FieldConfig>(
+        &self,
+        root: &MerkleHash,
+        value: &FieldElement<C>,
+    ) -> Result<(), MerkleError> {
+        self.verify(root, &value.to_u1024().to_be_bytes())
+    }
+
+    /// Verifies an already hashed leaf.
+    pub fn verify_hash(
+        &self,
+        root: &MerkleHash,
+        leaf_hash: &MerkleHash,
+    ) -> Result<(), MerkleError> {
+        let depth = self.siblings.len();
+        if depth < usize::BITS as usize && self.leaf_index >> depth != 0 {
+            return Err(MerkleError::IndexOutOfRange {
+                index: self.leaf_index,
+            });
+        }
+
+        let mut current = *leaf_hash;
+        for (level, sibling) in self.siblings.iter().enumerate() {
+            current = if (self.leaf_index >> level) & 1 == 0 {
+                hash_node(&current, sibling)
+            } else {
+                hash_node(sibling, &current)
+            };
+        }
+
+        if &current == root {
+            Ok(())
+        } else {
+            Err(MerkleError::RootMismatch)
+        }
+    }
+}
+
+/// A batched opening of several leaves.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MerkleMultiProof {
+    /// Sorted, deduplicated positions of the opened leaves.
+    pub indices: Vec<usize>,
+    /// Number of levels in the tree.
+    pub depth: usize,
+    /// Authentication digests not derivable from the opened leaves,
+    /// ordered level by level from the leaves upwards.
+    pub nodes: Vec<MerkleHash>,
+}
+
+impl MerkleMultiProof {
+    /// Returns the opened leaf positions in the order leaves must be supplied.
+    pub fn indices(&self) -> &[usize] {
+        &self.indices
+    }
+
+    /// Verifies byte-string leaves, given in the order of [`Self::indices`].
+    pub fn verify<T: AsRef<[u8]>>(
+        &self,
+        root: &MerkleHash,
+        leaves: &[T],
+    ) -> Result<(), MerkleError> {
+        let hashes: Vec<MerkleHash> = leaves.iter().map(|l| hash_leaf(l.as_ref())).collect();
+        self.verify_hashes(root, &hashes)
+    }
+
+    /// Verifies field element leaves, given in the order of [`Self::indices`].
+    pub fn verify_field_elements<C: FieldConfig>(
+        &self,
+        root: &MerkleHash,
+        values: &[FieldElement<C>],
+    ) -> Result<(), MerkleError> {
+        let hashes: Vec<MerkleHash> = values
+            .iter()
+            .map(|v| hash_leaf(&v.to_u1024().to_be_bytes()))
+            .collect();
+        self.verify_hashes(root, &hashes)
+    }
+
+    /// Verifies already hashed leaves, given in the order of [`Self::indices`].
+    pub fn verify_hashes(
+        &self,
+        root: &MerkleHash,
+        leaf_hashes: &[MerkleHash],
+    ) -> Result<(), MerkleError> {
+        if leaf_hashes.len() != self.indices.len() {
+            return Err(MerkleError::LeafCountMismatch);
+        }
+        if self.indices.is_empty() || self.indices.windows(2).any(|w| w[0] >= w[1]) {
+            return Err(MerkleError::MalformedProof);
+        }
+        if self.depth < usize::BITS as usize
+            && let Some(&index) = self.indices.iter().find(|&&i| i >> self.depth != 0)
+        {
+            return Err(MerkleError::IndexOutOfRange { index });
+        }
+
+        let mut known: Vec<(usize, MerkleHash)> = self
+            .indices
+            .iter()
+            .copied()
+            .zip(leaf_hashes.iter().copied())
+            .collect();
+        let mut nodes = self.nodes.iter();
+
+        for _ in 0..self.depth {
+            let mut next = Vec::with_capacity(known.len());
+            let mut i = 0;
+            while i < known.len() {
+                let (idx, hash) = known[i];
+                let parent =
+                    if idx.is_multiple_of(2) && i + 1 < known.len() && known[i + 1].0 == idx + 1 {
+                        i += 2;
+                        hash_node(&hash, &known[i - 1].1)
+                    } else {
+                        i += 1;
+                        let sibling = nodes.next().ok_or(MerkleError::MalformedProof)?;
+                        if idx.is_multiple_of(2) {
+                            hash_node(&hash, sibling)
+                        } else {
+                            hash_node(sibling, &hash)
+                        }
+                    };
+                next.push((idx >> 1, parent));
+            }
+            known = next;
+        }
+
+        if nodes.next().is_some() || known.len() != 1 {
+            return Err(MerkleError::MalformedProof);
+        }
+        if &known[0].1 == root {
+            Ok(())
+        } else {
+            Err(MerkleError::RootMismatch)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn leaves(n: usize) -> Vec<Vec<u8>> {
+        (0..n).map(|i| vec![i as u8; 3]).collect()
+    }
+
+    #[test]
+    fn test_single_leaf_tree() {
+        let tree = MerkleTree::new(&[b"only"]);
+        assert_eq!(tree.depth(), 0);
+        assert_eq!(tree.root(), hash_leaf(b"only"));
+        assert!(tree.open(0).verify(&tree.root(), b"only").is_ok());
+    }
+
+    #[test]
+    fn test_root_of_two_leaves() {
+        let tree = MerkleTree::new(&[b"a", b"b"]);
+        assert_eq!(tree.root(), hash_node(&hash_leaf(b"a"), &hash_leaf(b"b")));
+    }
+
+    #[test]
+    fn test_leaf_and_node_domains_differ() {
+        let a = hash_leaf(b"a");
+        let b = hash_leaf(b"b");
+        let mut concat = a.to_vec();
+        concat.extend_from_slice(&b);
+        assert_ne!(hash_leaf(&concat), hash_node(&a, &b));
+    }
+
+    #[test]
+    fn test_all_single_openings() {
+        let data = leaves(5);
+        let tree = MerkleTree::new(&data);
+        for (i, leaf) in data.iter().enumerate() {
+            assert!(tree.open(i).verify(&tree.root(), leaf).is_ok());
+        }
+    }
+
+    #[test]
+    fn test_multi_proof_shares_nodes() {
+        let data = leaves(8);
+        let tree = MerkleTree::new(&data);
+        let proof = tree.open_multi(&[0, 1]);
+        assert_eq!(proof.nodes.len(), 2);
+        assert!(proof.verify(&tree.root(), &data[..2]).is_ok());
+    }
+}
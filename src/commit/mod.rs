@@ -0,0 +1,11 @@
+//! Vector and polynomial commitment schemes.
+//!
+//! This module provides:
+//! - `kzg` - KZG polynomial commitments over BN254
+//! - `merkle` - SHA-256 Merkle trees with single and batched opening proofs
+
+pub mod kzg;
+pub mod merkle;
+
+pub use kzg::{KzgBatchOpening, KzgCommitment, KzgError, KzgOpening, KzgParams};
+pub use merkle::{MerkleError, MerkleHash, MerkleMultiProof, MerkleProof, MerkleTree};
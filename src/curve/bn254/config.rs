@@ -0,0 +1,182 @@
+//! Field configurations for the BN254 base and scalar fields.
+//!
+//! Both fields use the crate-wide Montgomery representation with R = 2^1024.
+
+use crate::{FieldConfig, U1024};
+
+/// The BN254 base field F_q, q = 0x30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd47.
+///
+/// q - 1 has two-adicity 1, so `ROOT_OF_UNITY` is -1 and the field is not
+/// suitable for NTT-based polynomial arithmetic.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct Bn254FqConfig;
+
+impl FieldConfig for Bn254FqConfig {
+    const MODULUS: U1024 = U1024([
+        4332616871279656263,
+        10917124144477883021,
+        13281191951274694749,
+        3486998266802970665,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+    ]);
+
+    const MODULUS_BITS: u32 = 254;
+
+    const R2: U1024 = U1024([
+        665897269111979929,
+        5968113403217760155,
+        722268890875614196,
+        3425006189733927403,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+    ]);
+
+    const N_PRIME: U1024 = U1024([
+        9786893198990664585,
+        11447725176084130505,
+        15613922527736486528,
+        17688488658267049067,
+        10632316063702658159,
+        8447153877574159123,
+        14028340714815136137,
+        5402467412825931408,
+        16685965446813412899,
+        17159811925230205540,
+        10359114067928109886,
+        8881410281086135865,
+        5217708493556910063,
+        2438898254527527543,
+        11386046922920200993,
+        9679322011994505845,
+    ]);
+
+    const ROOT_OF_UNITY: U1024 = U1024([
+        4332616871279656262,
+        10917124144477883021,
+        13281191951274694749,
+        3486998266802970665,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+    ]);
+
+    const TWO_ADICITY: u32 = 1;
+}
+
+/// The BN254 scalar field F_r, r = 0x30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000001.
+///
+/// r - 1 has two-adicity 28; `ROOT_OF_UNITY` is 5^((r - 1) / 2^28).
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct Bn254FrConfig;
+
+impl FieldConfig for Bn254FrConfig {
+    const MODULUS: U1024 = U1024([
+        4891460686036598785,
+        2896914383306846353,
+        13281191951274694749,
+        3486998266802970665,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+    ]);
+
+    const MODULUS_BITS: u32 = 254;
+
+    const R2: U1024 = U1024([
+        16504013634141702512,
+        5118860662480036577,
+        15053561323044524067,
+        2445142211928028433,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+    ]);
+
+    const N_PRIME: U1024 = U1024([
+        14042775128853446655,
+        7315682302672572851,
+        16400588293156512657,
+        8356480910413218226,
+        1004748558975297202,
+        13434759808136547316,
+        3744289118961185658,
+        7180124661618177864,
+        16688292969928257208,
+        14262722914260983121,
+        6030902178605647358,
+        6330773490725983487,
+        10816125107871937925,
+        15211216785933368130,
+        13653161237851859666,
+        10856674779910008378,
+    ]);
+
+    const ROOT_OF_UNITY: U1024 = U1024([
+        11229192882073836016,
+        4624371214017703636,
+        63235024940837564,
+        3043318377369730693,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+    ]);
+
+    const TWO_ADICITY: u32 = 28;
+}
@@ -0,0 +1,85 @@
+//! The BN254 (alt_bn128) pairing-friendly curve.
+//!
+//! - G1: y^2 = x^3 + 3 over Fq, generator (1, 2)
+//! - G2: y^2 = x^3 + 3/ξ over Fq2 (D-type sextic twist, ξ = 9 + u)
+//! - GT: the order-r subgroup of Fq12^*
+//!
+//! Both G1 and G2 have prime order r (the modulus of [`Fr`]). The pairing is
+//! the optimal Ate pairing, see [`pairing`](pairing::pairing).
+//!
+//! This implementation targets protocol prototyping and testing: point
+//! arithmetic and the pairing are not constant time.
+
+pub mod config;
+pub mod pairing;
+pub mod tower;
+
+pub use config::{Bn254FqConfig, Bn254FrConfig};
+pub use pairing::{final_exponentiation, miller_loop, multi_pairing, pairing};
+pub use tower::{Fq2, Fq6, Fq12};
+
+use crate::curve::short_weierstrass::{AffinePoint, ProjectivePoint, SwCurveConfig};
+use crate::{FieldElement, U1024};
+
+/// An element of the base field Fq.
+pub type Fq = FieldElement<Bn254FqConfig>;
+
+/// An element of the scalar field Fr.
+pub type Fr = FieldElement<Bn254FrConfig>;
+
+/// Curve parameters of G1.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct G1Config;
+
+impl SwCurveConfig for G1Config {
+    type BaseField = Fq;
+
+    fn coeff_b() -> Fq {
+        Fq::new(U1024::from_u64(3))
+    }
+
+    fn generator() -> G1Affine {
+        AffinePoint::new(Fq::new(U1024::from_u64(1)), Fq::new(U1024::from_u64(2)))
+    }
+}
+
+/// Curve parameters of G2 (the sextic twist).
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct G2Config;
+
+impl SwCurveConfig for G2Config {
+    type BaseField = Fq2;
+
+    fn coeff_b() -> Fq2 {
+        Fq2::new(
+            fq("2b149d40ceb8aaae81be18991be06ac3b5b4c5e559dbefa33267e6dc24a138e5"),
+            fq("009713b03af0fed4cd2cafadeed8fdf4a74fa084e52d1852e4a2bd0685c315d2"),
+        )
+    }
+
+    fn generator() -> G2Affine {
+        AffinePoint::new(
+            Fq2::new(
+                fq("1800deef121f1e76426a00665e5c4479674322d4f75edadd46debd5cd992f6ed"),
+                fq("198e9393920d483a7260bfb731fb5d25f1aa493335a9e71297e485b7aef312c2"),
+            ),
+            Fq2::new(
+                fq("12c85ea5db8c6deb4aab71808dcb408fe3d1e7690c43d37b4ce6cc0166fa7daa"),
+                fq("090689d0585ff075ec9e99ad690c3395bc4b313370b38ef355acdadcd122975b"),
+            ),
+        )
+    }
+}
+
+/// A G1 point in affine coordinates.
+pub type G1Affine = AffinePoint<G1Config>;
+/// A G1 point in Jacobian coordinates.
+pub type G1Projective = ProjectivePoint<G1Config>;
+/// A G2 point in affine coordinates.
+pub type G2Affine = AffinePoint<G2Config>;
+/// A G2 point in Jacobian coordinates.
+pub type G2Projective = ProjectivePoint<G2Config>;
+
+fn fq(hex: &str) -> Fq {
+    Fq::new(U1024::from_hex(hex))
+}
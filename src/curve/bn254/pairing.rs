@@ -0,0 +1,184 @@
+//! The optimal Ate pairing on BN254.
+//!
+//! For P ∈ G1 and Q ∈ G2 the pairing is
+//!
+//!   e(P, Q) = f_{6u+2, Q}(P) · l_{[6u+2]Q, π(Q)}(P) · l_{[6u+2]Q + π(Q), -π²(Q)}(P)
+//!
+//! raised to (q^12 - 1) / r, where u = 0x44e992b44a6909f1 is the BN parameter
+//! and π is the q-power Frobenius endomorphism on the twist.
+//!
+//! The Miller loop keeps the running G2 point in homogeneous projective
+//! coordinates so that no inversions are needed. The final exponentiation
+//! splits into the easy part (q^6 - 1)(q^2 + 1), computed with conjugation and
+//! Frobenius maps, and the hard part (q^4 - q^2 + 1) / r, computed by plain
+//! square-and-multiply.
+//!
+//! # Examples
+//!
+//! ```
+//! use lumen_math::curve::bn254::{G1Affine, G2Affine, pairing};
+//! use lumen_math::U1024;
+//!
+//! let p = G1Affine::generator();
+//! let q = G2Affine::generator();
+//! let a = U1024::from_u64(6);
+//!
+//! // e(aP, Q) = e(P, aQ)
+//! let lhs = pairing(&p.mul_scalar(&a).to_affine(), &q);
+//! let rhs = pairing(&p, &q.mul_scalar(&a).to_affine());
+//! assert_eq!(lhs, rhs);
+//! ```
+
+use super::tower::{Fq2, Fq6, Fq12, frobenius_coeff};
+use super::{Fq, G1Affine, G2Affine, G2Config, fq};
+use crate::U1024;
+use crate::curve::short_weierstrass::SwCurveConfig;
+
+/// 6u + 2, the optimal Ate loop count.
+const ATE_LOOP_COUNT: u128 = 29793968203157093288;
+
+/// (q^4 - q^2 + 1) / r, the hard part of the final exponent.
+const FINAL_EXPONENT_HARD: &str = "1baaa710b0759ad331ec15183177faf6c0eb522d5b122784e529a5861876f6b3\
+     b1b1355d189227d79581e16f3fd90c66b887d56d5095f23aaa441e3954bcf8adcc7b44c87cdbacff1154e7e1da014fd\
+     5abf5cc4f49c36d4e81bb482ccdf42b1";
+
+/// 1/2 in Fq, i.e. (q + 1) / 2.
+fn two_inv() -> Fq {
+    fq("183227397098d014dc2822db40c0ac2ecbc0b548b438e5469e10460b6c3e7ea4")
+}
+
+/// Coefficients of a line function evaluated at P as c0·P.y + c1·P.x·w + c2·v·w.
+type LineCoeffs = (Fq2, Fq2, Fq2);
+
+/// A G2 point in homogeneous projective coordinates (X/Z, Y/Z).
+struct G2Homogeneous {
+    x: Fq2,
+    y: Fq2,
+    z: Fq2,
+}
+
+impl G2Homogeneous {
+    fn from_affine(q: &G2Affine) -> Self {
+        Self {
+            x: q.x,
+            y: q.y,
+            z: Fq2::one(),
+        }
+    }
+
+    /// Doubles the point and returns the tangent line.
+    fn double_step(&mut self, two_inv: &Fq) -> LineCoeffs {
+        let a = (self.x * self.y).mul_by_fq(two_inv);
+        let b = self.y.square();
+        let c = self.z.square();
+        let e = G2Config::coeff_b() * (c.double() + c);
+        let f = e.double() + e;
+        let g = (b + f).mul_by_fq(two_inv);
+        let h = (self.y + self.z).square() - (b + c);
+        let i = e - b;
+        let j = self.x.square();
+        let e_square = e.square();
+
+        self.x = a * (b - f);
+        self.y = g.square() - (e_square.double() + e_square);
+        self.z = b * h;
+
+        (-h, j.double() + j, i)
+    }
+
+    /// Adds an affine point and returns the chord line.
+    fn add_step(&mut self, q: &G2Affine) -> LineCoeffs {
+        let theta = self.y - q.y * self.z;
+        let lambda = self.x - q.x * self.z;
+        let c = theta.square();
+        let d = lambda.square();
+        let e = lambda * d;
+        let f = self.z * c;
+        let g = self.x * d;
+        let h = e + f - g.double();
+
+        self.x = lambda * h;
+        self.y = theta * (g - h) - e * self.y;
+        self.z = self.z * e;
+
+        let j = theta * q.x - lambda * q.y;
+        (lambda, -theta, j)
+    }
+}
+
+/// Multiplies f by the sparse line value at P.
+fn ell(f: &Fq12, coeffs: &LineCoeffs, p: &G1Affine) -> Fq12 {
+    let (c0, c1, c2) = coeffs;
+    let line = Fq12::new(
+        Fq6::new(c0.mul_by_fq(&p.y), Fq2::zero(), Fq2::zero()),
+        Fq6::new(c1.mul_by_fq(&p.x), *c2, Fq2::zero()),
+    );
+    *f * line
+}
+
+/// Applies the q-power Frobenius endomorphism of the twist.
+fn mul_by_char(q: &G2Affine) -> G2Affine {
+    G2Affine::new(
+        q.x.frobenius_map(1) * frobenius_coeff(2),
+        q.y.frobenius_map(1) * frobenius_coeff(3),
+    )
+}
+
+/// Computes the product of the Miller loops for all pairs, before final exponentiation.
+///
+/// Pairs with a point at infinity contribute 1.
+pub fn miller_loop(pairs: &[(G1Affine, G2Affine)]) -> Fq12 {
+    let pairs: Vec<&(G1Affine, G2Affine)> = pairs
+        .iter()
+        .filter(|(p, q)| !p.is_identity() && !q.is_identity())
+        .collect();
+    let two_inv = two_inv();
+
+    let mut f = Fq12::one();
+    let mut rs: Vec<G2Homogeneous> = pairs
+        .iter()
+        .map(|(_, q)| G2Homogeneous::from_affine(q))
+        .collect();
+
+    let top_bit = 127 - ATE_LOOP_COUNT.leading_zeros();
+    for i in (0..top_bit).rev() {
+        f = f.square();
+        for ((p, _), r) in pairs.iter().zip(rs.iter_mut()) {
+            f = ell(&f, &r.double_step(&two_inv), p);
+        }
+        if (ATE_LOOP_COUNT >> i) & 1 == 1 {
+            for ((p, q), r) in pairs.iter().zip(rs.iter_mut()) {
+                f = ell(&f, &r.add_step(q), p);
+            }
+        }
+    }
+
+    for ((p, q), r) in pairs.iter().zip(rs.iter_mut()) {
+        let q1 = mul_by_char(q);
+        let q2 = -mul_by_char(&q1);
+        f = ell(&f, &r.add_step(&q1), p);
+        f = ell(&f, &r.add_step(&q2), p);
+    }
+
+    f
+}
+
+/// Raises a Miller loop output to (q^12 - 1) / r.
+pub fn final_exponentiation(f: &Fq12) -> Fq12 {
+    // Easy part: f^((q^6 - 1)(q^2 + 1)).
+    let f1 = f.conjugate() * f.inv();
+    let f2 = f1.frobenius_map(2) * f1;
+
+    // Hard part: f^((q^4 - q^2 + 1) / r).
+    f2.pow(&U1024::from_hex(FINAL_EXPONENT_HARD))
+}
+
+/// Computes the optimal Ate pairing e(P, Q).
+pub fn pairing(p: &G1Affine, q: &G2Affine) -> Fq12 {
+    final_exponentiation(&miller_loop(&[(*p, *q)]))
+}
+
+/// Computes Π e(P_i, Q_i) with a single final exponentiation.
+pub fn multi_pairing(pairs: &[(G1Affine, G2Affine)]) -> Fq12 {
+    final_exponentiation(&miller_loop(pairs))
+}
@@ -0,0 +1,418 @@
+//! The BN254 extension field tower.
+//!
+//! ```text
+//! Fq2  = Fq[u]  / (u^2 + 1)
+//! Fq6  = Fq2[v] / (v^3 - ξ),  ξ = 9 + u
+//! Fq12 = Fq6[w] / (w^2 - v)
+//! ```
+//!
+//! Multiplications use Karatsuba at every level. Frobenius maps on Fq12 use
+//! the constants γ_i = ξ^(i(q - 1)/6), derived from (w^i)^q = γ_i · w^i.
+
+use std::ops::{Add, Mul, Neg, Sub};
+
+use super::{Fq, fq};
+use crate::U1024;
+use crate::curve::short_weierstrass::CurveField;
+
+// ============================================================================
+// Fq2
+// ============================================================================
+
+/// An element c0 + c1·u of Fq2.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Fq2 {
+    pub c0: Fq,
+    pub c1: Fq,
+}
+
+impl Fq2 {
+    /// Creates c0 + c1·u.
+    pub const fn new(c0: Fq, c1: Fq) -> Self {
+        Self { c0, c1 }
+    }
+
+    /// Returns zero.
+    pub const fn zero() -> Self {
+        Self::new(Fq::zero(), Fq::zero())
+    }
+
+    /// Returns one.
+    pub const fn one() -> Self {
+        Self::new(Fq::one(), Fq::zero())
+    }
+
+    /// Returns true if both coefficients are zero.
+    pub fn is_zero(&self) -> bool {
+        self.c0.is_zero() && self.c1.is_zero()
+    }
+
+    /// Returns c0 - c1·u, which equals self^q.
+    pub fn conjugate(&self) -> Self {
+        Self::new(self.c0, -self.c1)
+    }
+
+    /// Returns self + self.
+    pub fn double(&self) -> Self {
+        Self::new(self.c0.double(), self.c1.double())
+    }
+
+    /// Returns self^2 using (c0 + c1)(c0 - c1) for the real part.
+    pub fn square(&self) -> Self {
+        let ab = self.c0 * self.c1;
+        Self::new((self.c0 + self.c1) * (self.c0 - self.c1), ab.double())
+    }
+
+    /// Multiplies both coefficients by an Fq scalar.
+    pub fn mul_by_fq(&self, s: &Fq) -> Self {
+        Self::new(self.c0 * *s, self.c1 * *s)
+    }
+
+    /// Multiplies by the non-residue ξ = 9 + u.
+    pub fn mul_by_nonresidue(&self) -> Self {
+        let nine = |x: Fq| x.double().double().double() + x;
+        Self::new(nine(self.c0) - self.c1, self.c0 + nine(self.c1))
+    }
+
+    /// Returns the inverse; zero maps to zero.
+    pub fn inv(&self) -> Self {
+        let t = (self.c0.square() + self.c1.square()).inv();
+        Self::new(self.c0 * t, -(self.c1 * t))
+    }
+
+    /// Raises self to the power q^power.
+    pub fn frobenius_map(&self, power: usize) -> Self {
+        if power % 2 == 1 {
+            self.conjugate()
+        } else {
+            *self
+        }
+    }
+
+    /// Computes self^exp by square-and-multiply.
+    pub fn pow(&self, exp: &U1024) -> Self {
+        let mut result = Self::one();
+        for i in (0..exp.bits()).rev() {
+            result = result.square();
+            if exp.bit(i) {
+                result = result * *self;
+            }
+        }
+        result
+    }
+}
+
+impl Add for Fq2 {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.c0 + rhs.c0, self.c1 + rhs.c1)
+    }
+}
+
+impl Sub for Fq2 {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(self.c0 - rhs.c0, self.c1 - rhs.c1)
+    }
+}
+
+impl Mul for Fq2 {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        let v0 = self.c0 * rhs.c0;
+        let v1 = self.c1 * rhs.c1;
+        let c1 = (self.c0 + self.c1) * (rhs.c0 + rhs.c1) - v0 - v1;
+        Self::new(v0 - v1, c1)
+    }
+}
+
+impl Neg for Fq2 {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self::new(-self.c0, -self.c1)
+    }
+}
+
+impl CurveField for Fq2 {
+    fn zero() -> Self {
+        Fq2::zero()
+    }
+
+    fn one() -> Self {
+        Fq2::one()
+    }
+
+    fn is_zero(&self) -> bool {
+        Fq2::is_zero(self)
+    }
+
+    fn square(&self) -> Self {
+        Fq2::square(self)
+    }
+
+    fn double(&self) -> Self {
+        Fq2::double(self)
+    }
+
+    fn inv(&self) -> Self {
+        Fq2::inv(self)
+    }
+}
+
+// ============================================================================
+// Fq6
+// ============================================================================
+
+/// An element c0 + c1·v + c2·v^2 of Fq6.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Fq6 {
+    pub c0: Fq2,
+    pub c1: Fq2,
+    pub c2: Fq2,
+}
+
+impl Fq6 {
+    /// Creates c0 + c1·v + c2·v^2.
+    pub const fn new(c0: Fq2, c1: Fq2, c2: Fq2) -> Self {
+        Self { c0, c1, c2 }
+    }
+
+    /// Returns zero.
+    pub const fn zero() -> Self {
+        Self::new(Fq2::zero(), Fq2::zero(), Fq2::zero())
+    }
+
+    /// Returns one.
+    pub const fn one() -> Self {
+        Self::new(Fq2::one(), Fq2::zero(), Fq2::zero())
+    }
+
+    /// Returns true if all coefficients are zero.
+    pub fn is_zero(&self) -> bool {
+        self.c0.is_zero() && self.c1.is_zero() && self.c2.is_zero()
+    }
+
+    /// Returns self^2.
+    pub fn square(&self) -> Self {
+        *self * *self
+    }
+
+    /// Multiplies by v, using v^3 = ξ.
+    pub fn mul_by_nonresidue(&self) -> Self {
+        Self::new(self.c2.mul_by_nonresidue(), self.c0, self.c1)
+    }
+
+    /// Returns the inverse; zero maps to zero.
+    pub fn inv(&self) -> Self {
+        let t0 = self.c0.square() - (self.c1 * self.c2).mul_by_nonresidue();
+        let t1 = self.c2.square().mul_by_nonresidue() - self.c0 * self.c1;
+        let t2 = self.c1.square() - self.c0 * self.c2;
+        let norm = self.c0 * t0 + (self.c2 * t1 + self.c1 * t2).mul_by_nonresidue();
+        let norm_inv = norm.inv();
+        Self::new(t0 * norm_inv, t1 * norm_inv, t2 * norm_inv)
+    }
+}
+
+impl Add for Fq6 {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.c0 + rhs.c0, self.c1 + rhs.c1, self.c2 + rhs.c2)
+    }
+}
+
+impl Sub for Fq6 {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(self.c0 - rhs.c0, self.c1 - rhs.c1, self.c2 - rhs.c2)
+    }
+}
+
+impl Mul for Fq6 {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        let v0 = self.c0 * rhs.c0;
+        let v1 = self.c1 * rhs.c1;
+        let v2 = self.c2 * rhs.c2;
+
+        let c0 = v0 + ((self.c1 + self.c2) * (rhs.c1 + rhs.c2) - v1 - v2).mul_by_nonresidue();
+        let c1 = (self.c0 + self.c1) * (rhs.c0 + rhs.c1) - v0 - v1 + v2.mul_by_nonresidue();
+        let c2 = (self.c0 + self.c2) * (rhs.c0 + rhs.c2) - v0 - v2 + v1;
+        Self::new(c0, c1, c2)
+    }
+}
+
+impl Neg for Fq6 {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self::new(-self.c0, -self.c1, -self.c2)
+    }
+}
+
+// ============================================================================
+// Fq12
+// ============================================================================
+
+/// An element c0 + c1·w of Fq12; the pairing target group lives here.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Fq12 {
+    pub c0: Fq6,
+    pub c1: Fq6,
+}
+
+/// γ_i = ξ^(i(q - 1)/6) for i = 1..=5, as (c0, c1) hex pairs.
+const FROBENIUS_COEFFS: [(&str, &str); 5] = [
+    (
+        "1284b71c2865a7dfe8b99fdd76e68b605c521e08292f2176d60b35dadcc9e470",
+        "246996f3b4fae7e6a6327cfe12150b8e747992778eeec7e5ca5cf05f80f362ac",
+    ),
+    (
+        "2fb347984f7911f74c0bec3cf559b143b78cc310c2c3330c99e39557176f553d",
+        "16c9e55061ebae204ba4cc8bd75a079432ae2a1d0b7c9dce1665d51c640fcba2",
+    ),
+    (
+        "063cf305489af5dcdc5ec698b6e2f9b9dbaae0eda9c95998dc54014671a0135a",
+        "07c03cbcac41049a0704b5a7ec796f2b21807dc98fa25bd282d37f632623b0e3",
+    ),
+    (
+        "05b54f5e64eea80180f3c0b75a181e84d33365f7be94ec72848a1f55921ea762",
+        "2c145edbe7fd8aee9f3a80b03b0b1c923685d2ea1bdec763c13b4711cd2b8126",
+    ),
+    (
+        "0183c1e74f798649e93a3661a4353ff4425c459b55aa1bd32ea2c810eab7692f",
+        "12acf2ca76fd0675a27fb246c7729f7db080cb99678e2ac024c6b8ee6e0c2c4b",
+    ),
+];
+
+/// Returns γ_i = ξ^(i(q - 1)/6) for 1 ≤ i ≤ 5.
+pub(crate) fn frobenius_coeff(i: usize) -> Fq2 {
+    let (c0, c1) = FROBENIUS_COEFFS[i - 1];
+    Fq2::new(fq(c0), fq(c1))
+}
+
+impl Fq12 {
+    /// Creates c0 + c1·w.
+    pub const fn new(c0: Fq6, c1: Fq6) -> Self {
+        Self { c0, c1 }
+    }
+
+    /// Returns zero.
+    pub const fn zero() -> Self {
+        Self::new(Fq6::zero(), Fq6::zero())
+    }
+
+    /// Returns one.
+    pub const fn one() -> Self {
+        Self::new(Fq6::one(), Fq6::zero())
+    }
+
+    /// Returns true if this is the multiplicative identity.
+    pub fn is_one(&self) -> bool {
+        *self == Self::one()
+    }
+
+    /// Returns self^2.
+    pub fn square(&self) -> Self {
+        *self * *self
+    }
+
+    /// Returns c0 - c1·w, which equals self^(q^6).
+    ///
+    /// For elements of norm one (such as pairing outputs) this is the inverse.
+    pub fn conjugate(&self) -> Self {
+        Self::new(self.c0, -self.c1)
+    }
+
+    /// Returns the inverse; zero maps to zero.
+    pub fn inv(&self) -> Self {
+        let t = (self.c0.square() - self.c1.square().mul_by_nonresidue()).inv();
+        Self::new(self.c0 * t, -(self.c1 * t))
+    }
+
+    /// Raises self to the power q^power.
+    pub fn frobenius_map(&self, power: usize) -> Self {
+        let mut result = *self;
+        for _ in 0..power % 12 {
+            result = result.frobenius();
+        }
+        result
+    }
+
+    /// Computes self^q coefficient-wise in the basis 1, w, ..., w^5.
+    fn frobenius(&self) -> Self {
+        // w-basis coefficients: g0 = c0.c0, g1 = c1.c0, g2 = c0.c1, g3 = c1.c1, g4 = c0.c2, g5 = c1.c2.
+        let g = |x: &Fq2, i: usize| x.conjugate() * frobenius_coeff(i);
+        Self::new(
+            Fq6::new(self.c0.c0.conjugate(), g(&self.c0.c1, 2), g(&self.c0.c2, 4)),
+            Fq6::new(g(&self.c1.c0, 1), g(&self.c1.c1, 3), g(&self.c1.c2, 5)),
+        )
+    }
+
+    /// Computes self^exp by square-and-multiply.
+    pub fn pow(&self, exp: &U1024) -> Self {
+        let mut result = Self::one();
+        for i in (0..exp.bits()).rev() {
+            result = result.square();
+            if exp.bit(i) {
+                result = result * *self;
+            }
+        }
+        result
+    }
+}
+
+impl Mul for Fq12 {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        let v0 = self.c0 * rhs.c0;
+        let v1 = self.c1 * rhs.c1;
+        let c1 = (self.c0 + self.c1) * (rhs.c0 + rhs.c1) - v0 - v1;
+        Self::new(v0 + v1.mul_by_nonresidue(), c1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_fq2(seed: u64) -> Fq2 {
+        Fq2::new(
+            Fq::new(U1024::from_u64(seed * 31 + 7)),
+            Fq::new(U1024::from_u64(seed * 17 + 3)),
+        )
+    }
+
+    fn sample_fq12(seed: u64) -> Fq12 {
+        let f6 = |s| Fq6::new(sample_fq2(s), sample_fq2(s + 1), sample_fq2(s + 2));
+        Fq12::new(f6(seed), f6(seed + 3))
+    }
+
+    #[test]
+    fn test_fq2_inverse() {
+        let a = sample_fq2(5);
+        assert_eq!(a * a.inv(), Fq2::one());
+    }
+
+    #[test]
+    fn test_fq12_inverse() {
+        let a = sample_fq12(2);
+        assert!((a * a.inv()).is_one());
+    }
+
+    #[test]
+    fn test_frobenius_coefficients() {
+        // γ_1 = ξ^((q - 1)/6)
+        let xi = Fq2::new(Fq::new(U1024::from_u64(9)), Fq::one());
+        let q_minus_one = <super::super::Bn254FqConfig as crate::FieldConfig>::MODULUS - U1024::ONE;
+        let (exp, _) = q_minus_one.div_rem(&U1024::from_u64(6));
+        assert_eq!(xi.pow(&exp), frobenius_coeff(1));
+    }
+
+    #[test]
+    fn test_frobenius_matches_pow() {
+        let a = sample_fq12(9);
+        let q = <super::super::Bn254FqConfig as crate::FieldConfig>::MODULUS;
+        assert_eq!(a.frobenius_map(1), a.pow(&q));
+        assert_eq!(a.frobenius_map(6), a.conjugate());
+        assert_eq!(a.frobenius_map(12), a);
+    }
+}
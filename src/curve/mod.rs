@@ -0,0 +1,10 @@
+//! Elliptic curves and pairings.
+//!
+//! This module provides:
+//! - `short_weierstrass` - Affine and Jacobian point arithmetic for y^2 = x^3 + b
+//! - `bn254` - The BN254 pairing-friendly curve with the optimal Ate pairing
+
+pub mod bn254;
+pub mod short_weierstrass;
+
+pub use short_weierstrass::{AffinePoint, CurveField, ProjectivePoint, SwCurveConfig, msm};
@@ -0,0 +1,359 @@
+//! Short Weierstrass curves y^2 = x^3 + b.
+//!
+//! Points are stored either in affine form or in Jacobian coordinates
+//! (X, Y, Z) representing (X / Z^2, Y / Z^3), with Z = 0 for the point at
+//! infinity. The formulas are specialised to a = 0, which covers the
+//! pairing-friendly curves in this crate.
+//!
+//! Scalar multiplication is a plain double-and-add and is **not** constant
+//! time; it is intended for public data such as commitments and setup values.
+
+use std::fmt::Debug;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub};
+
+use crate::{FieldConfig, FieldElement, U1024};
+
+/// Arithmetic required of a curve's base field.
+pub trait CurveField:
+    'static
+    + Copy
+    + Clone
+    + Debug
+    + PartialEq
+    + Eq
+    + Send
+    + Sync
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Neg<Output = Self>
+{
+    /// The additive identity.
+    fn zero() -> Self;
+    /// The multiplicative identity.
+    fn one() -> Self;
+    /// Returns true if this is zero.
+    fn is_zero(&self) -> bool;
+    /// Returns self * self.
+    fn square(&self) -> Self;
+    /// Returns self + self.
+    fn double(&self) -> Self;
+    /// Returns the multiplicative inverse (zero maps to zero).
+    fn inv(&self) -> Self;
+}
+
+impl<C: FieldConfig> CurveField for FieldElement<C> {
+    fn zero() -> Self {
+        FieldElement::zero()
+    }
+
+    fn one() -> Self {
+        FieldElement::one()
+    }
+
+    fn is_zero(&self) -> bool {
+        FieldElement::is_zero(self)
+    }
+
+    fn square(&self) -> Self {
+        FieldElement::square(self)
+    }
+
+    fn double(&self) -> Self {
+        FieldElement::double(self)
+    }
+
+    fn inv(&self) -> Self {
+        FieldElement::inv(self)
+    }
+}
+
+/// Type-level parameters of a curve y^2 = x^3 + b.
+pub trait SwCurveConfig:
+    'static + Copy + Clone + Debug + Default + PartialEq + Eq + Send + Sync
+{
+    /// The field the coordinates live in.
+    type BaseField: CurveField;
+
+    /// The coefficient b.
+    fn coeff_b() -> Self::BaseField;
+
+    /// A fixed generator of the prime-order subgroup.
+    fn generator() -> AffinePoint<Self>;
+}
+
+// ============================================================================
+// Affine points
+// ============================================================================
+
+/// A point in affine coordinates.
+#[derive(Clone, Copy, Debug)]
+pub struct AffinePoint<P: SwCurveConfig> {
+    pub x: P::BaseField,
+    pub y: P::BaseField,
+    /// True for the point at infinity (x and y are then ignored).
+    pub infinity: bool,
+}
+
+impl<P: SwCurveConfig> AffinePoint<P> {
+    /// Creates a point from coordinates without checking that it is on the curve.
+    pub fn new(x: P::BaseField, y: P::BaseField) -> Self {
+        Self {
+            x,
+            y,
+            infinity: false,
+        }
+    }
+
+    /// Returns the point at infinity.
+    pub fn identity() -> Self {
+        Self {
+            x: P::BaseField::zero(),
+            y: P::BaseField::one(),
+            infinity: true,
+        }
+    }
+
+    /// Returns the configured generator.
+    pub fn generator() -> Self {
+        P::generator()
+    }
+
+    /// Returns true for the point at infinity.
+    pub fn is_identity(&self) -> bool {
+        self.infinity
+    }
+
+    /// Checks the curve equation y^2 = x^3 + b.
+    pub fn is_on_curve(&self) -> bool {
+        self.infinity || self.y.square() == self.x.square() * self.x + P::coeff_b()
+    }
+
+    /// Converts to Jacobian coordinates.
+    pub fn to_projective(&self) -> ProjectivePoint<P> {
+        if self.infinity {
+            ProjectivePoint::identity()
+        } else {
+            ProjectivePoint {
+                x: self.x,
+                y: self.y,
+                z: P::BaseField::one(),
+            }
+        }
+    }
+
+    /// Computes scalar * self.
+    pub fn mul_scalar(&self, scalar: &U1024) -> ProjectivePoint<P> {
+        self.to_projective().mul_scalar(scalar)
+    }
+}
+
+impl<P: SwCurveConfig> PartialEq for AffinePoint<P> {
+    fn eq(&self, other: &Self) -> bool {
+        match (self.infinity, other.infinity) {
+            (true, true) => true,
+            (false, false) => self.x == other.x && self.y == other.y,
+            _ => false,
+        }
+    }
+}
+
+impl<P: SwCurveConfig> Eq for AffinePoint<P> {}
+
+impl<P: SwCurveConfig> Neg for AffinePoint<P> {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self {
+            x: self.x,
+            y: -self.y,
+            infinity: self.infinity,
+        }
+    }
+}
+
+// ============================================================================
+// Jacobian points
+// ============================================================================
+
+/// A point in Jacobian coordinates.
+#[derive(Clone, Copy, Debug)]
+pub struct ProjectivePoint<P: SwCurveConfig> {
+    pub x: P::BaseField,
+    pub y: P::BaseField,
+    pub z: P::BaseField,
+}
+
+impl<P: SwCurveConfig> ProjectivePoint<P> {
+    /// Returns the point at infinity.
+    pub fn identity() -> Self {
+        Self {
+            x: P::BaseField::one(),
+            y: P::BaseField::one(),
+            z: P::BaseField::zero(),
+        }
+    }
+
+    /// Returns the configured generator.
+    pub fn generator() -> Self {
+        P::generator().to_projective()
+    }
+
+    /// Returns true for the point at infinity.
+    pub fn is_identity(&self) -> bool {
+        self.z.is_zero()
+    }
+
+    /// Converts to affine coordinates (one field inversion).
+    pub fn to_affine(&self) -> AffinePoint<P> {
+        if self.is_identity() {
+            return AffinePoint::identity();
+        }
+        let z_inv = self.z.inv();
+        let z_inv2 = z_inv.square();
+        AffinePoint::new(self.x * z_inv2, self.y * z_inv2 * z_inv)
+    }
+
+    /// Returns 2 * self (dbl-2009-l, a = 0).
+    pub fn double(&self) -> Self {
+        if self.is_identity() {
+            return *self;
+        }
+
+        let a = self.x.square();
+        let b = self.y.square();
+        let c = b.square();
+        let d = ((self.x + b).square() - a - c).double();
+        let e = a.double() + a;
+        let f = e.square();
+
+        let x3 = f - d.double();
+        let y3 = e * (d - x3) - c.double().double().double();
+        let z3 = (self.y * self.z).double();
+        Self {
+            x: x3,
+            y: y3,
+            z: z3,
+        }
+    }
+
+    /// Computes scalar * self with MSB-first double-and-add.
+    pub fn mul_scalar(&self, scalar: &U1024) -> Self {
+        let mut result = Self::identity();
+        for i in (0..scalar.bits()).rev() {
+            result = result.double();
+            if scalar.bit(i) {
+                result += *self;
+            }
+        }
+        result
+    }
+}
+
+impl<P: SwCurveConfig> PartialEq for ProjectivePoint<P> {
+    fn eq(&self, other: &Self) -> bool {
+        match (self.is_identity(), other.is_identity()) {
+            (true, true) => true,
+            (false, false) => {
+                let z1z1 = self.z.square();
+                let z2z2 = other.z.square();
+                self.x * z2z2 == other.x * z1z1
+                    && self.y * z2z2 * other.z == other.y * z1z1 * self.z
+            }
+            _ => false,
+        }
+    }
+}
+
+impl<P: SwCurveConfig> Eq for ProjectivePoint<P> {}
+
+impl<P: SwCurveConfig> Add for ProjectivePoint<P> {
+    type Output = Self;
+
+    /// Point addition (add-2007-bl).
+    fn add(self, rhs: Self) -> Self {
+        if self.is_identity() {
+            return rhs;
+        }
+        if rhs.is_identity() {
+            return self;
+        }
+
+        let z1z1 = self.z.square();
+        let z2z2 = rhs.z.square();
+        let u1 = self.x * z2z2;
+        let u2 = rhs.x * z1z1;
+        let s1 = self.y * rhs.z * z2z2;
+        let s2 = rhs.y * self.z * z1z1;
+
+        let h = u2 - u1;
+        let r = (s2 - s1).double();
+        if h.is_zero() {
+            return if r.is_zero() {
+                self.double()
+            } else {
+                Self::identity()
+            };
+        }
+
+        let i = h.double().square();
+        let j = h * i;
+        let v = u1 * i;
+
+        let x3 = r.square() - j - v.double();
+        let y3 = r * (v - x3) - (s1 * j).double();
+        let z3 = ((self.z + rhs.z).square() - z1z1 - z2z2) * h;
+        Self {
+            x: x3,
+            y: y3,
+            z: z3,
+        }
+    }
+}
+
+impl<P: SwCurveConfig> AddAssign for ProjectivePoint<P> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<P: SwCurveConfig> Neg for ProjectivePoint<P> {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self {
+            x: self.x,
+            y: -self.y,
+            z: self.z,
+        }
+    }
+}
+
+impl<P: SwCurveConfig> Sub for ProjectivePoint<P> {
+    type Output = Self;
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn sub(self, rhs: Self) -> Self {
+        self + (-rhs)
+    }
+}
+
+impl<P: SwCurveConfig> std::iter::Sum for ProjectivePoint<P> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::identity(), |acc, p| acc + p)
+    }
+}
+
+/// Computes Σ scalars[i] * bases[i] (naive multi-scalar multiplication).
+///
+/// # Panics
+/// Panics if the slices have different lengths.
+pub fn msm<P: SwCurveConfig>(bases: &[AffinePoint<P>], scalars: &[U1024]) -> ProjectivePoint<P> {
+    assert_eq!(
+        bases.len(),
+        scalars.len(),
+        "Bases and scalars must have the same length"
+    );
+    bases
+        .iter()
+        .zip(scalars.iter())
+        .map(|(b, s)| b.mul_scalar(s))
+        .sum()
+}
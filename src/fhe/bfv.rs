@@ -0,0 +1,569 @@
+//! The BFV (Brakerski/Fan–Vercauteren) homomorphic encryption scheme.
+//!
+//! Plaintexts are polynomials in R_t = Z_t[X]/(X^N + 1), given as N
+//! coefficients modulo the plaintext modulus t. Ciphertexts live in R_Q for
+//! the RNS modulus Q = q₀ ⋯ q_(L-1) and hold the message scaled by
+//! Δ = ⌊Q/t⌋:
+//!
+//! - **Encryption**: (c₀, c₁) = (b·u + e₁ + Δ·m, a·u + e₂) for a public key
+//!   (b, a) = (-(a·s + e), a), a ternary secret s and ternary u.
+//! - **Decryption**: m = ⌊t · [c₀ + c₁·s]_Q / Q⌉ mod t.
+//! - **Multiplication**: the ciphertexts are lifted to centered integers,
+//!   tensored exactly in an auxiliary RNS basis, scaled by t/Q with rounding
+//!   and reduced back to R_Q. The result has three components and is
+//!   decrypted with (1, s, s²).
+//! - **Relinearisation**: returns a product to two components with a key
+//!   for s², decomposed over the RNS primes: the digit for qᵢ is the residue
+//!   [c₂]_(qᵢ), and the key component encrypts s² times the CRT idempotent
+//!   for qᵢ.
+//!
+//! The *noise budget* of a ciphertext is the number of bits by which its
+//! noise can still grow before decryption fails; it is 0 once decryption
+//! is no longer guaranteed.
+//!
+//! # Example
+//!
+//! ```
+//! use rand::SeedableRng;
+//! use rand::rngs::StdRng;
+//! use lumen_math::fhe::bfv::{BfvContext, BfvParams};
+//!
+//! let params = BfvParams {
+//!     n: 16,
+//!     prime_bits: 50,
+//!     num_primes: 3,
+//!     plaintext_modulus: 257,
+//!     eta: 2,
+//! };
+//! let ctx = BfvContext::new(params).unwrap();
+//! let mut rng = StdRng::seed_from_u64(7);
+//!
+//! let (sk, pk) = ctx.keygen(&mut rng);
+//! let rlk = ctx.relin_keygen(&sk, &mut rng);
+//!
+//! let mut a = vec![0u64; 16];
+//! a[0] = 3;
+//! let mut b = vec![0u64; 16];
+//! b[0] = 5;
+//! b[1] = 1;
+//!
+//! let ca = ctx.encrypt(&pk, &a, &mut rng);
+//! let cb = ctx.encrypt(&pk, &b, &mut rng);
+//! let prod = ctx.relinearize(&ctx.mul(&ca, &cb), &rlk);
+//!
+//! // 3 · (5 + X) = 15 + 3X
+//! let m = ctx.decrypt(&sk, &prod);
+//! assert_eq!(&m[..2], &[15, 3]);
+//! assert!(ctx.noise_budget(&sk, &prod) > 0);
+//! ```
+
+use std::fmt;
+use std::ops::{Add, Neg, Sub};
+use std::sync::Arc;
+
+use rand::Rng;
+
+use super::sampling::{sample_cbd, sample_ternary, sample_uniform};
+use crate::ring::RingElementState;
+use crate::ring::rns::{MAX_PRIME_BITS, RnsBasis, RnsError, RnsRingElement};
+use crate::{I1024, U1024};
+
+/// Error type for BFV parameter validation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BfvError {
+    /// The ciphertext modulus chain could not be built.
+    Rns(RnsError),
+    /// The plaintext modulus is below 2 or not below Q.
+    InvalidPlaintextModulus { t: u64 },
+    /// Q is too large for exact multiplication within 1024-bit integers.
+    ModulusTooLarge,
+}
+
+impl fmt::Display for BfvError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BfvError::Rns(e) => write!(f, "Invalid ciphertext modulus: {}", e),
+            BfvError::InvalidPlaintextModulus { t } => {
+                write!(f, "Plaintext modulus {} must be at least 2 and below Q", t)
+            }
+            BfvError::ModulusTooLarge => {
+                write!(f, "Ciphertext modulus is too large for 1024-bit tensoring")
+            }
+        }
+    }
+}
+
+impl std::error::Error for BfvError {}
+
+impl From<RnsError> for BfvError {
+    fn from(e: RnsError) -> Self {
+        BfvError::Rns(e)
+    }
+}
+
+/// A BFV parameter set.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BfvParams {
+    /// Ring degree N, a power of two.
+    pub n: usize,
+    /// Size in bits of each ciphertext prime.
+    pub prime_bits: u32,
+    /// Number of ciphertext primes.
+    pub num_primes: usize,
+    /// Plaintext modulus t.
+    pub plaintext_modulus: u64,
+    /// Centered binomial parameter for the error distribution.
+    pub eta: usize,
+}
+
+/// A BFV secret key s, stored in NTT form.
+#[derive(Clone, Debug)]
+pub struct BfvSecretKey {
+    s: RnsRingElement,
+}
+
+/// A BFV public key (b, a) = (-(a·s + e), a).
+#[derive(Clone, Debug)]
+pub struct BfvPublicKey {
+    b: RnsRingElement,
+    a: RnsRingElement,
+}
+
+/// A relinearisation key: one encryption of s² per RNS prime.
+#[derive(Clone, Debug)]
+pub struct BfvRelinKey {
+    keys: Vec<(RnsRingElement, RnsRingElement)>,
+}
+
+/// A BFV ciphertext (c₀, c₁, …), decrypted as Σ cᵢ · sⁱ.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BfvCiphertext {
+    parts: Vec<RnsRingElement>,
+}
+
+impl BfvCiphertext {
+    /// Returns the degree in s: 1 for fresh ciphertexts, 2 after a
+    /// multiplication and before relinearisation.
+    pub fn degree(&self) -> usize {
+        self.parts.len() - 1
+    }
+
+    /// Returns the components.
+    pub fn parts(&self) -> &[RnsRingElement] {
+        &self.parts
+    }
+}
+
+/// Precomputed data for a BFV parameter set.
+#[derive(Clone, Debug)]
+pub struct BfvContext {
+    params: BfvParams,
+    basis: Arc<RnsBasis>,
+    /// Auxiliary basis large enough to hold exact ciphertext tensor products.
+    mul_basis: Arc<RnsBasis>,
+    q: U1024,
+    delta: U1024,
+}
+
+impl BfvContext {
+    /// Builds the modulus chain and the auxiliary multiplication basis.
+    ///
+    /// # Errors
+    /// Returns [`BfvError::Rns`] if the ciphertext primes cannot be
+    /// generated, [`BfvError::InvalidPlaintextModulus`] unless 2 ≤ t < Q, and
+    /// [`BfvError::ModulusTooLarge`] if t · N · Q² does not fit in 1024 bits.
+    pub fn new(params: BfvParams) -> Result<Self, BfvError> {
+        let basis = RnsBasis::generate(params.n, params.prime_bits, params.num_primes)?;
+        let q = basis.modulus(params.num_primes);
+        let t = params.plaintext_modulus;
+        if t < 2 || U1024::from_u64(t) >= q {
+            return Err(BfvError::InvalidPlaintextModulus { t });
+        }
+
+        // Tensor coefficients are below N · Q² / 2 in absolute value and are
+        // multiplied by t before scaling.
+        let q_bits = q.bits();
+        let n_bits = params.n.trailing_zeros() as usize;
+        let t_bits = 64 - t.leading_zeros() as usize;
+        if t_bits + 2 * q_bits + n_bits > 1022 {
+            return Err(BfvError::ModulusTooLarge);
+        }
+        let tensor_bits = 2 * q_bits + n_bits + 2;
+        let mul_primes = tensor_bits.div_ceil(MAX_PRIME_BITS as usize - 1);
+        let mul_basis =
+            RnsBasis::generate(params.n, MAX_PRIME_BITS, mul_primes).map_err(|e| match e {
+                RnsError::ProductOverflow => BfvError::ModulusTooLarge,
+                e => BfvError::Rns(e),
+            })?;
+
+        Ok(Self {
+            params,
+            basis: Arc::new(basis),
+            mul_basis: Arc::new(mul_basis),
+            delta: q / U1024::from_u64(t),
+            q,
+        })
+    }
+
+    /// Returns the parameters.
+    pub fn params(&self) -> &BfvParams {
+        &self.params
+    }
+
+    /// Returns the ciphertext modulus chain.
+    pub fn basis(&self) -> &Arc<RnsBasis> {
+        &self.basis
+    }
+
+    /// Returns the ciphertext modulus Q.
+    pub fn modulus(&self) -> &U1024 {
+        &self.q
+    }
+
+    fn level(&self) -> usize {
+        self.params.num_primes
+    }
+
+    fn error<R: Rng>(&self, rng: &mut R) -> RnsRingElement {
+        sample_cbd(rng, &self.basis, self.level(), self.params.eta)
+    }
+
+    /// Generates a secret key and the matching public key.
+    pub fn keygen<R: Rng>(&self, rng: &mut R) -> (BfvSecretKey, BfvPublicKey) {
+        let s = sample_ternary(rng, &self.basis, self.level()).into_ntt();
+        let a = sample_uniform(rng, &self.basis, self.level()).into_ntt();
+        let e = self.error(rng);
+        let b = -(&(&a * &s) + &e);
+        (BfvSecretKey { s }, BfvPublicKey { b, a })
+    }
+
+    /// Generates a relinearisation key for `sk`.
+    pub fn relin_keygen<R: Rng>(&self, sk: &BfvSecretKey, rng: &mut R) -> BfvRelinKey {
+        let n = self.params.n;
+        let s2 = &sk.s * &sk.s;
+        let keys = (0..self.level())
+            .map(|i| {
+                let a = sample_uniform(rng, &self.basis, self.level()).into_ntt();
+                let e = self.error(rng);
+                // s² times the CRT idempotent for qᵢ: s² modulo qᵢ, zero elsewhere.
+                let mut limbs = vec![vec![0; n]; self.level()];
+                limbs[i] = s2.limbs()[i].clone();
+                let g =
+                    RnsRingElement::from_limbs(limbs, RingElementState::Ntt, self.basis.clone());
+                let b = &(&g - &(&a * &sk.s)) - &e;
+                (b, a)
+            })
+            .collect();
+        BfvRelinKey { keys }
+    }
+
+    /// Returns Δ · m for a plaintext with coefficients reduced modulo t.
+    fn scaled_plaintext(&self, m: &[u64]) -> RnsRingElement {
+        self.plaintext(m).mul_scalar(&self.delta)
+    }
+
+    fn plaintext(&self, m: &[u64]) -> RnsRingElement {
+        assert_eq!(
+            m.len(),
+            self.params.n,
+            "Plaintext length must match ring degree"
+        );
+        let t = self.params.plaintext_modulus;
+        let coeffs: Vec<U1024> = m.iter().map(|&x| U1024::from_u64(x % t)).collect();
+        RnsRingElement::from_u1024(&coeffs, self.basis.clone(), self.level())
+    }
+
+    /// Encrypts a plaintext of N coefficients, each reduced modulo t.
+    ///
+    /// # Panics
+    /// Panics if `m.len() != N`.
+    pub fn encrypt<R: Rng>(&self, pk: &BfvPublicKey, m: &[u64], rng: &mut R) -> BfvCiphertext {
+        let scaled = self.scaled_plaintext(m);
+        let u = sample_ternary(rng, &self.basis, self.level()).into_ntt();
+        let e1 = self.error(rng);
+        let e2 = self.error(rng);
+        let c0 = &(&(&pk.b * &u) + &e1) + &scaled;
+        let c1 = &(&pk.a * &u) + &e2;
+        BfvCiphertext {
+            parts: vec![c0, c1],
+        }
+    }
+
+    /// Returns [Σ cᵢ · sⁱ]_Q in coefficient form.
+    fn phase(&self, sk: &BfvSecretKey, ct: &BfvCiphertext) -> RnsRingElement {
+        let mut acc = ct.parts[0].clone_to_ntt();
+        let mut s_pow = sk.s.clone();
+        for (i, c) in ct.parts.iter().enumerate().skip(1) {
+            acc = &acc + &(c * &s_pow);
+            if i + 1 < ct.parts.len() {
+                s_pow = &s_pow * &sk.s;
+            }
+        }
+        acc.into_coefficient()
+    }
+
+    /// Decrypts to N coefficients modulo t.
+    pub fn decrypt(&self, sk: &BfvSecretKey, ct: &BfvCiphertext) -> Vec<u64> {
+        let t = U1024::from_u64(self.params.plaintext_modulus);
+        let half = self.q.shr(1);
+        self.phase(sk, ct)
+            .to_u1024_coefficients()
+            .into_iter()
+            .map(|x| ((t * x + half) / self.q % t).0[0])
+            .collect()
+    }
+
+    /// Returns the remaining noise budget in bits.
+    ///
+    /// With v = [Σ cᵢ · sⁱ]_Q, the invariant noise is w = [t · v]_Q. Decryption
+    /// is correct while ‖w‖∞ < Q/2; the budget is ⌊log₂(Q/2)⌋ minus the bit
+    /// length of ‖w‖∞, saturating at 0.
+    pub fn noise_budget(&self, sk: &BfvSecretKey, ct: &BfvCiphertext) -> u32 {
+        let t = U1024::from_u64(self.params.plaintext_modulus);
+        let half = self.q.shr(1);
+        let max = self
+            .phase(sk, ct)
+            .to_u1024_coefficients()
+            .into_iter()
+            .map(|x| {
+                let w = t * x % self.q;
+                if w > half { self.q - w } else { w }
+            })
+            .max()
+            .unwrap_or(U1024::ZERO);
+        (self.q.bits() - 1).saturating_sub(max.bits()) as u32
+    }
+
+    /// Adds a plaintext to a ciphertext.
+    pub fn add_plain(&self, ct: &BfvCiphertext, m: &[u64]) -> BfvCiphertext {
+        let mut parts = ct.parts.clone();
+        parts[0] = &parts[0] + &self.scaled_plaintext(m);
+        BfvCiphertext { parts }
+    }
+
+    /// Multiplies a ciphertext by a plaintext polynomial.
+    pub fn mul_plain(&self, ct: &BfvCiphertext, m: &[u64]) -> BfvCiphertext {
+        let p = self.plaintext(m).into_ntt();
+        BfvCiphertext {
+            parts: ct.parts.iter().map(|c| c * &p).collect(),
+        }
+    }
+
+    /// Multiplies two degree-1 ciphertexts, giving a degree-2 ciphertext.
+    ///
+    /// # Panics
+    /// Panics unless both ciphertexts have degree 1.
+    pub fn mul(&self, a: &BfvCiphertext, b: &BfvCiphertext) -> BfvCiphertext {
+        assert!(
+            a.degree() == 1 && b.degree() == 1,
+            "Ciphertexts must be relinearized before multiplication"
+        );
+        let mul_level = self.mul_basis.num_primes();
+        let lift = |c: &RnsRingElement| {
+            RnsRingElement::from_centered(
+                &c.to_centered_coefficients(),
+                self.mul_basis.clone(),
+                mul_level,
+            )
+            .into_ntt()
+        };
+        let (a0, a1) = (lift(&a.parts[0]), lift(&a.parts[1]));
+        let (b0, b1) = (lift(&b.parts[0]), lift(&b.parts[1]));
+
+        let tensor = [&a0 * &b0, &(&a0 * &b1) + &(&a1 * &b0), &a1 * &b1];
+        BfvCiphertext {
+            parts: tensor.iter().map(|d| self.scale_down(d)).collect(),
+        }
+    }
+
+    /// Maps an exact integer polynomial d to ⌊t · d / Q⌉ mod Q.
+    fn scale_down(&self, d: &RnsRingElement) -> RnsRingElement {
+        let t = U1024::from_u64(self.params.plaintext_modulus);
+        let half = self.q.shr(1);
+        let scaled: Vec<I1024> = d
+            .to_centered_coefficients()
+            .into_iter()
+            .map(|c| {
+                let r = (t * c.magnitude() + half) / self.q;
+                I1024::new(r, !c.is_negative())
+            })
+            .collect();
+        RnsRingElement::from_centered(&scaled, self.basis.clone(), self.level())
+    }
+
+    /// Reduces a degree-2 ciphertext to degree 1 with a relinearisation key.
+    /// Degree-1 ciphertexts are returned unchanged.
+    ///
+    /// # Panics
+    /// Panics if the ciphertext has degree above 2.
+    pub fn relinearize(&self, ct: &BfvCiphertext, rlk: &BfvRelinKey) -> BfvCiphertext {
+        if ct.degree() == 1 {
+            return ct.clone();
+        }
+        assert_eq!(
+            ct.degree(),
+            2,
+            "Only degree-2 ciphertexts can be relinearized"
+        );
+
+        let c2 = ct.parts[2].clone_to_coefficient();
+        let moduli = self.basis.moduli();
+        let mut c0 = ct.parts[0].clone_to_ntt();
+        let mut c1 = ct.parts[1].clone_to_ntt();
+        for (digit, (b, a)) in c2.limbs().iter().zip(&rlk.keys) {
+            // The residue modulo qᵢ, as an integer in [0, qᵢ), in every prime.
+            let limbs = moduli[..self.level()]
+                .iter()
+                .map(|&q| digit.iter().map(|&x| x % q).collect())
+                .collect();
+            let d = RnsRingElement::from_limbs(
+                limbs,
+                RingElementState::Coefficient,
+                self.basis.clone(),
+            )
+            .into_ntt();
+            c0 = &c0 + &(&d * b);
+            c1 = &c1 + &(&d * a);
+        }
+        BfvCiphertext {
+            parts: vec![c0, c1],
+        }
+    }
+}
+
+// =============================================================================
+// Arithmetic Operations
+// =============================================================================
+
+impl<'a> Add<&'a BfvCiphertext> for &'a BfvCiphertext {
+    type Output = BfvCiphertext;
+
+    /// Adds componentwise; the result has the larger degree.
+    fn add(self, rhs: &'a BfvCiphertext) -> BfvCiphertext {
+        let (long, short) = if self.parts.len() >= rhs.parts.len() {
+            (self, rhs)
+        } else {
+            (rhs, self)
+        };
+        let mut parts = long.parts.clone();
+        for (p, q) in parts.iter_mut().zip(&short.parts) {
+            *p = &*p + q;
+        }
+        BfvCiphertext { parts }
+    }
+}
+
+impl Add for BfvCiphertext {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        &self + &rhs
+    }
+}
+
+impl<'a> Sub<&'a BfvCiphertext> for &'a BfvCiphertext {
+    type Output = BfvCiphertext;
+
+    fn sub(self, rhs: &'a BfvCiphertext) -> BfvCiphertext {
+        self + &(-rhs)
+    }
+}
+
+impl Sub for BfvCiphertext {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        &self - &rhs
+    }
+}
+
+impl Neg for &BfvCiphertext {
+    type Output = BfvCiphertext;
+
+    fn neg(self) -> BfvCiphertext {
+        BfvCiphertext {
+            parts: self.parts.iter().map(|c| -c).collect(),
+        }
+    }
+}
+
+impl Neg for BfvCiphertext {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        -&self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+
+    const PARAMS: BfvParams = BfvParams {
+        n: 16,
+        prime_bits: 40,
+        num_primes: 2,
+        plaintext_modulus: 17,
+        eta: 2,
+    };
+
+    #[test]
+    fn test_invalid_params() {
+        let bad_t = BfvParams {
+            plaintext_modulus: 1,
+            ..PARAMS
+        };
+        assert_eq!(
+            BfvContext::new(bad_t).unwrap_err(),
+            BfvError::InvalidPlaintextModulus { t: 1 }
+        );
+        let bad_n = BfvParams { n: 12, ..PARAMS };
+        assert_eq!(
+            BfvContext::new(bad_n).unwrap_err(),
+            BfvError::Rns(RnsError::InvalidDegree { n: 12 })
+        );
+        let too_big = BfvParams {
+            prime_bits: 60,
+            num_primes: 9,
+            ..PARAMS
+        };
+        assert_eq!(
+            BfvContext::new(too_big).unwrap_err(),
+            BfvError::ModulusTooLarge
+        );
+        let wide_primes = BfvParams {
+            prime_bits: 63,
+            ..PARAMS
+        };
+        assert_eq!(
+            BfvContext::new(wide_primes).unwrap_err(),
+            BfvError::Rns(RnsError::InvalidPrimeBits { bits: 63 })
+        );
+    }
+
+    #[test]
+    fn test_relinearize_preserves_plaintext() {
+        let ctx = BfvContext::new(PARAMS).unwrap();
+        let mut rng = StdRng::seed_from_u64(1);
+        let (sk, pk) = ctx.keygen(&mut rng);
+        let rlk = ctx.relin_keygen(&sk, &mut rng);
+
+        let m: Vec<u64> = (0..16).collect();
+        let one = {
+            let mut v = vec![0; 16];
+            v[0] = 1;
+            v
+        };
+        let prod = ctx.mul(
+            &ctx.encrypt(&pk, &m, &mut rng),
+            &ctx.encrypt(&pk, &one, &mut rng),
+        );
+        assert_eq!(prod.degree(), 2);
+        assert_eq!(ctx.decrypt(&sk, &prod), m);
+        let relin = ctx.relinearize(&prod, &rlk);
+        assert_eq!(relin.degree(), 1);
+        assert_eq!(ctx.decrypt(&sk, &relin), m);
+    }
+}
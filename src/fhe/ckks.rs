@@ -0,0 +1,889 @@
+//! The CKKS (Cheon–Kim–Kim–Song) approximate homomorphic encryption scheme.
+//!
+//! CKKS encrypts vectors of N/2 complex numbers and computes on them
+//! approximately, slot by slot.
+//!
+//! # Encoding
+//!
+//! A vector z ∈ C^(N/2) is mapped to the real polynomial m with
+//! m(ζ^(5^j)) = zⱼ for ζ = e^(iπ/N), which is the inverse of the canonical
+//! embedding restricted to the orbit of 5. The map is evaluated with the
+//! "special FFT" of size N/2, multiplied by the scale Δ and rounded to
+//! integer coefficients. Because evaluation at roots of X^N + 1 is a ring
+//! homomorphism, polynomial products are slot-wise products.
+//!
+//! # Scheme
+//!
+//! - **Encryption**: (c₀, c₁) = (b·u + e₁ + m, a·u + e₂) with a public key
+//!   (b, a) = (-(a·s + e), a), as in [`super::bfv`] but without scaling the
+//!   message by ⌊Q/t⌋.
+//! - **Decryption**: c₀ + c₁·s = m + (small noise), decoded with the scale.
+//! - **Multiplication**: the tensor (c₀d₀, c₀d₁ + c₁d₀, c₁d₁) is reduced
+//!   back to two components by key switching c₁d₁ from s² to s. The scale
+//!   of the result is the product of the input scales.
+//! - **Rescaling**: [`CkksCiphertext::rescale`] divides by the last prime of
+//!   the chain, bringing the scale from Δ² back to about Δ and dropping one
+//!   level.
+//! - **Rotation**: the automorphism X ↦ X^(5^r) rotates the slots left by r;
+//!   a Galois key switches the result back to s.
+//!
+//! Key switching decomposes the component by RNS residues and uses a
+//! special prime P: the keys encrypt P·s' modulo Q·P, and the switched
+//! result is divided by P, so the noise added by the large digits is scaled
+//! back down by P.
+//!
+//! # Modulus chain
+//!
+//! The chain is q₀, p₁, …, p_L, P: a first prime of `first_prime_bits` bits
+//! that holds the final result, L primes of `scale_bits` bits, one per
+//! rescaling, and the special prime P of `first_prime_bits` bits, which only
+//! keys use. Fresh ciphertexts are at level L + 1.
+//!
+//! # Example
+//!
+//! ```
+//! use rand::SeedableRng;
+//! use rand::rngs::StdRng;
+//! use lumen_math::fhe::ckks::{CkksContext, CkksParams};
+//!
+//! let params = CkksParams {
+//!     n: 16,
+//!     first_prime_bits: 60,
+//!     scale_bits: 40,
+//!     levels: 1,
+//!     eta: 2,
+//! };
+//! let ctx = CkksContext::new(params).unwrap();
+//! let mut rng = StdRng::seed_from_u64(1);
+//! let (sk, pk) = ctx.keygen(&mut rng);
+//! let rlk = ctx.relin_keygen(&sk, &mut rng);
+//!
+//! let scale = ctx.default_scale();
+//! let a = ctx.encode_real(&[1.5, -2.0, 0.25], scale, ctx.max_level());
+//! let b = ctx.encode_real(&[2.0, 3.0, 4.0], scale, ctx.max_level());
+//! let ca = ctx.encrypt(&pk, &a, &mut rng);
+//! let cb = ctx.encrypt(&pk, &b, &mut rng);
+//!
+//! let prod = ctx.mul(&ca, &cb, &rlk).rescale();
+//! let out = ctx.decode(&ctx.decrypt(&sk, &prod));
+//! assert!((out[0].re - 3.0).abs() < 1e-4);
+//! assert!((out[1].re + 6.0).abs() < 1e-4);
+//! assert!((out[2].re - 1.0).abs() < 1e-4);
+//! ```
+
+use std::collections::HashMap;
+use std::f64::consts::PI;
+use std::fmt;
+use std::ops::{Add, Neg, Sub};
+use std::sync::Arc;
+
+use rand::Rng;
+
+use super::sampling::{sample_cbd, sample_ternary, sample_uniform};
+pub use crate::field::complex::Complex;
+use crate::ring::RingElementState;
+use crate::ring::rns::{RnsBasis, RnsError, RnsRingElement};
+use crate::{I1024, U1024};
+
+/// Largest relative difference between the scales of added ciphertexts.
+const SCALE_TOLERANCE: f64 = 1e-6;
+
+/// Error type for CKKS parameter validation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CkksError {
+    /// The modulus chain could not be built.
+    Rns(RnsError),
+    /// The first prime is not larger than the scale, so decrypted values
+    /// would not fit at the last level.
+    ScaleTooLarge { scale_bits: u32 },
+}
+
+impl fmt::Display for CkksError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CkksError::Rns(e) => write!(f, "Invalid modulus chain: {}", e),
+            CkksError::ScaleTooLarge { scale_bits } => write!(
+                f,
+                "Scale of {} bits must be smaller than the first prime",
+                scale_bits
+            ),
+        }
+    }
+}
+
+impl std::error::Error for CkksError {}
+
+impl From<RnsError> for CkksError {
+    fn from(e: RnsError) -> Self {
+        CkksError::Rns(e)
+    }
+}
+
+// =============================================================================
+// Keys, plaintexts and ciphertexts
+// =============================================================================
+
+/// A CKKS parameter set.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CkksParams {
+    /// Ring degree N, a power of two; there are N/2 slots.
+    pub n: usize,
+    /// Size in bits of the first prime q₀ and the special prime P.
+    pub first_prime_bits: u32,
+    /// log₂ of the default scale Δ, and the size of each rescaling prime.
+    pub scale_bits: u32,
+    /// Number of rescaling primes, i.e. the multiplicative depth.
+    pub levels: usize,
+    /// Centered binomial parameter for the error distribution.
+    pub eta: usize,
+}
+
+/// A CKKS secret key s, stored in NTT form modulo Q·P.
+#[derive(Clone, Debug)]
+pub struct CkksSecretKey {
+    s: RnsRingElement,
+}
+
+/// A CKKS public key (b, a) = (-(a·s + e), a), modulo Q·P.
+#[derive(Clone, Debug)]
+pub struct CkksPublicKey {
+    b: RnsRingElement,
+    a: RnsRingElement,
+}
+
+/// Encryptions modulo Q·P of P·s' times the CRT idempotent of each
+/// ciphertext prime, for switching a component multiplied by s' back to s.
+#[derive(Clone, Debug)]
+struct KeySwitchKey {
+    /// One key per ciphertext prime.
+    keys: Vec<(RnsRingElement, RnsRingElement)>,
+}
+
+/// A relinearisation key, switching from s² to s.
+#[derive(Clone, Debug)]
+pub struct CkksRelinKey {
+    key: KeySwitchKey,
+}
+
+/// Galois keys for a set of slot rotations.
+#[derive(Clone, Debug)]
+pub struct CkksGaloisKeys {
+    /// Keys switching from σ_(5^r)(s) to s, by rotation step r.
+    keys: HashMap<usize, KeySwitchKey>,
+}
+
+impl CkksGaloisKeys {
+    /// Returns `true` if a key for rotating by `steps` slots is present.
+    pub fn supports(&self, steps: usize) -> bool {
+        self.keys.contains_key(&steps)
+    }
+}
+
+/// An encoded plaintext: a polynomial and the scale it was encoded with.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CkksPlaintext {
+    poly: RnsRingElement,
+    scale: f64,
+}
+
+impl CkksPlaintext {
+    /// Returns the scale.
+    pub fn scale(&self) -> f64 {
+        self.scale
+    }
+
+    /// Returns the level.
+    pub fn level(&self) -> usize {
+        self.poly.level()
+    }
+
+    /// Returns the polynomial.
+    pub fn poly(&self) -> &RnsRingElement {
+        &self.poly
+    }
+}
+
+/// A CKKS ciphertext (c₀, c₁) with its scale.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CkksCiphertext {
+    c0: RnsRingElement,
+    c1: RnsRingElement,
+    scale: f64,
+}
+
+impl CkksCiphertext {
+    /// Returns the scale.
+    pub fn scale(&self) -> f64 {
+        self.scale
+    }
+
+    /// Returns the level, i.e. the number of active primes.
+    pub fn level(&self) -> usize {
+        self.c0.level()
+    }
+
+    /// Returns the components (c₀, c₁).
+    pub fn parts(&self) -> (&RnsRingElement, &RnsRingElement) {
+        (&self.c0, &self.c1)
+    }
+
+    /// Divides by the last prime of the current level and drops it, dividing
+    /// the scale by the same prime.
+    ///
+    /// # Panics
+    /// Panics if the ciphertext is at level 1.
+    pub fn rescale(&self) -> Self {
+        let q_last = self.c0.moduli()[self.level() - 1];
+        Self {
+            c0: self.c0.rescale(),
+            c1: self.c1.rescale(),
+            scale: self.scale / q_last as f64,
+        }
+    }
+
+    /// Reduces to a lower level without changing the scale.
+    ///
+    /// # Panics
+    /// Panics unless 1 ≤ `level` ≤ the current level.
+    pub fn drop_to_level(&self, level: usize) -> Self {
+        Self {
+            c0: self.c0.drop_to_level(level),
+            c1: self.c1.drop_to_level(level),
+            scale: self.scale,
+        }
+    }
+
+    /// Returns both ciphertexts at the lower of their levels, checking that
+    /// their scales agree.
+    fn aligned(&self, other: &Self) -> (Self, Self) {
+        assert!(
+            (self.scale / other.scale - 1.0).abs() < SCALE_TOLERANCE,
+            "Ciphertext scales must match"
+        );
+        let level = self.level().min(other.level());
+        (self.drop_to_level(level), other.drop_to_level(level))
+    }
+}
+
+// =============================================================================
+// CkksContext
+// =============================================================================
+
+/// Precomputed data for a CKKS parameter set.
+#[derive(Clone, Debug)]
+pub struct CkksContext {
+    params: CkksParams,
+    basis: Arc<RnsBasis>,
+    /// e^(2πik / 2N) for k ≤ 2N
+    ksi_pows: Vec<Complex>,
+    /// 5^j mod 2N for j < N/2
+    rot_group: Vec<usize>,
+}
+
+impl CkksContext {
+    /// Builds the modulus chain and the encoder tables.
+    ///
+    /// # Errors
+    /// Returns [`CkksError::ScaleTooLarge`] unless `first_prime_bits` >
+    /// `scale_bits`, and [`CkksError::Rns`] if the primes cannot be
+    /// generated.
+    pub fn new(params: CkksParams) -> Result<Self, CkksError> {
+        if params.scale_bits >= params.first_prime_bits {
+            return Err(CkksError::ScaleTooLarge {
+                scale_bits: params.scale_bits,
+            });
+        }
+        let large = RnsBasis::generate(params.n, params.first_prime_bits, 2)?;
+        let scaling = RnsBasis::generate(params.n, params.scale_bits, params.levels)?;
+        let mut primes = vec![large.moduli()[0]];
+        primes.extend_from_slice(scaling.moduli());
+        primes.push(large.moduli()[1]);
+        let basis = RnsBasis::new(params.n, &primes)?;
+
+        let m = 2 * params.n;
+        let ksi_pows = (0..=m)
+            .map(|k| Complex::from_angle(2.0 * PI * k as f64 / m as f64))
+            .collect();
+        let mut rot_group = Vec::with_capacity(params.n / 2);
+        let mut g = 1;
+        for _ in 0..params.n / 2 {
+            rot_group.push(g);
+            g = g * 5 % m;
+        }
+
+        Ok(Self {
+            params,
+            basis: Arc::new(basis),
+            ksi_pows,
+            rot_group,
+        })
+    }
+
+    /// Returns the parameters.
+    pub fn params(&self) -> &CkksParams {
+        &self.params
+    }
+
+    /// Returns the modulus chain, including the special prime P last.
+    pub fn basis(&self) -> &Arc<RnsBasis> {
+        &self.basis
+    }
+
+    /// Returns the number of slots, N/2.
+    pub fn slots(&self) -> usize {
+        self.params.n / 2
+    }
+
+    /// Returns the level of fresh ciphertexts, L + 1.
+    pub fn max_level(&self) -> usize {
+        self.basis.num_primes() - 1
+    }
+
+    /// Returns the level of keys, which includes the special prime.
+    fn key_level(&self) -> usize {
+        self.basis.num_primes()
+    }
+
+    /// Returns the default scale Δ = 2^`scale_bits`.
+    pub fn default_scale(&self) -> f64 {
+        (self.params.scale_bits as f64).exp2()
+    }
+
+    // -------------------------------------------------------------------------
+    // Encoding
+    // -------------------------------------------------------------------------
+
+    /// Evaluates the slot values of a polynomial given as N/2 complex
+    /// coefficients (mᵢ + i·m_(i+N/2)), in place.
+    fn special_fft(&self, vals: &mut [Complex]) {
+        let size = vals.len();
+        let m = 2 * self.params.n;
+        bit_reverse(vals);
+        let mut len = 2;
+        while len <= size {
+            let lenh = len / 2;
+            let lenq = len * 4;
+            for i in (0..size).step_by(len) {
+                for j in 0..lenh {
+                    let idx = (self.rot_group[j] % lenq) * (m / lenq);
+                    let u = vals[i + j];
+                    let v = vals[i + j + lenh] * self.ksi_pows[idx];
+                    vals[i + j] = u + v;
+                    vals[i + j + lenh] = u - v;
+                }
+            }
+            len *= 2;
+        }
+    }
+
+    /// Inverse of [`special_fft`](Self::special_fft), in place.
+    fn special_fft_inv(&self, vals: &mut [Complex]) {
+        let size = vals.len();
+        let m = 2 * self.params.n;
+        let mut len = size;
+        while len >= 2 {
+            let lenh = len / 2;
+            let lenq = len * 4;
+            for i in (0..size).step_by(len) {
+                for j in 0..lenh {
+                    let idx = (lenq - self.rot_group[j] % lenq) * (m / lenq);
+                    let u = vals[i + j] + vals[i + j + lenh];
+                    let v = (vals[i + j] - vals[i + j + lenh]) * self.ksi_pows[idx];
+                    vals[i + j] = u;
+                    vals[i + j + lenh] = v;
+                }
+            }
+            len /= 2;
+        }
+        bit_reverse(vals);
+        let inv = 1.0 / size as f64;
+        for v in vals.iter_mut() {
+            *v = Complex::new(v.re * inv, v.im * inv);
+        }
+    }
+
+    /// Encodes up to N/2 complex values at `scale` and `level`. Missing
+    /// slots are zero.
+    ///
+    /// # Panics
+    /// Panics if there are more values than slots, if a scaled coefficient
+    /// does not fit in 63 bits, or unless 1 ≤ `level` ≤
+    /// [`max_level`](Self::max_level).
+    pub fn encode(&self, values: &[Complex], scale: f64, level: usize) -> CkksPlaintext {
+        let slots = self.slots();
+        assert!(
+            values.len() <= slots,
+            "Too many values for the number of slots"
+        );
+        assert!(
+            level <= self.max_level(),
+            "Level exceeds the ciphertext modulus chain"
+        );
+        let mut u = values.to_vec();
+        u.resize(slots, Complex::default());
+        self.special_fft_inv(&mut u);
+
+        let limit = (62f64).exp2();
+        let round = |x: f64| {
+            let y = (x * scale).round();
+            assert!(y.abs() < limit, "Scaled value does not fit in 63 bits");
+            y as i64
+        };
+        let mut coeffs = vec![0i64; self.params.n];
+        for (i, z) in u.iter().enumerate() {
+            coeffs[i] = round(z.re);
+            coeffs[i + slots] = round(z.im);
+        }
+        CkksPlaintext {
+            poly: RnsRingElement::from_signed(&coeffs, self.basis.clone(), level),
+            scale,
+        }
+    }
+
+    /// Encodes up to N/2 real values at `scale` and `level`.
+    ///
+    /// # Panics
+    /// Same as [`encode`](Self::encode).
+    pub fn encode_real(&self, values: &[f64], scale: f64, level: usize) -> CkksPlaintext {
+        let values: Vec<Complex> = values.iter().map(|&x| Complex::from(x)).collect();
+        self.encode(&values, scale, level)
+    }
+
+    /// Decodes a plaintext to its N/2 slot values.
+    pub fn decode(&self, pt: &CkksPlaintext) -> Vec<Complex> {
+        let slots = self.slots();
+        let coeffs: Vec<f64> = pt
+            .poly
+            .to_centered_coefficients()
+            .iter()
+            .map(|c| i1024_to_f64(c) / pt.scale)
+            .collect();
+        let mut vals: Vec<Complex> = (0..slots)
+            .map(|i| Complex::new(coeffs[i], coeffs[i + slots]))
+            .collect();
+        self.special_fft(&mut vals);
+        vals
+    }
+
+    // -------------------------------------------------------------------------
+    // Keys
+    // -------------------------------------------------------------------------
+
+    fn error<R: Rng>(&self, rng: &mut R, level: usize) -> RnsRingElement {
+        sample_cbd(rng, &self.basis, level, self.params.eta)
+    }
+
+    /// Generates a secret key and the matching public key.
+    pub fn keygen<R: Rng>(&self, rng: &mut R) -> (CkksSecretKey, CkksPublicKey) {
+        let level = self.key_level();
+        let s = sample_ternary(rng, &self.basis, level).into_ntt();
+        let a = sample_uniform(rng, &self.basis, level).into_ntt();
+        let e = self.error(rng, level);
+        let b = -(&(&a * &s) + &e);
+        (CkksSecretKey { s }, CkksPublicKey { b, a })
+    }
+
+    /// Generates a key switching from `s_from` (NTT form) to the secret `sk`.
+    fn key_switch_keygen<R: Rng>(
+        &self,
+        sk: &CkksSecretKey,
+        s_from: &RnsRingElement,
+        rng: &mut R,
+    ) -> KeySwitchKey {
+        let level = self.key_level();
+        let n = self.params.n;
+        let moduli = self.basis.moduli();
+        let p = moduli[level - 1];
+        let keys = moduli[..level - 1]
+            .iter()
+            .enumerate()
+            .map(|(i, &q)| {
+                let a = sample_uniform(rng, &self.basis, level).into_ntt();
+                let e = self.error(rng, level);
+                // P · s' modulo qᵢ, zero modulo the other primes and P.
+                let factor = p % q;
+                let mut limbs = vec![vec![0; n]; level];
+                limbs[i] = s_from.limbs()[i]
+                    .iter()
+                    .map(|&x| ((x as u128 * factor as u128) % q as u128) as u64)
+                    .collect();
+                let g =
+                    RnsRingElement::from_limbs(limbs, RingElementState::Ntt, self.basis.clone());
+                let b = &(&g - &(&a * &sk.s)) - &e;
+                (b, a)
+            })
+            .collect();
+        KeySwitchKey { keys }
+    }
+
+    /// Generates a relinearisation key for `sk`.
+    pub fn relin_keygen<R: Rng>(&self, sk: &CkksSecretKey, rng: &mut R) -> CkksRelinKey {
+        let s2 = &sk.s * &sk.s;
+        CkksRelinKey {
+            key: self.key_switch_keygen(sk, &s2, rng),
+        }
+    }
+
+    /// Generates Galois keys for rotating left by each of `steps` slots.
+    /// Steps are taken modulo N/2.
+    pub fn rotation_keygen<R: Rng>(
+        &self,
+        sk: &CkksSecretKey,
+        steps: &[usize],
+        rng: &mut R,
+    ) -> CkksGaloisKeys {
+        let mut keys = HashMap::new();
+        for &r in steps {
+            let r = r % self.slots();
+            if r == 0 || keys.contains_key(&r) {
+                continue;
+            }
+            let s_rot = sk.s.automorphism(self.galois_element(r));
+            keys.insert(r, self.key_switch_keygen(sk, &s_rot, rng));
+        }
+        CkksGaloisKeys { keys }
+    }
+
+    /// Returns 5^r mod 2N.
+    fn galois_element(&self, r: usize) -> usize {
+        self.rot_group[r % self.slots()]
+    }
+
+    /// Returns ⌊(Σ dᵢ · bᵢ, Σ dᵢ · aᵢ) / P⌉ at the level of `c`, where the
+    /// dᵢ are the residues of `c`, lifted to every prime of Q·P.
+    fn key_switch(
+        &self,
+        c: &RnsRingElement,
+        ksk: &KeySwitchKey,
+    ) -> (RnsRingElement, RnsRingElement) {
+        let c = c.clone_to_coefficient();
+        let level = c.level();
+        let full = self.key_level();
+        let moduli = self.basis.moduli();
+
+        let mut acc0 = RnsRingElement::zero(self.basis.clone(), full).into_ntt();
+        let mut acc1 = acc0.clone();
+        for (digit, (b, a)) in c.limbs().iter().zip(&ksk.keys) {
+            let limbs = moduli
+                .iter()
+                .map(|&q| digit.iter().map(|&d| d % q).collect())
+                .collect();
+            let d = RnsRingElement::from_limbs(
+                limbs,
+                RingElementState::Coefficient,
+                self.basis.clone(),
+            )
+            .into_ntt();
+            acc0 = &acc0 + &(&d * b);
+            acc1 = &acc1 + &(&d * a);
+        }
+        // Dividing by P removes the special prime and scales the noise down.
+        (
+            acc0.rescale().drop_to_level(level),
+            acc1.rescale().drop_to_level(level),
+        )
+    }
+
+    // -------------------------------------------------------------------------
+    // Encryption
+    // -------------------------------------------------------------------------
+
+    /// Encrypts a plaintext at its level.
+    pub fn encrypt<R: Rng>(
+        &self,
+        pk: &CkksPublicKey,
+        pt: &CkksPlaintext,
+        rng: &mut R,
+    ) -> CkksCiphertext {
+        let level = pt.level();
+        let u = sample_ternary(rng, &self.basis, level).into_ntt();
+        let e1 = self.error(rng, level);
+        let e2 = self.error(rng, level);
+        let b = pk.b.drop_to_level(level);
+        let a = pk.a.drop_to_level(level);
+        CkksCiphertext {
+            c0: &(&(&b * &u) + &e1) + &pt.poly,
+            c1: &(&a * &u) + &e2,
+            scale: pt.scale,
+        }
+    }
+
+    /// Decrypts to a plaintext c₀ + c₁·s at the ciphertext's scale and level.
+    pub fn decrypt(&self, sk: &CkksSecretKey, ct: &CkksCiphertext) -> CkksPlaintext {
+        let s = sk.s.drop_to_level(ct.level());
+        CkksPlaintext {
+            poly: (&ct.c0 + &(&ct.c1 * &s)).into_coefficient(),
+            scale: ct.scale,
+        }
+    }
+
+    // -------------------------------------------------------------------------
+    // Homomorphic operations
+    // -------------------------------------------------------------------------
+
+    /// Adds a plaintext with the same scale.
+    ///
+    /// # Panics
+    /// Panics if the scales differ or the plaintext is at a lower level.
+    pub fn add_plain(&self, ct: &CkksCiphertext, pt: &CkksPlaintext) -> CkksCiphertext {
+        assert!(
+            (ct.scale / pt.scale - 1.0).abs() < SCALE_TOLERANCE,
+            "Ciphertext scales must match"
+        );
+        CkksCiphertext {
+            c0: &ct.c0 + &pt.poly.drop_to_level(ct.level()),
+            c1: ct.c1.clone(),
+            scale: ct.scale,
+        }
+    }
+
+    /// Multiplies by a plaintext; the scales multiply.
+    ///
+    /// # Panics
+    /// Panics if the plaintext is at a lower level than the ciphertext.
+    pub fn mul_plain(&self, ct: &CkksCiphertext, pt: &CkksPlaintext) -> CkksCiphertext {
+        let p = pt.poly.drop_to_level(ct.level());
+        CkksCiphertext {
+            c0: &ct.c0 * &p,
+            c1: &ct.c1 * &p,
+            scale: ct.scale * pt.scale,
+        }
+    }
+
+    /// Multiplies two ciphertexts and relinearises; the scales multiply.
+    /// The result is at the lower of the two levels and is usually followed
+    /// by [`CkksCiphertext::rescale`].
+    pub fn mul(
+        &self,
+        a: &CkksCiphertext,
+        b: &CkksCiphertext,
+        rlk: &CkksRelinKey,
+    ) -> CkksCiphertext {
+        let level = a.level().min(b.level());
+        let (a, b) = (a.drop_to_level(level), b.drop_to_level(level));
+        let d0 = &a.c0 * &b.c0;
+        let d1 = &(&a.c0 * &b.c1) + &(&a.c1 * &b.c0);
+        let d2 = &a.c1 * &b.c1;
+        let (k0, k1) = self.key_switch(&d2, &rlk.key);
+        CkksCiphertext {
+            c0: &d0 + &k0,
+            c1: &d1 + &k1,
+            scale: a.scale * b.scale,
+        }
+    }
+
+    /// Rotates the slots left by `steps`: slot i of the result holds slot
+    /// i + `steps` (mod N/2) of the input.
+    ///
+    /// # Panics
+    /// Panics if `gk` has no key for `steps` mod N/2.
+    pub fn rotate(&self, ct: &CkksCiphertext, steps: usize, gk: &CkksGaloisKeys) -> CkksCiphertext {
+        let r = steps % self.slots();
+        if r == 0 {
+            return ct.clone();
+        }
+        let key = gk
+            .keys
+            .get(&r)
+            .unwrap_or_else(|| panic!("No Galois key for rotation by {}", r));
+        let k = self.galois_element(r);
+        let (k0, k1) = self.key_switch(&ct.c1.automorphism(k), key);
+        CkksCiphertext {
+            c0: &ct.c0.automorphism(k) + &k0,
+            c1: k1,
+            scale: ct.scale,
+        }
+    }
+}
+
+// =============================================================================
+// Arithmetic Operations
+// =============================================================================
+
+impl<'a> Add<&'a CkksCiphertext> for &'a CkksCiphertext {
+    type Output = CkksCiphertext;
+
+    /// Adds slot-wise at the lower of the two levels.
+    ///
+    /// # Panics
+    /// Panics if the scales differ.
+    fn add(self, rhs: &'a CkksCiphertext) -> CkksCiphertext {
+        let (a, b) = self.aligned(rhs);
+        CkksCiphertext {
+            c0: &a.c0 + &b.c0,
+            c1: &a.c1 + &b.c1,
+            scale: a.scale,
+        }
+    }
+}
+
+impl Add for CkksCiphertext {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        &self + &rhs
+    }
+}
+
+impl<'a> Sub<&'a CkksCiphertext> for &'a CkksCiphertext {
+    type Output = CkksCiphertext;
+
+    /// Subtracts slot-wise at the lower of the two levels.
+    ///
+    /// # Panics
+    /// Panics if the scales differ.
+    fn sub(self, rhs: &'a CkksCiphertext) -> CkksCiphertext {
+        let (a, b) = self.aligned(rhs);
+        CkksCiphertext {
+            c0: &a.c0 - &b.c0,
+            c1: &a.c1 - &b.c1,
+            scale: a.scale,
+        }
+    }
+}
+
+impl Sub for CkksCiphertext {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        &self - &rhs
+    }
+}
+
+impl Neg for &CkksCiphertext {
+    type Output = CkksCiphertext;
+
+    fn neg(self) -> CkksCiphertext {
+        CkksCiphertext {
+            c0: -&self.c0,
+            c1: -&self.c1,
+            scale: self.scale,
+        }
+    }
+}
+
+impl Neg for CkksCiphertext {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        -&self
+    }
+}
+
+fn bit_reverse(a: &mut [Complex]) {
+    let n = a.len();
+    let mut j = 0;
+    for i in 1..n {
+        let mut bit = n >> 1;
+        while j & bit != 0 {
+            j ^= bit;
+            bit >>= 1;
+        }
+        j |= bit;
+        if i < j {
+            a.swap(i, j);
+        }
+    }
+}
+
+fn i1024_to_f64(x: &I1024) -> f64 {
+    let m: U1024 = x.magnitude();
+    let v =
+        m.0.iter()
+            .rev()
+            .fold(0.0, |acc, &limb| acc * 64f64.exp2() + limb as f64);
+    if x.is_negative() { -v } else { v }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn context() -> CkksContext {
+        CkksContext::new(CkksParams {
+            n: 16,
+            first_prime_bits: 50,
+            scale_bits: 30,
+            levels: 1,
+            eta: 2,
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn test_special_fft_roundtrip() {
+        let ctx = context();
+        let vals: Vec<Complex> = (0..8)
+            .map(|i| Complex::new(i as f64, -(i as f64) / 2.0))
+            .collect();
+        let mut v = vals.clone();
+        ctx.special_fft_inv(&mut v);
+        ctx.special_fft(&mut v);
+        for (a, b) in v.iter().zip(&vals) {
+            assert!((*a - *b).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_encoding_evaluates_at_powers_of_five() {
+        // m(ζ^(5^j)) = zⱼ for ζ = e^(iπ/N)
+        let ctx = context();
+        let z: Vec<Complex> = (0..8).map(|i| Complex::new(1.0 + i as f64, 0.5)).collect();
+        let pt = ctx.encode(&z, 2f64.powi(30), 1);
+        let coeffs: Vec<f64> = pt
+            .poly
+            .to_centered_coefficients()
+            .iter()
+            .map(|c| i1024_to_f64(c) / pt.scale)
+            .collect();
+        for (j, zj) in z.iter().enumerate() {
+            let root = Complex::from_angle(PI * ctx.rot_group[j] as f64 / 16.0);
+            let mut acc = Complex::default();
+            let mut pow = Complex::from(1.0);
+            for &c in &coeffs {
+                acc = acc + pow * Complex::from(c);
+                pow = pow * root;
+            }
+            assert!((acc - *zj).abs() < 1e-6, "slot {j}");
+        }
+    }
+
+    #[test]
+    fn test_scale_must_be_below_first_prime() {
+        let params = CkksParams {
+            n: 16,
+            first_prime_bits: 40,
+            scale_bits: 40,
+            levels: 2,
+            eta: 2,
+        };
+        assert_eq!(
+            CkksContext::new(params).unwrap_err(),
+            CkksError::ScaleTooLarge { scale_bits: 40 }
+        );
+    }
+
+    #[test]
+    fn test_invalid_prime_sizes_rejected() {
+        let params = CkksParams {
+            n: 16,
+            first_prime_bits: 63,
+            scale_bits: 30,
+            levels: 1,
+            eta: 2,
+        };
+        assert_eq!(
+            CkksContext::new(params).unwrap_err(),
+            CkksError::Rns(RnsError::InvalidPrimeBits { bits: 63 })
+        );
+        let params = CkksParams {
+            first_prime_bits: 40,
+            scale_bits: 0,
+            ..params
+        };
+        assert_eq!(
+            CkksContext::new(params).unwrap_err(),
+            CkksError::Rns(RnsError::InvalidPrimeBits { bits: 0 })
+        );
+    }
+}
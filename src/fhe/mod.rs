@@ -0,0 +1,24 @@
+//! Fully homomorphic encryption schemes over RNS ring elements.
+//!
+//! This module provides:
+//! - `bfv` - the BFV scheme for exact arithmetic modulo a plaintext modulus t
+//! - `ckks` - the CKKS scheme for approximate arithmetic on complex slots
+//! - `sampling` - RNG-driven secret, error and uniform samplers for
+//!   [`RnsRingElement`](crate::ring::rns::RnsRingElement)
+//!
+//! The schemes take any [`rand::Rng`], so a seeded RNG such as
+//! `rand::rngs::StdRng::seed_from_u64` gives reproducible keys and
+//! ciphertexts. The parameters are not vetted for security; they are sized
+//! for correctness and testing.
+
+pub mod bfv;
+pub mod ckks;
+pub mod sampling;
+
+pub use bfv::{
+    BfvCiphertext, BfvContext, BfvError, BfvParams, BfvPublicKey, BfvRelinKey, BfvSecretKey,
+};
+pub use ckks::{
+    CkksCiphertext, CkksContext, CkksError, CkksGaloisKeys, CkksParams, CkksPlaintext,
+    CkksPublicKey, CkksRelinKey, CkksSecretKey, Complex,
+};
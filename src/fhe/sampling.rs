@@ -0,0 +1,48 @@
+//! RNG-driven samplers for RNS ring elements.
+//!
+//! Unlike the seed-expanding samplers in [`crate::ring::sampling`], these draw
+//! directly from a caller-supplied [`Rng`]. Every sampler returns an element
+//! in coefficient form at the requested level.
+
+use std::sync::Arc;
+
+use rand::Rng;
+
+use crate::ring::RingElementState;
+use crate::ring::rns::{RnsBasis, RnsRingElement};
+
+/// Samples an element with coefficients uniform in Z_Q.
+pub fn sample_uniform<R: Rng>(rng: &mut R, basis: &Arc<RnsBasis>, level: usize) -> RnsRingElement {
+    let n = basis.degree();
+    let limbs = basis.moduli()[..level]
+        .iter()
+        .map(|&q| (0..n).map(|_| rng.random_range(0..q)).collect())
+        .collect();
+    RnsRingElement::from_limbs(limbs, RingElementState::Coefficient, basis.clone())
+}
+
+/// Samples an element with coefficients uniform in {-1, 0, 1}.
+pub fn sample_ternary<R: Rng>(rng: &mut R, basis: &Arc<RnsBasis>, level: usize) -> RnsRingElement {
+    let coeffs: Vec<i64> = (0..basis.degree())
+        .map(|_| rng.random_range(-1..=1))
+        .collect();
+    RnsRingElement::from_signed(&coeffs, basis.clone(), level)
+}
+
+/// Samples an element from the centered binomial distribution with
+/// parameter η: each coefficient is a difference of two sums of η bits.
+pub fn sample_cbd<R: Rng>(
+    rng: &mut R,
+    basis: &Arc<RnsBasis>,
+    level: usize,
+    eta: usize,
+) -> RnsRingElement {
+    let coeffs: Vec<i64> = (0..basis.degree())
+        .map(|_| {
+            let a: i64 = (0..eta).map(|_| rng.random_range(0..=1i64)).sum();
+            let b: i64 = (0..eta).map(|_| rng.random_range(0..=1i64)).sum();
+            a - b
+        })
+        .collect();
+    RnsRingElement::from_signed(&coeffs, basis.clone(), level)
+}
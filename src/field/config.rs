@@ -22,9 +22,13 @@ pub trait FieldConfig:
     /// The Montgomery constant n' satisfying P * n' ≡ -1 (mod 2^1024).
     const N_PRIME: U1024;
 
-    /// A primitive root of unity in the field.
+    /// A primitive root of unity in the field, of order 2^TWO_ADICITY.
     const ROOT_OF_UNITY: U1024;
 
+    /// log2 of the order of `ROOT_OF_UNITY`; bounds the cyclic NTT size.
+    /// Defaults to 32; `#[derive(FieldConfig)]` computes it from the root.
+    const TWO_ADICITY: u32 = 32;
+
     /// Primitive 2Nth root of unity (ψ) for Negacyclic NTT.
     /// Must satisfy ψ^N ≡ -1 (mod MODULUS).
     /// Defaults to ROOT_OF_UNITY for backward compatibility.
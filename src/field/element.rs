@@ -2,7 +2,7 @@ use std::fmt;
 use std::marker::PhantomData;
 use std::ops::{Add, Mul, Neg, Sub};
 
-use crate::{BigInt, FieldConfig, U1024};
+use crate::{BigInt, FieldConfig, I1024, U1024};
 
 /// Macro to create a FieldElement from different sources.
 ///
@@ -147,6 +147,37 @@ impl<C: FieldConfig> FieldElement<C> {
         C::to_montgomery_context().reduce(&self.value, &U1024::ZERO)
     }
 
+    /// Returns the magnitude and sign (`true` if negative) of the centered
+    /// representative in (-q/2, q/2], without branching on the value.
+    pub(crate) fn centered_parts(&self) -> (U1024, bool) {
+        let q = C::MODULUS;
+        let x = self.to_u1024();
+        // x > ⌊q/2⌋ exactly when ⌊q/2⌋ - x borrows.
+        let (_, negative) = q.shr(1).borrowing_sub(&x);
+        (U1024::conditional_select(&(q - x), &x, negative), negative)
+    }
+
+    /// Returns the centered representative in (-q/2, q/2].
+    pub fn to_centered_i1024(&self) -> I1024 {
+        let (magnitude, negative) = self.centered_parts();
+        I1024::new(magnitude, !negative)
+    }
+
+    /// Returns the centered representative in (-q/2, q/2] as an `i64`.
+    ///
+    /// # Panics
+    /// Panics if the modulus does not fit in 64 bits.
+    pub fn to_centered_i64(&self) -> i64 {
+        assert!(
+            C::MODULUS.bits() <= 64,
+            "Centered representative only fits in i64 for moduli below 2^64"
+        );
+        let (magnitude, negative) = self.centered_parts();
+        // |x| ≤ q/2 < 2^63; conditionally negate without branching.
+        let sign = negative as i64;
+        ((magnitude.0[0] as i64) ^ -sign) + sign
+    }
+
     /// Internal const reduction function logic.
     const fn const_reduce(lo: &U1024, hi: &U1024) -> U1024 {
         // m = (lo * n_prime) mod R (take lower 1024 bits)
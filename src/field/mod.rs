@@ -1,3 +1,4 @@
+pub mod complex;
 pub mod config;
 pub mod element;
 pub mod montgomery;
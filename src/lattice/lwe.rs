@@ -0,0 +1,250 @@
+//! Regev-style LWE public-key encryption of single bits.
+//!
+//! - **Key generation**: a uniform secret s ∈ Z_q^n, a uniform matrix
+//!   A ∈ Z_q^(m×n) and b = A·s + e for a centered binomial error e ∈ Z_q^m.
+//! - **Encryption**: for a uniform r ∈ {0, 1}^m, the bit μ is encrypted as
+//!   (u, v) = (Aᵀ·r, ⟨b, r⟩ + μ·⌊q/2⌋).
+//! - **Decryption**: v − ⟨u, s⟩ = μ·⌊q/2⌋ + ⟨e, r⟩ is rounded to the nearer
+//!   of 0 and ⌊q/2⌋.
+//!
+//! A fresh ciphertext has error |⟨e, r⟩| ≤ m·η. Adding ciphertexts adds
+//! their bits modulo 2 and their errors, plus 1 from 2·⌊q/2⌋ ≡ −1 for odd q.
+//!
+//! # Example
+//!
+//! ```
+//! use rand::SeedableRng;
+//! use rand::rngs::StdRng;
+//! use lumen_math::DefaultFieldConfig;
+//! use lumen_math::lattice::lwe::{LweContext, LweParams};
+//!
+//! let params = LweParams { n: 16, m: 32, eta: 2 };
+//! let ctx = LweContext::<DefaultFieldConfig>::new(params).unwrap();
+//! let mut rng = StdRng::seed_from_u64(1);
+//!
+//! let (sk, pk) = ctx.keygen(&mut rng);
+//! let a = ctx.encrypt(&pk, true, &mut rng);
+//! let b = ctx.encrypt(&pk, true, &mut rng);
+//! assert!(ctx.decrypt(&sk, &a));
+//! assert!(!ctx.decrypt(&sk, &(&a + &b)));
+//! ```
+
+use std::marker::PhantomData;
+use std::ops::Add;
+
+use rand::Rng;
+
+use super::{
+    LatticeError, check_noise_bound, decode_bit, encode_bit, sample_field_cbd, sample_field_uniform,
+};
+use crate::{FieldConfig, FieldElement, U1024};
+
+/// An LWE parameter set.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LweParams {
+    /// Secret dimension n.
+    pub n: usize,
+    /// Number of samples m in the public key.
+    pub m: usize,
+    /// Centered binomial parameter for the error distribution.
+    pub eta: usize,
+}
+
+/// An LWE secret key s ∈ Z_q^n.
+#[derive(Clone, Debug)]
+pub struct LweSecretKey<C: FieldConfig> {
+    s: Vec<FieldElement<C>>,
+}
+
+/// An LWE public key (A, b = A·s + e), with A stored row by row.
+#[derive(Clone, Debug)]
+pub struct LwePublicKey<C: FieldConfig> {
+    a: Vec<Vec<FieldElement<C>>>,
+    b: Vec<FieldElement<C>>,
+}
+
+/// An LWE ciphertext (u, v) of a single bit.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LweCiphertext<C: FieldConfig> {
+    u: Vec<FieldElement<C>>,
+    v: FieldElement<C>,
+    noise_bound: u64,
+}
+
+impl<C: FieldConfig> LweCiphertext<C> {
+    /// Returns the mask u ∈ Z_q^n.
+    pub fn u(&self) -> &[FieldElement<C>] {
+        &self.u
+    }
+
+    /// Returns the body v.
+    pub fn v(&self) -> &FieldElement<C> {
+        &self.v
+    }
+
+    /// Returns a worst-case bound on the error, tracked through additions.
+    pub fn noise_bound(&self) -> u64 {
+        self.noise_bound
+    }
+}
+
+impl<C: FieldConfig> Add for &LweCiphertext<C> {
+    type Output = LweCiphertext<C>;
+
+    /// Adds the encrypted bits modulo 2.
+    fn add(self, rhs: &LweCiphertext<C>) -> LweCiphertext<C> {
+        assert_eq!(
+            self.u.len(),
+            rhs.u.len(),
+            "LWE ciphertexts must have the same dimension"
+        );
+        LweCiphertext {
+            u: self.u.iter().zip(&rhs.u).map(|(a, b)| *a + *b).collect(),
+            v: self.v + rhs.v,
+            noise_bound: self
+                .noise_bound
+                .saturating_add(rhs.noise_bound)
+                .saturating_add(1),
+        }
+    }
+}
+
+/// Regev encryption for a fixed parameter set.
+#[derive(Clone, Debug)]
+pub struct LweContext<C: FieldConfig> {
+    params: LweParams,
+    _marker: PhantomData<C>,
+}
+
+impl<C: FieldConfig> LweContext<C> {
+    /// Creates a context, checking that fresh ciphertexts decrypt correctly.
+    pub fn new(params: LweParams) -> Result<Self, LatticeError> {
+        if params.n == 0 || params.m == 0 {
+            return Err(LatticeError::InvalidDimension);
+        }
+        check_noise_bound::<C>(Self::fresh_bound(&params))?;
+        Ok(Self {
+            params,
+            _marker: PhantomData,
+        })
+    }
+
+    fn fresh_bound(params: &LweParams) -> u64 {
+        (params.m as u64).saturating_mul(params.eta as u64)
+    }
+
+    /// Returns the parameter set.
+    pub fn params(&self) -> &LweParams {
+        &self.params
+    }
+
+    /// Generates a secret key and the matching public key.
+    pub fn keygen<R: Rng>(&self, rng: &mut R) -> (LweSecretKey<C>, LwePublicKey<C>) {
+        let LweParams { n, m, eta } = self.params;
+        let s: Vec<FieldElement<C>> = (0..n).map(|_| sample_field_uniform(rng)).collect();
+        let a: Vec<Vec<FieldElement<C>>> = (0..m)
+            .map(|_| (0..n).map(|_| sample_field_uniform(rng)).collect())
+            .collect();
+        let b = a
+            .iter()
+            .map(|row| inner_product(row, &s) + sample_field_cbd(rng, eta))
+            .collect();
+        (LweSecretKey { s }, LwePublicKey { a, b })
+    }
+
+    /// Encrypts a single bit.
+    pub fn encrypt<R: Rng>(
+        &self,
+        pk: &LwePublicKey<C>,
+        bit: bool,
+        rng: &mut R,
+    ) -> LweCiphertext<C> {
+        let mut u = vec![FieldElement::zero(); self.params.n];
+        let mut v = encode_bit(bit);
+        for (row, b) in pk.a.iter().zip(&pk.b) {
+            if rng.random::<bool>() {
+                for (ui, aij) in u.iter_mut().zip(row) {
+                    *ui = *ui + *aij;
+                }
+                v = v + *b;
+            }
+        }
+        LweCiphertext {
+            u,
+            v,
+            noise_bound: Self::fresh_bound(&self.params),
+        }
+    }
+
+    /// Encrypts a message vector bit by bit.
+    pub fn encrypt_bits<R: Rng>(
+        &self,
+        pk: &LwePublicKey<C>,
+        bits: &[bool],
+        rng: &mut R,
+    ) -> Vec<LweCiphertext<C>> {
+        bits.iter().map(|&bit| self.encrypt(pk, bit, rng)).collect()
+    }
+
+    /// Decrypts a single bit.
+    pub fn decrypt(&self, sk: &LweSecretKey<C>, ct: &LweCiphertext<C>) -> bool {
+        decode_bit(&self.phase(sk, ct))
+    }
+
+    /// Decrypts a message vector bit by bit.
+    pub fn decrypt_bits(&self, sk: &LweSecretKey<C>, cts: &[LweCiphertext<C>]) -> Vec<bool> {
+        cts.iter().map(|ct| self.decrypt(sk, ct)).collect()
+    }
+
+    /// Returns the magnitude of the actual error of `ct`, given the bit it
+    /// is meant to encrypt. Decryption is guaranteed while this stays below
+    /// [`noise_threshold`](super::noise_threshold) and fails once it exceeds it.
+    pub fn noise(&self, sk: &LweSecretKey<C>, ct: &LweCiphertext<C>, bit: bool) -> U1024 {
+        let (magnitude, _) = (self.phase(sk, ct) - encode_bit(bit)).centered_parts();
+        magnitude
+    }
+
+    /// Returns v − ⟨u, s⟩ = μ·⌊q/2⌋ + error.
+    fn phase(&self, sk: &LweSecretKey<C>, ct: &LweCiphertext<C>) -> FieldElement<C> {
+        ct.v - inner_product(&ct.u, &sk.s)
+    }
+}
+
+fn inner_product<C: FieldConfig>(a: &[FieldElement<C>], b: &[FieldElement<C>]) -> FieldElement<C> {
+    a.iter()
+        .zip(b)
+        .fold(FieldElement::zero(), |acc, (x, y)| acc + *x * *y)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DefaultFieldConfig;
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+
+    #[test]
+    fn test_rejects_invalid_params() {
+        let empty = LweParams { n: 0, m: 4, eta: 2 };
+        assert_eq!(
+            LweContext::<DefaultFieldConfig>::new(empty).unwrap_err(),
+            LatticeError::InvalidDimension
+        );
+    }
+
+    #[test]
+    fn test_fresh_noise_within_bound() {
+        let params = LweParams {
+            n: 8,
+            m: 16,
+            eta: 2,
+        };
+        let ctx = LweContext::<DefaultFieldConfig>::new(params).unwrap();
+        let mut rng = StdRng::seed_from_u64(3);
+        let (sk, pk) = ctx.keygen(&mut rng);
+        for bit in [false, true] {
+            let ct = ctx.encrypt(&pk, bit, &mut rng);
+            assert!(ctx.noise(&sk, &ct, bit) <= U1024::from_u64(ct.noise_bound()));
+        }
+    }
+}
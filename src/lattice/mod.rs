@@ -0,0 +1,109 @@
+//! Textbook lattice schemes and NTRU key generation.
+//!
+//! This module provides:
+//! - `lwe` - Regev public-key encryption of bits over vectors of [`FieldElement`]
+//! - `ntru` - NTRUSolve for f·G − g·F = q, as used by Falcon key generation
+//! - `rlwe` - Ring-LWE public-key encryption of bit vectors over [`RingElement`]
+//!
+//! The LWE and RLWE schemes encode a bit μ as μ · ⌊q/2⌋ and decrypt by rounding, so a
+//! ciphertext decrypts correctly as long as its error stays below q/4.
+//! Ciphertexts carry a worst-case bound on their error that grows under
+//! homomorphic addition, and the schemes can measure the actual error with
+//! the secret key. They are meant as a correct, readable baseline; the
+//! parameters are not vetted for security.
+//!
+//! [`RingElement`]: crate::ring::RingElement
+
+pub mod lwe;
+pub mod ntru;
+pub mod rlwe;
+
+use std::fmt;
+
+use rand::Rng;
+
+use crate::{FieldConfig, FieldElement, U1024};
+
+pub use lwe::{LweCiphertext, LweContext, LweParams, LwePublicKey, LweSecretKey};
+pub use ntru::{NtruError, ntru_solve};
+pub use rlwe::{RlweCiphertext, RlweContext, RlwePublicKey, RlweSecretKey};
+
+/// Error type for LWE and RLWE parameter validation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LatticeError {
+    /// The dimension or number of samples is zero.
+    InvalidDimension,
+    /// The error of a fresh ciphertext can reach q/4.
+    NoiseTooLarge { bound: u64 },
+}
+
+impl fmt::Display for LatticeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LatticeError::InvalidDimension => write!(f, "Dimensions must be positive"),
+            LatticeError::NoiseTooLarge { bound } => {
+                write!(f, "Fresh noise bound {} is not below q/4", bound)
+            }
+        }
+    }
+}
+
+impl std::error::Error for LatticeError {}
+
+/// Returns ⌊q/4⌋, the largest error that still guarantees decryption.
+pub fn noise_threshold<C: FieldConfig>() -> U1024 {
+    C::MODULUS.shr(2)
+}
+
+/// Checks that a fresh error bound stays below q/4.
+fn check_noise_bound<C: FieldConfig>(bound: u64) -> Result<(), LatticeError> {
+    if U1024::from_u64(bound) < noise_threshold::<C>() {
+        Ok(())
+    } else {
+        Err(LatticeError::NoiseTooLarge { bound })
+    }
+}
+
+/// Returns the encoding μ · ⌊q/2⌋ of a bit.
+fn encode_bit<C: FieldConfig>(bit: bool) -> FieldElement<C> {
+    if bit {
+        FieldElement::new(C::MODULUS.shr(1))
+    } else {
+        FieldElement::zero()
+    }
+}
+
+/// Decodes x to 1 if its centered representative exceeds q/4 in magnitude.
+fn decode_bit<C: FieldConfig>(x: &FieldElement<C>) -> bool {
+    let (magnitude, _) = x.centered_parts();
+    magnitude > noise_threshold::<C>()
+}
+
+/// Samples a field element uniformly by rejection.
+fn sample_field_uniform<C: FieldConfig, R: Rng>(rng: &mut R) -> FieldElement<C> {
+    let bits = C::MODULUS.bits();
+    let limbs = bits.div_ceil(64);
+    let top_mask = if bits.is_multiple_of(64) {
+        u64::MAX
+    } else {
+        (1u64 << (bits % 64)) - 1
+    };
+    loop {
+        let mut x = U1024::ZERO;
+        for limb in x.0.iter_mut().take(limbs) {
+            *limb = rng.random();
+        }
+        x.0[limbs - 1] &= top_mask;
+        if x < C::MODULUS {
+            return FieldElement::new(x);
+        }
+    }
+}
+
+/// Samples a field element from the centered binomial distribution with
+/// parameter η.
+fn sample_field_cbd<C: FieldConfig, R: Rng>(rng: &mut R, eta: usize) -> FieldElement<C> {
+    let a = (0..eta).filter(|_| rng.random::<bool>()).count() as u64;
+    let b = (0..eta).filter(|_| rng.random::<bool>()).count() as u64;
+    FieldElement::new(U1024::from_u64(a)) - FieldElement::new(U1024::from_u64(b))
+}
@@ -0,0 +1,355 @@
+//! Solving the NTRU equation for Falcon-style key generation.
+//!
+//! Given small f, g ∈ Z[X]/(X^N + 1), [`ntru_solve`] finds small F, G with
+//!
+//! f·G − g·F = q
+//!
+//! following NTRUSolve from the Falcon specification:
+//!
+//! - **Field norms**: writing f(X) = f₀(X²) + X·f₁(X²), the norm
+//!   N(f) = f₀² − X·f₁² ∈ Z[X]/(X^(N/2) + 1) satisfies N(f)(X²) = f(X)·f(−X).
+//!   Recursing on (N(f), N(g)) down to N = 1 leaves the resultants of f and
+//!   g with X^N + 1, where the extended Euclidean algorithm gives
+//!   u·f + v·g = 1 and so (F, G) = (−v·q, u·q).
+//! - **Lifting**: a solution (F', G') for (N(f), N(g)) lifts to
+//!   F = F'(X²)·g(−X), G = G'(X²)·f(−X).
+//! - **Babai reduction**: F and G are reduced by k·f and k·g for
+//!   k ≈ (F·f* + G·g*) / (f·f* + g·g*), where f* is the adjoint
+//!   f(1/X). The quotient is computed with `f64` approximations of the
+//!   leading bits in the canonical embedding, so each round removes about
+//!   25 bits until the last one rounds exactly.
+//!
+//! For Falcon-512 the resultants at the bottom of the recursion reach
+//! several thousand bits, beyond [`I1024`], so the recursion runs on
+//! arbitrary-precision integers and only the inputs and the reduced
+//! solution are [`I1024`] coefficient vectors.
+//!
+//! # Example
+//!
+//! ```
+//! use lumen_math::I1024;
+//! use lumen_math::lattice::ntru::{NtruError, ntru_solve};
+//!
+//! let poly = |c: [i64; 4]| c.map(I1024::from).to_vec();
+//!
+//! // Res(f) = 4 and Res(g) = 121 are coprime.
+//! let (big_f, big_g) = ntru_solve(&poly([2, 1, 0, -1]), &poly([1, 3, -1, 0]), 12289).unwrap();
+//! assert_eq!((big_f.len(), big_g.len()), (4, 4));
+//!
+//! // Res(f) = 153 and Res(g) = 17 share the factor 17.
+//! let err = ntru_solve(&poly([3, 1, -1, 2]), &poly([1, -2, 1, 1]), 12289);
+//! assert_eq!(err, Err(NtruError::NotCoprime));
+//! ```
+
+use std::f64::consts::PI;
+use std::fmt;
+
+use num_bigint::{BigInt, BigUint, Sign};
+use num_integer::Integer;
+
+use crate::field::complex::Complex;
+use crate::{I1024, U1024};
+
+/// Number of bits of the Babai quotient recovered per reduction round.
+const REDUCTION_BITS: i64 = 25;
+
+/// Number of leading bits kept in the `f64` approximations.
+const APPROX_BITS: u64 = 52;
+
+/// Error type for [`ntru_solve`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NtruError {
+    /// The degree is not a power of two.
+    InvalidDegree { n: usize },
+    /// f and g have different lengths.
+    LengthMismatch { f: usize, g: usize },
+    /// The resultants of f and g with X^N + 1 are not coprime.
+    NotCoprime,
+    /// The reduced solution does not fit in 1024-bit coefficients.
+    Overflow,
+}
+
+impl fmt::Display for NtruError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NtruError::InvalidDegree { n } => write!(f, "Degree {} is not a power of two", n),
+            NtruError::LengthMismatch { f: lf, g: lg } => {
+                write!(f, "f has {} coefficients but g has {}", lf, lg)
+            }
+            NtruError::NotCoprime => write!(f, "Resultants of f and g are not coprime"),
+            NtruError::Overflow => write!(f, "Solution does not fit in 1024-bit coefficients"),
+        }
+    }
+}
+
+impl std::error::Error for NtruError {}
+
+/// Solves f·G − g·F = q in Z[X]/(X^N + 1) and returns the reduced (F, G).
+///
+/// `f` and `g` hold the N coefficients of f and g, lowest degree first.
+pub fn ntru_solve(f: &[I1024], g: &[I1024], q: u64) -> Result<(Vec<I1024>, Vec<I1024>), NtruError> {
+    let n = f.len();
+    if !n.is_power_of_two() {
+        return Err(NtruError::InvalidDegree { n });
+    }
+    if g.len() != n {
+        return Err(NtruError::LengthMismatch { f: n, g: g.len() });
+    }
+    let f: Vec<BigInt> = f.iter().map(to_bigint).collect();
+    let g: Vec<BigInt> = g.iter().map(to_bigint).collect();
+    let (big_f, big_g) = solve(&f, &g, &BigInt::from(q))?;
+    let convert = |p: Vec<BigInt>| p.iter().map(from_bigint).collect::<Option<Vec<_>>>();
+    match (convert(big_f), convert(big_g)) {
+        (Some(big_f), Some(big_g)) => Ok((big_f, big_g)),
+        _ => Err(NtruError::Overflow),
+    }
+}
+
+fn solve(f: &[BigInt], g: &[BigInt], q: &BigInt) -> Result<(Vec<BigInt>, Vec<BigInt>), NtruError> {
+    if f.len() == 1 {
+        let e = f[0].extended_gcd(&g[0]);
+        if e.gcd != BigInt::from(1) {
+            return Err(NtruError::NotCoprime);
+        }
+        // u·f + v·g = 1, so f·(u·q) − g·(−v·q) = q.
+        return Ok((vec![-(e.y * q)], vec![e.x * q]));
+    }
+
+    let (big_f, big_g) = solve(&field_norm(f), &field_norm(g), q)?;
+    let mut big_f = mul(&lift(&big_f), &galois_conjugate(g));
+    let mut big_g = mul(&lift(&big_g), &galois_conjugate(f));
+    reduce(f, g, &mut big_f, &mut big_g);
+    Ok((big_f, big_g))
+}
+
+/// Returns N(f) = f₀² − X·f₁² for f(X) = f₀(X²) + X·f₁(X²).
+fn field_norm(f: &[BigInt]) -> Vec<BigInt> {
+    let f0: Vec<BigInt> = f.iter().step_by(2).cloned().collect();
+    let f1: Vec<BigInt> = f.iter().skip(1).step_by(2).cloned().collect();
+    let even = mul(&f0, &f0);
+    let odd = mul(&f1, &f1);
+    let h = odd.len();
+    // X·f₁² rotates by one with the wrapped coefficient negated.
+    even.iter()
+        .enumerate()
+        .map(|(i, e)| {
+            if i == 0 {
+                e + &odd[h - 1]
+            } else {
+                e - &odd[i - 1]
+            }
+        })
+        .collect()
+}
+
+/// Returns a(X²) in the ring of twice the degree.
+fn lift(a: &[BigInt]) -> Vec<BigInt> {
+    let mut out = vec![BigInt::ZERO; 2 * a.len()];
+    for (i, c) in a.iter().enumerate() {
+        out[2 * i] = c.clone();
+    }
+    out
+}
+
+/// Returns a(−X).
+fn galois_conjugate(a: &[BigInt]) -> Vec<BigInt> {
+    a.iter()
+        .enumerate()
+        .map(|(i, c)| if i % 2 == 0 { c.clone() } else { -c })
+        .collect()
+}
+
+/// Multiplies in Z[X]/(X^n + 1).
+fn mul(a: &[BigInt], b: &[BigInt]) -> Vec<BigInt> {
+    let n = a.len();
+    let mut out = vec![BigInt::ZERO; n];
+    for (i, x) in a.iter().enumerate() {
+        if x.sign() == Sign::NoSign {
+            continue;
+        }
+        for (j, y) in b.iter().enumerate() {
+            if i + j < n {
+                out[i + j] += x * y;
+            } else {
+                out[i + j - n] -= x * y;
+            }
+        }
+    }
+    out
+}
+
+/// Reduces (F, G) against (f, g) with Babai's round-off algorithm.
+fn reduce(f: &[BigInt], g: &[BigInt], big_f: &mut [BigInt], big_g: &mut [BigInt]) {
+    let n = f.len();
+    let roots: Vec<Complex> = (0..2 * n)
+        .map(|t| Complex::from_angle(PI * t as f64 / n as f64))
+        .collect();
+
+    let shift_fg = max_bits(f, g).saturating_sub(APPROX_BITS);
+    let f_fft = embed(&approximate(f, shift_fg), &roots);
+    let g_fft = embed(&approximate(g, shift_fg), &roots);
+    let inv_norm: Vec<f64> = f_fft
+        .iter()
+        .zip(&g_fft)
+        .map(|(a, b)| 1.0 / (a.re * a.re + a.im * a.im + b.re * b.re + b.im * b.im))
+        .collect();
+
+    loop {
+        let shift_big = max_bits(big_f, big_g).saturating_sub(APPROX_BITS);
+        let big_f_fft = embed(&approximate(big_f, shift_big), &roots);
+        let big_g_fft = embed(&approximate(big_g, shift_big), &roots);
+        let quotient: Vec<Complex> = (0..n)
+            .map(|j| {
+                let num = big_f_fft[j] * f_fft[j].conj() + big_g_fft[j] * g_fft[j].conj();
+                num * Complex::from(inv_norm[j])
+            })
+            .collect();
+
+        // The exact quotient is `quotient`·2^(shift_big − shift_fg); keep at
+        // most REDUCTION_BITS of it and scale the correction back up.
+        let diff = shift_big as i64 - shift_fg as i64;
+        let shift = (diff - REDUCTION_BITS).max(0);
+        let scale = 2f64.powi((diff - shift) as i32);
+        let k: Vec<i64> = unembed(&quotient, &roots)
+            .iter()
+            .map(|x| (x * scale).round() as i64)
+            .collect();
+        if k.iter().all(|&x| x == 0) {
+            break;
+        }
+
+        let kf = mul_small(&k, f);
+        let kg = mul_small(&k, g);
+        for i in 0..n {
+            big_f[i] -= &kf[i] << shift;
+            big_g[i] -= &kg[i] << shift;
+        }
+        if shift == 0 {
+            break;
+        }
+    }
+}
+
+/// Multiplies a small polynomial by a big one in Z[X]/(X^n + 1).
+fn mul_small(k: &[i64], a: &[BigInt]) -> Vec<BigInt> {
+    let n = a.len();
+    let mut out = vec![BigInt::ZERO; n];
+    for (i, &x) in k.iter().enumerate() {
+        if x == 0 {
+            continue;
+        }
+        for (j, y) in a.iter().enumerate() {
+            if i + j < n {
+                out[i + j] += y * x;
+            } else {
+                out[i + j - n] -= y * x;
+            }
+        }
+    }
+    out
+}
+
+fn max_bits(a: &[BigInt], b: &[BigInt]) -> u64 {
+    a.iter().chain(b).map(BigInt::bits).max().unwrap_or(0)
+}
+
+/// Returns the coefficients divided by 2^shift as `f64`.
+fn approximate(a: &[BigInt], shift: u64) -> Vec<f64> {
+    a.iter()
+        .map(|c| i64::try_from(c >> shift).expect("shifted coefficient fits in 53 bits") as f64)
+        .collect()
+}
+
+/// Evaluates a at the primitive 2n-th roots ζ_j = e^(iπ(2j+1)/n).
+fn embed(a: &[f64], roots: &[Complex]) -> Vec<Complex> {
+    let n = a.len();
+    (0..n)
+        .map(|j| {
+            a.iter()
+                .enumerate()
+                .fold(Complex::default(), |acc, (i, &c)| {
+                    acc + roots[((2 * j + 1) * i) % (2 * n)] * Complex::from(c)
+                })
+        })
+        .collect()
+}
+
+/// Inverts [`embed`] and returns the real coefficients.
+fn unembed(values: &[Complex], roots: &[Complex]) -> Vec<f64> {
+    let n = values.len();
+    (0..n)
+        .map(|i| {
+            let sum = values.iter().enumerate().fold(0.0, |acc, (j, v)| {
+                let root = roots[(2 * n - ((2 * j + 1) * i) % (2 * n)) % (2 * n)];
+                acc + (*v * root).re
+            });
+            sum / n as f64
+        })
+        .collect()
+}
+
+fn to_bigint(x: &I1024) -> BigInt {
+    let digits = x
+        .magnitude()
+        .0
+        .iter()
+        .flat_map(|&limb| [limb as u32, (limb >> 32) as u32])
+        .collect();
+    let sign = if x.is_negative() {
+        Sign::Minus
+    } else {
+        Sign::Plus
+    };
+    BigInt::from_biguint(sign, BigUint::new(digits))
+}
+
+fn from_bigint(x: &BigInt) -> Option<I1024> {
+    let (sign, digits) = x.to_u64_digits();
+    let mut magnitude = U1024::ZERO;
+    if digits.len() > magnitude.0.len() {
+        return None;
+    }
+    magnitude.0[..digits.len()].copy_from_slice(&digits);
+    Some(I1024::new(magnitude, sign != Sign::Minus))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn poly(coeffs: &[i64]) -> Vec<BigInt> {
+        coeffs.iter().map(|&c| BigInt::from(c)).collect()
+    }
+
+    #[test]
+    fn test_field_norm_matches_definition() {
+        // N(f)(X²) = f(X)·f(−X)
+        let f = poly(&[3, -1, 4, 1, -5, 9, 2, -6]);
+        let lhs = lift(&field_norm(&f));
+        let rhs = mul(&f, &galois_conjugate(&f));
+        assert_eq!(lhs, rhs);
+    }
+
+    #[test]
+    fn test_bigint_roundtrip() {
+        for v in [0i64, 1, -1, i64::MAX, i64::MIN + 1] {
+            let x = I1024::from(v);
+            assert_eq!(from_bigint(&to_bigint(&x)), Some(x));
+        }
+        let big = BigInt::from(1) << 1100;
+        assert_eq!(from_bigint(&big), None);
+    }
+
+    #[test]
+    fn test_embedding_roundtrip() {
+        let n = 8;
+        let roots: Vec<Complex> = (0..2 * n)
+            .map(|t| Complex::from_angle(PI * t as f64 / n as f64))
+            .collect();
+        let a = [1.0, -2.0, 3.5, 0.0, 7.0, -1.0, 0.25, 4.0];
+        let back = unembed(&embed(&a, &roots), &roots);
+        for (x, y) in a.iter().zip(&back) {
+            assert!((x - y).abs() < 1e-9);
+        }
+    }
+}
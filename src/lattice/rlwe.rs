@@ -0,0 +1,212 @@
+//! Ring-LWE public-key encryption of bit vectors.
+//!
+//! Messages are up to N bits, one per coefficient of Rq = Zq[X]/(X^N + 1).
+//!
+//! - **Key generation**: a small secret s, a uniform a and
+//!   (b, a) = (−(a·s + e), a), with s and e centered binomial.
+//! - **Encryption**: (c₀, c₁) = (b·u + e₁ + ⌊q/2⌋·m, a·u + e₂) for small u,
+//!   e₁ and e₂.
+//! - **Decryption**: c₀ + c₁·s = ⌊q/2⌋·m − e·u + e₁ + e₂·s is rounded
+//!   coefficient-wise to the nearer of 0 and ⌊q/2⌋.
+//!
+//! A fresh ciphertext has error at most 2·N·η² + η in every coefficient.
+//! Adding ciphertexts adds their messages modulo 2 and their errors, plus 1
+//! from 2·⌊q/2⌋ ≡ −1 for odd q.
+//!
+//! # Example
+//!
+//! ```
+//! use std::sync::Arc;
+//! use rand::SeedableRng;
+//! use rand::rngs::StdRng;
+//! use lumen_math::{FieldConfig, NttContext};
+//! use lumen_math::lattice::rlwe::RlweContext;
+//!
+//! /// q = 8380417 with a primitive 512th root of unity.
+//! #[derive(FieldConfig, Clone, Copy, Debug, Default, PartialEq, Eq)]
+//! #[modulus = "0x7fe001"]
+//! #[root = "0x6d9"]
+//! struct Q;
+//!
+//! let ntt = Arc::new(NttContext::<Q>::new(256));
+//! let ctx = RlweContext::new(ntt, 2).unwrap();
+//! let mut rng = StdRng::seed_from_u64(1);
+//!
+//! let (sk, pk) = ctx.keygen(&mut rng);
+//! let a = ctx.encrypt(&pk, &[true, false, true], &mut rng);
+//! let b = ctx.encrypt(&pk, &[true, true], &mut rng);
+//! let sum = ctx.decrypt(&sk, &(&a + &b));
+//! assert_eq!(&sum[..3], &[false, true, true]);
+//! ```
+
+use std::ops::Add;
+use std::sync::Arc;
+
+use rand::Rng;
+
+use super::{
+    LatticeError, check_noise_bound, decode_bit, encode_bit, sample_field_cbd, sample_field_uniform,
+};
+use crate::poly::ntt::NttContext;
+use crate::ring::RingElement;
+use crate::{FieldConfig, U1024};
+
+/// An RLWE secret key s, stored in NTT form.
+#[derive(Clone, Debug)]
+pub struct RlweSecretKey<C: FieldConfig> {
+    s: RingElement<C>,
+}
+
+/// An RLWE public key (b, a) = (−(a·s + e), a), stored in NTT form.
+#[derive(Clone, Debug)]
+pub struct RlwePublicKey<C: FieldConfig> {
+    b: RingElement<C>,
+    a: RingElement<C>,
+}
+
+/// An RLWE ciphertext (c₀, c₁) of up to N bits.
+#[derive(Clone, Debug)]
+pub struct RlweCiphertext<C: FieldConfig> {
+    c0: RingElement<C>,
+    c1: RingElement<C>,
+    noise_bound: u64,
+}
+
+impl<C: FieldConfig> RlweCiphertext<C> {
+    /// Returns the components (c₀, c₁).
+    pub fn parts(&self) -> (&RingElement<C>, &RingElement<C>) {
+        (&self.c0, &self.c1)
+    }
+
+    /// Returns a worst-case bound on the error of every coefficient,
+    /// tracked through additions.
+    pub fn noise_bound(&self) -> u64 {
+        self.noise_bound
+    }
+}
+
+impl<C: FieldConfig> Add for &RlweCiphertext<C> {
+    type Output = RlweCiphertext<C>;
+
+    /// Adds the encrypted bit vectors modulo 2.
+    fn add(self, rhs: &RlweCiphertext<C>) -> RlweCiphertext<C> {
+        RlweCiphertext {
+            c0: &self.c0 + &rhs.c0,
+            c1: &self.c1 + &rhs.c1,
+            noise_bound: self
+                .noise_bound
+                .saturating_add(rhs.noise_bound)
+                .saturating_add(1),
+        }
+    }
+}
+
+/// Ring-LWE encryption over a fixed ring.
+#[derive(Clone)]
+pub struct RlweContext<C: FieldConfig> {
+    ctx: Arc<NttContext<C>>,
+    eta: usize,
+}
+
+impl<C: FieldConfig> RlweContext<C> {
+    /// Creates a context with centered binomial parameter `eta`, checking
+    /// that fresh ciphertexts decrypt correctly.
+    pub fn new(ctx: Arc<NttContext<C>>, eta: usize) -> Result<Self, LatticeError> {
+        if ctx.n == 0 {
+            return Err(LatticeError::InvalidDimension);
+        }
+        check_noise_bound::<C>(Self::fresh_bound(ctx.n, eta))?;
+        Ok(Self { ctx, eta })
+    }
+
+    fn fresh_bound(n: usize, eta: usize) -> u64 {
+        let eta = eta as u64;
+        (2 * n as u64)
+            .saturating_mul(eta.saturating_mul(eta))
+            .saturating_add(eta)
+    }
+
+    /// Returns the NTT context of the ring.
+    pub fn context(&self) -> &Arc<NttContext<C>> {
+        &self.ctx
+    }
+
+    /// Returns the ring degree N, the number of bits per ciphertext.
+    pub fn degree(&self) -> usize {
+        self.ctx.n
+    }
+
+    /// Generates a secret key and the matching public key.
+    pub fn keygen<R: Rng>(&self, rng: &mut R) -> (RlweSecretKey<C>, RlwePublicKey<C>) {
+        let s = self.small(rng).into_ntt();
+        let a = self.uniform(rng).into_ntt();
+        let e = self.small(rng);
+        let b = -&(&(&a * &s) + &e);
+        (RlweSecretKey { s }, RlwePublicKey { b, a })
+    }
+
+    /// Encrypts up to N bits, one per coefficient; missing bits are zero.
+    ///
+    /// # Panics
+    /// Panics if `bits` has more than N entries.
+    pub fn encrypt<R: Rng>(
+        &self,
+        pk: &RlwePublicKey<C>,
+        bits: &[bool],
+        rng: &mut R,
+    ) -> RlweCiphertext<C> {
+        let n = self.ctx.n;
+        assert!(bits.len() <= n, "At most {} bits fit in one ciphertext", n);
+        let u = self.small(rng).into_ntt();
+        let e1 = self.small(rng);
+        let e2 = self.small(rng);
+        let c0 = &(&(&pk.b * &u) + &e1) + &self.encode(bits);
+        let c1 = &(&pk.a * &u) + &e2;
+        RlweCiphertext {
+            c0,
+            c1,
+            noise_bound: Self::fresh_bound(n, self.eta),
+        }
+    }
+
+    /// Decrypts all N bits of a ciphertext.
+    pub fn decrypt(&self, sk: &RlweSecretKey<C>, ct: &RlweCiphertext<C>) -> Vec<bool> {
+        self.phase(sk, ct)
+            .coefficients()
+            .iter()
+            .map(decode_bit)
+            .collect()
+    }
+
+    /// Returns the largest coefficient magnitude of the actual error of
+    /// `ct`, given the bits it is meant to encrypt. Decryption is
+    /// guaranteed while this stays below
+    /// [`noise_threshold`](super::noise_threshold).
+    pub fn noise(&self, sk: &RlweSecretKey<C>, ct: &RlweCiphertext<C>, bits: &[bool]) -> U1024 {
+        (&self.phase(sk, ct) - &self.encode(bits)).infinity_norm()
+    }
+
+    /// Returns c₀ + c₁·s in coefficient form.
+    fn phase(&self, sk: &RlweSecretKey<C>, ct: &RlweCiphertext<C>) -> RingElement<C> {
+        (&ct.c0 + &(&ct.c1 * &sk.s)).into_coefficient()
+    }
+
+    /// Returns ⌊q/2⌋·m in coefficient form.
+    fn encode(&self, bits: &[bool]) -> RingElement<C> {
+        let mut coeffs: Vec<_> = bits.iter().map(|&bit| encode_bit(bit)).collect();
+        coeffs.resize(self.ctx.n, encode_bit(false));
+        RingElement::new(coeffs, self.ctx.clone())
+    }
+
+    fn small<R: Rng>(&self, rng: &mut R) -> RingElement<C> {
+        let coeffs = (0..self.ctx.n)
+            .map(|_| sample_field_cbd(rng, self.eta))
+            .collect();
+        RingElement::new(coeffs, self.ctx.clone())
+    }
+
+    fn uniform<R: Rng>(&self, rng: &mut R) -> RingElement<C> {
+        let coeffs = (0..self.ctx.n).map(|_| sample_field_uniform(rng)).collect();
+        RingElement::new(coeffs, self.ctx.clone())
+    }
+}
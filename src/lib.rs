@@ -6,11 +6,23 @@
 //! - **Field Arithmetic**: `FieldElement` with Montgomery multiplication
 //! - **Polynomials**: Univariate and multivariate polynomial operations
 //! - **Protocols**: GCD, CRT, and other cryptographic protocols
+//! - **Codes**: Reed–Solomon encoding and decoding
+//! - **Curves**: BN254 with the optimal Ate pairing
+//! - **Commitments**: SHA-256 Merkle trees and KZG polynomial commitments
+//! - **Lattices**: Regev LWE and Ring-LWE encryption, NTRUSolve for Falcon keys
+//! - **Post-Quantum**: ML-KEM key encapsulation and ML-DSA signatures
+//! - **Homomorphic Encryption**: BFV and CKKS over RNS ring elements
 //! - **Traits**: `BigInt`, `Digest` for common operations
 
 pub mod big_int;
+pub mod codes;
+pub mod commit;
+pub mod curve;
+pub mod fhe;
 pub mod field;
+pub mod lattice;
 pub mod poly;
+pub mod pqc;
 pub mod protocol;
 pub mod ring;
 pub mod traits;
@@ -28,7 +40,12 @@ pub use crate::field::{
 };
 
 // Polynomials
-pub use crate::poly::{multivariate::MultivariatePolynomial, ntt::*, univariate::Polynomial};
+pub use crate::poly::{
+    multilinear::{BooleanHypercube, DenseMultilinearExtension},
+    multivariate::MultivariatePolynomial,
+    ntt::*,
+    univariate::Polynomial,
+};
 
 // Lattice-specific configs (deprecated - use small module types instead)
 #[allow(deprecated)]
@@ -40,6 +57,16 @@ pub use crate::poly::ntt::small::{
     KyberFieldElement,
 };
 
+// Dilithium native NTT (FIPS 204)
+pub use crate::poly::ntt::dilithium::{
+    DILITHIUM_ZETAS, dilithium_intt, dilithium_ntt, dilithium_pointwise_mul,
+};
+
+// Kyber incomplete NTT (FIPS 203)
+pub use crate::poly::ntt::kyber::{
+    KYBER_GAMMAS, KYBER_ZETAS, kyber_basemul, kyber_intt, kyber_ntt,
+};
+
 // Negacyclic NTT (explicit re-export for convenience)
 pub use crate::poly::ntt::{NttContext, intt_negacyclic, mul_negacyclic, ntt_negacyclic};
 
@@ -47,7 +74,9 @@ pub use crate::poly::ntt::{NttContext, intt_negacyclic, mul_negacyclic, ntt_nega
 pub use traits::{BigInt, Digest};
 
 // Ring elements for lattice crypto
-pub use crate::ring::{RingElement, RingElementState};
+pub use crate::ring::{
+    RingElement, RingElementState, RingMatrix, RingVector, RnsBasis, RnsRingElement,
+};
 
 /// Computes N' for Montgomery reduction where P * N' = -1 mod 2^1024.
 ///
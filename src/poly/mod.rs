@@ -3,13 +3,16 @@
 //! This module provides:
 //! - [`Polynomial`] - Univariate polynomial with comprehensive operations
 //! - [`MultivariatePolynomial`] - Multivariate polynomial with sparse representation
+//! - [`DenseMultilinearExtension`] - Multilinear polynomial stored as hypercube evaluations
 //! - NTT-based fast polynomial multiplication
 
+pub mod multilinear;
 pub mod multivariate;
 pub mod ntt;
 pub mod univariate;
 
 // Primary exports
+pub use multilinear::{BooleanHypercube, DenseMultilinearExtension};
 pub use multivariate::MultivariatePolynomial;
 pub use ntt::*;
 pub use univariate::Polynomial;
@@ -0,0 +1,410 @@
+//! Dense multilinear extensions over the boolean hypercube.
+//!
+//! A multilinear polynomial in n variables is uniquely determined by its 2^n
+//! evaluations over {0,1}^n. Storing those evaluations densely makes evaluation,
+//! partial evaluation and summation over the hypercube linear-time, which is what
+//! sum-check style protocols need.
+//!
+//! # Variable Ordering
+//!
+//! Evaluations are indexed little-endian: the entry at index
+//! `b = b_0 + 2·b_1 + ... + 2^(n-1)·b_(n-1)` is the value at (b_0, b_1, ..., b_(n-1)).
+//! Variable x_0 is therefore the least significant bit, and
+//! [`DenseMultilinearExtension::fix_variables`] binds variables starting from x_0.
+
+use std::fmt;
+use std::marker::PhantomData;
+use std::ops::{Add, Neg, Sub};
+
+use crate::poly::multivariate::MultivariatePolynomial;
+use crate::{FieldConfig, FieldElement};
+
+/// A multilinear polynomial stored as its evaluations over {0,1}^n.
+#[derive(Clone, PartialEq, Eq)]
+pub struct DenseMultilinearExtension<C: FieldConfig> {
+    /// Number of variables n
+    num_vars: usize,
+    /// Evaluations over the hypercube (length 2^n, little-endian indexing)
+    evaluations: Vec<FieldElement<C>>,
+}
+
+impl<C: FieldConfig> DenseMultilinearExtension<C> {
+    /// Creates a multilinear extension from its evaluations over {0,1}^n.
+    ///
+    /// # Panics
+    /// Panics if `evaluations.len() != 2^num_vars`.
+    pub fn new(num_vars: usize, evaluations: Vec<FieldElement<C>>) -> Self {
+        assert_eq!(
+            evaluations.len(),
+            1 << num_vars,
+            "Evaluation count must be 2^num_vars"
+        );
+        Self {
+            num_vars,
+            evaluations,
+        }
+    }
+
+    /// Creates a multilinear extension from evaluations whose length is a power of two.
+    ///
+    /// # Panics
+    /// Panics if `evaluations.len()` is not a power of two.
+    pub fn from_evaluations(evaluations: Vec<FieldElement<C>>) -> Self {
+        assert!(
+            evaluations.len().is_power_of_two(),
+            "Evaluation count must be a power of two"
+        );
+        let num_vars = evaluations.len().trailing_zeros() as usize;
+        Self::new(num_vars, evaluations)
+    }
+
+    /// Creates the zero polynomial in `num_vars` variables.
+    pub fn zero(num_vars: usize) -> Self {
+        Self::new(num_vars, vec![FieldElement::zero(); 1 << num_vars])
+    }
+
+    /// Creates the constant polynomial `c` in `num_vars` variables.
+    pub fn constant(num_vars: usize, c: FieldElement<C>) -> Self {
+        Self::new(num_vars, vec![c; 1 << num_vars])
+    }
+
+    /// Creates the equality polynomial eq(x, r) = Π (x_i·r_i + (1 - x_i)(1 - r_i)).
+    ///
+    /// Its evaluation at a boolean point b is 1 if b = r (for boolean r) and,
+    /// in general, the Lagrange basis weight of b at r. Built in O(2^n).
+    pub fn eq_polynomial(r: &[FieldElement<C>]) -> Self {
+        let mut evaluations = Vec::with_capacity(1 << r.len());
+        evaluations.push(FieldElement::one());
+
+        // Each step doubles the table: entries with bit i clear get (1 - r_i),
+        // entries with bit i set get r_i.
+        for r_i in r {
+            let one_minus = FieldElement::one() - *r_i;
+            let len = evaluations.len();
+            for j in 0..len {
+                let e = evaluations[j];
+                evaluations[j] = e * one_minus;
+                evaluations.push(e * *r_i);
+            }
+        }
+
+        Self::new(r.len(), evaluations)
+    }
+
+    /// Returns the number of variables.
+    #[inline]
+    pub fn num_vars(&self) -> usize {
+        self.num_vars
+    }
+
+    /// Returns the evaluations over the hypercube.
+    #[inline]
+    pub fn evaluations(&self) -> &[FieldElement<C>] {
+        &self.evaluations
+    }
+
+    /// Consumes the polynomial and returns the evaluation vector.
+    pub fn into_evaluations(self) -> Vec<FieldElement<C>> {
+        self.evaluations
+    }
+
+    /// Returns true if every evaluation is zero.
+    pub fn is_zero(&self) -> bool {
+        self.evaluations.iter().all(|e| e.is_zero())
+    }
+
+    /// Evaluates the polynomial at an arbitrary point in F^n.
+    ///
+    /// # Panics
+    /// Panics if `point.len() != num_vars`.
+    pub fn evaluate(&self, point: &[FieldElement<C>]) -> FieldElement<C> {
+        assert_eq!(
+            point.len(),
+            self.num_vars,
+            "Point dimension must match num_vars"
+        );
+        let mut folded = self.clone();
+        folded.fix_variables(point);
+        folded.evaluations[0]
+    }
+
+    /// Binds the first `values.len()` variables in place.
+    ///
+    /// After the call the polynomial has `num_vars - values.len()` variables:
+    /// f'(x_k, ..., x_(n-1)) = f(values[0], ..., values[k-1], x_k, ..., x_(n-1)).
+    ///
+    /// # Panics
+    /// Panics if more values than variables are supplied.
+    pub fn fix_variables(&mut self, values: &[FieldElement<C>]) {
+        assert!(
+            values.len() <= self.num_vars,
+            "Cannot fix more variables than the polynomial has"
+        );
+
+        for r in values {
+            let half = self.evaluations.len() / 2;
+            for j in 0..half {
+                let lo = self.evaluations[2 * j];
+                let hi = self.evaluations[2 * j + 1];
+                self.evaluations[j] = lo + *r * (hi - lo);
+            }
+            self.evaluations.truncate(half);
+            self.num_vars -= 1;
+        }
+    }
+
+    /// Returns a copy with the first `values.len()` variables bound.
+    pub fn fixed_variables(&self, values: &[FieldElement<C>]) -> Self {
+        let mut result = self.clone();
+        result.fix_variables(values);
+        result
+    }
+
+    /// Sums the polynomial over the whole boolean hypercube.
+    pub fn sum_over_hypercube(&self) -> FieldElement<C> {
+        self.evaluations
+            .iter()
+            .fold(FieldElement::zero(), |acc, e| acc + *e)
+    }
+
+    /// Multiplies every evaluation by a scalar.
+    pub fn scale(&self, scalar: &FieldElement<C>) -> Self {
+        Self {
+            num_vars: self.num_vars,
+            evaluations: self.evaluations.iter().map(|e| *e * *scalar).collect(),
+        }
+    }
+
+    /// Converts a multivariate polynomial to the multilinear extension of its
+    /// values on {0,1}^n.
+    ///
+    /// Higher powers collapse on the hypercube (x^2 = x), so the result agrees
+    /// with `poly` on boolean points but equals it everywhere only if `poly`
+    /// is multilinear. Intended for small n.
+    pub fn from_multivariate(poly: &MultivariatePolynomial<C>) -> Self {
+        let n = poly.num_vars;
+        let evaluations = BooleanHypercube::<C>::new(n)
+            .map(|point| poly.evaluate(&point))
+            .collect();
+        Self::new(n, evaluations)
+    }
+
+    /// Converts to a sparse multivariate polynomial in the monomial basis.
+    ///
+    /// Uses the Möbius transform over subsets, so it runs in O(n·2^n).
+    pub fn to_multivariate(&self) -> MultivariatePolynomial<C> {
+        let n = self.num_vars;
+        let mut coeffs = self.evaluations.clone();
+        for i in 0..n {
+            let bit = 1 << i;
+            for idx in 0..coeffs.len() {
+                if idx & bit != 0 {
+                    coeffs[idx] = coeffs[idx] - coeffs[idx ^ bit];
+                }
+            }
+        }
+
+        let mut result = MultivariatePolynomial::new(n);
+        for (idx, c) in coeffs.into_iter().enumerate() {
+            if !c.is_zero() {
+                let exponents = (0..n).map(|i| (idx >> i) & 1).collect();
+                result.add_term(exponents, c);
+            }
+        }
+        result
+    }
+}
+
+// =============================================================================
+// Arithmetic Operations
+// =============================================================================
+
+impl<C: FieldConfig> Add for DenseMultilinearExtension<C> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        &self + &rhs
+    }
+}
+
+impl<'a, C: FieldConfig> Add<&'a DenseMultilinearExtension<C>>
+    for &'a DenseMultilinearExtension<C>
+{
+    type Output = DenseMultilinearExtension<C>;
+
+    fn add(self, rhs: &'a DenseMultilinearExtension<C>) -> DenseMultilinearExtension<C> {
+        assert_eq!(
+            self.num_vars, rhs.num_vars,
+            "Number of variables must match"
+        );
+        DenseMultilinearExtension {
+            num_vars: self.num_vars,
+            evaluations: self
+                .evaluations
+                .iter()
+                .zip(rhs.evaluations.iter())
+                .map(|(a, b)| *a + *b)
+                .collect(),
+        }
+    }
+}
+
+impl<C: FieldConfig> Sub for DenseMultilinearExtension<C> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        &self - &rhs
+    }
+}
+
+impl<'a, C: FieldConfig> Sub<&'a DenseMultilinearExtension<C>>
+    for &'a DenseMultilinearExtension<C>
+{
+    type Output = DenseMultilinearExtension<C>;
+
+    fn sub(self, rhs: &'a DenseMultilinearExtension<C>) -> DenseMultilinearExtension<C> {
+        assert_eq!(
+            self.num_vars, rhs.num_vars,
+            "Number of variables must match"
+        );
+        DenseMultilinearExtension {
+            num_vars: self.num_vars,
+            evaluations: self
+                .evaluations
+                .iter()
+                .zip(rhs.evaluations.iter())
+                .map(|(a, b)| *a - *b)
+                .collect(),
+        }
+    }
+}
+
+impl<C: FieldConfig> Neg for DenseMultilinearExtension<C> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self {
+            num_vars: self.num_vars,
+            evaluations: self.evaluations.into_iter().map(|e| -e).collect(),
+        }
+    }
+}
+
+impl<C: FieldConfig> fmt::Debug for DenseMultilinearExtension<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "DenseMultilinearExtension({} vars, {} evaluations)",
+            self.num_vars,
+            self.evaluations.len()
+        )
+    }
+}
+
+// =============================================================================
+// Boolean Hypercube
+// =============================================================================
+
+/// Iterator over the points of {0,1}^n as field element vectors.
+///
+/// Points are produced in the same little-endian order used to index
+/// [`DenseMultilinearExtension`] evaluations.
+#[derive(Clone, Debug)]
+pub struct BooleanHypercube<C: FieldConfig> {
+    num_vars: usize,
+    next: usize,
+    _marker: PhantomData<C>,
+}
+
+impl<C: FieldConfig> BooleanHypercube<C> {
+    /// Creates an iterator over {0,1}^num_vars.
+    pub fn new(num_vars: usize) -> Self {
+        Self {
+            num_vars,
+            next: 0,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<C: FieldConfig> Iterator for BooleanHypercube<C> {
+    type Item = Vec<FieldElement<C>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.next >= 1 << self.num_vars {
+            return None;
+        }
+        let idx = self.next;
+        self.next += 1;
+        Some(
+            (0..self.num_vars)
+                .map(|i| {
+                    if (idx >> i) & 1 == 1 {
+                        FieldElement::one()
+                    } else {
+                        FieldElement::zero()
+                    }
+                })
+                .collect(),
+        )
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = (1usize << self.num_vars) - self.next;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<C: FieldConfig> ExactSizeIterator for BooleanHypercube<C> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::field::config::DefaultFieldConfig;
+    use crate::fp;
+
+    type Mle = DenseMultilinearExtension<DefaultFieldConfig>;
+
+    #[test]
+    fn test_evaluate_on_hypercube() {
+        let mle = Mle::from_evaluations((0..8).map(|i| fp!(i as u64 + 1)).collect());
+        for (idx, point) in BooleanHypercube::new(3).enumerate() {
+            assert_eq!(mle.evaluate(&point), mle.evaluations()[idx]);
+        }
+    }
+
+    #[test]
+    fn test_fix_variables_reduces_arity() {
+        let mut mle = Mle::from_evaluations((0..8).map(|i| fp!(i as u64)).collect());
+        let expected = mle.evaluate(&[fp!(5u64), fp!(6u64), fp!(7u64)]);
+
+        mle.fix_variables(&[fp!(5u64)]);
+        assert_eq!(mle.num_vars(), 2);
+        assert_eq!(mle.evaluate(&[fp!(6u64), fp!(7u64)]), expected);
+    }
+
+    #[test]
+    fn test_eq_polynomial_is_indicator_on_boolean_points() {
+        let r = vec![fp!(1u64), fp!(0u64), fp!(1u64)];
+        let eq = Mle::eq_polynomial(&r);
+        for (idx, e) in eq.evaluations().iter().enumerate() {
+            if idx == 0b101 {
+                assert_eq!(*e, FieldElement::one());
+            } else {
+                assert!(e.is_zero());
+            }
+        }
+    }
+
+    #[test]
+    fn test_hypercube_size() {
+        let cube = BooleanHypercube::<DefaultFieldConfig>::new(4);
+        assert_eq!(cube.len(), 16);
+    }
+
+    #[test]
+    #[should_panic(expected = "Evaluation count must be 2^num_vars")]
+    fn test_wrong_length_panics() {
+        let _ = Mle::new(2, vec![fp!(1u64); 3]);
+    }
+}
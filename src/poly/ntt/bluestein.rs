@@ -0,0 +1,186 @@
+//! Bluestein (chirp-z) NTT for arbitrary lengths.
+//!
+//! A length-n NTT exists whenever n divides q − 1, but the radix-2, 3 and 5
+//! transforms only cover lengths made of those primes. Bluestein's algorithm
+//! handles any such n by rewriting the transform as a convolution, which is
+//! then computed with a power-of-two [`CyclicNttContext`].
+//!
+//! The usual identity jk = (j² + k² − (k − j)²)/2 needs a square root of ω.
+//! Instead this module uses jk = T(j + k) − T(j) − T(k) with the integer
+//! triangular numbers T(m) = m(m − 1)/2, so only ω itself is required:
+//!
+//! A_k = ω^(−T(k)) · Σ_j (a_j ω^(−T(j))) · ω^(T(j + k))
+//!
+//! The sum is a correlation of length n against the chirp ω^(T(m)) for
+//! m < 2n − 1, evaluated with one cyclic convolution of size at least
+//! 2n − 1.
+//!
+//! # Example
+//!
+//! ```
+//! use lumen_math::poly::ntt::BluesteinNttContext;
+//! use lumen_math::{DefaultFieldConfig, FieldElement, U1024};
+//!
+//! // 281 is prime and divides q − 1.
+//! let ctx = BluesteinNttContext::<DefaultFieldConfig>::new(281).unwrap();
+//! let coeffs: Vec<FieldElement<DefaultFieldConfig>> = (0..281)
+//!     .map(|i| FieldElement::new(U1024::from_u64(i * i)))
+//!     .collect();
+//!
+//! let mut values = coeffs.clone();
+//! ctx.ntt(&mut values);
+//! ctx.intt(&mut values);
+//! assert_eq!(values, coeffs);
+//! ```
+
+use std::sync::Arc;
+
+use super::cyclic::CyclicNttContext;
+use super::mixed_radix::root_of_unity_of_order;
+use crate::{FieldConfig, FieldElement, U1024};
+
+/// Precomputed chirps for one direction of the transform.
+#[derive(Clone, Debug)]
+struct Chirp<C: FieldConfig> {
+    /// ω^(−T(k)) for k < n
+    weights: Vec<FieldElement<C>>,
+    /// NTT of ω^(T(m)) for m < 2n − 1, zero-padded to the convolution size
+    kernel: Vec<FieldElement<C>>,
+}
+
+impl<C: FieldConfig> Chirp<C> {
+    fn new(omega: FieldElement<C>, n: usize, ctx: &CyclicNttContext<C>, size: usize) -> Self {
+        let powers: Vec<_> = std::iter::successors(Some(FieldElement::one()), |x| Some(*x * omega))
+            .take(n)
+            .collect();
+        let triangular = |m: usize| (m * m.saturating_sub(1) / 2) % n;
+
+        let weights = (0..n).map(|k| powers[(n - triangular(k)) % n]).collect();
+        let mut kernel = vec![FieldElement::zero(); size];
+        for (m, slot) in kernel.iter_mut().enumerate().take(2 * n - 1) {
+            *slot = powers[triangular(m)];
+        }
+        ctx.ntt(&mut kernel);
+        Self { weights, kernel }
+    }
+}
+
+/// Context for NTTs of a single length n dividing q − 1, with no
+/// restriction on the prime factors of n.
+///
+/// [`ntt`](Self::ntt) maps coefficients a to A_k = Σ a_j ω^(jk) in natural
+/// order, where ω is [`root`](Self::root). For lengths of the form
+/// 2^a · 3^b · 5^c, [`MixedRadixNttContext`](super::MixedRadixNttContext)
+/// uses the same root and is faster.
+#[derive(Clone, Debug)]
+pub struct BluesteinNttContext<C: FieldConfig> {
+    n: usize,
+    root: FieldElement<C>,
+    cyclic: Arc<CyclicNttContext<C>>,
+    forward: Chirp<C>,
+    inverse: Chirp<C>,
+    n_inv: FieldElement<C>,
+}
+
+impl<C: FieldConfig> BluesteinNttContext<C> {
+    /// Creates a context for length `n`, or returns `None` if `n` is zero or
+    /// does not divide q − 1.
+    ///
+    /// # Panics
+    /// Panics if the convolution size, the next power of two at or above
+    /// 2n − 1, exceeds 2^TWO_ADICITY.
+    pub fn new(n: usize) -> Option<Self> {
+        let root = root_of_unity_of_order::<C>(n)?;
+        let size = (2 * n - 1).next_power_of_two();
+        let cyclic = CyclicNttContext::<C>::shared(size);
+        Some(Self {
+            n,
+            root,
+            forward: Chirp::new(root, n, &cyclic, size),
+            inverse: Chirp::new(root.inv(), n, &cyclic, size),
+            cyclic,
+            n_inv: FieldElement::<C>::new(U1024::from_u64(n as u64)).inv(),
+        })
+    }
+
+    /// Returns the transform length.
+    pub fn len(&self) -> usize {
+        self.n
+    }
+
+    /// Returns `false`; a context always has a positive length.
+    pub fn is_empty(&self) -> bool {
+        false
+    }
+
+    /// Returns the primitive n-th root of unity ω used by the transform.
+    pub fn root(&self) -> FieldElement<C> {
+        self.root
+    }
+
+    /// Performs the forward NTT in place.
+    ///
+    /// # Panics
+    /// Panics if `coeffs.len() != self.len()`.
+    pub fn ntt(&self, coeffs: &mut [FieldElement<C>]) {
+        self.chirp_z(coeffs, &self.forward);
+    }
+
+    /// Performs the inverse NTT in place.
+    ///
+    /// # Panics
+    /// Panics if `values.len() != self.len()`.
+    pub fn intt(&self, values: &mut [FieldElement<C>]) {
+        self.chirp_z(values, &self.inverse);
+        for v in values.iter_mut() {
+            *v = *v * self.n_inv;
+        }
+    }
+
+    /// Multiplies two polynomials in Zq[X]/(X^n − 1).
+    ///
+    /// # Panics
+    /// Panics if either input length differs from `self.len()`.
+    pub fn mul(&self, a: &[FieldElement<C>], b: &[FieldElement<C>]) -> Vec<FieldElement<C>> {
+        assert_eq!(
+            a.len(),
+            self.n,
+            "First polynomial length must match context size"
+        );
+        assert_eq!(
+            b.len(),
+            self.n,
+            "Second polynomial length must match context size"
+        );
+        let mut a_ntt = a.to_vec();
+        let mut b_ntt = b.to_vec();
+        self.ntt(&mut a_ntt);
+        self.ntt(&mut b_ntt);
+        for (x, y) in a_ntt.iter_mut().zip(&b_ntt) {
+            *x = *x * *y;
+        }
+        self.intt(&mut a_ntt);
+        a_ntt
+    }
+
+    fn chirp_z(&self, data: &mut [FieldElement<C>], chirp: &Chirp<C>) {
+        let n = self.n;
+        assert_eq!(data.len(), n, "Coefficient length must match context size");
+
+        // u_j = a_j ω^(−T(j)), stored reversed so the correlation becomes a
+        // convolution whose entries n − 1 + k hold the sums.
+        let mut u = vec![FieldElement::zero(); chirp.kernel.len()];
+        for (j, (a, w)) in data.iter().zip(&chirp.weights).enumerate() {
+            u[n - 1 - j] = *a * *w;
+        }
+        self.cyclic.ntt(&mut u);
+        for (x, k) in u.iter_mut().zip(&chirp.kernel) {
+            *x = *x * *k;
+        }
+        self.cyclic.intt(&mut u);
+
+        for (k, (out, w)) in data.iter_mut().zip(&chirp.weights).enumerate() {
+            *out = u[n - 1 + k] * *w;
+        }
+    }
+}
@@ -69,6 +69,8 @@ impl FieldConfig for KyberFieldConfig {
     /// ζ = 17 is a primitive 256th root of unity mod 3329.
     const ROOT_OF_UNITY: U1024 = U1024([17, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
 
+    const TWO_ADICITY: u32 = 8;
+
     /// For Kyber, ψ = 17 (the same as ROOT_OF_UNITY for incomplete NTT).
     const PRIMITIVE_2NTH_ROOT: U1024 = U1024([17, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
 
@@ -128,6 +130,8 @@ impl FieldConfig for DilithiumFieldConfig {
     /// This means ω^256 ≡ 1 (mod q).
     const ROOT_OF_UNITY: U1024 = U1024([3073009, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
 
+    const TWO_ADICITY: u32 = 8;
+
     /// ψ = 1753, satisfying ψ^256 ≡ -1 (mod q).
     const PRIMITIVE_2NTH_ROOT: U1024 = U1024([1753, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
 
@@ -1,13 +1,23 @@
 //! Cyclic NTT (Number Theoretic Transform) over Zq[X]/(X^N - 1).
 //!
 //! This module provides the standard cyclic NTT for polynomial multiplication
-//! in the ring Zq[X]/(X^N - 1).
+//! in the ring Zq[X]/(X^N - 1). The free [`ntt`] and [`intt`] functions
+//! derive their roots on every call; [`CyclicNttContext`] precomputes them
+//! once for all sizes up to a maximum, and [`CyclicNttContext::shared`] keeps
+//! one such context per field configuration.
+
+use std::any::{Any, TypeId};
+use std::collections::HashMap;
+use std::sync::{Arc, OnceLock, RwLock};
 
 use crate::{FieldConfig, FieldElement, U1024};
 
 /// Reorders coefficients in bit-reversal permutation order.
 pub fn bit_reverse<C: FieldConfig>(coeffs: &mut [FieldElement<C>]) {
     let n = coeffs.len();
+    if n < 2 {
+        return;
+    }
     let leading_zeros = n.leading_zeros() + 1;
 
     for i in 0..n {
@@ -22,6 +32,10 @@ pub fn bit_reverse<C: FieldConfig>(coeffs: &mut [FieldElement<C>]) {
 pub fn ntt<C: FieldConfig>(coeffs: &mut [FieldElement<C>]) {
     let n = coeffs.len();
     assert!(n.is_power_of_two(), "NTT size must be power of two");
+    assert!(
+        n.trailing_zeros() <= C::TWO_ADICITY,
+        "NTT size exceeds the field's two-adicity"
+    );
 
     bit_reverse(coeffs);
 
@@ -29,11 +43,11 @@ pub fn ntt<C: FieldConfig>(coeffs: &mut [FieldElement<C>]) {
     while len <= n {
         let half_len = len / 2;
 
-        // Compute twiddle factor ω^(2^32/len) for this layer.
-        // ROOT_OF_UNITY is a primitive 2^32-th root of unity, so we square it
-        // (32 - log2(len)) times to get the proper twiddle factor for length len.
+        // Compute twiddle factor ω^(2^TWO_ADICITY/len) for this layer.
+        // ROOT_OF_UNITY is a primitive 2^TWO_ADICITY-th root of unity, so we square it
+        // (TWO_ADICITY - log2(len)) times to get the proper twiddle factor for length len.
         let log_len = len.trailing_zeros();
-        let factor = 32 - log_len;
+        let factor = C::TWO_ADICITY - log_len;
 
         let mut w_len = FieldElement::<C>::new(C::ROOT_OF_UNITY);
         for _ in 0..factor {
@@ -73,3 +87,306 @@ pub fn intt<C: FieldConfig>(coeffs: &mut [FieldElement<C>]) {
         *c = *c * n_inv;
     }
 }
+
+/// Returns log2 of the largest cyclic NTT size the field supports:
+/// `TWO_ADICITY` if `ROOT_OF_UNITY` really has order 2^TWO_ADICITY, and 0
+/// otherwise.
+pub(crate) fn checked_two_adicity<C: FieldConfig>() -> u32 {
+    let mut half = FieldElement::<C>::new(C::ROOT_OF_UNITY);
+    if C::TWO_ADICITY == 0 {
+        return 0;
+    }
+    for _ in 1..C::TWO_ADICITY {
+        half = half * half;
+    }
+    if half == -FieldElement::one() {
+        C::TWO_ADICITY
+    } else {
+        0
+    }
+}
+
+/// Returns a primitive `2^log_n`-th root of unity derived from `ROOT_OF_UNITY`,
+/// the same root the free [`ntt`] uses for size `2^log_n`.
+///
+/// # Panics
+/// Panics if `ROOT_OF_UNITY` does not have order 2^TWO_ADICITY, so that the
+/// result is not primitive.
+pub(crate) fn root_of_order<C: FieldConfig>(log_n: u32) -> FieldElement<C> {
+    let mut w = FieldElement::<C>::new(C::ROOT_OF_UNITY);
+    for _ in log_n..C::TWO_ADICITY {
+        w = w * w;
+    }
+    // w is primitive exactly when w^(2^(log_n - 1)) = −1.
+    let mut half = if log_n == 0 { -w } else { w };
+    for _ in 1..log_n {
+        half = half * half;
+    }
+    assert!(
+        half == -FieldElement::one(),
+        "ROOT_OF_UNITY does not have order 2^TWO_ADICITY"
+    );
+    w
+}
+
+/// Context for cyclic NTTs of every power-of-two size up to a maximum.
+///
+/// The twiddles are stored once in bit-reversed order: entry `i` holds
+/// ω^brv(i), where ω is a primitive root of order `max_size` and brv reverses
+/// log2(`max_size`/2) bits. Splitting X^m − r into X^(m/2) ∓ √r block by block
+/// needs twiddle `i` for block `i` at every layer and every size, so one table
+/// serves all sizes and no roots are recomputed per call.
+///
+/// [`ntt`](Self::ntt) and [`intt`](Self::intt) produce the same results as the
+/// free [`ntt`] and [`intt`] functions. [`mul`](Self::mul) works on the
+/// bit-reversed spectrum directly and skips the permutations.
+///
+/// # Example
+/// ```
+/// use lumen_math::poly::ntt::{CyclicNttContext, ntt};
+/// use lumen_math::{DefaultFieldConfig, fp};
+///
+/// let ctx = CyclicNttContext::<DefaultFieldConfig>::new(16);
+/// let coeffs: Vec<_> = (0..8).map(|i| fp!(i as u64)).collect();
+///
+/// let mut a = coeffs.clone();
+/// let mut b = coeffs.clone();
+/// ctx.ntt(&mut a);
+/// ntt(&mut b);
+/// assert_eq!(a, b);
+/// ```
+#[derive(Clone, Debug)]
+pub struct CyclicNttContext<C: FieldConfig> {
+    max_size: usize,
+    /// ω^brv(i) for i < max_size / 2
+    twiddles: Vec<FieldElement<C>>,
+    /// ω^-brv(i) for i < max_size / 2
+    inv_twiddles: Vec<FieldElement<C>>,
+    /// 2^-k for k = 0, …, log2(max_size)
+    size_inv: Vec<FieldElement<C>>,
+}
+
+impl<C: FieldConfig> CyclicNttContext<C> {
+    /// Creates a context for transforms of every power-of-two size up to
+    /// `max_size`.
+    ///
+    /// # Panics
+    /// Panics if `max_size` is not a power of two or exceeds 2^TWO_ADICITY.
+    pub fn new(max_size: usize) -> Self {
+        assert!(max_size.is_power_of_two(), "NTT size must be power of two");
+        let log_max = max_size.trailing_zeros();
+        assert!(
+            log_max <= C::TWO_ADICITY,
+            "NTT size exceeds the field's two-adicity"
+        );
+
+        let half = max_size / 2;
+        let bits = half.trailing_zeros();
+        let bit_reversed = |powers: Vec<FieldElement<C>>| -> Vec<FieldElement<C>> {
+            (0..half)
+                .map(|i| match bits {
+                    0 => powers[0],
+                    _ => powers[i.reverse_bits() >> (usize::BITS - bits)],
+                })
+                .collect()
+        };
+        let powers_of = |w: FieldElement<C>| -> Vec<FieldElement<C>> {
+            std::iter::successors(Some(FieldElement::one()), |x| Some(*x * w))
+                .take(half)
+                .collect()
+        };
+        let omega = root_of_order::<C>(log_max);
+        let twiddles = bit_reversed(powers_of(omega));
+        let inv_twiddles = bit_reversed(powers_of(omega.inv()));
+
+        let two_inv = FieldElement::<C>::new(U1024::from_u64(2)).inv();
+        let mut size_inv = Vec::with_capacity(log_max as usize + 1);
+        let mut current = FieldElement::<C>::one();
+        for _ in 0..=log_max {
+            size_inv.push(current);
+            current = current * two_inv;
+        }
+
+        Self {
+            max_size,
+            twiddles,
+            inv_twiddles,
+            size_inv,
+        }
+    }
+
+    /// Returns the largest supported transform size.
+    pub fn max_size(&self) -> usize {
+        self.max_size
+    }
+
+    fn check_size(&self, n: usize) {
+        assert!(n.is_power_of_two(), "NTT size must be power of two");
+        assert!(
+            n <= self.max_size,
+            "NTT size exceeds the context's maximum size"
+        );
+    }
+
+    /// Forward transform from natural order to bit-reversed order
+    /// (Cooley-Tukey butterflies).
+    fn forward_bit_reversed(&self, coeffs: &mut [FieldElement<C>]) {
+        let n = coeffs.len();
+        let mut len = n;
+        let mut blocks = 1;
+        while len >= 2 {
+            let half_len = len / 2;
+            for (block, w) in self.twiddles[..blocks].iter().enumerate() {
+                let start = block * len;
+                for j in start..start + half_len {
+                    let u = coeffs[j];
+                    let v = coeffs[j + half_len] * *w;
+                    coeffs[j] = u + v;
+                    coeffs[j + half_len] = u - v;
+                }
+            }
+            len = half_len;
+            blocks <<= 1;
+        }
+    }
+
+    /// Inverse transform from bit-reversed order to natural order
+    /// (Gentleman-Sande butterflies), without the 1/n scaling.
+    fn inverse_bit_reversed(&self, coeffs: &mut [FieldElement<C>]) {
+        let n = coeffs.len();
+        let mut len = 2;
+        let mut blocks = n / 2;
+        while len <= n {
+            let half_len = len / 2;
+            for (block, w) in self.inv_twiddles[..blocks].iter().enumerate() {
+                let start = block * len;
+                for j in start..start + half_len {
+                    let u = coeffs[j];
+                    let v = coeffs[j + half_len];
+                    coeffs[j] = u + v;
+                    coeffs[j + half_len] = (u - v) * *w;
+                }
+            }
+            len <<= 1;
+            blocks >>= 1;
+        }
+    }
+
+    fn scale_by_size_inv(&self, coeffs: &mut [FieldElement<C>]) {
+        let n_inv = self.size_inv[coeffs.len().trailing_zeros() as usize];
+        for c in coeffs.iter_mut() {
+            *c = *c * n_inv;
+        }
+    }
+
+    /// Performs the forward NTT in place; equivalent to the free [`ntt`].
+    ///
+    /// # Panics
+    /// Panics if the length is not a power of two or exceeds `max_size`.
+    pub fn ntt(&self, coeffs: &mut [FieldElement<C>]) {
+        self.check_size(coeffs.len());
+        self.forward_bit_reversed(coeffs);
+        bit_reverse(coeffs);
+    }
+
+    /// Performs the inverse NTT in place; equivalent to the free [`intt`].
+    ///
+    /// # Panics
+    /// Panics if the length is not a power of two or exceeds `max_size`.
+    pub fn intt(&self, coeffs: &mut [FieldElement<C>]) {
+        self.check_size(coeffs.len());
+        bit_reverse(coeffs);
+        self.inverse_bit_reversed(coeffs);
+        self.scale_by_size_inv(coeffs);
+    }
+
+    /// Multiplies two polynomials in Zq[X]/(X^n − 1), where n is their
+    /// common length.
+    ///
+    /// # Panics
+    /// Panics if the lengths differ, are not a power of two, or exceed
+    /// `max_size`.
+    pub fn mul(&self, a: &[FieldElement<C>], b: &[FieldElement<C>]) -> Vec<FieldElement<C>> {
+        assert_eq!(a.len(), b.len(), "Polynomial lengths must match");
+        self.check_size(a.len());
+
+        let mut a_ntt = a.to_vec();
+        let mut b_ntt = b.to_vec();
+        self.forward_bit_reversed(&mut a_ntt);
+        self.forward_bit_reversed(&mut b_ntt);
+        for (x, y) in a_ntt.iter_mut().zip(&b_ntt) {
+            *x = *x * *y;
+        }
+        self.inverse_bit_reversed(&mut a_ntt);
+        self.scale_by_size_inv(&mut a_ntt);
+        a_ntt
+    }
+
+    /// Returns a shared context for `C` supporting at least `min_size`.
+    ///
+    /// Contexts are cached per field configuration and built on first use;
+    /// a request for a larger size replaces the cached context with one
+    /// covering it. The cache is safe to use from multiple threads.
+    ///
+    /// # Panics
+    /// Panics if `min_size` rounded up to a power of two exceeds
+    /// 2^TWO_ADICITY.
+    pub fn shared(min_size: usize) -> Arc<Self> {
+        let size = min_size.max(1).next_power_of_two();
+        let cache = CYCLIC_CONTEXTS.get_or_init(Default::default);
+        let key = TypeId::of::<C>();
+
+        let cached = |map: &HashMap<TypeId, Arc<dyn Any + Send + Sync>>| {
+            map.get(&key)
+                .and_then(|ctx| ctx.clone().downcast::<Self>().ok())
+                .filter(|ctx| ctx.max_size >= size)
+        };
+
+        if let Some(ctx) = cached(&cache.read().unwrap_or_else(|e| e.into_inner())) {
+            return ctx;
+        }
+        let mut map = cache.write().unwrap_or_else(|e| e.into_inner());
+        // Another thread may have built a large enough context meanwhile.
+        if let Some(ctx) = cached(&map) {
+            return ctx;
+        }
+        let ctx = Arc::new(Self::new(size));
+        map.insert(key, ctx.clone());
+        ctx
+    }
+}
+
+/// Shared [`CyclicNttContext`]s, keyed by field configuration.
+static CYCLIC_CONTEXTS: OnceLock<RwLock<HashMap<TypeId, Arc<dyn Any + Send + Sync>>>> =
+    OnceLock::new();
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::field::config::DefaultFieldConfig;
+    use crate::fp;
+
+    #[test]
+    fn test_context_matches_free_functions() {
+        let ctx = CyclicNttContext::<DefaultFieldConfig>::new(64);
+        for n in [1, 2, 4, 32, 64] {
+            let coeffs: Vec<_> = (0..n).map(|i| fp!((i * i + 7) as u64)).collect();
+            let mut expected = coeffs.clone();
+            ntt(&mut expected);
+            let mut actual = coeffs.clone();
+            ctx.ntt(&mut actual);
+            assert_eq!(actual, expected, "n = {n}");
+
+            ctx.intt(&mut actual);
+            assert_eq!(actual, coeffs, "n = {n}");
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "NTT size exceeds the context's maximum size")]
+    fn test_context_rejects_oversized_input() {
+        let ctx = CyclicNttContext::<DefaultFieldConfig>::new(8);
+        let mut coeffs = vec![FieldElement::<DefaultFieldConfig>::zero(); 16];
+        ctx.ntt(&mut coeffs);
+    }
+}
@@ -0,0 +1,161 @@
+//! Kyber's incomplete NTT over Z_3329[X]/(X^256 + 1).
+//!
+//! q = 3329 has only 256th roots of unity, so the NTT from FIPS 203 stops one
+//! layer early: it maps a polynomial to 128 residues modulo the quadratics
+//! X^2 - ζ^(2·BitRev7(i)+1). Multiplication in the NTT domain is therefore a
+//! pairwise "base-case" product of degree-one polynomials rather than a
+//! coefficient-wise product.
+//!
+//! The functions follow Algorithms 9–12 of FIPS 203 exactly, including the
+//! bit-reversed zeta ordering and the final scaling by 128^(-1) = 3303 in the
+//! inverse transform, so their outputs can be compared directly with the
+//! intermediate values of other ML-KEM implementations.
+//!
+//! # Examples
+//!
+//! ```
+//! use lumen_math::{KyberFieldElement, kyber_basemul, kyber_intt, kyber_ntt};
+//!
+//! let mut a = [KyberFieldElement::zero(); 256];
+//! let mut b = [KyberFieldElement::zero(); 256];
+//! a[1] = KyberFieldElement::one(); // X
+//! b[255] = KyberFieldElement::one(); // X^255
+//!
+//! kyber_ntt(&mut a);
+//! kyber_ntt(&mut b);
+//! let mut c = kyber_basemul(&a, &b);
+//! kyber_intt(&mut c);
+//!
+//! // X · X^255 = X^256 = -1
+//! assert_eq!(c[0], -KyberFieldElement::one());
+//! ```
+
+use super::small::KyberFieldElement;
+
+/// ζ^BitRev7(i) mod 3329 for i = 0..128 (FIPS 203, Appendix A).
+pub const KYBER_ZETAS: [u16; 128] = [
+    1, 1729, 2580, 3289, 2642, 630, 1897, 848, 1062, 1919, 193, 797, 2786, 3260, 569, 1746, 296,
+    2447, 1339, 1476, 3046, 56, 2240, 1333, 1426, 2094, 535, 2882, 2393, 2879, 1974, 821, 289, 331,
+    3253, 1756, 1197, 2304, 2277, 2055, 650, 1977, 2513, 632, 2865, 33, 1320, 1915, 2319, 1435,
+    807, 452, 1438, 2868, 1534, 2402, 2647, 2617, 1481, 648, 2474, 3110, 1227, 910, 17, 2761, 583,
+    2649, 1637, 723, 2288, 1100, 1409, 2662, 3281, 233, 756, 2156, 3015, 3050, 1703, 1651, 2789,
+    1789, 1847, 952, 1461, 2687, 939, 2308, 2437, 2388, 733, 2337, 268, 641, 1584, 2298, 2037,
+    3220, 375, 2549, 2090, 1645, 1063, 319, 2773, 757, 2099, 561, 2466, 2594, 2804, 1092, 403,
+    1026, 1143, 2150, 2775, 886, 1722, 1212, 1874, 1029, 2110, 2935, 885, 2154,
+];
+
+/// ζ^(2·BitRev7(i)+1) mod 3329 for i = 0..128 (FIPS 203, Appendix A).
+pub const KYBER_GAMMAS: [u16; 128] = [
+    17, 3312, 2761, 568, 583, 2746, 2649, 680, 1637, 1692, 723, 2606, 2288, 1041, 1100, 2229, 1409,
+    1920, 2662, 667, 3281, 48, 233, 3096, 756, 2573, 2156, 1173, 3015, 314, 3050, 279, 1703, 1626,
+    1651, 1678, 2789, 540, 1789, 1540, 1847, 1482, 952, 2377, 1461, 1868, 2687, 642, 939, 2390,
+    2308, 1021, 2437, 892, 2388, 941, 733, 2596, 2337, 992, 268, 3061, 641, 2688, 1584, 1745, 2298,
+    1031, 2037, 1292, 3220, 109, 375, 2954, 2549, 780, 2090, 1239, 1645, 1684, 1063, 2266, 319,
+    3010, 2773, 556, 757, 2572, 2099, 1230, 561, 2768, 2466, 863, 2594, 735, 2804, 525, 1092, 2237,
+    403, 2926, 1026, 2303, 1143, 2186, 2150, 1179, 2775, 554, 886, 2443, 1722, 1607, 1212, 2117,
+    1874, 1455, 1029, 2300, 2110, 1219, 2935, 394, 885, 2444, 2154, 1175,
+];
+
+/// 128^(-1) mod 3329, the scaling factor of the inverse transform.
+const KYBER_N_INV: u32 = 3303;
+
+/// Forward NTT (FIPS 203, Algorithm 9), in place.
+///
+/// Input is in standard coefficient order; output is in the bit-reversed
+/// order used by [`kyber_basemul`].
+pub fn kyber_ntt(f: &mut [KyberFieldElement; 256]) {
+    let mut k = 1;
+    let mut len = 128;
+    while len >= 2 {
+        for start in (0..256).step_by(2 * len) {
+            let zeta = KyberFieldElement::from_raw(KYBER_ZETAS[k]);
+            k += 1;
+            for j in start..start + len {
+                let t = zeta * f[j + len];
+                f[j + len] = f[j] - t;
+                f[j] = f[j] + t;
+            }
+        }
+        len /= 2;
+    }
+}
+
+/// Inverse NTT (FIPS 203, Algorithm 10), in place.
+///
+/// Undoes [`kyber_ntt`], including the scaling by 128^(-1).
+pub fn kyber_intt(f: &mut [KyberFieldElement; 256]) {
+    let mut k = 127;
+    let mut len = 2;
+    while len <= 128 {
+        for start in (0..256).step_by(2 * len) {
+            let zeta = KyberFieldElement::from_raw(KYBER_ZETAS[k]);
+            k -= 1;
+            for j in start..start + len {
+                let t = f[j];
+                f[j] = t + f[j + len];
+                f[j + len] = zeta * (f[j + len] - t);
+            }
+        }
+        len *= 2;
+    }
+
+    let n_inv = KyberFieldElement::new(KYBER_N_INV);
+    for c in f.iter_mut() {
+        *c = *c * n_inv;
+    }
+}
+
+/// Multiplies two polynomials in the NTT domain (FIPS 203, Algorithms 11 and 12).
+///
+/// Each pair (a_{2i}, a_{2i+1}) represents a_{2i} + a_{2i+1}·X modulo
+/// X^2 - γ_i with γ_i = [`KYBER_GAMMAS`]\[i\].
+pub fn kyber_basemul(
+    a: &[KyberFieldElement; 256],
+    b: &[KyberFieldElement; 256],
+) -> [KyberFieldElement; 256] {
+    let mut c = [KyberFieldElement::zero(); 256];
+    for i in 0..128 {
+        let gamma = KyberFieldElement::from_raw(KYBER_GAMMAS[i]);
+        let (a0, a1) = (a[2 * i], a[2 * i + 1]);
+        let (b0, b1) = (b[2 * i], b[2 * i + 1]);
+        c[2 * i] = a0 * b0 + a1 * b1 * gamma;
+        c[2 * i + 1] = a0 * b1 + a1 * b0;
+    }
+    c
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::poly::ntt::small::{KYBER_Q, KYBER_ZETA};
+
+    fn bit_rev7(i: u32) -> u32 {
+        i.reverse_bits() >> 25
+    }
+
+    #[test]
+    fn test_zeta_tables_match_definition() {
+        let zeta = KyberFieldElement::new(KYBER_ZETA);
+        for i in 0..128u32 {
+            let br = bit_rev7(i);
+            assert_eq!(zeta.pow(br).value(), KYBER_ZETAS[i as usize]);
+            assert_eq!(zeta.pow(2 * br + 1).value(), KYBER_GAMMAS[i as usize]);
+        }
+    }
+
+    #[test]
+    fn test_n_inv() {
+        assert_eq!((128 * KYBER_N_INV) % KYBER_Q, 1);
+    }
+
+    #[test]
+    fn test_ntt_of_constant_is_constant() {
+        let mut f = [KyberFieldElement::zero(); 256];
+        f[0] = KyberFieldElement::new(5);
+        kyber_ntt(&mut f);
+        for i in 0..128 {
+            assert_eq!(f[2 * i].value(), 5);
+            assert_eq!(f[2 * i + 1].value(), 0);
+        }
+    }
+}
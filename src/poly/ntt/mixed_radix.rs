@@ -0,0 +1,388 @@
+//! Mixed-radix NTT for lengths of the form 2^a · 3^b · 5^c.
+//!
+//! A length-n NTT needs a primitive n-th root of unity, which exists exactly
+//! when n divides q − 1. Fields whose multiplicative group order has factors
+//! 3 or 5 therefore support lengths between the powers of two, which lets a
+//! product of length L be computed at a size much closer to L than the next
+//! power of two.
+//!
+//! The transform is a recursive decimation-in-time Cooley-Tukey FFT. Each
+//! level splits the input into r interleaved subsequences, transforms them,
+//! and recombines them with a radix-r butterfly:
+//!
+//! - **Radix 2**: one twiddle multiplication per pair.
+//! - **Radix 3**: with ω₃² = −1 − ω₃, the two non-trivial outputs share the
+//!   single product ω₃ · (b − c).
+//! - **Radix 5**: the terms for s and 5 − s are paired, so the butterfly
+//!   needs eight products instead of sixteen.
+//!
+//! # Example
+//!
+//! ```
+//! use lumen_math::poly::ntt::MixedRadixNttContext;
+//! use lumen_math::{DefaultFieldConfig, FieldElement, U1024};
+//!
+//! // q − 1 is divisible by 2^32 · 5, so length 40 is supported.
+//! let ctx = MixedRadixNttContext::<DefaultFieldConfig>::new(40).unwrap();
+//! let coeffs: Vec<FieldElement<DefaultFieldConfig>> = (0..40)
+//!     .map(|i| FieldElement::new(U1024::from_u64(i)))
+//!     .collect();
+//!
+//! let mut values = coeffs.clone();
+//! ctx.ntt(&mut values);
+//! ctx.intt(&mut values);
+//! assert_eq!(values, coeffs);
+//!
+//! // 3 does not divide q − 1.
+//! assert!(MixedRadixNttContext::<DefaultFieldConfig>::new(12).is_none());
+//! ```
+
+use std::any::{Any, TypeId};
+use std::collections::HashMap;
+use std::sync::{Arc, OnceLock, RwLock};
+
+use crate::{FieldConfig, FieldElement, U1024};
+
+/// Radices supported by [`MixedRadixNttContext`].
+pub const MIXED_RADICES: [usize; 3] = [2, 3, 5];
+
+/// Returns the distinct prime factors of `n`.
+pub(crate) fn prime_factors(mut n: usize) -> Vec<usize> {
+    let mut primes = Vec::new();
+    let mut p = 2;
+    while p * p <= n {
+        if n.is_multiple_of(p) {
+            primes.push(p);
+            while n.is_multiple_of(p) {
+                n /= p;
+            }
+        }
+        p += 1;
+    }
+    if n > 1 {
+        primes.push(n);
+    }
+    primes
+}
+
+/// Returns the exponent of the prime `p` in q − 1.
+pub(crate) fn multiplicative_order_valuation<C: FieldConfig>(p: usize) -> u32 {
+    let divisor = U1024::from_u64(p as u64);
+    let mut m = C::MODULUS - U1024::ONE;
+    let mut e = 0;
+    loop {
+        let (quotient, remainder) = m.div_rem(&divisor);
+        if remainder != U1024::ZERO {
+            return e;
+        }
+        m = quotient;
+        e += 1;
+    }
+}
+
+/// Returns a primitive n-th root of unity, or `None` if n does not divide
+/// q − 1.
+///
+/// Candidates g = 2, 3, … are raised to (q − 1)/n until one has order
+/// exactly n, so the result is deterministic for a given field and length.
+pub(crate) fn root_of_unity_of_order<C: FieldConfig>(n: usize) -> Option<FieldElement<C>> {
+    if n == 0 {
+        return None;
+    }
+    let (cofactor, remainder) = (C::MODULUS - U1024::ONE).div_rem(&U1024::from_u64(n as u64));
+    if remainder != U1024::ZERO {
+        return None;
+    }
+
+    let one = FieldElement::<C>::one();
+    let primes = prime_factors(n);
+    // Every element of the order-n subgroup is hit with probability
+    // φ(n)/n, so a few candidates almost always suffice.
+    (2..1024u64)
+        .map(U1024::from_u64)
+        .take_while(|g| *g < C::MODULUS)
+        .map(|g| FieldElement::<C>::new(g).pow(cofactor))
+        .find(|w| {
+            primes
+                .iter()
+                .all(|&p| w.pow(U1024::from_u64((n / p) as u64)) != one)
+        })
+}
+
+/// Splits `n` into radices 2, 3 and 5, or returns `None` if it has another
+/// prime factor.
+fn factor_radices(mut n: usize) -> Option<Vec<usize>> {
+    let mut radices = Vec::new();
+    for r in MIXED_RADICES.into_iter().rev() {
+        while n.is_multiple_of(r) {
+            radices.push(r);
+            n /= r;
+        }
+    }
+    (n == 1).then_some(radices)
+}
+
+/// Powers of one root of unity and the butterfly constants derived from it.
+#[derive(Clone, Debug)]
+struct Twiddles<C: FieldConfig> {
+    /// ω^i for i < n
+    powers: Vec<FieldElement<C>>,
+    /// ω₃ = ω^(n/3), if 3 divides n
+    w3: FieldElement<C>,
+    /// (ω₅^k + ω₅^-k)/2 and (ω₅^k − ω₅^-k)/2 for k = 1, 2, if 5 divides n
+    c1: FieldElement<C>,
+    c2: FieldElement<C>,
+    s1: FieldElement<C>,
+    s2: FieldElement<C>,
+}
+
+impl<C: FieldConfig> Twiddles<C> {
+    fn new(omega: FieldElement<C>, n: usize) -> Self {
+        let powers: Vec<_> = std::iter::successors(Some(FieldElement::one()), |x| Some(*x * omega))
+            .take(n)
+            .collect();
+        let zero = FieldElement::zero();
+        let w3 = if n.is_multiple_of(3) {
+            powers[n / 3]
+        } else {
+            zero
+        };
+        let (mut c1, mut c2, mut s1, mut s2) = (zero, zero, zero, zero);
+        if n.is_multiple_of(5) {
+            let half = FieldElement::<C>::new(U1024::from_u64(2)).inv();
+            let w = |k: usize| powers[k * n / 5];
+            c1 = (w(1) + w(4)) * half;
+            s1 = (w(1) - w(4)) * half;
+            c2 = (w(2) + w(3)) * half;
+            s2 = (w(2) - w(3)) * half;
+        }
+        Self {
+            powers,
+            w3,
+            c1,
+            c2,
+            s1,
+            s2,
+        }
+    }
+
+    /// Returns ω_len^j for a sub-transform of length `len`.
+    fn root(&self, len: usize, j: usize) -> FieldElement<C> {
+        self.powers[j * (self.powers.len() / len)]
+    }
+}
+
+/// Context for NTTs of one length n = 2^a · 3^b · 5^c dividing q − 1.
+///
+/// [`ntt`](Self::ntt) maps coefficients a to the values
+/// A_k = Σ a_j ω^(jk) in natural order, where ω is [`root`](Self::root), and
+/// [`intt`](Self::intt) inverts it.
+#[derive(Clone, Debug)]
+pub struct MixedRadixNttContext<C: FieldConfig> {
+    n: usize,
+    radices: Vec<usize>,
+    forward: Twiddles<C>,
+    inverse: Twiddles<C>,
+    n_inv: FieldElement<C>,
+}
+
+impl<C: FieldConfig> MixedRadixNttContext<C> {
+    /// Creates a context for length `n`, or returns `None` if `n` has a
+    /// prime factor other than 2, 3 and 5 or does not divide q − 1.
+    pub fn new(n: usize) -> Option<Self> {
+        if n == 0 {
+            return None;
+        }
+        let radices = factor_radices(n)?;
+        let omega = root_of_unity_of_order::<C>(n)?;
+        Some(Self {
+            n,
+            radices,
+            forward: Twiddles::new(omega, n),
+            inverse: Twiddles::new(omega.inv(), n),
+            n_inv: FieldElement::<C>::new(U1024::from_u64(n as u64)).inv(),
+        })
+    }
+
+    /// Returns a shared context for `C` and length `n`, building it on first
+    /// use. The cache is safe to use from multiple threads.
+    pub fn shared(n: usize) -> Option<Arc<Self>> {
+        type Cache = RwLock<HashMap<(TypeId, usize), Option<Arc<dyn Any + Send + Sync>>>>;
+        static CONTEXTS: OnceLock<Cache> = OnceLock::new();
+
+        let cache = CONTEXTS.get_or_init(Default::default);
+        let key = (TypeId::of::<C>(), n);
+        let downcast = |ctx: &Option<Arc<dyn Any + Send + Sync>>| {
+            ctx.clone().and_then(|ctx| ctx.downcast::<Self>().ok())
+        };
+
+        if let Some(ctx) = cache.read().unwrap_or_else(|e| e.into_inner()).get(&key) {
+            return downcast(ctx);
+        }
+        let mut map = cache.write().unwrap_or_else(|e| e.into_inner());
+        let ctx = map
+            .entry(key)
+            .or_insert_with(|| Self::new(n).map(|ctx| Arc::new(ctx) as Arc<dyn Any + Send + Sync>));
+        downcast(ctx)
+    }
+
+    /// Returns the transform length.
+    pub fn len(&self) -> usize {
+        self.n
+    }
+
+    /// Returns `false`; a context always has a positive length.
+    pub fn is_empty(&self) -> bool {
+        false
+    }
+
+    /// Returns the radices the length factors into, largest first.
+    pub fn radices(&self) -> &[usize] {
+        &self.radices
+    }
+
+    /// Returns the primitive n-th root of unity ω used by the transform.
+    pub fn root(&self) -> FieldElement<C> {
+        self.forward.powers[1 % self.n]
+    }
+
+    /// Performs the forward NTT in place.
+    ///
+    /// # Panics
+    /// Panics if `coeffs.len() != self.len()`.
+    pub fn ntt(&self, coeffs: &mut [FieldElement<C>]) {
+        assert_eq!(
+            coeffs.len(),
+            self.n,
+            "Coefficient length must match context size"
+        );
+        let values = transform(coeffs, &self.radices, &self.forward);
+        coeffs.copy_from_slice(&values);
+    }
+
+    /// Performs the inverse NTT in place.
+    ///
+    /// # Panics
+    /// Panics if `values.len() != self.len()`.
+    pub fn intt(&self, values: &mut [FieldElement<C>]) {
+        assert_eq!(
+            values.len(),
+            self.n,
+            "Coefficient length must match context size"
+        );
+        let coeffs = transform(values, &self.radices, &self.inverse);
+        for (out, c) in values.iter_mut().zip(coeffs) {
+            *out = c * self.n_inv;
+        }
+    }
+
+    /// Multiplies two polynomials in Zq[X]/(X^n − 1).
+    ///
+    /// # Panics
+    /// Panics if either input length differs from `self.len()`.
+    pub fn mul(&self, a: &[FieldElement<C>], b: &[FieldElement<C>]) -> Vec<FieldElement<C>> {
+        assert_eq!(
+            a.len(),
+            self.n,
+            "First polynomial length must match context size"
+        );
+        assert_eq!(
+            b.len(),
+            self.n,
+            "Second polynomial length must match context size"
+        );
+        let a_ntt = transform(a, &self.radices, &self.forward);
+        let b_ntt = transform(b, &self.radices, &self.forward);
+        let product: Vec<_> = a_ntt.iter().zip(&b_ntt).map(|(x, y)| *x * *y).collect();
+        transform(&product, &self.radices, &self.inverse)
+            .into_iter()
+            .map(|c| c * self.n_inv)
+            .collect()
+    }
+}
+
+/// Recursive decimation-in-time transform of `input` with `radices[0]` as
+/// the outermost split.
+fn transform<C: FieldConfig>(
+    input: &[FieldElement<C>],
+    radices: &[usize],
+    tw: &Twiddles<C>,
+) -> Vec<FieldElement<C>> {
+    let len = input.len();
+    let Some((&r, rest)) = radices.split_first() else {
+        return input.to_vec();
+    };
+    let m = len / r;
+
+    // Transform the r subsequences a_s, a_(s+r), a_(s+2r), …
+    let subs: Vec<Vec<FieldElement<C>>> = (0..r)
+        .map(|s| {
+            let sub: Vec<_> = input[s..].iter().step_by(r).copied().collect();
+            transform(&sub, rest, tw)
+        })
+        .collect();
+
+    let mut out = vec![FieldElement::zero(); len];
+    for k in 0..m {
+        // y[s] = ω_len^(sk) · SUB_s[k]; the outputs k + t·m form a radix-r DFT of y.
+        match r {
+            2 => {
+                let u = subs[0][k];
+                let v = subs[1][k] * tw.root(len, k);
+                out[k] = u + v;
+                out[k + m] = u - v;
+            }
+            3 => {
+                let a = subs[0][k];
+                let b = subs[1][k] * tw.root(len, k);
+                let c = subs[2][k] * tw.root(len, 2 * k);
+                let t = tw.w3 * (b - c);
+                out[k] = a + b + c;
+                out[k + m] = a - c + t;
+                out[k + 2 * m] = a - b - t;
+            }
+            5 => {
+                let a0 = subs[0][k];
+                let a: Vec<_> = (1..5).map(|s| subs[s][k] * tw.root(len, s * k)).collect();
+                let (p1, m1) = (a[0] + a[3], a[0] - a[3]);
+                let (p2, m2) = (a[1] + a[2], a[1] - a[2]);
+                let (c1p1, c2p1) = (tw.c1 * p1, tw.c2 * p1);
+                let (c1p2, c2p2) = (tw.c1 * p2, tw.c2 * p2);
+                let (s1m1, s2m1) = (tw.s1 * m1, tw.s2 * m1);
+                let (s1m2, s2m2) = (tw.s1 * m2, tw.s2 * m2);
+                out[k] = a0 + p1 + p2;
+                out[k + m] = a0 + c1p1 + c2p2 + s1m1 + s2m2;
+                out[k + 2 * m] = a0 + c2p1 + c1p2 + s2m1 - s1m2;
+                out[k + 3 * m] = a0 + c2p1 + c1p2 - s2m1 + s1m2;
+                out[k + 4 * m] = a0 + c1p1 + c2p2 - s1m1 - s2m2;
+            }
+            _ => unreachable!("unsupported radix {r}"),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DefaultFieldConfig;
+
+    #[test]
+    fn test_factor_radices() {
+        assert_eq!(factor_radices(60), Some(vec![5, 3, 2, 2]));
+        assert_eq!(factor_radices(1), Some(vec![]));
+        assert_eq!(factor_radices(14), None);
+    }
+
+    #[test]
+    fn test_root_of_unity_of_order() {
+        let one = FieldElement::<DefaultFieldConfig>::one();
+        let w = root_of_unity_of_order::<DefaultFieldConfig>(281 * 5).unwrap();
+        assert_eq!(w.pow(U1024::from_u64(281 * 5)), one);
+        assert_ne!(w.pow(U1024::from_u64(281)), one);
+        assert_ne!(w.pow(U1024::from_u64(5)), one);
+        assert!(root_of_unity_of_order::<DefaultFieldConfig>(3).is_none());
+        assert_eq!(multiplicative_order_valuation::<DefaultFieldConfig>(2), 32);
+        assert_eq!(multiplicative_order_valuation::<DefaultFieldConfig>(5), 1);
+    }
+}
@@ -2,8 +2,19 @@
 //!
 //! This module provides NTT implementations for fast polynomial multiplication:
 //!
-//! - **Cyclic NTT**: Standard NTT over Zq[X]/(X^N - 1)
+//! - **Cyclic NTT**: Standard NTT over Zq[X]/(X^N - 1), with cached twiddles in
+//!   [`CyclicNttContext`]
 //! - **Negacyclic NTT**: NTT over Zq[X]/(X^N + 1) for lattice-based crypto
+//! - **Dilithium NTT**: FIPS 204 NTT on [`DilithiumFieldElement`](small::DilithiumFieldElement) arrays
+//! - **Kyber NTT**: FIPS 203 incomplete NTT on [`KyberFieldElement`](small::KyberFieldElement) arrays
+//! - **Mixed-radix NTT**: lengths 2^a · 3^b · 5^c dividing q − 1, in [`MixedRadixNttContext`]
+//! - **Bluestein NTT**: any length dividing q − 1, in [`BluesteinNttContext`]
+//! - **Truncated FFT**: products evaluated at exactly as many points as they
+//!   have coefficients, in [`mul_truncated`]
+//!
+//! [`MulStrategy`] picks among the power-of-two, mixed-radix and truncated
+//! transforms for a product of a given length, and is what
+//! [`Polynomial::mul_fast`](crate::Polynomial::mul_fast) uses.
 //!
 //! # Small-Modulus Field Types (Recommended for Production)
 //!
@@ -30,13 +41,31 @@
 //! intt::<DefaultFieldConfig>(&mut coeffs);
 //! ```
 
+pub mod bluestein;
 pub mod config;
 pub mod cyclic;
+pub mod dilithium;
+pub mod kyber;
+pub mod mixed_radix;
 pub mod negacyclic;
 pub mod small;
+pub mod strategy;
+pub mod truncated;
 
 // Re-export cyclic NTT functions for backward compatibility
-pub use cyclic::{bit_reverse, intt, ntt};
+pub use cyclic::{CyclicNttContext, bit_reverse, intt, ntt};
+
+// Re-export the non-power-of-two transforms and the multiplication planner
+pub use bluestein::BluesteinNttContext;
+pub use mixed_radix::{MIXED_RADICES, MixedRadixNttContext};
+pub use strategy::MulStrategy;
+pub use truncated::mul_truncated;
+
+// Re-export Dilithium's native NTT
+pub use dilithium::{DILITHIUM_ZETAS, dilithium_intt, dilithium_ntt, dilithium_pointwise_mul};
+
+// Re-export Kyber's incomplete NTT
+pub use kyber::{KYBER_GAMMAS, KYBER_ZETAS, kyber_basemul, kyber_intt, kyber_ntt};
 
 // Re-export negacyclic NTT functions
 pub use negacyclic::{intt_negacyclic, mul_negacyclic, ntt_negacyclic};
@@ -115,6 +144,13 @@ impl<C: FieldConfig> NttContext<C> {
         }
     }
 
+    /// Returns `true` if ψ is a primitive 2n-th root of unity (ψ^n = -1), so
+    /// that the transform multiplies in Zq[X]/(X^n + 1).
+    pub fn is_negacyclic(&self) -> bool {
+        let psi = FieldElement::<C>::new(C::PRIMITIVE_2NTH_ROOT);
+        self.psi_powers[self.n - 1] * psi == -FieldElement::one()
+    }
+
     /// Forward negacyclic NTT using precomputed values.
     ///
     /// # Panics
@@ -15,6 +15,17 @@
 
 use std::ops::{Add, Mul, Neg, Sub};
 
+/// Maps v ∈ [0, q) to v or v - q, whichever lies in [-(q-1)/2, (q-1)/2],
+/// without branching on v.
+#[inline]
+pub(crate) const fn centered_i64(v: u32, q: u32) -> i64 {
+    let v = v as i64;
+    let q = q as i64;
+    // All ones when v > (q-1)/2.
+    let mask = ((q - 1) / 2 - v) >> 63;
+    v - (q & mask)
+}
+
 // =============================================================================
 // Kyber Field Element (q = 3329)
 // =============================================================================
@@ -57,6 +68,14 @@ impl KyberFieldElement {
         self.0
     }
 
+    /// Returns the centered representative in [-(q-1)/2, (q-1)/2].
+    ///
+    /// Branch-free, so it can be used on secret coefficients.
+    #[inline]
+    pub const fn to_centered_i64(self) -> i64 {
+        centered_i64(self.0 as u32, KYBER_Q)
+    }
+
     /// Returns zero.
     #[inline]
     pub const fn zero() -> Self {
@@ -245,6 +264,14 @@ impl DilithiumFieldElement {
         self.0
     }
 
+    /// Returns the centered representative in [-(q-1)/2, (q-1)/2].
+    ///
+    /// Branch-free, so it can be used on secret coefficients.
+    #[inline]
+    pub const fn to_centered_i64(self) -> i64 {
+        centered_i64(self.0, DILITHIUM_Q)
+    }
+
     /// Returns zero.
     #[inline]
     pub const fn zero() -> Self {
@@ -0,0 +1,193 @@
+//! Choosing a transform for polynomial multiplication.
+//!
+//! A product of length L can be computed by a cyclic convolution of any
+//! size at least L. [`MulStrategy::select`] compares three candidates with a
+//! simple count of field multiplications:
+//!
+//! - **Power of two**: the next power of two N ≥ L, with the cached
+//!   [`CyclicNttContext`]; about 1.5·N·log₂N for three transforms.
+//! - **Mixed radix**: the smallest 2^a · 3^b · 5^c ≥ L dividing q − 1, with
+//!   [`MixedRadixNttContext`]; radix-3 and radix-5 butterflies cost about
+//!   one and 2.4 multiplications per element, against 0.5 for radix 2.
+//! - **Truncated**: [`mul_truncated`] on blocks following the binary
+//!   expansion of L, at the price of extra folds and twists.
+//!
+//! [`MulStrategy::Bluestein`] is only used when a caller asks for it. Its
+//! chirp-z transforms run a power-of-two convolution of size at least
+//! 2L − 1, so whenever it applies, padding to a power of two is cheaper.
+
+use std::any::TypeId;
+use std::collections::HashMap;
+use std::sync::{OnceLock, RwLock};
+
+use super::bluestein::BluesteinNttContext;
+use super::cyclic::{CyclicNttContext, checked_two_adicity};
+use super::mixed_radix::{MixedRadixNttContext, multiplicative_order_valuation};
+use super::truncated::{mul_truncated, truncated_cost};
+use crate::{FieldConfig, FieldElement};
+
+/// How [`Polynomial::mul_fast`](crate::Polynomial::mul_fast) computes a
+/// product.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MulStrategy {
+    /// Cyclic convolution of a power-of-two size.
+    PowerOfTwo { size: usize },
+    /// Cyclic convolution of a size 2^a · 3^b · 5^c with a non-trivial 3 or
+    /// 5 part.
+    MixedRadix { size: usize },
+    /// Truncated FFT evaluating exactly `len` points.
+    Truncated { len: usize },
+    /// Cyclic convolution of a size dividing q − 1 with any prime factors,
+    /// through Bluestein's chirp-z transform. Never returned by
+    /// [`select`](Self::select).
+    Bluestein { size: usize },
+}
+
+/// Returns the exponents of 2, 3 and 5 in q − 1 and the checked two-adicity
+/// of `ROOT_OF_UNITY`, cached per field.
+///
+/// `TWO_ADICITY` defaults to 32 whatever the root's order, so the
+/// power-of-two transforms are gated on the checked value instead.
+fn field_valuations<C: FieldConfig>() -> [u32; 4] {
+    static VALUATIONS: OnceLock<RwLock<HashMap<TypeId, [u32; 4]>>> = OnceLock::new();
+    let cache = VALUATIONS.get_or_init(Default::default);
+    let key = TypeId::of::<C>();
+    if let Some(v) = cache.read().unwrap_or_else(|e| e.into_inner()).get(&key) {
+        return *v;
+    }
+    let [v2, v3, v5] = [2, 3, 5].map(multiplicative_order_valuation::<C>);
+    let v = [v2, v3, v5, checked_two_adicity::<C>()];
+    cache
+        .write()
+        .unwrap_or_else(|e| e.into_inner())
+        .insert(key, v);
+    v
+}
+
+/// Estimated multiplications for a cyclic product of size 2^a · 3^b · 5^c.
+fn cyclic_cost(a: u32, b: u32, c: u32) -> f64 {
+    let size = (1usize << a) as f64 * 3f64.powi(b as i32) * 5f64.powi(c as i32);
+    3.0 * size * (0.5 * a as f64 + b as f64 + 2.4 * c as f64) + 2.0 * size
+}
+
+impl MulStrategy {
+    /// Picks the cheapest strategy for a product of `len` coefficients in
+    /// the field `C`.
+    ///
+    /// # Panics
+    /// Panics if no strategy applies, i.e. no power of two or mixed-radix
+    /// size of at least `len` has a root of unity in the field.
+    pub fn select<C: FieldConfig>(len: usize) -> Self {
+        let len = len.max(1);
+        let n = len.next_power_of_two();
+        let log_n = n.trailing_zeros();
+        let [v2, v3, v5, two_adicity] = field_valuations::<C>();
+        let mut best: Option<(f64, Self)> = None;
+        let mut consider = |cost: f64, strategy: Self| {
+            if best.is_none_or(|(c, _)| cost < c) {
+                best = Some((cost, strategy));
+            }
+        };
+
+        if log_n <= two_adicity {
+            consider(cyclic_cost(log_n, 0, 0), Self::PowerOfTwo { size: n });
+            if len != n {
+                consider(truncated_cost(len), Self::Truncated { len });
+            }
+        }
+
+        let mut odd = 1usize;
+        'fives: for c in 0..=v5 {
+            let mut base = odd;
+            for b in 0..=v3 {
+                if b + c > 0 {
+                    // Smallest 2^a with base · 2^a ≥ len.
+                    let a = len.div_ceil(base).next_power_of_two().trailing_zeros();
+                    if a <= v2 {
+                        consider(cyclic_cost(a, b, c), Self::MixedRadix { size: base << a });
+                    }
+                }
+                if base >= len {
+                    break;
+                }
+                base *= 3;
+            }
+            if odd >= len {
+                break 'fives;
+            }
+            odd *= 5;
+        }
+
+        best.map(|(_, s)| s)
+            .expect("no NTT size supports a product of this length")
+    }
+
+    /// Multiplies `a` and `b` with this strategy, returning the
+    /// `a.len() + b.len() - 1` coefficients of the product.
+    ///
+    /// # Panics
+    /// Panics if the strategy's size is below the product length or the
+    /// field lacks the required roots of unity.
+    pub fn mul<C: FieldConfig>(
+        &self,
+        a: &[FieldElement<C>],
+        b: &[FieldElement<C>],
+    ) -> Vec<FieldElement<C>> {
+        if a.is_empty() || b.is_empty() {
+            return Vec::new();
+        }
+        let len = a.len() + b.len() - 1;
+        let pad = |v: &[FieldElement<C>], size: usize| {
+            let mut v = v.to_vec();
+            v.resize(size, FieldElement::zero());
+            v
+        };
+        let mut product = match *self {
+            Self::PowerOfTwo { size } => {
+                assert!(size >= len, "NTT size is below the product length");
+                CyclicNttContext::<C>::shared(size).mul(&pad(a, size), &pad(b, size))
+            }
+            Self::MixedRadix { size } => {
+                assert!(size >= len, "NTT size is below the product length");
+                let ctx = MixedRadixNttContext::<C>::shared(size)
+                    .expect("field has no root of unity of the mixed-radix size");
+                ctx.mul(&pad(a, size), &pad(b, size))
+            }
+            Self::Truncated { .. } => mul_truncated(a, b),
+            Self::Bluestein { size } => {
+                assert!(size >= len, "NTT size is below the product length");
+                let ctx = BluesteinNttContext::<C>::new(size)
+                    .expect("field has no root of unity of the Bluestein size");
+                ctx.mul(&pad(a, size), &pad(b, size))
+            }
+        };
+        product.truncate(len);
+        product
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DefaultFieldConfig;
+
+    #[test]
+    fn test_select_prefers_exact_powers_of_two() {
+        assert_eq!(
+            MulStrategy::select::<DefaultFieldConfig>(512),
+            MulStrategy::PowerOfTwo { size: 512 }
+        );
+        assert_eq!(
+            MulStrategy::select::<DefaultFieldConfig>(1),
+            MulStrategy::PowerOfTwo { size: 1 }
+        );
+    }
+
+    #[test]
+    fn test_select_avoids_padding_just_above_a_power_of_two() {
+        assert_ne!(
+            MulStrategy::select::<DefaultFieldConfig>(513),
+            MulStrategy::PowerOfTwo { size: 1024 }
+        );
+    }
+}
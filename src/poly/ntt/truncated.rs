@@ -0,0 +1,262 @@
+//! Truncated FFT multiplication.
+//!
+//! Padding a product of length L to the next power of two N wastes up to a
+//! factor of two when L is just above N/2. The truncated FFT only evaluates
+//! the product at as many points as it has coefficients, following van der
+//! Hoeven's recursive split of the power-of-two transform.
+//!
+//! X^N − 1 factors into blocks X^k − τ along the same tree as the cyclic
+//! NTT, and c mod (X^k − τ) costs one size-k twisted cyclic product. To
+//! recover a polynomial h of degree below L from the residues of such
+//! blocks, split X^(2m) − ρ = (X^m − σ)(X^m + σ) with σ² = ρ:
+//!
+//! - If L ≤ m, h = h mod (X^m − σ), so recurse into that half alone.
+//! - Otherwise write h = h_lo + X^m · h_hi. The full residue
+//!   A = h mod (X^m − σ) = h_lo + σ · h_hi is computed directly. Modulo any
+//!   factor Q of X^m + σ, h ≡ h_lo − σ · h_hi, so
+//!   h_hi ≡ (A − h)/(2σ) mod Q. Since deg h_hi < L − m, h_hi is recovered
+//!   recursively from these residues, and then h_lo = A − σ · h_hi.
+//!
+//! The blocks evaluated follow the binary expansion of L, so the transform
+//! sizes add up to L instead of N.
+//!
+//! # Example
+//!
+//! ```
+//! use lumen_math::poly::ntt::mul_truncated;
+//! use lumen_math::{DefaultFieldConfig, FieldElement, U1024};
+//!
+//! let a: Vec<FieldElement<DefaultFieldConfig>> =
+//!     (1..=3).map(|i| FieldElement::new(U1024::from_u64(i))).collect();
+//! // (1 + 2X + 3X²)² = 1 + 4X + 10X² + 12X³ + 9X⁴
+//! let expected: Vec<_> = [1, 4, 10, 12, 9]
+//!     .iter()
+//!     .map(|&c| FieldElement::new(U1024::from_u64(c)))
+//!     .collect();
+//! assert_eq!(mul_truncated(&a, &a), expected);
+//! ```
+
+use super::cyclic::{CyclicNttContext, root_of_order};
+use crate::{FieldConfig, FieldElement, U1024};
+
+/// Residue oracle: returns h mod (X^k − ω^t) for a block of the tree.
+type Oracle<'a, C> = dyn Fn(usize, usize) -> Vec<FieldElement<C>> + 'a;
+
+/// The tree of factors X^k − ω^t of X^N − 1, with ω a primitive N-th root
+/// of unity; every exponent t is a multiple of k.
+struct Blocks<C: FieldConfig> {
+    n: usize,
+    omega: FieldElement<C>,
+    /// 1/2 = (q + 1)/2, kept to avoid field inversions
+    half: FieldElement<C>,
+}
+
+/// Blocks of size N/8 or less are not split further, so a product uses at
+/// most three block products; smaller blocks cost more in folds than they
+/// save in transforms.
+const MIN_SPLIT_DIVISOR: usize = 8;
+
+impl<C: FieldConfig> Blocks<C> {
+    /// Returns ω^t by square-and-multiply over the bits of t, which is far
+    /// cheaper than [`FieldElement::pow`] over a full 1024-bit exponent.
+    fn root(&self, t: usize) -> FieldElement<C> {
+        let (mut result, mut base, mut e) = (FieldElement::one(), self.omega, t % self.n);
+        while e > 0 {
+            if e & 1 == 1 {
+                result = result * base;
+            }
+            base = base * base;
+            e >>= 1;
+        }
+        result
+    }
+
+    /// Reduces `a` modulo X^k − ω^t.
+    fn fold(&self, a: &[FieldElement<C>], k: usize, t: usize) -> Vec<FieldElement<C>> {
+        let tau = self.root(t);
+        let mut out = vec![FieldElement::zero(); k];
+        let mut scale = FieldElement::one();
+        for (i, chunk) in a.chunks(k).enumerate() {
+            if i == 0 {
+                out[..chunk.len()].copy_from_slice(chunk);
+                continue;
+            }
+            scale = scale * tau;
+            for (o, c) in out.iter_mut().zip(chunk) {
+                *o = *o + *c * scale;
+            }
+        }
+        out
+    }
+
+    /// Returns a · b mod (X^k − ω^t) as a cyclic product twisted by a k-th
+    /// root μ = ω^(t/k) of ω^t.
+    fn mul_mod(
+        &self,
+        ctx: &CyclicNttContext<C>,
+        a: &[FieldElement<C>],
+        b: &[FieldElement<C>],
+        k: usize,
+        t: usize,
+    ) -> Vec<FieldElement<C>> {
+        let a = self.fold(a, k, t);
+        let b = self.fold(b, k, t);
+        if t == 0 {
+            return ctx.mul(&a, &b);
+        }
+
+        let mu = self.root(t / k);
+        let powers: Vec<_> = std::iter::successors(Some(FieldElement::one()), |x| Some(*x * mu))
+            .take(k)
+            .collect();
+        let twist = |v: Vec<FieldElement<C>>| -> Vec<FieldElement<C>> {
+            v.into_iter().zip(&powers).map(|(x, p)| x * *p).collect()
+        };
+        // μ^(−i) = μ^(k−i) / μ^k, and μ^k = ω^t.
+        let tau_inv = self.root(self.n - t);
+        let product = ctx.mul(&twist(a), &twist(b));
+        let mut out = Vec::with_capacity(k);
+        out.push(product[0]);
+        for i in 1..k {
+            out.push(product[i] * powers[k - i] * tau_inv);
+        }
+        out
+    }
+
+    /// Recovers h with deg h < `len` ≤ `m2` from residues modulo the factors
+    /// of X^m2 − ω^t.
+    fn recover(
+        &self,
+        len: usize,
+        m2: usize,
+        t: usize,
+        oracle: &Oracle<'_, C>,
+    ) -> Vec<FieldElement<C>> {
+        if len == 0 {
+            return Vec::new();
+        }
+        if len == m2 || m2 <= self.n / MIN_SPLIT_DIVISOR {
+            let mut h = oracle(m2, t);
+            h.truncate(len);
+            return h;
+        }
+        let m = m2 / 2;
+        // X^m2 − ω^t = (X^m − ω^(t/2)) (X^m − ω^(t/2 + N/2))
+        let (t_lo, t_hi) = (t / 2, (t / 2 + self.n / 2) % self.n);
+        if len <= m {
+            return self.recover(len, m, t_lo, oracle);
+        }
+
+        let sigma = self.root(t_lo);
+        let residue = oracle(m, t_lo);
+        let scale = self.root(self.n - t_lo) * self.half;
+        let hi_oracle = |k: usize, u: usize| -> Vec<FieldElement<C>> {
+            self.fold(&residue, k, u)
+                .into_iter()
+                .zip(oracle(k, u))
+                .map(|(a, h)| (a - h) * scale)
+                .collect()
+        };
+        let hi = self.recover(len - m, m, t_hi, &hi_oracle);
+
+        let mut h = residue;
+        for (lo, x) in h.iter_mut().zip(&hi) {
+            *lo = *lo - sigma * *x;
+        }
+        h.extend(hi);
+        h
+    }
+}
+
+/// Multiplies two polynomials with a truncated FFT.
+///
+/// Returns the `a.len() + b.len() - 1` coefficients of the product, or an
+/// empty vector if either input is empty.
+///
+/// # Panics
+/// Panics if the product length rounded up to a power of two exceeds
+/// 2^TWO_ADICITY.
+pub fn mul_truncated<C: FieldConfig>(
+    a: &[FieldElement<C>],
+    b: &[FieldElement<C>],
+) -> Vec<FieldElement<C>> {
+    if a.is_empty() || b.is_empty() {
+        return Vec::new();
+    }
+    let len = a.len() + b.len() - 1;
+    let n = len.next_power_of_two();
+    let ctx = CyclicNttContext::<C>::shared(n);
+    let blocks = Blocks {
+        n,
+        omega: root_of_order::<C>(n.trailing_zeros()),
+        half: FieldElement::new(C::MODULUS.shr(1) + U1024::ONE),
+    };
+    let oracle = |k: usize, t: usize| blocks.mul_mod(&ctx, a, b, k, t);
+    blocks.recover(len, n, 0, &oracle)
+}
+
+/// Estimates the field multiplications [`mul_truncated`] spends on a product
+/// of length `len`, following the same splits as the recovery.
+pub(crate) fn truncated_cost(len: usize) -> f64 {
+    let n = len.next_power_of_two();
+    let (mut cost, mut enclosing, mut m2, mut rest) = (0.0, 0.0, n, len);
+    while rest > 0 {
+        let whole = rest == m2 || m2 <= n / MIN_SPLIT_DIVISOR;
+        let k = if whole { m2 } else { m2 / 2 };
+        if whole || rest > k {
+            let kf = k as f64;
+            // Three transforms, pointwise product and scaling, and folds of
+            // a and b beyond the first chunk. Blocks other than the first
+            // are also twisted, and fold every enclosing residue.
+            cost += 1.5 * kf * kf.log2() + 2.0 * kf + (len - len.min(k)) as f64;
+            if enclosing > 0.0 {
+                cost += 5.0 * kf + enclosing;
+            }
+            rest -= rest.min(k);
+            enclosing += kf;
+        }
+        m2 = k;
+    }
+    cost
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DefaultFieldConfig;
+
+    fn field(v: u64) -> FieldElement<DefaultFieldConfig> {
+        FieldElement::new(U1024::from_u64(v))
+    }
+
+    fn schoolbook(
+        a: &[FieldElement<DefaultFieldConfig>],
+        b: &[FieldElement<DefaultFieldConfig>],
+    ) -> Vec<FieldElement<DefaultFieldConfig>> {
+        let mut out = vec![FieldElement::zero(); a.len() + b.len() - 1];
+        for (i, x) in a.iter().enumerate() {
+            for (j, y) in b.iter().enumerate() {
+                out[i + j] = out[i + j] + *x * *y;
+            }
+        }
+        out
+    }
+
+    #[test]
+    fn test_mul_truncated_all_small_lengths() {
+        for la in 1..12 {
+            for lb in 1..12 {
+                let a: Vec<_> = (0..la).map(|i| field(3 * i + 1)).collect();
+                let b: Vec<_> = (0..lb).map(|i| field(i * i + 2)).collect();
+                assert_eq!(mul_truncated(&a, &b), schoolbook(&a, &b), "{la} x {lb}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_truncated_cost() {
+        let padded = |n: f64| 1.5 * n * n.log2() + 2.0 * n;
+        assert_eq!(truncated_cost(1024), padded(1024.0));
+        assert!(truncated_cost(513) < padded(1024.0));
+    }
+}
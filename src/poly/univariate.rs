@@ -9,7 +9,7 @@
 use std::fmt;
 use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
 
-use crate::poly::ntt::{intt, ntt};
+use crate::poly::ntt::{CyclicNttContext, MulStrategy};
 use crate::{FieldConfig, FieldElement, U1024};
 
 /// A univariate polynomial over a finite field.
@@ -170,6 +170,9 @@ impl<C: FieldConfig> Polynomial<C> {
     }
 
     /// Multiplies two polynomials using NTT for large degrees.
+    ///
+    /// Twiddle factors come from the shared [`CyclicNttContext`] for `C`,
+    /// which is built on first use and reused by later products.
     pub fn mul_ntt(&self, other: &Self) -> Self {
         if self.is_zero() || other.is_zero() {
             return Self::zero();
@@ -188,25 +191,24 @@ impl<C: FieldConfig> Polynomial<C> {
         b_coeffs.extend_from_slice(&other.coeffs);
         b_coeffs.resize(size, zero);
 
-        ntt(&mut a_coeffs);
-        ntt(&mut b_coeffs);
-
-        for i in 0..size {
-            a_coeffs[i] = a_coeffs[i] * b_coeffs[i];
-        }
-
-        intt(&mut a_coeffs);
-        a_coeffs.truncate(output_len);
+        let mut product = CyclicNttContext::<C>::shared(size).mul(&a_coeffs, &b_coeffs);
+        product.truncate(output_len);
 
-        Self::new(a_coeffs)
+        Self::new(product)
     }
 
-    /// Multiplies two polynomials using NTT (alias for `mul_ntt`).
+    /// Multiplies two polynomials with the cheapest available transform.
     ///
-    /// This is provided for backward compatibility with `DensePolynomial`.
-    #[inline]
+    /// Unlike [`mul_ntt`](Self::mul_ntt), which always pads to a power of
+    /// two, this picks a power-of-two, mixed-radix or truncated transform
+    /// with [`MulStrategy::select`] based on the product length and the
+    /// roots of unity the field provides.
     pub fn mul_fast(&self, other: &Self) -> Self {
-        self.mul_ntt(other)
+        if self.is_zero() || other.is_zero() {
+            return Self::zero();
+        }
+        let len = self.coeffs.len() + other.coeffs.len() - 1;
+        Self::new(MulStrategy::select::<C>(len).mul(&self.coeffs, &other.coeffs))
     }
 
     /// Multiplies polynomial by a scalar.
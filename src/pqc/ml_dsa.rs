@@ -0,0 +1,900 @@
+//! ML-DSA digital signatures (FIPS 204).
+//!
+//! ML-DSA is a Fiat–Shamir-with-aborts signature over the ring
+//! R_q = Z_8380417[X]/(X^256 + 1). The public key is t1, the high bits of
+//! t = A·s1 + s2; a signature is (c̃, z, h) with z = y + c·s1, where the
+//! signer restarts with a fresh mask y whenever z or the low bits of
+//! w - c·s2 would leak information about the secret. The verifier recomputes
+//! the high bits of w with the help of the hint h.
+//!
+//! Polynomials are `[DilithiumFieldElement; 256]` arrays and ring products use
+//! [`dilithium_ntt`] and [`dilithium_pointwise_mul`]. H is SHAKE256 and the
+//! matrix Â is sampled with SHAKE128.
+//!
+//! Three parameter sets are provided: [`ML_DSA_44`], [`ML_DSA_65`] and
+//! [`ML_DSA_87`]. [`SigningKey::sign_deterministic`] uses rnd = 0^32 (the
+//! deterministic variant of FIPS 204); [`SigningKey::sign`] draws rnd from
+//! the supplied RNG (the hedged variant). Only "pure" ML-DSA is implemented,
+//! not the pre-hash variant HashML-DSA.
+//!
+//! # Security
+//!
+//! The implementation follows the algorithms exactly but is not constant
+//! time: the rejection loop and the coefficient comparisons branch on
+//! secret data. Key material is not zeroized on drop.
+//!
+//! # Examples
+//!
+//! ```
+//! use lumen_math::pqc::ml_dsa::ML_DSA_44;
+//!
+//! let (vk, sk) = ML_DSA_44.keygen_from_seed(&[7u8; 32]);
+//!
+//! let signature = sk.sign_deterministic(b"message", b"").unwrap();
+//! assert!(vk.verify(b"message", &signature, b"").is_ok());
+//! assert!(vk.verify(b"other message", &signature, b"").is_err());
+//! ```
+
+use rand::Rng;
+use sha3::Shake256;
+use sha3::digest::{ExtendableOutput, Update, XofReader};
+
+use crate::poly::ntt::dilithium::{dilithium_intt, dilithium_ntt, dilithium_pointwise_mul};
+use crate::poly::ntt::small::{DILITHIUM_Q, DilithiumFieldElement};
+use crate::ring::norm::infinity_norm_small;
+use crate::ring::sampling::sample_uniform_small;
+
+/// A polynomial in R_q, either in coefficient or NTT form.
+type Poly = [DilithiumFieldElement; 256];
+
+/// Number of dropped bits of t (the parameter d).
+pub const ML_DSA_D: u32 = 13;
+
+/// Maximum length of the context string.
+pub const MAX_CONTEXT_LEN: usize = 255;
+
+/// Error type for ML-DSA keys and signatures.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MlDsaError {
+    /// An input has the wrong length for the parameter set.
+    InvalidLength { expected: usize, actual: usize },
+    /// The context string is longer than 255 bytes.
+    ContextTooLong { len: usize },
+    /// The hint encoding in the signature is malformed.
+    MalformedSignature,
+    /// The signature does not verify.
+    VerificationFailed,
+}
+
+impl std::fmt::Display for MlDsaError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MlDsaError::InvalidLength { expected, actual } => {
+                write!(f, "Expected {} bytes, got {}", expected, actual)
+            }
+            MlDsaError::ContextTooLong { len } => write!(
+                f,
+                "Context string of {} bytes exceeds {} bytes",
+                len, MAX_CONTEXT_LEN
+            ),
+            MlDsaError::MalformedSignature => write!(f, "Malformed signature hint"),
+            MlDsaError::VerificationFailed => write!(f, "ML-DSA verification failed"),
+        }
+    }
+}
+
+impl std::error::Error for MlDsaError {}
+
+/// An ML-DSA parameter set (FIPS 204, Table 1).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MlDsaParams {
+    /// Human-readable name, e.g. `"ML-DSA-65"`.
+    pub name: &'static str,
+    /// Number of rows of A.
+    pub k: usize,
+    /// Number of columns of A.
+    pub l: usize,
+    /// Bound on the secret coefficients.
+    pub eta: u32,
+    /// Number of ±1 coefficients in the challenge c.
+    pub tau: usize,
+    /// Collision strength λ; the commitment hash c̃ has λ/4 bytes.
+    pub lambda: usize,
+    /// Range of the mask coefficients.
+    pub gamma1: u32,
+    /// Low-order rounding range.
+    pub gamma2: u32,
+    /// Maximum number of 1s in the hint.
+    pub omega: usize,
+}
+
+/// ML-DSA-44 (security category 2).
+pub const ML_DSA_44: MlDsaParams = MlDsaParams {
+    name: "ML-DSA-44",
+    k: 4,
+    l: 4,
+    eta: 2,
+    tau: 39,
+    lambda: 128,
+    gamma1: 1 << 17,
+    gamma2: (DILITHIUM_Q - 1) / 88,
+    omega: 80,
+};
+
+/// ML-DSA-65 (security category 3).
+pub const ML_DSA_65: MlDsaParams = MlDsaParams {
+    name: "ML-DSA-65",
+    k: 6,
+    l: 5,
+    eta: 4,
+    tau: 49,
+    lambda: 192,
+    gamma1: 1 << 19,
+    gamma2: (DILITHIUM_Q - 1) / 32,
+    omega: 55,
+};
+
+/// ML-DSA-87 (security category 5).
+pub const ML_DSA_87: MlDsaParams = MlDsaParams {
+    name: "ML-DSA-87",
+    k: 8,
+    l: 7,
+    eta: 2,
+    tau: 60,
+    lambda: 256,
+    gamma1: 1 << 19,
+    gamma2: (DILITHIUM_Q - 1) / 32,
+    omega: 75,
+};
+
+/// Number of bits needed to represent x.
+const fn bitlen(x: u32) -> usize {
+    (u32::BITS - x.leading_zeros()) as usize
+}
+
+impl MlDsaParams {
+    /// β = τ·η, the bound on ‖c·s‖∞.
+    pub const fn beta(&self) -> u32 {
+        self.tau as u32 * self.eta
+    }
+
+    const fn eta_bits(&self) -> usize {
+        bitlen(2 * self.eta)
+    }
+
+    const fn z_bits(&self) -> usize {
+        1 + bitlen(self.gamma1 - 1)
+    }
+
+    const fn w1_bits(&self) -> usize {
+        bitlen((DILITHIUM_Q - 1) / (2 * self.gamma2) - 1)
+    }
+
+    const fn c_tilde_len(&self) -> usize {
+        self.lambda / 4
+    }
+
+    /// Length in bytes of a public key: 32 + 320k.
+    pub const fn public_key_len(&self) -> usize {
+        32 + 320 * self.k
+    }
+
+    /// Length in bytes of a secret key.
+    pub const fn secret_key_len(&self) -> usize {
+        128 + 32 * ((self.k + self.l) * self.eta_bits() + ML_DSA_D as usize * self.k)
+    }
+
+    /// Length in bytes of a signature.
+    pub const fn signature_len(&self) -> usize {
+        self.c_tilde_len() + 32 * self.l * self.z_bits() + self.omega + self.k
+    }
+
+    /// Generates a key pair (FIPS 204, Algorithm 1).
+    pub fn keygen<R: Rng>(&self, rng: &mut R) -> (VerifyingKey, SigningKey) {
+        let mut xi = [0u8; 32];
+        rng.fill_bytes(&mut xi);
+        self.keygen_from_seed(&xi)
+    }
+
+    /// Deterministically derives a key pair from the seed ξ
+    /// (ML-DSA.KeyGen_internal, FIPS 204, Algorithm 6).
+    pub fn keygen_from_seed(&self, xi: &[u8; 32]) -> (VerifyingKey, SigningKey) {
+        let seeds = shake256(&[xi, &[self.k as u8, self.l as u8]], 128);
+        let (rho, rest) = seeds.split_at(32);
+        let (rho_prime, key) = rest.split_at(64);
+
+        let a_hat = self.expand_a(rho);
+        let (s1, s2) = self.expand_s(rho_prime);
+        let t = self.matrix_vector_ntt(&a_hat, &ntt_vector(&s1));
+
+        let mut t1 = Vec::with_capacity(self.k);
+        let mut t0 = Vec::with_capacity(self.k);
+        for (t_i, s2_i) in t.iter().zip(s2.iter()) {
+            let mut high = [0u32; 256];
+            let mut low = [0i32; 256];
+            for j in 0..256 {
+                (high[j], low[j]) = power2round(t_i[j] + s2_i[j]);
+            }
+            t1.push(high);
+            t0.push(low);
+        }
+
+        let mut pk = Vec::with_capacity(self.public_key_len());
+        pk.extend_from_slice(rho);
+        for t1_i in &t1 {
+            pk.extend_from_slice(&pack_bits(t1_i.iter().copied(), 10));
+        }
+        let tr = shake256(&[&pk], 64);
+
+        let eta = self.eta as i32;
+        let mut sk = Vec::with_capacity(self.secret_key_len());
+        sk.extend_from_slice(rho);
+        sk.extend_from_slice(key);
+        sk.extend_from_slice(&tr);
+        for s in s1.iter().chain(s2.iter()) {
+            let values = s.iter().map(|c| (eta - centered(*c)) as u32);
+            sk.extend_from_slice(&pack_bits(values, self.eta_bits()));
+        }
+        let half = 1i32 << (ML_DSA_D - 1);
+        for t0_i in &t0 {
+            let values = t0_i.iter().map(|c| (half - c) as u32);
+            sk.extend_from_slice(&pack_bits(values, ML_DSA_D as usize));
+        }
+
+        (
+            VerifyingKey {
+                params: *self,
+                bytes: pk,
+            },
+            SigningKey {
+                params: *self,
+                bytes: sk,
+            },
+        )
+    }
+
+    // ------------------------------------------------------------------------
+    // Samplers (FIPS 204, Section 7.3)
+    // ------------------------------------------------------------------------
+
+    /// Expands ρ into the k × l matrix Â in NTT form (Algorithms 30 and 32).
+    pub fn expand_a(&self, rho: &[u8]) -> Vec<Vec<[DilithiumFieldElement; 256]>> {
+        (0..self.k)
+            .map(|r| {
+                (0..self.l)
+                    .map(|s| sample_uniform_small(rho, &[s as u8, r as u8]))
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Expands ρ' into the secret vectors s1 ∈ R^l and s2 ∈ R^k with
+    /// coefficients in [-η, η] (Algorithm 33).
+    pub fn expand_s(&self, rho_prime: &[u8]) -> (Vec<Poly>, Vec<Poly>) {
+        let s1 = (0..self.l)
+            .map(|r| rej_bounded_poly(rho_prime, r as u16, self.eta))
+            .collect();
+        let s2 = (0..self.k)
+            .map(|r| rej_bounded_poly(rho_prime, (r + self.l) as u16, self.eta))
+            .collect();
+        (s1, s2)
+    }
+
+    /// Expands ρ'' and the counter κ into the mask y ∈ R^l with coefficients
+    /// in (-γ1, γ1] (Algorithm 34).
+    pub fn expand_mask(&self, rho: &[u8], kappa: u16) -> Vec<Poly> {
+        let bits = self.z_bits();
+        (0..self.l)
+            .map(|r| {
+                let nonce = (kappa + r as u16).to_le_bytes();
+                let bytes = shake256(&[rho, &nonce], 32 * bits);
+                let values = unpack_bits(&bytes, bits);
+                std::array::from_fn(|i| from_signed(self.gamma1 as i32 - values[i] as i32))
+            })
+            .collect()
+    }
+
+    // ------------------------------------------------------------------------
+    // Internal helpers
+    // ------------------------------------------------------------------------
+
+    /// Computes NTT^-1(Â ∘ v̂) for v̂ in NTT form.
+    fn matrix_vector_ntt(&self, a_hat: &[Vec<Poly>], v_hat: &[Poly]) -> Vec<Poly> {
+        a_hat
+            .iter()
+            .map(|row| {
+                let mut acc = [DilithiumFieldElement::zero(); 256];
+                for (a, v) in row.iter().zip(v_hat.iter()) {
+                    acc = poly_add(&acc, &dilithium_pointwise_mul(a, v));
+                }
+                dilithium_intt(&mut acc);
+                acc
+            })
+            .collect()
+    }
+
+    /// Encodes w1 with SimpleBitPack (Algorithm 28).
+    fn w1_encode(&self, w1: &[[u32; 256]]) -> Vec<u8> {
+        w1.iter()
+            .flat_map(|p| pack_bits(p.iter().copied(), self.w1_bits()))
+            .collect()
+    }
+
+    /// Encodes the hint vector (Algorithm 20).
+    fn hint_bit_pack(&self, h: &[[bool; 256]]) -> Vec<u8> {
+        let mut y = vec![0u8; self.omega + self.k];
+        let mut index = 0;
+        for (i, h_i) in h.iter().enumerate() {
+            for (j, bit) in h_i.iter().enumerate() {
+                if *bit {
+                    y[index] = j as u8;
+                    index += 1;
+                }
+            }
+            y[self.omega + i] = index as u8;
+        }
+        y
+    }
+
+    /// Decodes the hint vector, rejecting non-canonical encodings (Algorithm 21).
+    fn hint_bit_unpack(&self, y: &[u8]) -> Option<Vec<[bool; 256]>> {
+        let mut h = vec![[false; 256]; self.k];
+        let mut index = 0;
+        for (i, h_i) in h.iter_mut().enumerate() {
+            let end = y[self.omega + i] as usize;
+            if end < index || end > self.omega {
+                return None;
+            }
+            let first = index;
+            while index < end {
+                if index > first && y[index - 1] >= y[index] {
+                    return None;
+                }
+                h_i[y[index] as usize] = true;
+                index += 1;
+            }
+        }
+        if y[index..self.omega].iter().any(|b| *b != 0) {
+            return None;
+        }
+        Some(h)
+    }
+}
+
+// ============================================================================
+// Keys
+// ============================================================================
+
+/// An ML-DSA verifying (public) key: ρ || t1.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VerifyingKey {
+    params: MlDsaParams,
+    bytes: Vec<u8>,
+}
+
+impl VerifyingKey {
+    /// Parses a verifying key of the right length.
+    pub fn from_bytes(params: MlDsaParams, bytes: &[u8]) -> Result<Self, MlDsaError> {
+        check_len(params.public_key_len(), bytes.len())?;
+        Ok(Self {
+            params,
+            bytes: bytes.to_vec(),
+        })
+    }
+
+    /// Returns the parameter set.
+    pub fn params(&self) -> MlDsaParams {
+        self.params
+    }
+
+    /// Returns the encoded key.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// Verifies a signature on `message` under the context string `ctx`
+    /// (ML-DSA.Verify, FIPS 204, Algorithm 3).
+    pub fn verify(&self, message: &[u8], signature: &[u8], ctx: &[u8]) -> Result<(), MlDsaError> {
+        let m_prime = format_message(message, ctx)?;
+        self.verify_internal(&m_prime, signature)
+    }
+
+    /// Verifies a signature on the already formatted message M'
+    /// (ML-DSA.Verify_internal, FIPS 204, Algorithm 8).
+    pub fn verify_internal(&self, m_prime: &[u8], signature: &[u8]) -> Result<(), MlDsaError> {
+        let p = &self.params;
+        check_len(p.signature_len(), signature.len())?;
+
+        let (rho, t1_bytes) = self.bytes.split_at(32);
+        let (c_tilde, rest) = signature.split_at(p.c_tilde_len());
+        let (z_bytes, h_bytes) = rest.split_at(32 * p.l * p.z_bits());
+        let h = p
+            .hint_bit_unpack(h_bytes)
+            .ok_or(MlDsaError::MalformedSignature)?;
+
+        let z: Vec<Poly> = z_bytes
+            .chunks(32 * p.z_bits())
+            .map(|chunk| {
+                let values = unpack_bits(chunk, p.z_bits());
+                std::array::from_fn(|i| from_signed(p.gamma1 as i32 - values[i] as i32))
+            })
+            .collect();
+        if infinity_norm(&z) >= p.gamma1 - p.beta() {
+            return Err(MlDsaError::VerificationFailed);
+        }
+
+        let a_hat = p.expand_a(rho);
+        let tr = shake256(&[&self.bytes], 64);
+        let mu = shake256(&[&tr, m_prime], 64);
+        let mut c_hat = sample_in_ball(c_tilde, p.tau);
+        dilithium_ntt(&mut c_hat);
+
+        let az = p.matrix_vector_ntt(&a_hat, &ntt_vector(&z));
+        let scale = DilithiumFieldElement::new(1 << ML_DSA_D);
+        let mut w1 = Vec::with_capacity(p.k);
+        for ((az_i, chunk), h_i) in az.iter().zip(t1_bytes.chunks(320)).zip(h.iter()) {
+            let values = unpack_bits(chunk, 10);
+            let mut t1_i: Poly =
+                std::array::from_fn(|j| DilithiumFieldElement::new(values[j]) * scale);
+            dilithium_ntt(&mut t1_i);
+            let mut ct1 = dilithium_pointwise_mul(&c_hat, &t1_i);
+            dilithium_intt(&mut ct1);
+
+            let mut w1_i = [0u32; 256];
+            for j in 0..256 {
+                w1_i[j] = use_hint(h_i[j], az_i[j] - ct1[j], p.gamma2);
+            }
+            w1.push(w1_i);
+        }
+
+        let c_tilde_prime = shake256(&[&mu, &p.w1_encode(&w1)], p.c_tilde_len());
+        if c_tilde_prime == c_tilde {
+            Ok(())
+        } else {
+            Err(MlDsaError::VerificationFailed)
+        }
+    }
+}
+
+/// An ML-DSA signing (secret) key: ρ || K || tr || s1 || s2 || t0.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SigningKey {
+    params: MlDsaParams,
+    bytes: Vec<u8>,
+}
+
+impl SigningKey {
+    /// Parses a signing key of the right length.
+    pub fn from_bytes(params: MlDsaParams, bytes: &[u8]) -> Result<Self, MlDsaError> {
+        check_len(params.secret_key_len(), bytes.len())?;
+        Ok(Self {
+            params,
+            bytes: bytes.to_vec(),
+        })
+    }
+
+    /// Returns the parameter set.
+    pub fn params(&self) -> MlDsaParams {
+        self.params
+    }
+
+    /// Returns the encoded key.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// Signs `message` under the context string `ctx` with fresh randomness
+    /// (hedged ML-DSA.Sign, FIPS 204, Algorithm 2).
+    pub fn sign<R: Rng>(
+        &self,
+        message: &[u8],
+        ctx: &[u8],
+        rng: &mut R,
+    ) -> Result<Vec<u8>, MlDsaError> {
+        let m_prime = format_message(message, ctx)?;
+        let mut rnd = [0u8; 32];
+        rng.fill_bytes(&mut rnd);
+        Ok(self.sign_internal(&m_prime, &rnd))
+    }
+
+    /// Signs `message` under the context string `ctx` with rnd = 0^32
+    /// (deterministic ML-DSA.Sign).
+    pub fn sign_deterministic(&self, message: &[u8], ctx: &[u8]) -> Result<Vec<u8>, MlDsaError> {
+        let m_prime = format_message(message, ctx)?;
+        Ok(self.sign_internal(&m_prime, &[0u8; 32]))
+    }
+
+    /// Signs the already formatted message M' with the given randomness
+    /// (ML-DSA.Sign_internal, FIPS 204, Algorithm 7).
+    pub fn sign_internal(&self, m_prime: &[u8], rnd: &[u8; 32]) -> Vec<u8> {
+        let p = &self.params;
+        let (rho, rest) = self.bytes.split_at(32);
+        let (key, rest) = rest.split_at(32);
+        let (tr, rest) = rest.split_at(64);
+        let (s_bytes, t0_bytes) = rest.split_at(32 * (p.k + p.l) * p.eta_bits());
+
+        let eta = p.eta as i32;
+        let s: Vec<Poly> = s_bytes
+            .chunks(32 * p.eta_bits())
+            .map(|chunk| {
+                let values = unpack_bits(chunk, p.eta_bits());
+                std::array::from_fn(|i| from_signed(eta - values[i] as i32))
+            })
+            .collect();
+        let half = 1i32 << (ML_DSA_D - 1);
+        let t0: Vec<Poly> = t0_bytes
+            .chunks(32 * ML_DSA_D as usize)
+            .map(|chunk| {
+                let values = unpack_bits(chunk, ML_DSA_D as usize);
+                std::array::from_fn(|i| from_signed(half - values[i] as i32))
+            })
+            .collect();
+
+        let s1_hat = ntt_vector(&s[..p.l]);
+        let s2_hat = ntt_vector(&s[p.l..]);
+        let t0_hat = ntt_vector(&t0);
+        let a_hat = p.expand_a(rho);
+
+        let mu = shake256(&[tr, m_prime], 64);
+        let rho_pp = shake256(&[key, rnd, &mu], 64);
+
+        let gamma1_bound = p.gamma1 - p.beta();
+        let gamma2_bound = p.gamma2 - p.beta();
+        let mut kappa: u16 = 0;
+        loop {
+            let y = p.expand_mask(&rho_pp, kappa);
+            kappa += p.l as u16;
+
+            let w = p.matrix_vector_ntt(&a_hat, &ntt_vector(&y));
+            let w1: Vec<[u32; 256]> = w
+                .iter()
+                .map(|w_i| std::array::from_fn(|j| high_bits(w_i[j], p.gamma2)))
+                .collect();
+
+            let c_tilde = shake256(&[&mu, &p.w1_encode(&w1)], p.c_tilde_len());
+            let mut c_hat = sample_in_ball(&c_tilde, p.tau);
+            dilithium_ntt(&mut c_hat);
+
+            let z: Vec<Poly> = y
+                .iter()
+                .zip(mul_by_challenge(&c_hat, &s1_hat))
+                .map(|(y_i, cs1_i)| poly_add(y_i, &cs1_i))
+                .collect();
+            if infinity_norm(&z) >= gamma1_bound {
+                continue;
+            }
+
+            let r: Vec<Poly> = w
+                .iter()
+                .zip(mul_by_challenge(&c_hat, &s2_hat))
+                .map(|(w_i, cs2_i)| poly_sub(w_i, &cs2_i))
+                .collect();
+            let r0_max = r
+                .iter()
+                .flat_map(|p_i| p_i.iter())
+                .map(|c| low_bits(*c, p.gamma2).unsigned_abs())
+                .max()
+                .unwrap_or(0);
+            if r0_max >= gamma2_bound {
+                continue;
+            }
+
+            let ct0 = mul_by_challenge(&c_hat, &t0_hat);
+            if infinity_norm(&ct0) >= p.gamma2 {
+                continue;
+            }
+            let mut ones = 0;
+            let h: Vec<[bool; 256]> = ct0
+                .iter()
+                .zip(r.iter())
+                .map(|(ct0_i, r_i)| {
+                    std::array::from_fn(|j| {
+                        let hint = make_hint(-ct0_i[j], r_i[j] + ct0_i[j], p.gamma2);
+                        ones += hint as usize;
+                        hint
+                    })
+                })
+                .collect();
+            if ones > p.omega {
+                continue;
+            }
+
+            let mut sig = Vec::with_capacity(p.signature_len());
+            sig.extend_from_slice(&c_tilde);
+            for z_i in &z {
+                let values = z_i.iter().map(|c| (p.gamma1 as i32 - centered(*c)) as u32);
+                sig.extend_from_slice(&pack_bits(values, p.z_bits()));
+            }
+            sig.extend_from_slice(&p.hint_bit_pack(&h));
+            return sig;
+        }
+    }
+}
+
+/// Builds M' = 0 || |ctx| || ctx || M for pure ML-DSA.
+fn format_message(message: &[u8], ctx: &[u8]) -> Result<Vec<u8>, MlDsaError> {
+    if ctx.len() > MAX_CONTEXT_LEN {
+        return Err(MlDsaError::ContextTooLong { len: ctx.len() });
+    }
+    let mut m_prime = Vec::with_capacity(2 + ctx.len() + message.len());
+    m_prime.push(0);
+    m_prime.push(ctx.len() as u8);
+    m_prime.extend_from_slice(ctx);
+    m_prime.extend_from_slice(message);
+    Ok(m_prime)
+}
+
+fn check_len(expected: usize, actual: usize) -> Result<(), MlDsaError> {
+    if expected == actual {
+        Ok(())
+    } else {
+        Err(MlDsaError::InvalidLength { expected, actual })
+    }
+}
+
+// ============================================================================
+// Rounding and hints (FIPS 204, Section 7.4)
+// ============================================================================
+
+/// Splits r into (r1, r0) with r = r1·2^d + r0 and r0 ∈ (-2^(d-1), 2^(d-1)]
+/// (Algorithm 35).
+pub fn power2round(r: DilithiumFieldElement) -> (u32, i32) {
+    let r_plus = r.value() as i32;
+    let half = 1 << (ML_DSA_D - 1);
+    let mut r0 = r_plus & ((1 << ML_DSA_D) - 1);
+    if r0 > half {
+        r0 -= 1 << ML_DSA_D;
+    }
+    (((r_plus - r0) >> ML_DSA_D) as u32, r0)
+}
+
+/// Splits r into high and low bits with r = r1·2γ2 + r0 and
+/// r0 ∈ (-γ2, γ2], handling the wrap-around at q - 1 (Algorithm 36).
+pub fn decompose(r: DilithiumFieldElement, gamma2: u32) -> (u32, i32) {
+    let r_plus = r.value() as i32;
+    let alpha = 2 * gamma2 as i32;
+    let mut r0 = r_plus % alpha;
+    if r0 > gamma2 as i32 {
+        r0 -= alpha;
+    }
+    if r_plus - r0 == DILITHIUM_Q as i32 - 1 {
+        (0, r0 - 1)
+    } else {
+        (((r_plus - r0) / alpha) as u32, r0)
+    }
+}
+
+/// Returns r1 from [`decompose`] (Algorithm 37).
+pub fn high_bits(r: DilithiumFieldElement, gamma2: u32) -> u32 {
+    decompose(r, gamma2).0
+}
+
+/// Returns r0 from [`decompose`] (Algorithm 38).
+pub fn low_bits(r: DilithiumFieldElement, gamma2: u32) -> i32 {
+    decompose(r, gamma2).1
+}
+
+/// Returns true if adding z to r changes the high bits of r (Algorithm 39).
+pub fn make_hint(z: DilithiumFieldElement, r: DilithiumFieldElement, gamma2: u32) -> bool {
+    high_bits(r, gamma2) != high_bits(r + z, gamma2)
+}
+
+/// Recovers the high bits of r + z from r and the hint h (Algorithm 40).
+pub fn use_hint(h: bool, r: DilithiumFieldElement, gamma2: u32) -> u32 {
+    let m = (DILITHIUM_Q - 1) / (2 * gamma2);
+    let (r1, r0) = decompose(r, gamma2);
+    match (h, r0 > 0) {
+        (false, _) => r1,
+        (true, true) => (r1 + 1) % m,
+        (true, false) => (r1 + m - 1) % m,
+    }
+}
+
+// ============================================================================
+// Sampling (FIPS 204, Section 7.3)
+// ============================================================================
+
+/// Hashes ρ into a polynomial with exactly τ coefficients in {-1, 1} and the
+/// rest zero (Algorithm 29).
+pub fn sample_in_ball(rho: &[u8], tau: usize) -> [DilithiumFieldElement; 256] {
+    let mut xof = Shake256::default();
+    xof.update(rho);
+    let mut reader = xof.finalize_xof();
+
+    let mut sign_bytes = [0u8; 8];
+    reader.read(&mut sign_bytes);
+    let signs = u64::from_le_bytes(sign_bytes);
+
+    let mut c = [DilithiumFieldElement::zero(); 256];
+    let mut byte = [0u8; 1];
+    for (t, i) in (256 - tau..256).enumerate() {
+        let j = loop {
+            reader.read(&mut byte);
+            if byte[0] as usize <= i {
+                break byte[0] as usize;
+            }
+        };
+        c[i] = c[j];
+        c[j] = if (signs >> t) & 1 == 1 {
+            -DilithiumFieldElement::one()
+        } else {
+            DilithiumFieldElement::one()
+        };
+    }
+    c
+}
+
+/// Samples a polynomial with coefficients in [-η, η] from
+/// SHAKE256(ρ || r) (Algorithm 31).
+fn rej_bounded_poly(rho: &[u8], r: u16, eta: u32) -> Poly {
+    let mut xof = Shake256::default();
+    xof.update(rho);
+    xof.update(&r.to_le_bytes());
+    let mut reader = xof.finalize_xof();
+
+    let from_half_byte = |b: u32| -> Option<i32> {
+        match eta {
+            2 if b < 15 => Some(2 - (b % 5) as i32),
+            4 if b < 9 => Some(4 - b as i32),
+            _ => None,
+        }
+    };
+
+    let mut a = [DilithiumFieldElement::zero(); 256];
+    let mut count = 0;
+    let mut byte = [0u8; 1];
+    while count < 256 {
+        reader.read(&mut byte);
+        for half in [byte[0] as u32 & 0x0f, byte[0] as u32 >> 4] {
+            if count < 256
+                && let Some(v) = from_half_byte(half)
+            {
+                a[count] = from_signed(v);
+                count += 1;
+            }
+        }
+    }
+    a
+}
+
+fn shake256(parts: &[&[u8]], len: usize) -> Vec<u8> {
+    let mut xof = Shake256::default();
+    for part in parts {
+        xof.update(part);
+    }
+    let mut out = vec![0u8; len];
+    xof.finalize_xof().read(&mut out);
+    out
+}
+
+// ============================================================================
+// Polynomial helpers
+// ============================================================================
+
+/// Returns the representative of x in (-(q-1)/2, (q-1)/2].
+fn centered(x: DilithiumFieldElement) -> i32 {
+    x.to_centered_i64() as i32
+}
+
+fn from_signed(v: i32) -> DilithiumFieldElement {
+    DilithiumFieldElement::new(v.rem_euclid(DILITHIUM_Q as i32) as u32)
+}
+
+fn infinity_norm(v: &[Poly]) -> u32 {
+    v.iter().map(|p| infinity_norm_small(p)).max().unwrap_or(0)
+}
+
+fn poly_add(a: &Poly, b: &Poly) -> Poly {
+    std::array::from_fn(|i| a[i] + b[i])
+}
+
+fn poly_sub(a: &Poly, b: &Poly) -> Poly {
+    std::array::from_fn(|i| a[i] - b[i])
+}
+
+fn ntt_vector(v: &[Poly]) -> Vec<Poly> {
+    v.iter()
+        .map(|p| {
+            let mut p = *p;
+            dilithium_ntt(&mut p);
+            p
+        })
+        .collect()
+}
+
+/// Computes NTT^-1(ĉ ∘ v̂_i) for each entry.
+fn mul_by_challenge(c_hat: &Poly, v_hat: &[Poly]) -> Vec<Poly> {
+    v_hat
+        .iter()
+        .map(|v| {
+            let mut p = dilithium_pointwise_mul(c_hat, v);
+            dilithium_intt(&mut p);
+            p
+        })
+        .collect()
+}
+
+/// Packs 256 values of `bits` bits each, least significant bit first.
+fn pack_bits(values: impl Iterator<Item = u32>, bits: usize) -> Vec<u8> {
+    let mut out = vec![0u8; 32 * bits];
+    for (i, value) in values.enumerate() {
+        for j in 0..bits {
+            let pos = i * bits + j;
+            out[pos / 8] |= (((value >> j) & 1) as u8) << (pos % 8);
+        }
+    }
+    out
+}
+
+/// Unpacks 256 values of `bits` bits each.
+fn unpack_bits(bytes: &[u8], bits: usize) -> [u32; 256] {
+    std::array::from_fn(|i| {
+        (0..bits).fold(0u32, |acc, j| {
+            let pos = i * bits + j;
+            acc | ((((bytes[pos / 8] >> (pos % 8)) & 1) as u32) << j)
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sizes_match_fips204_table2() {
+        assert_eq!(ML_DSA_44.public_key_len(), 1312);
+        assert_eq!(ML_DSA_44.secret_key_len(), 2560);
+        assert_eq!(ML_DSA_44.signature_len(), 2420);
+        assert_eq!(ML_DSA_65.public_key_len(), 1952);
+        assert_eq!(ML_DSA_65.secret_key_len(), 4032);
+        assert_eq!(ML_DSA_65.signature_len(), 3309);
+        assert_eq!(ML_DSA_87.public_key_len(), 2592);
+        assert_eq!(ML_DSA_87.secret_key_len(), 4896);
+        assert_eq!(ML_DSA_87.signature_len(), 4627);
+    }
+
+    #[test]
+    fn test_power2round_recombines() {
+        for v in [0, 1, 4096, 4097, 8191, 8192, 123456, DILITHIUM_Q - 1] {
+            let r = DilithiumFieldElement::new(v);
+            let (r1, r0) = power2round(r);
+            assert!(r0 > -(1 << 12) && r0 <= 1 << 12);
+            assert_eq!(from_signed((r1 << ML_DSA_D) as i32 + r0), r);
+        }
+    }
+
+    #[test]
+    fn test_decompose_recombines() {
+        for gamma2 in [ML_DSA_44.gamma2, ML_DSA_65.gamma2] {
+            for v in (0..DILITHIUM_Q).step_by(9973).chain([DILITHIUM_Q - 1]) {
+                let r = DilithiumFieldElement::new(v);
+                let (r1, r0) = decompose(r, gamma2);
+                assert!(r0 >= -(gamma2 as i32) && r0 <= gamma2 as i32);
+                assert_eq!(from_signed((r1 * 2 * gamma2) as i32 + r0), r);
+            }
+        }
+    }
+
+    #[test]
+    fn test_use_hint_recovers_high_bits() {
+        let gamma2 = ML_DSA_44.gamma2;
+        for v in (0..DILITHIUM_Q).step_by(7919) {
+            let r = DilithiumFieldElement::new(v);
+            for z in [-60000i32, -1, 0, 1, 60000] {
+                let z = from_signed(z);
+                let h = make_hint(z, r, gamma2);
+                assert_eq!(use_hint(h, r, gamma2), high_bits(r + z, gamma2));
+            }
+        }
+    }
+
+    #[test]
+    fn test_sample_in_ball_weight() {
+        for tau in [39, 49, 60] {
+            let c = sample_in_ball(b"seed", tau);
+            let nonzero: Vec<_> = c.iter().filter(|x| !x.is_zero()).collect();
+            assert_eq!(nonzero.len(), tau);
+            assert!(nonzero.iter().all(|x| centered(**x).abs() == 1));
+        }
+    }
+}
@@ -0,0 +1,597 @@
+//! ML-KEM key encapsulation (FIPS 203).
+//!
+//! ML-KEM is built from the K-PKE public-key encryption scheme over the ring
+//! R_q = Z_3329[X]/(X^256 + 1) and turned into an IND-CCA2 KEM with the
+//! Fujisaki–Okamoto transform. Decapsulation re-encrypts the recovered
+//! message and, if the ciphertext does not match, returns the pseudo-random
+//! rejection key J(z || c) instead of failing ("implicit rejection").
+//!
+//! Polynomials are `[KyberFieldElement; 256]` arrays and all ring
+//! multiplications use [`kyber_ntt`] and [`kyber_basemul`]. Hashing follows
+//! Section 4.1 of the standard: H = SHA3-256, G = SHA3-512, J = SHAKE256 and
+//! the XOF used by SampleNTT is SHAKE128.
+//!
+//! Three parameter sets are provided: [`ML_KEM_512`], [`ML_KEM_768`] and
+//! [`ML_KEM_1024`]. The `*_from_seed` / `*_deterministic` entry points
+//! expose the internal algorithms of FIPS 203 Section 6 for testing against
+//! known-answer vectors.
+//!
+//! # Security
+//!
+//! The implementation follows the algorithms exactly but has not been
+//! hardened against side channels beyond a branch-free ciphertext
+//! comparison. Key material is not zeroized on drop.
+//!
+//! # Examples
+//!
+//! ```
+//! use lumen_math::pqc::ml_kem::ML_KEM_768;
+//!
+//! let mut rng = rand::rng();
+//! let (ek, dk) = ML_KEM_768.keygen(&mut rng);
+//!
+//! let (shared, ciphertext) = ek.encapsulate(&mut rng);
+//! assert_eq!(dk.decapsulate(&ciphertext).unwrap(), shared);
+//! ```
+
+use rand::Rng;
+use sha3::digest::{ExtendableOutput, Update, XofReader};
+use sha3::{Digest, Sha3_256, Sha3_512, Shake256};
+
+use crate::poly::ntt::kyber::{kyber_basemul, kyber_intt, kyber_ntt};
+use crate::poly::ntt::small::{KYBER_Q, KyberFieldElement};
+use crate::ring::sampling::{sample_cbd_small, sample_uniform_small};
+
+/// A polynomial in R_q, either in coefficient or NTT form.
+type Poly = [KyberFieldElement; 256];
+
+/// A 32-byte shared secret.
+pub type SharedSecret = [u8; 32];
+
+/// Length in bytes of the shared secret.
+pub const SHARED_SECRET_LEN: usize = 32;
+
+/// Error type for ML-KEM key and ciphertext handling.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MlKemError {
+    /// An input has the wrong length for the parameter set.
+    InvalidLength { expected: usize, actual: usize },
+    /// The encapsulation key contains a coefficient ≥ q (modulus check).
+    InvalidEncapsulationKey,
+    /// The stored hash of the encapsulation key does not match (hash check).
+    InvalidDecapsulationKey,
+}
+
+impl std::fmt::Display for MlKemError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MlKemError::InvalidLength { expected, actual } => {
+                write!(f, "Expected {} bytes, got {}", expected, actual)
+            }
+            MlKemError::InvalidEncapsulationKey => {
+                write!(f, "Encapsulation key failed the modulus check")
+            }
+            MlKemError::InvalidDecapsulationKey => {
+                write!(f, "Decapsulation key failed the hash check")
+            }
+        }
+    }
+}
+
+impl std::error::Error for MlKemError {}
+
+/// An ML-KEM parameter set (FIPS 203, Table 2).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MlKemParams {
+    /// Human-readable name, e.g. `"ML-KEM-768"`.
+    pub name: &'static str,
+    /// Module rank k.
+    pub k: usize,
+    /// CBD parameter for the secret and the encryption randomness y.
+    pub eta1: usize,
+    /// CBD parameter for the encryption errors e1 and e2.
+    pub eta2: usize,
+    /// Compression bits for u.
+    pub du: u32,
+    /// Compression bits for v.
+    pub dv: u32,
+}
+
+/// ML-KEM-512 (security category 1).
+pub const ML_KEM_512: MlKemParams = MlKemParams {
+    name: "ML-KEM-512",
+    k: 2,
+    eta1: 3,
+    eta2: 2,
+    du: 10,
+    dv: 4,
+};
+
+/// ML-KEM-768 (security category 3).
+pub const ML_KEM_768: MlKemParams = MlKemParams {
+    name: "ML-KEM-768",
+    k: 3,
+    eta1: 2,
+    eta2: 2,
+    du: 10,
+    dv: 4,
+};
+
+/// ML-KEM-1024 (security category 5).
+pub const ML_KEM_1024: MlKemParams = MlKemParams {
+    name: "ML-KEM-1024",
+    k: 4,
+    eta1: 2,
+    eta2: 2,
+    du: 11,
+    dv: 5,
+};
+
+impl MlKemParams {
+    /// Length in bytes of an encapsulation key: 384k + 32.
+    pub const fn encapsulation_key_len(&self) -> usize {
+        384 * self.k + 32
+    }
+
+    /// Length in bytes of a decapsulation key: 768k + 96.
+    pub const fn decapsulation_key_len(&self) -> usize {
+        768 * self.k + 96
+    }
+
+    /// Length in bytes of a ciphertext: 32(du·k + dv).
+    pub const fn ciphertext_len(&self) -> usize {
+        32 * (self.du as usize * self.k + self.dv as usize)
+    }
+
+    /// Generates a key pair (FIPS 203, Algorithm 19).
+    pub fn keygen<R: Rng>(&self, rng: &mut R) -> (EncapsulationKey, DecapsulationKey) {
+        let mut d = [0u8; 32];
+        let mut z = [0u8; 32];
+        rng.fill_bytes(&mut d);
+        rng.fill_bytes(&mut z);
+        self.keygen_from_seed(&d, &z)
+    }
+
+    /// Deterministically derives a key pair from the seeds d and z
+    /// (ML-KEM.KeyGen_internal, FIPS 203, Algorithm 16).
+    pub fn keygen_from_seed(
+        &self,
+        d: &[u8; 32],
+        z: &[u8; 32],
+    ) -> (EncapsulationKey, DecapsulationKey) {
+        let (ek_pke, dk_pke) = self.pke_keygen(d);
+
+        let mut dk = Vec::with_capacity(self.decapsulation_key_len());
+        dk.extend_from_slice(&dk_pke);
+        dk.extend_from_slice(&ek_pke);
+        dk.extend_from_slice(&hash_h(&ek_pke));
+        dk.extend_from_slice(z);
+
+        (
+            EncapsulationKey {
+                params: *self,
+                bytes: ek_pke,
+            },
+            DecapsulationKey {
+                params: *self,
+                bytes: dk,
+            },
+        )
+    }
+
+    // ------------------------------------------------------------------------
+    // K-PKE (FIPS 203, Section 5)
+    // ------------------------------------------------------------------------
+
+    /// Expands ρ into the matrix Â in NTT form, with Â[i][j] = SampleNTT(ρ || j || i).
+    fn expand_a(&self, rho: &[u8]) -> Vec<Vec<Poly>> {
+        (0..self.k)
+            .map(|i| {
+                (0..self.k)
+                    .map(|j| sample_ntt(rho, j as u8, i as u8))
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Samples a vector of k CBD polynomials, advancing the PRF counter.
+    fn sample_vector(&self, seed: &[u8], eta: usize, counter: &mut u8) -> Vec<Poly> {
+        (0..self.k)
+            .map(|_| {
+                let poly = sample_cbd_small(seed, &[*counter], eta);
+                *counter += 1;
+                poly
+            })
+            .collect()
+    }
+
+    /// K-PKE.KeyGen (Algorithm 13).
+    fn pke_keygen(&self, d: &[u8; 32]) -> (Vec<u8>, Vec<u8>) {
+        let mut input = [0u8; 33];
+        input[..32].copy_from_slice(d);
+        input[32] = self.k as u8;
+        let g = hash_g(&input);
+        let (rho, sigma) = g.split_at(32);
+
+        let a_hat = self.expand_a(rho);
+        let mut counter = 0;
+        let mut s = self.sample_vector(sigma, self.eta1, &mut counter);
+        let mut e = self.sample_vector(sigma, self.eta1, &mut counter);
+        s.iter_mut().for_each(kyber_ntt);
+        e.iter_mut().for_each(kyber_ntt);
+
+        let mut ek = Vec::with_capacity(self.encapsulation_key_len());
+        for (row, e_i) in a_hat.iter().zip(e.iter()) {
+            let t_i = poly_add(&inner_product(row, &s), e_i);
+            ek.extend_from_slice(&byte_encode(12, &t_i));
+        }
+        ek.extend_from_slice(rho);
+
+        let dk: Vec<u8> = s.iter().flat_map(|s_i| byte_encode(12, s_i)).collect();
+        (ek, dk)
+    }
+
+    /// K-PKE.Encrypt (Algorithm 14).
+    fn pke_encrypt(&self, ek: &[u8], m: &[u8; 32], r: &[u8]) -> Vec<u8> {
+        let (t_bytes, rho) = ek.split_at(384 * self.k);
+        let t_hat: Vec<Poly> = t_bytes.chunks(384).map(|c| byte_decode(12, c)).collect();
+        let a_hat = self.expand_a(rho);
+
+        let mut counter = 0;
+        let mut y = self.sample_vector(r, self.eta1, &mut counter);
+        let e1 = self.sample_vector(r, self.eta2, &mut counter);
+        let e2 = sample_cbd_small(r, &[counter], self.eta2);
+        y.iter_mut().for_each(kyber_ntt);
+
+        let mut c = Vec::with_capacity(self.ciphertext_len());
+        for (i, e1_i) in e1.iter().enumerate() {
+            let column: Vec<Poly> = a_hat.iter().map(|row| row[i]).collect();
+            let mut u_i = inner_product(&column, &y);
+            kyber_intt(&mut u_i);
+            let u_i = poly_add(&u_i, e1_i);
+            c.extend_from_slice(&byte_encode(self.du, &poly_compress(self.du, &u_i)));
+        }
+
+        let mu = poly_decompress(1, &byte_decode(1, m));
+        let mut v = inner_product(&t_hat, &y);
+        kyber_intt(&mut v);
+        let v = poly_add(&poly_add(&v, &e2), &mu);
+        c.extend_from_slice(&byte_encode(self.dv, &poly_compress(self.dv, &v)));
+        c
+    }
+
+    /// K-PKE.Decrypt (Algorithm 15).
+    fn pke_decrypt(&self, dk: &[u8], c: &[u8]) -> [u8; 32] {
+        let (c1, c2) = c.split_at(32 * self.du as usize * self.k);
+        let mut u: Vec<Poly> = c1
+            .chunks(32 * self.du as usize)
+            .map(|chunk| poly_decompress(self.du, &byte_decode(self.du, chunk)))
+            .collect();
+        let v = poly_decompress(self.dv, &byte_decode(self.dv, c2));
+        let s_hat: Vec<Poly> = dk.chunks(384).map(|chunk| byte_decode(12, chunk)).collect();
+
+        u.iter_mut().for_each(kyber_ntt);
+        let mut su = inner_product(&s_hat, &u);
+        kyber_intt(&mut su);
+        let w = poly_sub(&v, &su);
+
+        let mut m = [0u8; 32];
+        m.copy_from_slice(&byte_encode(1, &poly_compress(1, &w)));
+        m
+    }
+}
+
+// ============================================================================
+// Keys
+// ============================================================================
+
+/// An ML-KEM encapsulation (public) key.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EncapsulationKey {
+    params: MlKemParams,
+    bytes: Vec<u8>,
+}
+
+impl EncapsulationKey {
+    /// Parses an encapsulation key, applying the length and modulus checks of
+    /// FIPS 203, Section 7.2.
+    pub fn from_bytes(params: MlKemParams, bytes: &[u8]) -> Result<Self, MlKemError> {
+        check_len(params.encapsulation_key_len(), bytes.len())?;
+        let t_bytes = &bytes[..384 * params.k];
+        for chunk in t_bytes.chunks(384) {
+            if byte_encode(12, &byte_decode(12, chunk)) != chunk {
+                return Err(MlKemError::InvalidEncapsulationKey);
+            }
+        }
+        Ok(Self {
+            params,
+            bytes: bytes.to_vec(),
+        })
+    }
+
+    /// Returns the parameter set.
+    pub fn params(&self) -> MlKemParams {
+        self.params
+    }
+
+    /// Returns the encoded key.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// Encapsulates a fresh shared secret (FIPS 203, Algorithm 20).
+    ///
+    /// Returns the shared secret and the ciphertext.
+    pub fn encapsulate<R: Rng>(&self, rng: &mut R) -> (SharedSecret, Vec<u8>) {
+        let mut m = [0u8; 32];
+        rng.fill_bytes(&mut m);
+        self.encapsulate_deterministic(&m)
+    }
+
+    /// Encapsulates with caller-provided randomness m
+    /// (ML-KEM.Encaps_internal, FIPS 203, Algorithm 17).
+    pub fn encapsulate_deterministic(&self, m: &[u8; 32]) -> (SharedSecret, Vec<u8>) {
+        let mut input = [0u8; 64];
+        input[..32].copy_from_slice(m);
+        input[32..].copy_from_slice(&hash_h(&self.bytes));
+        let g = hash_g(&input);
+
+        let mut shared = [0u8; 32];
+        shared.copy_from_slice(&g[..32]);
+        let ciphertext = self.params.pke_encrypt(&self.bytes, m, &g[32..]);
+        (shared, ciphertext)
+    }
+}
+
+/// An ML-KEM decapsulation (secret) key: dk_pke || ek || H(ek) || z.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DecapsulationKey {
+    params: MlKemParams,
+    bytes: Vec<u8>,
+}
+
+impl DecapsulationKey {
+    /// Parses a decapsulation key, applying the length and hash checks of
+    /// FIPS 203, Section 7.3.
+    pub fn from_bytes(params: MlKemParams, bytes: &[u8]) -> Result<Self, MlKemError> {
+        check_len(params.decapsulation_key_len(), bytes.len())?;
+        let k = params.k;
+        if hash_h(&bytes[384 * k..768 * k + 32]) != bytes[768 * k + 32..768 * k + 64] {
+            return Err(MlKemError::InvalidDecapsulationKey);
+        }
+        Ok(Self {
+            params,
+            bytes: bytes.to_vec(),
+        })
+    }
+
+    /// Returns the parameter set.
+    pub fn params(&self) -> MlKemParams {
+        self.params
+    }
+
+    /// Returns the encoded key.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// Returns the encapsulation key embedded in this key.
+    pub fn encapsulation_key(&self) -> EncapsulationKey {
+        let k = self.params.k;
+        EncapsulationKey {
+            params: self.params,
+            bytes: self.bytes[384 * k..768 * k + 32].to_vec(),
+        }
+    }
+
+    /// Recovers the shared secret from a ciphertext
+    /// (ML-KEM.Decaps, FIPS 203, Algorithms 18 and 21).
+    ///
+    /// A well-formed but invalid ciphertext is not an error: it yields the
+    /// implicit-rejection key J(z || c).
+    pub fn decapsulate(&self, ciphertext: &[u8]) -> Result<SharedSecret, MlKemError> {
+        let params = &self.params;
+        check_len(params.ciphertext_len(), ciphertext.len())?;
+
+        let k = params.k;
+        let dk_pke = &self.bytes[..384 * k];
+        let ek_pke = &self.bytes[384 * k..768 * k + 32];
+        let h = &self.bytes[768 * k + 32..768 * k + 64];
+        let z = &self.bytes[768 * k + 64..];
+
+        let m = params.pke_decrypt(dk_pke, ciphertext);
+        let mut input = [0u8; 64];
+        input[..32].copy_from_slice(&m);
+        input[32..].copy_from_slice(h);
+        let g = hash_g(&input);
+
+        let mut rejection = [0u8; 32];
+        let mut xof = Shake256::default();
+        xof.update(z);
+        xof.update(ciphertext);
+        xof.finalize_xof().read(&mut rejection);
+
+        let reencrypted = params.pke_encrypt(ek_pke, &m, &g[32..]);
+        let diff = reencrypted
+            .iter()
+            .zip(ciphertext.iter())
+            .fold(0u8, |acc, (a, b)| acc | (a ^ b));
+        // mask = 0xff if the ciphertexts match, 0x00 otherwise.
+        let mask = ((diff as u16).wrapping_sub(1) >> 8) as u8;
+
+        let mut shared = [0u8; 32];
+        for i in 0..32 {
+            shared[i] = (g[i] & mask) | (rejection[i] & !mask);
+        }
+        Ok(shared)
+    }
+}
+
+fn check_len(expected: usize, actual: usize) -> Result<(), MlKemError> {
+    if expected == actual {
+        Ok(())
+    } else {
+        Err(MlKemError::InvalidLength { expected, actual })
+    }
+}
+
+// ============================================================================
+// Hash functions (FIPS 203, Section 4.1)
+// ============================================================================
+
+fn hash_h(data: &[u8]) -> [u8; 32] {
+    Sha3_256::digest(data).into()
+}
+
+fn hash_g(data: &[u8]) -> [u8; 64] {
+    Sha3_512::digest(data).into()
+}
+
+// ============================================================================
+// Sampling (FIPS 203, Section 4.2.2)
+// ============================================================================
+
+/// Samples a uniform polynomial in NTT form from SHAKE128(ρ || j || i)
+/// by rejection (Algorithm 7).
+pub fn sample_ntt(rho: &[u8], j: u8, i: u8) -> [KyberFieldElement; 256] {
+    sample_uniform_small(rho, &[j, i])
+}
+
+// ============================================================================
+// Encoding and compression (FIPS 203, Section 4.2.1)
+// ============================================================================
+
+/// Compress_d(x) = ⌈(2^d / q) · x⌋ mod 2^d.
+fn compress(d: u32, x: KyberFieldElement) -> u16 {
+    let scaled = ((x.value() as u32) << d) + KYBER_Q / 2;
+    ((scaled / KYBER_Q) & ((1 << d) - 1)) as u16
+}
+
+/// Decompress_d(y) = ⌈(q / 2^d) · y⌋.
+fn decompress(d: u32, y: u16) -> KyberFieldElement {
+    KyberFieldElement::new((y as u32 * KYBER_Q + (1 << (d - 1))) >> d)
+}
+
+fn poly_compress(d: u32, f: &Poly) -> Poly {
+    std::array::from_fn(|i| KyberFieldElement::from_raw(compress(d, f[i])))
+}
+
+fn poly_decompress(d: u32, f: &Poly) -> Poly {
+    std::array::from_fn(|i| decompress(d, f[i].value()))
+}
+
+/// Packs 256 d-bit integers into 32d bytes, least significant bit first (Algorithm 5).
+///
+/// For d < 12 the coefficients are taken as already reduced modulo 2^d.
+fn byte_encode(d: u32, f: &[KyberFieldElement; 256]) -> Vec<u8> {
+    let d = d as usize;
+    let mut out = vec![0u8; 32 * d];
+    for (i, c) in f.iter().enumerate() {
+        let value = c.value();
+        for j in 0..d {
+            let bit = ((value >> j) & 1) as u8;
+            let pos = i * d + j;
+            out[pos / 8] |= bit << (pos % 8);
+        }
+    }
+    out
+}
+
+/// Unpacks 32d bytes into 256 d-bit integers (Algorithm 6).
+///
+/// For d = 12 the values are reduced modulo q; otherwise they lie in [0, 2^d).
+///
+/// # Panics
+/// Panics if `bytes.len() != 32 * d`.
+fn byte_decode(d: u32, bytes: &[u8]) -> [KyberFieldElement; 256] {
+    let d = d as usize;
+    assert_eq!(bytes.len(), 32 * d, "ByteDecode input must be 32d bytes");
+    std::array::from_fn(|i| {
+        let mut value = 0u32;
+        for j in 0..d {
+            let pos = i * d + j;
+            value |= (((bytes[pos / 8] >> (pos % 8)) & 1) as u32) << j;
+        }
+        KyberFieldElement::new(value)
+    })
+}
+
+// ============================================================================
+// Polynomial helpers
+// ============================================================================
+
+fn poly_add(a: &Poly, b: &Poly) -> Poly {
+    std::array::from_fn(|i| a[i] + b[i])
+}
+
+fn poly_sub(a: &Poly, b: &Poly) -> Poly {
+    std::array::from_fn(|i| a[i] - b[i])
+}
+
+/// Σ a_i ∘ b_i in the NTT domain.
+fn inner_product(a: &[Poly], b: &[Poly]) -> Poly {
+    a.iter()
+        .zip(b.iter())
+        .fold([KyberFieldElement::zero(); 256], |acc, (x, y)| {
+            poly_add(&acc, &kyber_basemul(x, y))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::U1024;
+    use crate::ring::encoding::{compress_u1024, decompress_u1024};
+
+    #[test]
+    fn test_sizes_match_fips203_table3() {
+        assert_eq!(ML_KEM_512.encapsulation_key_len(), 800);
+        assert_eq!(ML_KEM_512.decapsulation_key_len(), 1632);
+        assert_eq!(ML_KEM_512.ciphertext_len(), 768);
+        assert_eq!(ML_KEM_768.encapsulation_key_len(), 1184);
+        assert_eq!(ML_KEM_768.decapsulation_key_len(), 2400);
+        assert_eq!(ML_KEM_768.ciphertext_len(), 1088);
+        assert_eq!(ML_KEM_1024.encapsulation_key_len(), 1568);
+        assert_eq!(ML_KEM_1024.decapsulation_key_len(), 3168);
+        assert_eq!(ML_KEM_1024.ciphertext_len(), 1568);
+    }
+
+    #[test]
+    fn test_compress_decompress_error_bound() {
+        for d in [1, 4, 5, 10, 11] {
+            let bound = (KYBER_Q + (1 << d)) / (1 << (d + 1));
+            for x in 0..KYBER_Q {
+                let x = KyberFieldElement::new(x);
+                let y = decompress(d, compress(d, x));
+                let diff = (x - y).value() as u32;
+                assert!(diff.min(KYBER_Q - diff) <= bound);
+            }
+        }
+    }
+
+    #[test]
+    fn test_byte_encode_decode_roundtrip() {
+        for d in [1, 4, 5, 10, 11, 12] {
+            let modulus = if d == 12 { KYBER_Q } else { 1 << d };
+            let f: Poly =
+                std::array::from_fn(|i| KyberFieldElement::new((i as u32 * 97) % modulus));
+            assert_eq!(byte_decode(d, &byte_encode(d, &f)), f);
+        }
+    }
+
+    #[test]
+    fn test_compress_matches_ring_encoding() {
+        let q = U1024::from_u64(KYBER_Q as u64);
+        for d in [1, 4, 5, 10, 11] {
+            for x in 0..KYBER_Q {
+                let c = compress(d, KyberFieldElement::new(x));
+                let expected = compress_u1024(U1024::from_u64(x as u64), d, &q);
+                assert_eq!(U1024::from_u64(c as u64), expected, "d = {d}, x = {x}");
+
+                let y = decompress(d, c).value();
+                let expected = decompress_u1024(expected, d, &q);
+                assert_eq!(U1024::from_u64(y as u64), expected, "d = {d}, y = {c}");
+            }
+        }
+    }
+}
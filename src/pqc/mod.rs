@@ -0,0 +1,16 @@
+//! Post-quantum cryptographic schemes built on the small-modulus field types.
+//!
+//! This module provides:
+//! - `ml_dsa` - ML-DSA-44/65/87 signatures (FIPS 204)
+//! - `ml_kem` - ML-KEM-512/768/1024 key encapsulation (FIPS 203)
+
+pub mod ml_dsa;
+pub mod ml_kem;
+
+pub use ml_dsa::{
+    ML_DSA_44, ML_DSA_65, ML_DSA_87, MlDsaError, MlDsaParams, SigningKey, VerifyingKey,
+};
+pub use ml_kem::{
+    DecapsulationKey, EncapsulationKey, ML_KEM_512, ML_KEM_768, ML_KEM_1024, MlKemError,
+    MlKemParams, SharedSecret,
+};
@@ -85,53 +85,148 @@ pub fn chinese_remainder_solver(remainders: &[U1024], moduli: &[U1024]) -> Resul
         return Err(CrtError::LengthMismatch);
     }
 
-    // Compute the product of all moduli
-    let mut n = U1024::from_u64(1);
-    for m in moduli {
-        let (prod_lo, prod_hi) = n.const_mul(m);
-        if prod_hi != U1024::ZERO {
-            return Err(CrtError::ProductOverflow);
+    Ok(CrtBasis::new(moduli)?.reconstruct(remainders))
+}
+
+/// Precomputed data for repeated CRT reconstruction over fixed moduli.
+///
+/// [`chinese_remainder_solver`] recomputes the cofactors Nᵢ = N / nᵢ and
+/// their inverses on every call. When many residue vectors share the same
+/// moduli (e.g. every coefficient of an RNS polynomial), build a `CrtBasis`
+/// once and call [`CrtBasis::reconstruct`] for each vector.
+///
+/// # Examples
+///
+/// ```
+/// use lumen_math::protocol::CrtBasis;
+/// use lumen_math::U1024;
+///
+/// let basis = CrtBasis::new(&[U1024::from_u64(3), U1024::from_u64(5), U1024::from_u64(7)]).unwrap();
+/// assert_eq!(basis.product(), &U1024::from_u64(105));
+///
+/// let x = basis.reconstruct(&[U1024::from_u64(2), U1024::from_u64(3), U1024::from_u64(2)]);
+/// assert_eq!(x, U1024::from_u64(23));
+/// ```
+#[derive(Clone, Debug)]
+pub struct CrtBasis {
+    moduli: Vec<U1024>,
+    product: U1024,
+    /// Nᵢ = N / nᵢ
+    cofactors: Vec<U1024>,
+    /// Nᵢ⁻¹ mod nᵢ
+    inverses: Vec<U1024>,
+}
+
+impl CrtBasis {
+    /// Precomputes the reconstruction data for `moduli`.
+    ///
+    /// # Errors
+    ///
+    /// * [`CrtError::EmptyInput`] if `moduli` is empty
+    /// * [`CrtError::ProductOverflow`] if the product does not fit in 1024 bits
+    /// * [`CrtError::ModuliNotCoprime`] if the moduli are not pairwise coprime
+    pub fn new(moduli: &[U1024]) -> Result<Self, CrtError> {
+        if moduli.is_empty() {
+            return Err(CrtError::EmptyInput);
+        }
+
+        // Compute the product of all moduli
+        let mut n = U1024::from_u64(1);
+        for m in moduli {
+            let (prod_lo, prod_hi) = n.const_mul(m);
+            if prod_hi != U1024::ZERO {
+                return Err(CrtError::ProductOverflow);
+            }
+            n = prod_lo;
         }
-        n = prod_lo;
+
+        let mut cofactors = Vec::with_capacity(moduli.len());
+        let mut inverses = Vec::with_capacity(moduli.len());
+        for n_i in moduli {
+            // N_i = N / n_i and y_i = N_i^(-1) mod n_i
+            let big_n_i = n / *n_i;
+            let y_i = mod_inverse(big_n_i, *n_i).ok_or(CrtError::ModuliNotCoprime)?;
+            cofactors.push(big_n_i);
+            inverses.push(y_i);
+        }
+
+        Ok(Self {
+            moduli: moduli.to_vec(),
+            product: n,
+            cofactors,
+            inverses,
+        })
+    }
+
+    /// Returns the moduli.
+    pub fn moduli(&self) -> &[U1024] {
+        &self.moduli
     }
 
-    let mut result = U1024::ZERO;
-
-    for (a_i, n_i) in remainders.iter().zip(moduli.iter()) {
-        // N_i = N / n_i
-        let big_n_i = n / *n_i;
-
-        // y_i = N_i^(-1) mod n_i
-        let y_i = mod_inverse(big_n_i, *n_i).ok_or(CrtError::ModuliNotCoprime)?;
-
-        // Compute a_i * N_i * y_i mod n
-        // We use mod_mul to safely handle products up to 2048 bits.
-        // term = (a_i * N_i * y_i) mod n
-        let tmp = a_i.mod_mul(&big_n_i, &n);
-        let term = tmp.mod_mul(&y_i, &n);
-
-        // Add to result mod n using safe modular addition
-        // Both result and term are < n, so their true sum is < 2n
-        // We need to compute (result + term) % n safely, handling potential overflow
-        let (sum, carry) = result.carrying_add(&term);
-        if carry {
-            // sum wrapped around 2^1024, true_sum = sum + 2^1024
-            // Since result < n and term < n, true_sum < 2n
-            // And true_sum >= 2^1024 >= n, so true_sum - n is the correct result
-            // true_sum - n = sum + 2^1024 - n = sum + (2^1024 - n)
-            // (2^1024 - n) in wrapping arithmetic is (U1024::ZERO - n)
-            // This addition is safe: sum < 2n - 2^1024, and when n > 2^1023,
-            // sum < 2n - 2^1024 < n, so sum + (2^1024 - n) < 2^1024 (no overflow)
-            result = sum + (U1024::ZERO - n);
-        } else if sum >= n {
-            // No overflow, but sum >= n, so subtract n
-            result = sum - n;
-        } else {
-            result = sum;
+    /// Returns the product N of the moduli.
+    pub fn product(&self) -> &U1024 {
+        &self.product
+    }
+
+    /// Returns the unique x ∈ [0, N) with x ≡ aᵢ (mod nᵢ) for all i.
+    ///
+    /// Computes x = Σ ((aᵢ · yᵢ) mod nᵢ) · Nᵢ mod N. Each term is below N, so
+    /// no double-width products are needed; word-sized moduli use native
+    /// 128-bit arithmetic for the inner reduction.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `remainders.len()` differs from the number of moduli.
+    pub fn reconstruct(&self, remainders: &[U1024]) -> U1024 {
+        assert_eq!(
+            remainders.len(),
+            self.moduli.len(),
+            "Remainders and moduli must have same length"
+        );
+
+        let mut result = U1024::ZERO;
+        for (((a_i, n_i), big_n_i), y_i) in remainders
+            .iter()
+            .zip(&self.moduli)
+            .zip(&self.cofactors)
+            .zip(&self.inverses)
+        {
+            let t_i = if fits_u64(a_i) && fits_u64(n_i) {
+                let n = n_i.0[0] as u128;
+                let t = (a_i.0[0] as u128 % n) * (y_i.0[0] as u128) % n;
+                U1024::from_u64(t as u64)
+            } else {
+                a_i.mod_mul(y_i, n_i)
+            };
+
+            // t_i < n_i, so t_i * N_i < N fits in 1024 bits.
+            result = add_mod(&result, &(t_i * *big_n_i), &self.product);
         }
+
+        result
     }
+}
 
-    Ok(result)
+fn fits_u64(x: &U1024) -> bool {
+    x.0[1..].iter().all(|&limb| limb == 0)
+}
+
+/// Computes (a + b) mod n for a, b < n, including when a + b overflows.
+fn add_mod(a: &U1024, b: &U1024, n: &U1024) -> U1024 {
+    let (sum, carry) = a.carrying_add(b);
+    if carry {
+        // sum wrapped around 2^1024, true_sum = sum + 2^1024
+        // Since a < n and b < n, true_sum < 2n
+        // And true_sum >= 2^1024 >= n, so true_sum - n is the correct result
+        // true_sum - n = sum + 2^1024 - n = sum + (2^1024 - n)
+        // (2^1024 - n) in wrapping arithmetic is (U1024::ZERO - n)
+        sum + (U1024::ZERO - *n)
+    } else if sum >= *n {
+        // No overflow, but sum >= n, so subtract n
+        sum - *n
+    } else {
+        sum
+    }
 }
 
 /// Simpler wrapper for solving two congruences.
@@ -239,6 +334,40 @@ mod tests {
         assert_eq!(result, U1024::from_u64(5));
     }
 
+    #[test]
+    fn test_crt_basis_matches_solver() {
+        let moduli: Vec<U1024> = [0x3fff_ffff_fff0_0001u64, 0x3fff_ffff_ffe8_0001, 97]
+            .into_iter()
+            .map(U1024::from_u64)
+            .collect();
+        let basis = CrtBasis::new(&moduli).unwrap();
+        for k in 0..20u64 {
+            let remainders: Vec<U1024> = moduli
+                .iter()
+                .map(|m| U1024::from_u64(k.wrapping_mul(0x9e37_79b9_7f4a_7c15) + 1) % *m)
+                .collect();
+            let x = basis.reconstruct(&remainders);
+            assert!(x < *basis.product());
+            for (r, m) in remainders.iter().zip(&moduli) {
+                assert_eq!(x % *m, *r);
+            }
+        }
+    }
+
+    #[test]
+    fn test_crt_basis_errors() {
+        assert_eq!(CrtBasis::new(&[]).unwrap_err(), CrtError::EmptyInput);
+        assert_eq!(
+            CrtBasis::new(&[U1024::from_u64(4), U1024::from_u64(6)]).unwrap_err(),
+            CrtError::ModuliNotCoprime
+        );
+        let big = U1024::ONE.shl(600);
+        assert_eq!(
+            CrtBasis::new(&[big, big + U1024::ONE]).unwrap_err(),
+            CrtError::ProductOverflow
+        );
+    }
+
     #[test]
     fn test_crt_larger_numbers() {
         // x ≡ 0 (mod 3)
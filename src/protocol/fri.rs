@@ -0,0 +1,547 @@
+//! FRI (Fast Reed–Solomon Interactive Oracle Proof of Proximity).
+//!
+//! FRI convinces a verifier that a committed vector of evaluations over a
+//! multiplicative subgroup is close to a polynomial of degree below a bound d.
+//! The prover evaluates p on a domain of size N = d · blowup and repeatedly
+//! folds it: writing p(X) = Σ_{j<f} X^j p_j(X^f) for folding factor f, the
+//! next layer is
+//!
+//!   p'(Y) = Σ_{j<f} β^j p_j(Y)
+//!
+//! for a verifier challenge β, evaluated on the domain {x^f} of size N / f.
+//! Each layer is committed with a SHA-256 Merkle tree whose leaves hold the f
+//! evaluations that fold into one point of the next layer. Once the degree
+//! bound reaches the configured final bound, the prover sends the remaining
+//! polynomial in the clear and the verifier spot-checks the folding chain at
+//! random query positions.
+//!
+//! Challenges come from a [`Transcript`]; query positions are drawn after the
+//! final polynomial has been appended.
+//!
+//! Evaluation domains are the subgroups generated by `C::ROOT_OF_UNITY`, which
+//! must have order 2^TWO_ADICITY (as for the cyclic [`ntt`]), so N may be at
+//! most 2^TWO_ADICITY.
+
+use crate::commit::merkle::{
+    MerkleError, MerkleHash, MerkleProof, MerkleTree, field_elements_to_bytes,
+};
+use crate::poly::ntt::{intt, ntt};
+use crate::protocol::transcript::Transcript;
+use crate::{FieldConfig, FieldElement, Polynomial, U1024};
+
+/// Error type for FRI configuration, proving and verification.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FriError {
+    /// Folding factor, blowup or degree bound is not a power of two ≥ the minimum.
+    InvalidParameters,
+    /// The evaluation domain does not fit in the field's two-adic subgroup.
+    InsufficientTwoAdicity,
+    /// The proof has the wrong number of layers, queries or leaf values.
+    MalformedProof,
+    /// The final polynomial exceeds the final degree bound.
+    FinalPolynomialTooLarge,
+    /// A Merkle authentication path failed for the given layer.
+    InvalidOpening { layer: usize, error: MerkleError },
+    /// An opened value does not match the fold of the previous layer.
+    FoldingMismatch { layer: usize },
+    /// The last folded value disagrees with the final polynomial.
+    FinalLayerMismatch,
+}
+
+impl std::fmt::Display for FriError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FriError::InvalidParameters => write!(f, "Invalid FRI parameters"),
+            FriError::InsufficientTwoAdicity => {
+                write!(f, "Field has insufficient two-adicity for the FRI domain")
+            }
+            FriError::MalformedProof => write!(f, "Malformed FRI proof"),
+            FriError::FinalPolynomialTooLarge => {
+                write!(f, "Final polynomial exceeds the degree bound")
+            }
+            FriError::InvalidOpening { layer, error } => {
+                write!(f, "Invalid opening in layer {}: {}", layer, error)
+            }
+            FriError::FoldingMismatch { layer } => {
+                write!(f, "Folding mismatch in layer {}", layer)
+            }
+            FriError::FinalLayerMismatch => {
+                write!(f, "Final layer does not match the final polynomial")
+            }
+        }
+    }
+}
+
+impl std::error::Error for FriError {}
+
+/// Parameters of a FRI instance.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FriConfig {
+    /// Number of evaluations folded into one per round (power of two, ≥ 2).
+    pub folding_factor: usize,
+    /// Ratio of domain size to degree bound (power of two, ≥ 2).
+    pub blowup_factor: usize,
+    /// Number of query positions checked by the verifier.
+    pub num_queries: usize,
+    /// Folding stops once the degree bound is at most this value.
+    pub final_degree_bound: usize,
+}
+
+impl Default for FriConfig {
+    fn default() -> Self {
+        Self {
+            folding_factor: 2,
+            blowup_factor: 4,
+            num_queries: 40,
+            final_degree_bound: 1,
+        }
+    }
+}
+
+impl FriConfig {
+    /// Creates a configuration with a final degree bound of 1 (a constant).
+    pub fn new(
+        folding_factor: usize,
+        blowup_factor: usize,
+        num_queries: usize,
+    ) -> Result<Self, FriError> {
+        let config = Self {
+            folding_factor,
+            blowup_factor,
+            num_queries,
+            final_degree_bound: 1,
+        };
+        config.validate()?;
+        Ok(config)
+    }
+
+    /// Sets the degree bound at which folding stops.
+    pub fn with_final_degree_bound(mut self, final_degree_bound: usize) -> Self {
+        self.final_degree_bound = final_degree_bound;
+        self
+    }
+
+    fn validate(&self) -> Result<(), FriError> {
+        let ok = self.folding_factor >= 2
+            && self.folding_factor.is_power_of_two()
+            && self.blowup_factor >= 2
+            && self.blowup_factor.is_power_of_two()
+            && self.num_queries > 0
+            && self.final_degree_bound > 0;
+        if ok {
+            Ok(())
+        } else {
+            Err(FriError::InvalidParameters)
+        }
+    }
+
+    /// Returns the degree bounds of each folded layer, ending with the final bound.
+    fn degree_schedule<C: FieldConfig>(&self, degree_bound: usize) -> Result<Vec<usize>, FriError> {
+        self.validate()?;
+        if degree_bound == 0 || !degree_bound.is_power_of_two() {
+            return Err(FriError::InvalidParameters);
+        }
+        if degree_bound
+            .checked_mul(self.blowup_factor)
+            .is_none_or(|n| n.trailing_zeros() > C::TWO_ADICITY)
+        {
+            return Err(FriError::InsufficientTwoAdicity);
+        }
+
+        let mut bounds = vec![degree_bound];
+        let mut d = degree_bound;
+        while d > self.final_degree_bound && d >= self.folding_factor {
+            d /= self.folding_factor;
+            bounds.push(d);
+        }
+        Ok(bounds)
+    }
+}
+
+/// The values and authentication path opened in one layer for one query.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FriLayerOpening<C: FieldConfig> {
+    /// The `folding_factor` evaluations that fold into one point of the next layer.
+    pub values: Vec<FieldElement<C>>,
+    /// Merkle path for `values` against the layer root.
+    pub proof: MerkleProof,
+}
+
+/// A non-interactive FRI proof.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FriProof<C: FieldConfig> {
+    /// Merkle roots of every folded layer, starting with the input evaluations.
+    pub layer_roots: Vec<MerkleHash>,
+    /// The last layer as a polynomial below the final degree bound.
+    pub final_polynomial: Polynomial<C>,
+    /// For each query, the openings in every layer.
+    pub queries: Vec<Vec<FriLayerOpening<C>>>,
+}
+
+/// Returns a primitive n-th root of unity for a power-of-two n ≤ 2^TWO_ADICITY.
+fn domain_generator<C: FieldConfig>(n: usize) -> Result<FieldElement<C>, FriError> {
+    let mut omega = FieldElement::<C>::new(C::ROOT_OF_UNITY);
+    for _ in 0..C::TWO_ADICITY - n.trailing_zeros() {
+        omega = omega.square();
+    }
+
+    // ω^(n/2) must be -1 for ω to have exact order n.
+    if n > 1 {
+        let mut half = omega;
+        for _ in 0..n.trailing_zeros() - 1 {
+            half = half.square();
+        }
+        if half != -FieldElement::one() {
+            return Err(FriError::InsufficientTwoAdicity);
+        }
+    }
+    Ok(omega)
+}
+
+/// Computes base^exp by square-and-multiply on a small exponent.
+fn pow_small<C: FieldConfig>(base: FieldElement<C>, mut exp: usize) -> FieldElement<C> {
+    let mut result = FieldElement::one();
+    let mut b = base;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = result * b;
+        }
+        b = b.square();
+        exp >>= 1;
+    }
+    result
+}
+
+/// Precomputed constants for folding a layer of a given size.
+struct Folder<C: FieldConfig> {
+    /// ζ^(-m) for m in 0..f, where ζ is a primitive f-th root of unity.
+    zeta_inv_powers: Vec<FieldElement<C>>,
+    /// 1 / f.
+    f_inv: FieldElement<C>,
+}
+
+impl<C: FieldConfig> Folder<C> {
+    fn new(folding_factor: usize, omega: FieldElement<C>, domain_size: usize) -> Self {
+        let zeta_inv = pow_small(omega, domain_size - domain_size / folding_factor);
+        let mut zeta_inv_powers = Vec::with_capacity(folding_factor);
+        let mut z = FieldElement::one();
+        for _ in 0..folding_factor {
+            zeta_inv_powers.push(z);
+            z = z * zeta_inv;
+        }
+        Self {
+            zeta_inv_powers,
+            f_inv: FieldElement::new(U1024::from_u64(folding_factor as u64)).inv(),
+        }
+    }
+
+    /// Folds the coset values v_k = p(x·ζ^k) into p'(x^f) for challenge β.
+    fn fold(
+        &self,
+        values: &[FieldElement<C>],
+        x_inv: FieldElement<C>,
+        beta: FieldElement<C>,
+    ) -> FieldElement<C> {
+        let f = values.len();
+        let t = beta * x_inv;
+
+        // p'(x^f) = (1/f) Σ_j t^j Σ_k v_k ζ^(-jk)
+        let mut acc = FieldElement::zero();
+        let mut t_pow = FieldElement::one();
+        for j in 0..f {
+            let c_j = values
+                .iter()
+                .enumerate()
+                .fold(FieldElement::zero(), |s, (k, v)| {
+                    s + *v * self.zeta_inv_powers[(j * k) % f]
+                });
+            acc = acc + c_j * t_pow;
+            t_pow = t_pow * t;
+        }
+        acc * self.f_inv
+    }
+}
+
+/// Regroups a layer so that row r holds the evaluations at r + k·(n/f).
+fn layer_rows<C: FieldConfig>(
+    evals: &[FieldElement<C>],
+    folding_factor: usize,
+) -> Vec<FieldElement<C>> {
+    let rows = evals.len() / folding_factor;
+    let mut grouped = Vec::with_capacity(evals.len());
+    for r in 0..rows {
+        for k in 0..folding_factor {
+            grouped.push(evals[r + k * rows]);
+        }
+    }
+    grouped
+}
+
+fn append_parameters(transcript: &mut Transcript, config: &FriConfig, degree_bound: usize) {
+    transcript.append_u64(b"fri_folding_factor", config.folding_factor as u64);
+    transcript.append_u64(b"fri_blowup_factor", config.blowup_factor as u64);
+    transcript.append_u64(b"fri_num_queries", config.num_queries as u64);
+    transcript.append_u64(b"fri_final_degree_bound", config.final_degree_bound as u64);
+    transcript.append_u64(b"fri_degree_bound", degree_bound as u64);
+}
+
+/// Proves that `poly` has degree below `degree_bound`.
+///
+/// The polynomial is evaluated on the subgroup of size `degree_bound · blowup`
+/// (reduced modulo X^N - 1 if it is longer). A polynomial that violates the bound
+/// still yields a proof, which the verifier rejects with high probability.
+///
+/// # Examples
+///
+/// ```
+/// use lumen_math::protocol::{FriConfig, Transcript, prove_fri, verify_fri};
+/// use lumen_math::{DefaultFieldConfig, FieldElement, Polynomial, U1024};
+///
+/// let coeffs: Vec<FieldElement<DefaultFieldConfig>> = (1..=8u64)
+///     .map(|i| FieldElement::new(U1024::from_u64(i)))
+///     .collect();
+/// let poly = Polynomial::new(coeffs);
+/// let config = FriConfig::new(2, 4, 8).unwrap();
+///
+/// let proof = prove_fri(&config, &poly, 8, &mut Transcript::new(b"fri")).unwrap();
+/// assert!(verify_fri(&config, 8, &proof, &mut Transcript::new(b"fri")).is_ok());
+/// ```
+pub fn prove_fri<C: FieldConfig>(
+    config: &FriConfig,
+    poly: &Polynomial<C>,
+    degree_bound: usize,
+    transcript: &mut Transcript,
+) -> Result<FriProof<C>, FriError> {
+    let bounds = config.degree_schedule::<C>(degree_bound)?;
+    let f = config.folding_factor;
+    let mut n = degree_bound * config.blowup_factor;
+    let mut omega = domain_generator::<C>(n)?;
+
+    let mut evals = vec![FieldElement::<C>::zero(); n];
+    for (i, c) in poly.coeffs.iter().enumerate() {
+        evals[i % n] = evals[i % n] + *c;
+    }
+    ntt(&mut evals);
+
+    append_parameters(transcript, config, degree_bound);
+
+    let mut trees = Vec::with_capacity(bounds.len() - 1);
+    let mut layers = Vec::with_capacity(bounds.len() - 1);
+
+    for _ in 1..bounds.len() {
+        let rows = layer_rows(&evals, f);
+        let tree = MerkleTree::from_field_element_chunks(&rows, f);
+        transcript.append_message(b"fri_layer_root", &tree.root());
+        let beta: FieldElement<C> = transcript.challenge_field_element(b"fri_folding_challenge");
+
+        let folder = Folder::new(f, omega, n);
+        let omega_inv = pow_small(omega, n - 1);
+        let mut x_inv = FieldElement::one();
+        let next: Vec<FieldElement<C>> = rows
+            .chunks(f)
+            .map(|row| {
+                let folded = folder.fold(row, x_inv, beta);
+                x_inv = x_inv * omega_inv;
+                folded
+            })
+            .collect();
+
+        trees.push(tree);
+        layers.push(rows);
+        evals = next;
+        n /= f;
+        omega = pow_small(omega, f);
+    }
+
+    let final_bound = *bounds.last().unwrap();
+    intt(&mut evals);
+    evals.truncate(final_bound);
+    let final_polynomial = Polynomial::new(evals);
+    transcript.append_polynomial(b"fri_final_polynomial", &final_polynomial);
+
+    let mut queries = Vec::new();
+    if !trees.is_empty() {
+        let first_rows = degree_bound * config.blowup_factor / f;
+        for _ in 0..config.num_queries {
+            let mut position = transcript.challenge_index(b"fri_query", first_rows);
+            let mut openings = Vec::with_capacity(trees.len());
+            for (tree, rows) in trees.iter().zip(layers.iter()) {
+                let row = position % tree.num_leaves();
+                openings.push(FriLayerOpening {
+                    values: rows[row * f..(row + 1) * f].to_vec(),
+                    proof: tree.open(row),
+                });
+                position = row;
+            }
+            queries.push(openings);
+        }
+    }
+
+    Ok(FriProof {
+        layer_roots: trees.iter().map(|t| t.root()).collect(),
+        final_polynomial,
+        queries,
+    })
+}
+
+/// Verifies a FRI proof that the committed polynomial has degree below `degree_bound`.
+///
+/// `transcript` must be in the same state the prover's was in when calling [`prove_fri`].
+pub fn verify_fri<C: FieldConfig>(
+    config: &FriConfig,
+    degree_bound: usize,
+    proof: &FriProof<C>,
+    transcript: &mut Transcript,
+) -> Result<(), FriError> {
+    let bounds = config.degree_schedule::<C>(degree_bound)?;
+    let f = config.folding_factor;
+    let num_layers = bounds.len() - 1;
+    if proof.layer_roots.len() != num_layers {
+        return Err(FriError::MalformedProof);
+    }
+    if proof.final_polynomial.coeffs.len() > *bounds.last().unwrap() {
+        return Err(FriError::FinalPolynomialTooLarge);
+    }
+
+    append_parameters(transcript, config, degree_bound);
+
+    // Replay the commit phase to recover challenges and per-layer domains.
+    let mut betas = Vec::with_capacity(num_layers);
+    let mut omegas = Vec::with_capacity(num_layers + 1);
+    let mut sizes = Vec::with_capacity(num_layers + 1);
+    let mut n = degree_bound * config.blowup_factor;
+    let mut omega = domain_generator::<C>(n)?;
+    for root in &proof.layer_roots {
+        transcript.append_message(b"fri_layer_root", root);
+        betas.push(transcript.challenge_field_element::<C>(b"fri_folding_challenge"));
+        omegas.push(omega);
+        sizes.push(n);
+        omega = pow_small(omega, f);
+        n /= f;
+    }
+    let final_omega = omega;
+    transcript.append_polynomial(b"fri_final_polynomial", &proof.final_polynomial);
+
+    if num_layers == 0 {
+        return if proof.queries.is_empty() {
+            Ok(())
+        } else {
+            Err(FriError::MalformedProof)
+        };
+    }
+    if proof.queries.len() != config.num_queries {
+        return Err(FriError::MalformedProof);
+    }
+
+    let folders: Vec<Folder<C>> = omegas
+        .iter()
+        .zip(sizes.iter())
+        .map(|(&w, &size)| Folder::new(f, w, size))
+        .collect();
+
+    for openings in &proof.queries {
+        if openings.len() != num_layers {
+            return Err(FriError::MalformedProof);
+        }
+
+        let mut position = transcript.challenge_index(b"fri_query", sizes[0] / f);
+        let mut expected: Option<FieldElement<C>> = None;
+
+        for (layer, opening) in openings.iter().enumerate() {
+            if opening.values.len() != f {
+                return Err(FriError::MalformedProof);
+            }
+            let rows = sizes[layer] / f;
+            let row = position % rows;
+            let slot = position / rows;
+
+            if let Some(value) = expected
+                && opening.values[slot] != value
+            {
+                return Err(FriError::FoldingMismatch { layer });
+            }
+
+            if opening.proof.leaf_index != row {
+                return Err(FriError::InvalidOpening {
+                    layer,
+                    error: MerkleError::IndexOutOfRange {
+                        index: opening.proof.leaf_index,
+                    },
+                });
+            }
+            opening
+                .proof
+                .verify(
+                    &proof.layer_roots[layer],
+                    &field_elements_to_bytes(&opening.values),
+                )
+                .map_err(|error| FriError::InvalidOpening { layer, error })?;
+
+            let x_inv = pow_small(omegas[layer], sizes[layer] - row);
+            expected = Some(folders[layer].fold(&opening.values, x_inv, betas[layer]));
+            position = row;
+        }
+
+        let x = pow_small(final_omega, position);
+        if proof.final_polynomial.evaluate(&x) != expected.unwrap() {
+            return Err(FriError::FinalLayerMismatch);
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::field::config::DefaultFieldConfig;
+
+    type FE = FieldElement<DefaultFieldConfig>;
+
+    fn fe(v: u64) -> FE {
+        FE::new(U1024::from_u64(v))
+    }
+
+    #[test]
+    fn test_fold_matches_definition() {
+        // p(X) = 1 + 2X + 3X^2 + 4X^3, f = 2: p_0 = 1 + 3Y, p_1 = 2 + 4Y.
+        let poly = Polynomial::new(vec![fe(1), fe(2), fe(3), fe(4)]);
+        let n = 8;
+        let omega = domain_generator::<DefaultFieldConfig>(n).unwrap();
+        let folder = Folder::new(2, omega, n);
+        let beta = fe(5);
+
+        for r in 0..n / 2 {
+            let x = pow_small(omega, r);
+            let values = [
+                poly.evaluate(&x),
+                poly.evaluate(&pow_small(omega, r + n / 2)),
+            ];
+            let y = x.square();
+            let expected = (fe(1) + fe(3) * y) + beta * (fe(2) + fe(4) * y);
+            let x_inv = pow_small(omega, n - r);
+            assert_eq!(folder.fold(&values, x_inv, beta), expected);
+        }
+    }
+
+    #[test]
+    fn test_degree_schedule() {
+        let config = FriConfig::new(4, 2, 1).unwrap();
+        assert_eq!(
+            config.degree_schedule::<DefaultFieldConfig>(64).unwrap(),
+            vec![64, 16, 4, 1]
+        );
+        let config = config.with_final_degree_bound(8);
+        assert_eq!(
+            config.degree_schedule::<DefaultFieldConfig>(64).unwrap(),
+            vec![64, 16, 4]
+        );
+    }
+
+    #[test]
+    fn test_invalid_config() {
+        assert_eq!(FriConfig::new(3, 4, 8), Err(FriError::InvalidParameters));
+        assert_eq!(FriConfig::new(2, 1, 8), Err(FriError::InvalidParameters));
+        assert_eq!(FriConfig::new(2, 4, 0), Err(FriError::InvalidParameters));
+    }
+}
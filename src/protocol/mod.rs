@@ -3,9 +3,21 @@
 //! This module provides implementations of fundamental cryptographic protocols:
 //! - `gcd` - Extended Euclidean Algorithm with modular inverse
 //! - `crt` - Chinese Remainder Theorem solver
+//! - `fri` - FRI low-degree test prover and verifier
+//! - `sumcheck` - Non-interactive sum-check prover and verifier
+//! - `transcript` - Fiat–Shamir transcript for deriving verifier challenges
 
 pub mod crt;
+pub mod fri;
 pub mod gcd;
+pub mod sumcheck;
+pub mod transcript;
 
-pub use crt::{CrtError, chinese_remainder, chinese_remainder_solver};
+pub use crt::{CrtBasis, CrtError, chinese_remainder, chinese_remainder_solver};
+pub use fri::{FriConfig, FriError, FriLayerOpening, FriProof, prove_fri, verify_fri};
 pub use gcd::{ExtendedGcdResult, extended_gcd, mod_inverse};
+pub use sumcheck::{
+    SumcheckError, SumcheckProof, SumcheckSubclaim, prove_product_sumcheck, prove_sumcheck,
+    verify_product_sumcheck, verify_sumcheck, verify_sumcheck_rounds,
+};
+pub use transcript::Transcript;
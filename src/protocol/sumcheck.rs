@@ -0,0 +1,371 @@
+//! Sum-check protocol over multivariate and multilinear polynomials.
+//!
+//! The sum-check protocol lets a prover convince a verifier that
+//!
+//!   H = Σ_{b ∈ {0,1}^n} g(b)
+//!
+//! while the verifier only evaluates g at a single random point. In round i the
+//! prover sends the univariate polynomial
+//!
+//!   g_i(X) = Σ_{b ∈ {0,1}^(n-i-1)} g(r_0, ..., r_(i-1), X, b)
+//!
+//! and the verifier checks g_i(0) + g_i(1) against the running claim, then
+//! samples the challenge r_i. After n rounds the claim reduces to g(r_0, ..., r_(n-1)).
+//!
+//! Challenges are derived non-interactively from a [`Transcript`]: the number
+//! of variables, the claimed sum and every round polynomial are appended before
+//! each challenge is drawn. Prover and verifier must pass transcripts in the
+//! same state, which lets sum-check run as one step of a larger protocol.
+//!
+//! Two provers are provided:
+//! - [`prove_sumcheck`] for an arbitrary sparse [`MultivariatePolynomial`]
+//! - [`prove_product_sumcheck`] for a product of [`DenseMultilinearExtension`]s
+
+use crate::poly::multilinear::DenseMultilinearExtension;
+use crate::poly::multivariate::MultivariatePolynomial;
+use crate::protocol::transcript::Transcript;
+use crate::{FieldConfig, FieldElement, Polynomial, U1024};
+
+/// Error type for sum-check verification.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SumcheckError {
+    /// The proof does not contain one round polynomial per variable.
+    WrongNumberOfRounds,
+    /// A round polynomial exceeds the degree bound for its variable.
+    DegreeTooHigh { round: usize },
+    /// g_i(0) + g_i(1) does not equal the running claim.
+    RoundSumMismatch { round: usize },
+    /// The final oracle evaluation does not match the reduced claim.
+    FinalEvaluationMismatch,
+    /// A factor's number of variables differs from the number of rounds.
+    NumVarsMismatch { factor: usize },
+}
+
+impl std::fmt::Display for SumcheckError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SumcheckError::WrongNumberOfRounds => write!(f, "Wrong number of sum-check rounds"),
+            SumcheckError::DegreeTooHigh { round } => {
+                write!(f, "Round {} polynomial exceeds the degree bound", round)
+            }
+            SumcheckError::RoundSumMismatch { round } => {
+                write!(f, "Round {} polynomial does not match the claim", round)
+            }
+            SumcheckError::FinalEvaluationMismatch => {
+                write!(f, "Final evaluation does not match the reduced claim")
+            }
+            SumcheckError::NumVarsMismatch { factor } => {
+                write!(f, "Factor {} has the wrong number of variables", factor)
+            }
+        }
+    }
+}
+
+impl std::error::Error for SumcheckError {}
+
+/// A non-interactive sum-check proof.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SumcheckProof<C: FieldConfig> {
+    /// The claimed sum H over the boolean hypercube.
+    pub claimed_sum: FieldElement<C>,
+    /// Round polynomials g_0, ..., g_(n-1) in coefficient form.
+    pub round_polynomials: Vec<Polynomial<C>>,
+}
+
+/// The claim left after all rounds: g(point) should equal `expected_evaluation`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SumcheckSubclaim<C: FieldConfig> {
+    /// The random point (r_0, ..., r_(n-1)) chosen by the verifier.
+    pub point: Vec<FieldElement<C>>,
+    /// The value g must take at `point` for the proof to be valid.
+    pub expected_evaluation: FieldElement<C>,
+}
+
+/// Proves the sum of a multivariate polynomial over {0,1}^n.
+///
+/// Round polynomials are computed term by term: for a monomial c·Π x_j^(e_j),
+/// the sum over a boolean variable x_j contributes a factor 2 if e_j = 0 and 1 otherwise.
+///
+/// # Examples
+///
+/// ```
+/// use lumen_math::protocol::{Transcript, prove_sumcheck, verify_sumcheck};
+/// use lumen_math::{MultivariatePolynomial, fp};
+///
+/// // g(x0, x1) = 3·x0·x1 + x1 + 2
+/// let mut g = MultivariatePolynomial::new(2);
+/// g.add_term(vec![1, 1], fp!(3u64));
+/// g.add_term(vec![0, 1], fp!(1u64));
+/// g.add_term(vec![0, 0], fp!(2u64));
+///
+/// let proof = prove_sumcheck(&g, &mut Transcript::new(b"example"));
+/// assert_eq!(proof.claimed_sum, fp!(13u64));
+/// assert!(verify_sumcheck(&g, &proof, &mut Transcript::new(b"example")).is_ok());
+/// ```
+pub fn prove_sumcheck<C: FieldConfig>(
+    poly: &MultivariatePolynomial<C>,
+    transcript: &mut Transcript,
+) -> SumcheckProof<C> {
+    let n = poly.num_vars;
+
+    let claimed_sum = poly
+        .terms
+        .iter()
+        .fold(FieldElement::zero(), |acc, (exp, c)| {
+            let term = exp.iter().filter(|&&e| e == 0).fold(*c, |t, _| t.double());
+            acc + term
+        });
+
+    transcript.append_u64(b"sumcheck_num_vars", n as u64);
+    transcript.append_field_element(b"sumcheck_claimed_sum", &claimed_sum);
+
+    let mut challenges: Vec<FieldElement<C>> = Vec::with_capacity(n);
+    let mut round_polynomials = Vec::with_capacity(n);
+
+    for i in 0..n {
+        let mut coeffs = vec![FieldElement::zero(); poly.degree_in(i) + 1];
+        for (exp, c) in &poly.terms {
+            let mut term = *c;
+            for (r, &e) in challenges.iter().zip(exp.iter()) {
+                for _ in 0..e {
+                    term = term * *r;
+                }
+            }
+            for &e in &exp[i + 1..] {
+                if e == 0 {
+                    term = term.double();
+                }
+            }
+            coeffs[exp[i]] = coeffs[exp[i]] + term;
+        }
+
+        let round_poly = Polynomial::new(coeffs);
+        transcript.append_polynomial(b"sumcheck_round", &round_poly);
+        challenges.push(transcript.challenge_field_element(b"sumcheck_challenge"));
+        round_polynomials.push(round_poly);
+    }
+
+    SumcheckProof {
+        claimed_sum,
+        round_polynomials,
+    }
+}
+
+/// Verifies a sum-check proof for a multivariate polynomial.
+///
+/// The final check evaluates `poly` at the challenge point directly.
+pub fn verify_sumcheck<C: FieldConfig>(
+    poly: &MultivariatePolynomial<C>,
+    proof: &SumcheckProof<C>,
+    transcript: &mut Transcript,
+) -> Result<(), SumcheckError> {
+    let degree_bounds: Vec<usize> = (0..poly.num_vars).map(|i| poly.degree_in(i)).collect();
+    let subclaim = verify_sumcheck_rounds(&degree_bounds, proof, transcript)?;
+
+    if poly.evaluate(&subclaim.point) != subclaim.expected_evaluation {
+        return Err(SumcheckError::FinalEvaluationMismatch);
+    }
+    Ok(())
+}
+
+/// Proves the sum of a product of multilinear polynomials over {0,1}^n.
+///
+/// Each round polynomial has degree equal to the number of factors; it is
+/// computed from its evaluations at 0, 1, ..., m and interpolated.
+///
+/// # Panics
+/// Panics if `factors` is empty or the factors have different numbers of variables.
+pub fn prove_product_sumcheck<C: FieldConfig>(
+    factors: &[DenseMultilinearExtension<C>],
+    transcript: &mut Transcript,
+) -> SumcheckProof<C> {
+    assert!(!factors.is_empty(), "At least one factor is required");
+    let n = factors[0].num_vars();
+    assert!(
+        factors.iter().all(|f| f.num_vars() == n),
+        "All factors must have the same number of variables"
+    );
+
+    let degree = factors.len();
+    let points: Vec<FieldElement<C>> = (0..=degree)
+        .map(|t| FieldElement::new(U1024::from_u64(t as u64)))
+        .collect();
+
+    let claimed_sum = (0..1usize << n).fold(FieldElement::zero(), |acc, b| {
+        acc + factors
+            .iter()
+            .fold(FieldElement::one(), |p, f| p * f.evaluations()[b])
+    });
+
+    transcript.append_u64(b"sumcheck_num_vars", n as u64);
+    transcript.append_field_element(b"sumcheck_claimed_sum", &claimed_sum);
+
+    let mut tables: Vec<DenseMultilinearExtension<C>> = factors.to_vec();
+    let mut round_polynomials = Vec::with_capacity(n);
+
+    for _ in 0..n {
+        let half = tables[0].evaluations().len() / 2;
+        let mut evals = vec![FieldElement::<C>::zero(); degree + 1];
+
+        for b in 0..half {
+            for (t, point) in points.iter().enumerate() {
+                let product = tables.iter().fold(FieldElement::one(), |p, f| {
+                    let lo = f.evaluations()[2 * b];
+                    let hi = f.evaluations()[2 * b + 1];
+                    p * (lo + *point * (hi - lo))
+                });
+                evals[t] = evals[t] + product;
+            }
+        }
+
+        let round_poly = Polynomial::interpolate(&points, &evals);
+        transcript.append_polynomial(b"sumcheck_round", &round_poly);
+        let r = transcript.challenge_field_element(b"sumcheck_challenge");
+        for table in tables.iter_mut() {
+            table.fix_variables(&[r]);
+        }
+        round_polynomials.push(round_poly);
+    }
+
+    SumcheckProof {
+        claimed_sum,
+        round_polynomials,
+    }
+}
+
+/// Verifies a sum-check proof for a product of multilinear polynomials.
+///
+/// Every factor must have one variable per round of the proof; otherwise
+/// [`SumcheckError::NumVarsMismatch`] is returned.
+///
+/// # Panics
+/// Panics if `factors` is empty.
+pub fn verify_product_sumcheck<C: FieldConfig>(
+    factors: &[DenseMultilinearExtension<C>],
+    proof: &SumcheckProof<C>,
+    transcript: &mut Transcript,
+) -> Result<(), SumcheckError> {
+    assert!(!factors.is_empty(), "At least one factor is required");
+    let n = proof.round_polynomials.len();
+    if let Some(factor) = factors.iter().position(|f| f.num_vars() != n) {
+        return Err(SumcheckError::NumVarsMismatch { factor });
+    }
+    let subclaim = verify_sumcheck_rounds(&vec![factors.len(); n], proof, transcript)?;
+
+    let product = factors
+        .iter()
+        .fold(FieldElement::one(), |p, f| p * f.evaluate(&subclaim.point));
+    if product != subclaim.expected_evaluation {
+        return Err(SumcheckError::FinalEvaluationMismatch);
+    }
+    Ok(())
+}
+
+/// Runs the verifier's round checks without the final oracle query.
+///
+/// `degree_bounds[i]` is the maximum allowed degree of the round-i polynomial.
+/// On success, returns the subclaim that the caller must check against g.
+pub fn verify_sumcheck_rounds<C: FieldConfig>(
+    degree_bounds: &[usize],
+    proof: &SumcheckProof<C>,
+    transcript: &mut Transcript,
+) -> Result<SumcheckSubclaim<C>, SumcheckError> {
+    let n = degree_bounds.len();
+    if proof.round_polynomials.len() != n {
+        return Err(SumcheckError::WrongNumberOfRounds);
+    }
+
+    transcript.append_u64(b"sumcheck_num_vars", n as u64);
+    transcript.append_field_element(b"sumcheck_claimed_sum", &proof.claimed_sum);
+
+    let mut claim = proof.claimed_sum;
+    let mut point = Vec::with_capacity(n);
+
+    for (round, (round_poly, &bound)) in proof
+        .round_polynomials
+        .iter()
+        .zip(degree_bounds.iter())
+        .enumerate()
+    {
+        if round_poly.degree().unwrap_or(0) > bound {
+            return Err(SumcheckError::DegreeTooHigh { round });
+        }
+
+        let at_zero = round_poly.evaluate(&FieldElement::zero());
+        let at_one = round_poly.evaluate(&FieldElement::one());
+        if at_zero + at_one != claim {
+            return Err(SumcheckError::RoundSumMismatch { round });
+        }
+
+        transcript.append_polynomial(b"sumcheck_round", round_poly);
+        let r = transcript.challenge_field_element(b"sumcheck_challenge");
+        claim = round_poly.evaluate(&r);
+        point.push(r);
+    }
+
+    Ok(SumcheckSubclaim {
+        point,
+        expected_evaluation: claim,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::field::config::DefaultFieldConfig;
+    use crate::fp;
+
+    type MvPoly = MultivariatePolynomial<DefaultFieldConfig>;
+
+    fn sample_poly() -> MvPoly {
+        // g = x0^2·x2 + 5·x1 + 7
+        let mut g = MvPoly::new(3);
+        g.add_term(vec![2, 0, 1], fp!(1u64));
+        g.add_term(vec![0, 1, 0], fp!(5u64));
+        g.add_term(vec![0, 0, 0], fp!(7u64));
+        g
+    }
+
+    #[test]
+    fn test_claimed_sum_matches_brute_force() {
+        let g = sample_poly();
+        let proof = prove_sumcheck(&g, &mut Transcript::new(b"test"));
+
+        let mut expected = FieldElement::zero();
+        for b in 0..8u64 {
+            let point: Vec<_> = (0..3).map(|i| fp!((b >> i) & 1)).collect();
+            expected = expected + g.evaluate(&point);
+        }
+        assert_eq!(proof.claimed_sum, expected);
+    }
+
+    #[test]
+    fn test_honest_proof_verifies() {
+        let g = sample_poly();
+        let proof = prove_sumcheck(&g, &mut Transcript::new(b"test"));
+        assert_eq!(proof.round_polynomials.len(), 3);
+        assert!(verify_sumcheck(&g, &proof, &mut Transcript::new(b"test")).is_ok());
+    }
+
+    #[test]
+    fn test_wrong_claim_rejected() {
+        let g = sample_poly();
+        let mut proof = prove_sumcheck(&g, &mut Transcript::new(b"test"));
+        proof.claimed_sum = proof.claimed_sum + FieldElement::one();
+        assert_eq!(
+            verify_sumcheck(&g, &proof, &mut Transcript::new(b"test")),
+            Err(SumcheckError::RoundSumMismatch { round: 0 })
+        );
+    }
+
+    #[test]
+    fn test_missing_round_rejected() {
+        let g = sample_poly();
+        let mut proof = prove_sumcheck(&g, &mut Transcript::new(b"test"));
+        proof.round_polynomials.pop();
+        assert_eq!(
+            verify_sumcheck(&g, &proof, &mut Transcript::new(b"test")),
+            Err(SumcheckError::WrongNumberOfRounds)
+        );
+    }
+}
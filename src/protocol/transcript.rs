@@ -0,0 +1,270 @@
+//! Fiat–Shamir transcripts built on SHA-256.
+//!
+//! A [`Transcript`] records every public message exchanged in a protocol and
+//! derives verifier challenges from that history, turning an interactive
+//! public-coin protocol into a non-interactive one. Prover and verifier must
+//! append the same messages in the same order to obtain the same challenges.
+//!
+//! # Construction
+//!
+//! The transcript keeps a 32-byte state, initialised from a protocol label.
+//! Every operation is absorbed as
+//!
+//! ```text
+//! state = SHA256(state || op || len(label) || label || len(data) || data)
+//! ```
+//!
+//! where `op` is a one-byte operation tag and lengths are 8-byte big-endian.
+//! Challenges are produced with the same `expand_message_xmd` (SHA-256) used by
+//! [`Digest`](crate::Digest), keyed by the current state and challenge label,
+//! and the output is absorbed back into the state before it is returned.
+//!
+//! Field elements and `U1024` values are always encoded as their canonical
+//! 128-byte big-endian representation, so transcripts are identical across
+//! platforms and backends.
+//!
+//! # Test Vector
+//!
+//! ```
+//! use lumen_math::protocol::Transcript;
+//!
+//! let mut t = Transcript::new(b"lumen-math test");
+//! t.append_message(b"msg", b"hello");
+//! let c = t.challenge_bytes(b"c", 16);
+//! assert_eq!(
+//!     c,
+//!     [
+//!         0xc2, 0x2b, 0x3c, 0x75, 0xbb, 0x6b, 0xbe, 0xc6,
+//!         0xc7, 0x8c, 0x2d, 0xbc, 0xdc, 0xa4, 0x74, 0x85,
+//!     ]
+//! );
+//! ```
+
+use sha2::{Digest as Sha2Digest, Sha256};
+
+use crate::big_int::u1024::expand_message_sha256;
+use crate::{FieldConfig, FieldElement, Polynomial, U1024};
+
+/// Domain separator mixed into every transcript.
+const TRANSCRIPT_DOMAIN: &[u8] = b"lumen_math_transcript_v1";
+
+/// Maximum number of bytes a single challenge can produce (255 SHA-256 blocks).
+pub const MAX_CHALLENGE_BYTES: usize = 255 * 32;
+
+/// Operation tags absorbed ahead of each transcript entry.
+const OP_INIT: u8 = 0x00;
+const OP_MESSAGE: u8 = 0x01;
+const OP_CHALLENGE: u8 = 0x02;
+
+/// A Fiat–Shamir transcript with domain-separated messages and challenges.
+///
+/// # Examples
+///
+/// ```
+/// use lumen_math::protocol::Transcript;
+/// use lumen_math::{DefaultFieldConfig, FieldElement, fp};
+///
+/// let mut prover = Transcript::new(b"my-protocol");
+/// let mut verifier = Transcript::new(b"my-protocol");
+///
+/// prover.append_field_element(b"commitment", &fp!(42u64));
+/// verifier.append_field_element(b"commitment", &fp!(42u64));
+///
+/// let a: FieldElement<DefaultFieldConfig> = prover.challenge_field_element(b"alpha");
+/// let b: FieldElement<DefaultFieldConfig> = verifier.challenge_field_element(b"alpha");
+/// assert_eq!(a, b);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Transcript {
+    state: [u8; 32],
+}
+
+impl Transcript {
+    /// Creates a new transcript for the protocol identified by `label`.
+    pub fn new(label: &[u8]) -> Self {
+        let mut transcript = Self { state: [0u8; 32] };
+        transcript.absorb(OP_INIT, TRANSCRIPT_DOMAIN, label);
+        transcript
+    }
+
+    /// Absorbs one tagged, length-prefixed entry into the state.
+    fn absorb(&mut self, op: u8, label: &[u8], data: &[u8]) {
+        let mut hasher = Sha256::new();
+        hasher.update(self.state);
+        hasher.update([op]);
+        hasher.update((label.len() as u64).to_be_bytes());
+        hasher.update(label);
+        hasher.update((data.len() as u64).to_be_bytes());
+        hasher.update(data);
+        self.state = hasher.finalize().into();
+    }
+
+    /// Appends an arbitrary byte message under `label`.
+    pub fn append_message(&mut self, label: &[u8], message: &[u8]) {
+        self.absorb(OP_MESSAGE, label, message);
+    }
+
+    /// Appends a `u64` (8-byte big-endian) under `label`.
+    pub fn append_u64(&mut self, label: &[u8], value: u64) {
+        self.append_message(label, &value.to_be_bytes());
+    }
+
+    /// Appends a `U1024` (128-byte big-endian) under `label`.
+    pub fn append_u1024(&mut self, label: &[u8], value: &U1024) {
+        self.append_message(label, &value.to_be_bytes());
+    }
+
+    /// Appends a field element by its canonical (non-Montgomery) value.
+    pub fn append_field_element<C: FieldConfig>(&mut self, label: &[u8], value: &FieldElement<C>) {
+        self.append_u1024(label, &value.to_u1024());
+    }
+
+    /// Appends a slice of field elements as one length-prefixed message.
+    pub fn append_field_elements<C: FieldConfig>(
+        &mut self,
+        label: &[u8],
+        values: &[FieldElement<C>],
+    ) {
+        let mut bytes = Vec::with_capacity(8 + 128 * values.len());
+        bytes.extend_from_slice(&(values.len() as u64).to_be_bytes());
+        for v in values {
+            bytes.extend_from_slice(&v.to_u1024().to_be_bytes());
+        }
+        self.append_message(label, &bytes);
+    }
+
+    /// Appends a polynomial by its (trimmed) coefficient vector.
+    pub fn append_polynomial<C: FieldConfig>(&mut self, label: &[u8], poly: &Polynomial<C>) {
+        self.append_field_elements(label, &poly.coeffs);
+    }
+
+    /// Derives `len` challenge bytes under `label`.
+    ///
+    /// # Panics
+    /// Panics if `len` exceeds [`MAX_CHALLENGE_BYTES`].
+    pub fn challenge_bytes(&mut self, label: &[u8], len: usize) -> Vec<u8> {
+        assert!(
+            len <= MAX_CHALLENGE_BYTES,
+            "Challenge length exceeds expand_message limit"
+        );
+
+        let mut input = Vec::with_capacity(32 + 8 + label.len());
+        input.extend_from_slice(&self.state);
+        input.extend_from_slice(&(label.len() as u64).to_be_bytes());
+        input.extend_from_slice(label);
+
+        let output = expand_message_sha256(&input, len);
+        self.absorb(OP_CHALLENGE, label, &output);
+        output
+    }
+
+    /// Derives a uniformly random `U1024` under `label`.
+    pub fn challenge_u1024(&mut self, label: &[u8]) -> U1024 {
+        U1024::from_be_bytes(&self.challenge_bytes(label, 128))
+    }
+
+    /// Derives a uniformly random field element under `label`.
+    ///
+    /// Uses rejection sampling: candidates are truncated to the bit length of the
+    /// modulus and redrawn (each draw advancing the transcript) until one is below
+    /// the modulus, so the result carries no modular-reduction bias.
+    pub fn challenge_field_element<C: FieldConfig>(&mut self, label: &[u8]) -> FieldElement<C> {
+        let bits = C::MODULUS.bits();
+        loop {
+            let candidate = self.challenge_u1024(label).shr(1024 - bits);
+            if candidate < C::MODULUS {
+                return FieldElement::new(candidate);
+            }
+        }
+    }
+
+    /// Derives `count` field elements under `label`.
+    pub fn challenge_field_elements<C: FieldConfig>(
+        &mut self,
+        label: &[u8],
+        count: usize,
+    ) -> Vec<FieldElement<C>> {
+        (0..count)
+            .map(|_| self.challenge_field_element(label))
+            .collect()
+    }
+
+    /// Derives an index in `[0, bound)` under `label` (e.g. a query position).
+    ///
+    /// # Panics
+    /// Panics if `bound` is zero.
+    pub fn challenge_index(&mut self, label: &[u8], bound: usize) -> usize {
+        assert!(bound > 0, "Index bound must be positive");
+        if bound == 1 {
+            return 0;
+        }
+
+        let bits = u64::BITS - ((bound - 1) as u64).leading_zeros();
+        loop {
+            let bytes = self.challenge_bytes(label, 8);
+            let raw = u64::from_be_bytes(bytes.try_into().unwrap());
+            let candidate = (raw >> (64 - bits)) as usize;
+            if candidate < bound {
+                return candidate;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::field::config::DefaultFieldConfig;
+    use crate::fp;
+
+    #[test]
+    fn test_same_inputs_same_challenges() {
+        let mut a = Transcript::new(b"test");
+        let mut b = Transcript::new(b"test");
+        a.append_message(b"m", b"data");
+        b.append_message(b"m", b"data");
+        assert_eq!(a.challenge_bytes(b"c", 32), b.challenge_bytes(b"c", 32));
+    }
+
+    #[test]
+    fn test_protocol_label_separates() {
+        let mut a = Transcript::new(b"protocol-a");
+        let mut b = Transcript::new(b"protocol-b");
+        assert_ne!(a.challenge_u1024(b"c"), b.challenge_u1024(b"c"));
+    }
+
+    #[test]
+    fn test_message_label_separates() {
+        let mut a = Transcript::new(b"test");
+        let mut b = Transcript::new(b"test");
+        a.append_message(b"x", b"data");
+        b.append_message(b"y", b"data");
+        assert_ne!(a.challenge_bytes(b"c", 32), b.challenge_bytes(b"c", 32));
+    }
+
+    #[test]
+    fn test_challenges_advance_state() {
+        let mut t = Transcript::new(b"test");
+        let c1 = t.challenge_bytes(b"c", 32);
+        let c2 = t.challenge_bytes(b"c", 32);
+        assert_ne!(c1, c2);
+    }
+
+    #[test]
+    fn test_field_challenge_in_range() {
+        let mut t = Transcript::new(b"test");
+        t.append_field_element(b"x", &fp!(7u64));
+        for _ in 0..8 {
+            let c: FieldElement<DefaultFieldConfig> = t.challenge_field_element(b"c");
+            assert!(c.to_u1024() < DefaultFieldConfig::MODULUS);
+        }
+    }
+
+    #[test]
+    fn test_challenge_index_in_range() {
+        let mut t = Transcript::new(b"test");
+        for bound in [1, 2, 3, 7, 8, 1000] {
+            assert!(t.challenge_index(b"i", bound) < bound);
+        }
+    }
+}
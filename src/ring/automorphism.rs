@@ -0,0 +1,146 @@
+//! Galois automorphisms and traces of ring elements.
+//!
+//! For odd k, the map σ_k: a(X) ↦ a(X^k) is an automorphism of
+//! Zq[X]/(X^N + 1). The σ_k form the Galois group (Z/2NZ)^*, which FHE
+//! schemes use for slot rotations (k = 5^r), conjugation (k = 2N - 1) and
+//! key switching.
+//!
+//! # NTT form
+//!
+//! [`NttContext`](crate::poly::ntt::NttContext) stores slot j as the
+//! evaluation a(ψ^(2j+1)). Since σ_k(a)(ψ^(2j+1)) = a(ψ^(k(2j+1))), an
+//! automorphism only permutes the slots, so elements in NTT form stay in NTT
+//! form and no transform is needed. In coefficient form, X^i ↦ ±X^(ik mod N)
+//! with the sign given by X^N = -1.
+//!
+//! # Traces
+//!
+//! The subring Zq[X^(N/M)] is a copy of Zq[Y]/(Y^M + 1), fixed by the σ_k
+//! with k ≡ 1 (mod 2M). [`RingElement::trace`] sums a over that subgroup,
+//! one halving step (a + σ_(d+1)(a)) at a time.
+//!
+//! # Example
+//!
+//! ```
+//! use std::sync::Arc;
+//! use lumen_math::{DefaultFieldConfig, FieldElement, NttContext, RingElement};
+//!
+//! let ctx = Arc::new(NttContext::<DefaultFieldConfig>::new(8));
+//! let mut coeffs = vec![FieldElement::zero(); 8];
+//! coeffs[1] = FieldElement::one();
+//! let x = RingElement::new(coeffs, ctx);
+//!
+//! // σ_3(X) = X^3
+//! let x3 = x.automorphism(3);
+//! assert_eq!(x3.coefficients()[3], FieldElement::one());
+//! ```
+
+use super::element::{RingElement, RingElementState};
+use crate::{FieldConfig, FieldElement};
+
+impl<C: FieldConfig> RingElement<C> {
+    /// Applies the Galois automorphism σ_k: a(X) ↦ a(X^k).
+    ///
+    /// Works in either state and preserves it: coefficients are permuted
+    /// with sign changes, NTT slots are permuted.
+    ///
+    /// # Panics
+    /// Panics if `k` is even.
+    pub fn automorphism(&self, k: usize) -> Self {
+        assert!(k % 2 == 1, "Automorphism index must be odd");
+        let n = self.degree();
+        let two_n = 2 * n;
+        let k = k % two_n;
+        let data = self.data();
+
+        match self.state() {
+            RingElementState::Coefficient => {
+                let mut out = vec![FieldElement::zero(); n];
+                for (i, c) in data.iter().enumerate() {
+                    let e = (i * k) % two_n;
+                    if e < n {
+                        out[e] = *c;
+                    } else {
+                        out[e - n] = -*c;
+                    }
+                }
+                RingElement::new(out, self.context().clone())
+            }
+            RingElementState::Ntt => {
+                let out = (0..n)
+                    .map(|j| data[((2 * j + 1) * k % two_n - 1) / 2])
+                    .collect();
+                RingElement::from_ntt(out, self.context().clone())
+            }
+        }
+    }
+
+    /// Returns the trace Tr(a) = Σ σ_k(a) over k ≡ 1 (mod 2M), the Galois
+    /// group of Zq[X]/(X^N + 1) over its subring Zq[X^(N/M)] of degree M.
+    ///
+    /// The result lies in the subring: it equals N/M times the coefficients
+    /// of a at multiples of N/M, with all other coefficients zero. It keeps
+    /// the state of `self`.
+    ///
+    /// # Panics
+    /// Panics unless `subring_degree` is a power of two dividing N.
+    pub fn trace(&self, subring_degree: usize) -> Self {
+        let n = self.degree();
+        assert!(
+            subring_degree.is_power_of_two() && subring_degree <= n,
+            "Subring degree must be a power of two dividing N"
+        );
+
+        let mut acc = self.clone();
+        let mut d = n;
+        while d > subring_degree {
+            acc = &acc + &acc.automorphism(d + 1);
+            d /= 2;
+        }
+        acc
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::field::config::DefaultFieldConfig;
+    use crate::fp;
+    use crate::poly::ntt::NttContext;
+    use std::sync::Arc;
+
+    fn monomial(i: usize) -> RingElement<DefaultFieldConfig> {
+        let ctx = Arc::new(NttContext::new(8));
+        let mut coeffs = vec![FieldElement::zero(); 8];
+        coeffs[i] = FieldElement::one();
+        RingElement::new(coeffs, ctx)
+    }
+
+    #[test]
+    fn test_coefficient_automorphism_signs() {
+        // σ_3(X^3) = X^9 = -X
+        let a = monomial(3).automorphism(3);
+        assert_eq!(a.coefficients()[1], -FieldElement::one());
+        // σ_15(X) = X^15 = -X^7
+        let b = monomial(1).automorphism(15);
+        assert_eq!(b.coefficients()[7], -FieldElement::one());
+        // σ_1 and σ_17 are the identity
+        let a = monomial(5);
+        assert_eq!(a.automorphism(1), a);
+        assert_eq!(a.automorphism(17), a);
+    }
+
+    #[test]
+    fn test_trace_of_constant() {
+        let ctx = Arc::new(NttContext::<DefaultFieldConfig>::new(8));
+        let one = RingElement::one(ctx);
+        assert_eq!(one.trace(8), one);
+        assert_eq!(one.trace(1), one.scale(&fp!(8u64)));
+    }
+
+    #[test]
+    #[should_panic(expected = "Automorphism index must be odd")]
+    fn test_even_index_panics() {
+        let _ = monomial(1).automorphism(2);
+    }
+}
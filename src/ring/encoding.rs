@@ -0,0 +1,381 @@
+//! Compression, rounding and bit-packing of ring elements.
+//!
+//! These are the coefficient-wise maps used to serialise lattice keys and
+//! ciphertexts, generalised from FIPS 203/204 to any modulus q:
+//!
+//! - **Compress_d / Decompress_d**: x ↦ ⌈(2^d / q) · x⌋ mod 2^d and
+//!   y ↦ ⌈(q / 2^d) · y⌋.
+//! - **Power2Round_d**: r = r1 · 2^d + r0 with r0 ∈ (-2^(d-1), 2^(d-1)].
+//! - **Bit-packing**: each coefficient stored in a `bits`-wide field, least
+//!   significant bit first, as in ByteEncode_d / SimpleBitPack.
+//! - **Centered representatives**: coefficients as signed integers in
+//!   (-q/2, q/2].
+//!
+//! All maps act on coefficients, so elements in NTT form are converted to
+//! coefficient form first. The arithmetic is carried out on `U1024` without
+//! intermediate overflow, so the full 1024-bit moduli are supported.
+//!
+//! # Example
+//!
+//! ```
+//! use std::sync::Arc;
+//! use lumen_math::{DefaultFieldConfig, NttContext, RingElement};
+//!
+//! let ctx = Arc::new(NttContext::<DefaultFieldConfig>::new(8));
+//! let a = RingElement::one(ctx.clone());
+//!
+//! let c = a.compress(4);
+//! let bytes = c.pack(4);
+//! assert_eq!(bytes.len(), 4);
+//! assert_eq!(RingElement::unpack(&bytes, 4, ctx).unwrap(), c);
+//! ```
+
+use std::fmt;
+use std::sync::Arc;
+
+use super::element::RingElement;
+use crate::poly::ntt::NttContext;
+use crate::{FieldConfig, FieldElement, I1024, U1024};
+
+/// Error type for decoding packed ring elements.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RingEncodingError {
+    /// The byte string has the wrong length for the degree and bit width.
+    InvalidLength { expected: usize, actual: usize },
+    /// A decoded coefficient is not below the modulus.
+    CoefficientOutOfRange { index: usize },
+}
+
+impl fmt::Display for RingEncodingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RingEncodingError::InvalidLength { expected, actual } => {
+                write!(f, "Expected {} bytes, got {}", expected, actual)
+            }
+            RingEncodingError::CoefficientOutOfRange { index } => {
+                write!(f, "Coefficient {} is not below the modulus", index)
+            }
+        }
+    }
+}
+
+impl std::error::Error for RingEncodingError {}
+
+/// Returns the length in bytes of `n` coefficients packed into `bits`-bit fields.
+pub fn packed_len(n: usize, bits: u32) -> usize {
+    (n * bits as usize).div_ceil(8)
+}
+
+/// Returns x mod 2^d.
+fn low_bits(x: U1024, d: usize) -> U1024 {
+    if d >= 1024 { x } else { x - x.shr(d).shl(d) }
+}
+
+/// Reduces a signed integer into [0, q).
+pub(crate) fn reduce_signed(x: &I1024, q: &U1024) -> U1024 {
+    let r = x.magnitude() % *q;
+    if x.is_negative() && r != U1024::ZERO {
+        *q - r
+    } else {
+        r
+    }
+}
+
+/// ⌈(2^d / q) · x⌋ mod 2^d, by binary long division so that x · 2^d is
+/// never formed.
+pub(crate) fn compress_u1024(x: U1024, d: u32, q: &U1024) -> U1024 {
+    let mut quotient = U1024::ZERO;
+    let mut rem = x;
+    for i in (0..d as usize).rev() {
+        // rem ← 2·rem mod q, without overflowing when q is close to 2^1024.
+        let gap = *q - rem;
+        if rem >= gap {
+            rem = rem - gap;
+            quotient = quotient.with_bit(i);
+        } else {
+            rem = rem + rem;
+        }
+    }
+    // Round half up: add one when rem ≥ q - ⌊q/2⌋.
+    if rem >= *q - q.shr(1) {
+        quotient = quotient + U1024::ONE;
+    }
+    low_bits(quotient, d as usize)
+}
+
+/// ⌈(q / 2^d) · y⌋, using the double-width product q · y.
+pub(crate) fn decompress_u1024(y: U1024, d: u32, q: &U1024) -> U1024 {
+    let d = d as usize;
+    let (lo, hi) = q.full_mul(&y);
+    let (lo, carry) = lo.const_add(&U1024::ONE.shl(d - 1));
+    let hi = if carry { hi + U1024::ONE } else { hi };
+    lo.shr(d) ^ hi.shl(1024 - d)
+}
+
+impl<C: FieldConfig> RingElement<C> {
+    fn map_coefficients<F>(&self, f: F) -> Self
+    where
+        F: Fn(U1024) -> U1024,
+    {
+        let coeffs = self
+            .clone_to_coefficient()
+            .coefficients()
+            .iter()
+            .map(|c| FieldElement::new(f(c.to_u1024())))
+            .collect();
+        RingElement::new(coeffs, self.context().clone())
+    }
+
+    /// Applies Compress_d to every coefficient.
+    ///
+    /// The result has coefficients in [0, 2^d) and is in coefficient form.
+    ///
+    /// # Panics
+    /// Panics unless 1 ≤ d < log2 q.
+    pub fn compress(&self, d: u32) -> Self {
+        let q = C::MODULUS;
+        assert!(
+            d >= 1 && (d as usize) < q.bits(),
+            "Compression requires 1 ≤ d < log2 q"
+        );
+        self.map_coefficients(|x| compress_u1024(x, d, &q))
+    }
+
+    /// Applies Decompress_d to every coefficient.
+    ///
+    /// Coefficients are read as integers in [0, 2^d); higher bits are ignored.
+    ///
+    /// # Panics
+    /// Panics unless 1 ≤ d < log2 q.
+    pub fn decompress(&self, d: u32) -> Self {
+        let q = C::MODULUS;
+        assert!(
+            d >= 1 && (d as usize) < q.bits(),
+            "Compression requires 1 ≤ d < log2 q"
+        );
+        self.map_coefficients(|y| decompress_u1024(low_bits(y, d as usize), d, &q))
+    }
+
+    /// Splits every coefficient as r = r1 · 2^d + r0 with
+    /// r0 ∈ (-2^(d-1), 2^(d-1)] (Power2Round).
+    ///
+    /// Returns `(r1, r0)`, both in coefficient form.
+    ///
+    /// # Panics
+    /// Panics unless 1 ≤ d < log2 q.
+    pub fn power2round(&self, d: u32) -> (Self, Self) {
+        let q = C::MODULUS;
+        assert!(
+            d >= 1 && (d as usize) < q.bits(),
+            "Power2Round requires 1 ≤ d < log2 q"
+        );
+        let d = d as usize;
+        let half = U1024::ONE.shl(d - 1);
+
+        let coeffs = self.clone_to_coefficient();
+        let (high, low): (Vec<_>, Vec<_>) = coeffs
+            .coefficients()
+            .iter()
+            .map(|c| {
+                let r = c.to_u1024();
+                let r0 = low_bits(r, d);
+                if r0 > half {
+                    let r1 = r.shr(d) + U1024::ONE;
+                    (
+                        FieldElement::new(r1),
+                        -FieldElement::new(U1024::ONE.shl(d) - r0),
+                    )
+                } else {
+                    (FieldElement::new(r.shr(d)), FieldElement::new(r0))
+                }
+            })
+            .unzip();
+
+        let ctx = self.context();
+        (
+            RingElement::new(high, ctx.clone()),
+            RingElement::new(low, ctx.clone()),
+        )
+    }
+
+    /// Packs the coefficients into `bits`-bit fields, least significant bit
+    /// first.
+    ///
+    /// The output has [`packed_len`]`(n, bits)` bytes.
+    ///
+    /// # Panics
+    /// Panics if `bits` is 0 or a coefficient does not fit in `bits` bits.
+    pub fn pack(&self, bits: u32) -> Vec<u8> {
+        assert!(bits >= 1, "Bit width must be positive");
+        let coeffs = self.clone_to_coefficient();
+        let mut out = vec![0u8; packed_len(self.degree(), bits)];
+        let width = bits as usize;
+
+        for (i, c) in coeffs.coefficients().iter().enumerate() {
+            let value = c.to_u1024();
+            assert!(
+                value.bits() <= width,
+                "Coefficient does not fit in {} bits",
+                bits
+            );
+            for j in 0..value.bits() {
+                if value.bit(j) {
+                    let pos = i * width + j;
+                    out[pos / 8] |= 1 << (pos % 8);
+                }
+            }
+        }
+        out
+    }
+
+    /// Unpacks a ring element in coefficient form from `bits`-bit fields.
+    ///
+    /// # Errors
+    /// Returns [`RingEncodingError::InvalidLength`] if `bytes` does not have
+    /// [`packed_len`]`(n, bits)` bytes and
+    /// [`RingEncodingError::CoefficientOutOfRange`] if a field holds a value
+    /// ≥ q.
+    ///
+    /// # Panics
+    /// Panics if `bits` is 0.
+    pub fn unpack(
+        bytes: &[u8],
+        bits: u32,
+        ctx: Arc<NttContext<C>>,
+    ) -> Result<Self, RingEncodingError> {
+        assert!(bits >= 1, "Bit width must be positive");
+        let expected = packed_len(ctx.n, bits);
+        if bytes.len() != expected {
+            return Err(RingEncodingError::InvalidLength {
+                expected,
+                actual: bytes.len(),
+            });
+        }
+
+        let width = bits as usize;
+        let mut coeffs = Vec::with_capacity(ctx.n);
+        for i in 0..ctx.n {
+            let mut value = U1024::ZERO;
+            for j in 0..width {
+                let pos = i * width + j;
+                if (bytes[pos / 8] >> (pos % 8)) & 1 == 1 {
+                    if j >= 1024 {
+                        return Err(RingEncodingError::CoefficientOutOfRange { index: i });
+                    }
+                    value = value.with_bit(j);
+                }
+            }
+            if value >= C::MODULUS {
+                return Err(RingEncodingError::CoefficientOutOfRange { index: i });
+            }
+            coeffs.push(FieldElement::new(value));
+        }
+        Ok(RingElement::new(coeffs, ctx))
+    }
+
+    /// Returns the coefficients as centered representatives in (-q/2, q/2].
+    pub fn centered_coefficients(&self) -> Vec<I1024> {
+        self.clone_to_coefficient()
+            .coefficients()
+            .iter()
+            .map(FieldElement::to_centered_i1024)
+            .collect()
+    }
+
+    /// Creates a ring element from signed coefficients, reducing each mod q.
+    ///
+    /// # Panics
+    /// Panics if `coeffs.len() != ctx.n`.
+    pub fn from_centered_coefficients(coeffs: &[I1024], ctx: Arc<NttContext<C>>) -> Self {
+        let q = C::MODULUS;
+        let coeffs = coeffs
+            .iter()
+            .map(|c| FieldElement::new(reduce_signed(c, &q)))
+            .collect();
+        RingElement::new(coeffs, ctx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::field::config::DefaultFieldConfig;
+
+    fn make_ctx() -> Arc<NttContext<DefaultFieldConfig>> {
+        Arc::new(NttContext::new(8))
+    }
+
+    #[test]
+    fn test_compress_extremes_for_large_modulus() {
+        let q = DefaultFieldConfig::MODULUS;
+        assert_eq!(compress_u1024(U1024::ZERO, 10, &q), U1024::ZERO);
+        // q - 1 ≈ q rounds to 2^d, which wraps to 0.
+        assert_eq!(compress_u1024(q - U1024::ONE, 10, &q), U1024::ZERO);
+        // ⌊q/2⌋ maps to 2^(d-1).
+        assert_eq!(compress_u1024(q.shr(1), 10, &q), U1024::from_u64(512));
+    }
+
+    #[test]
+    fn test_decompress_compress_error_is_bounded() {
+        let q = DefaultFieldConfig::MODULUS;
+        let d = 16;
+        // |x - Decompress(Compress(x))| mod± q ≤ ⌈q / 2^(d+1)⌋
+        let bound = q.shr(d as usize + 1) + U1024::ONE;
+        for k in 1..50u64 {
+            let x = q.shr(6) * U1024::from_u64(k) % q;
+            let y = decompress_u1024(compress_u1024(x, d, &q), d, &q);
+            let diff = FieldElement::<DefaultFieldConfig>::new((x + q - y) % q).to_centered_i1024();
+            assert!(diff.magnitude() <= bound);
+        }
+    }
+
+    #[test]
+    fn test_power2round_recombines() {
+        let ctx = make_ctx();
+        let q = DefaultFieldConfig::MODULUS;
+        let coeffs: Vec<_> = (0..8)
+            .map(|i| FieldElement::new(q.shr(i * 9 + 1) - U1024::from_u64(i as u64)))
+            .collect();
+        let a = RingElement::new(coeffs, ctx);
+        let d = 13;
+        let (r1, r0) = a.power2round(d);
+
+        let two_d = FieldElement::new(U1024::ONE.shl(d as usize));
+        assert_eq!(&r1.scale(&two_d) + &r0, a);
+        for c in r0.centered_coefficients() {
+            let m = c.magnitude();
+            assert!(m <= U1024::from_u64(1 << (d - 1)));
+            if c.is_negative() {
+                assert!(m < U1024::from_u64(1 << (d - 1)));
+            }
+        }
+    }
+
+    #[test]
+    fn test_centered_roundtrip() {
+        let ctx = make_ctx();
+        let values: Vec<I1024> = [-3i64, 0, 1, -1, 7, i64::MIN, i64::MAX, 42]
+            .into_iter()
+            .map(I1024::from)
+            .collect();
+        let a = RingElement::from_centered_coefficients(&values, ctx);
+        assert_eq!(a.centered_coefficients(), values);
+    }
+
+    #[test]
+    fn test_unpack_rejects_bad_input() {
+        let ctx = make_ctx();
+        assert_eq!(
+            RingElement::unpack(&[0u8; 3], 4, ctx.clone()),
+            Err(RingEncodingError::InvalidLength {
+                expected: 4,
+                actual: 3
+            })
+        );
+        let all_ones = vec![0xffu8; packed_len(8, 1024)];
+        assert_eq!(
+            RingElement::unpack(&all_ones, 1024, ctx),
+            Err(RingEncodingError::CoefficientOutOfRange { index: 0 })
+        );
+    }
+}
@@ -0,0 +1,263 @@
+//! Gadget decomposition and key switching for ring elements.
+//!
+//! The gadget vector g = (1, B, B², …, B^(ℓ-1)) lets a ring element with
+//! arbitrary coefficients be written as Σ dⱼ · Bʲ with small digits dⱼ.
+//! Multiplying the digits instead of the element keeps noise growth
+//! proportional to B rather than q, which is what relinearisation and key
+//! switching rely on.
+//!
+//! # Balanced digits
+//!
+//! [`RingElement::gadget_decompose`] works on the centered representative
+//! x ∈ (-q/2, q/2] of each coefficient and produces digits with
+//! |dⱼ| ≤ ⌊B/2⌋, such that Σ dⱼ · Bʲ = x over the integers. This requires
+//! B^ℓ ≥ q.
+//!
+//! # Key switching
+//!
+//! A [`KeySwitchingKey`] from s to s' holds, for each j,
+//! (bⱼ, aⱼ) = (-aⱼ·s' + eⱼ + Bʲ·s, aⱼ). A ciphertext (c₀, c₁) with phase
+//! c₀ + c₁·s is switched to (c₀ + Σ dⱼ·bⱼ, Σ dⱼ·aⱼ) for the digits dⱼ of
+//! c₁, whose phase under s' is the same up to the noise Σ dⱼ·eⱼ.
+//!
+//! # Example
+//!
+//! ```
+//! use std::sync::Arc;
+//! use lumen_math::{DefaultFieldConfig, NttContext, RingElement};
+//! use lumen_math::ring::sampling::sample_uniform;
+//!
+//! let ctx = Arc::new(NttContext::<DefaultFieldConfig>::new(8));
+//! let a = sample_uniform(b"seed", &[0], ctx);
+//!
+//! // 2^32-ary digits: 32 levels cover any modulus below 2^1024.
+//! let base = 1u64 << 32;
+//! let digits = a.gadget_decompose(base, 32);
+//! assert_eq!(RingElement::gadget_recompose(&digits, base), a);
+//! ```
+
+use std::sync::Arc;
+
+use super::element::RingElement;
+use super::sampling::{field_from_signed, sample_cbd, sample_uniform};
+use crate::poly::ntt::NttContext;
+use crate::{FieldConfig, FieldElement, U1024};
+
+/// Returns (x / b, x mod b) for a word-sized divisor.
+fn div_rem_small(x: &U1024, b: u64) -> (U1024, u64) {
+    let mut q = U1024::ZERO;
+    let mut r: u128 = 0;
+    for i in (0..x.0.len()).rev() {
+        let cur = (r << 64) | x.0[i] as u128;
+        q.0[i] = (cur / b as u128) as u64;
+        r = cur % b as u128;
+    }
+    (q, r as u64)
+}
+
+/// Returns `true` if base^levels ≥ `bound`.
+fn power_reaches(base: u64, levels: usize, bound: &U1024) -> bool {
+    let b = U1024::from_u64(base);
+    let mut power = U1024::ONE;
+    for _ in 0..levels {
+        let (lo, hi) = power.const_mul(&b);
+        if hi != U1024::ZERO {
+            return true;
+        }
+        power = lo;
+    }
+    power >= *bound
+}
+
+/// Returns the gadget vector (1, B, …, B^(ℓ-1)) reduced modulo q.
+pub fn gadget_vector<C: FieldConfig>(base: u64, levels: usize) -> Vec<FieldElement<C>> {
+    let b = FieldElement::new(U1024::from_u64(base) % C::MODULUS);
+    let mut power = FieldElement::one();
+    (0..levels)
+        .map(|_| {
+            let current = power;
+            power = power * b;
+            current
+        })
+        .collect()
+}
+
+impl<C: FieldConfig> RingElement<C> {
+    /// Decomposes into `levels` digit polynomials with balanced coefficients
+    /// |dⱼ| ≤ ⌊`base`/2⌋, such that Σ dⱼ · `base`ʲ equals the centered
+    /// representative of each coefficient.
+    ///
+    /// The digits are in coefficient form, least significant first.
+    ///
+    /// # Panics
+    /// Panics if `base < 2` or `base`^`levels` < q.
+    pub fn gadget_decompose(&self, base: u64, levels: usize) -> Vec<RingElement<C>> {
+        assert!(base >= 2, "Gadget base must be at least 2");
+        assert!(
+            power_reaches(base, levels, &C::MODULUS),
+            "Gadget base^levels must be at least the modulus"
+        );
+
+        let n = self.degree();
+        let half = base / 2;
+        let elem = self.clone_to_coefficient();
+        let mut digits = vec![Vec::with_capacity(n); levels];
+        for c in elem.coefficients() {
+            let (mut rest, negative) = c.centered_parts();
+            for (j, digit) in digits.iter_mut().enumerate() {
+                let d = if j + 1 == levels {
+                    // The bound B^ℓ ≥ q leaves at most ⌊B/2⌋ for the last digit.
+                    rest.0[0] as i64
+                } else {
+                    let (quotient, r) = div_rem_small(&rest, base);
+                    rest = quotient;
+                    if r > half {
+                        rest = rest + U1024::ONE;
+                        r as i64 - base as i64
+                    } else {
+                        r as i64
+                    }
+                };
+                digit.push(field_from_signed(if negative { -d } else { d }));
+            }
+        }
+
+        let ctx = self.context();
+        digits
+            .into_iter()
+            .map(|d| RingElement::new(d, ctx.clone()))
+            .collect()
+    }
+
+    /// Returns Σ dⱼ · `base`ʲ for digits in either state.
+    ///
+    /// # Panics
+    /// Panics if `digits` is empty or the digits do not share a context.
+    pub fn gadget_recompose(digits: &[RingElement<C>], base: u64) -> RingElement<C> {
+        assert!(!digits.is_empty(), "At least one digit is required");
+        let g = gadget_vector::<C>(base, digits.len());
+        digits
+            .iter()
+            .zip(&g)
+            .skip(1)
+            .fold(digits[0].clone(), |acc, (d, gj)| &acc + &d.scale(gj))
+    }
+}
+
+/// A key switching RLWE ciphertexts from a secret s to a secret s'.
+#[derive(Clone, Debug)]
+pub struct KeySwitchingKey<C: FieldConfig> {
+    base: u64,
+    /// (bⱼ, aⱼ) = (-aⱼ·s' + eⱼ + Bʲ·s, aⱼ) in NTT form
+    keys: Vec<(RingElement<C>, RingElement<C>)>,
+}
+
+impl<C: FieldConfig> KeySwitchingKey<C> {
+    /// Generates a key from `s_from` to `s_to` with gadget `base` and
+    /// `levels` digits.
+    ///
+    /// The masks aⱼ are expanded from `seed` with SHAKE128 and the errors
+    /// eⱼ from `seed` with SHAKE256 (centered binomial, parameter `eta`),
+    /// using nonce `[j]`; the seed must be secret and used only once.
+    ///
+    /// # Panics
+    /// Panics if `base`^`levels` < q, or the secrets do not share a context.
+    pub fn generate(
+        s_from: &RingElement<C>,
+        s_to: &RingElement<C>,
+        base: u64,
+        levels: usize,
+        seed: &[u8],
+        eta: usize,
+    ) -> Self {
+        assert!(
+            power_reaches(base, levels, &C::MODULUS),
+            "Gadget base^levels must be at least the modulus"
+        );
+        assert!(levels <= 256, "At most 256 gadget levels are supported");
+        let ctx: Arc<NttContext<C>> = s_to.context().clone();
+        let s_to = s_to.clone_to_ntt();
+        let keys = gadget_vector::<C>(base, levels)
+            .iter()
+            .enumerate()
+            .map(|(j, gj)| {
+                let nonce = [j as u8];
+                let a = sample_uniform(seed, &nonce, ctx.clone());
+                let e = sample_cbd(seed, &nonce, eta, ctx.clone());
+                let b = &(&e + &s_from.scale(gj)) - &(&a * &s_to);
+                (b.into_ntt(), a)
+            })
+            .collect();
+        Self { base, keys }
+    }
+
+    /// Creates a key from precomputed components (bⱼ, aⱼ).
+    ///
+    /// # Panics
+    /// Panics if `keys` is empty or `base`^`keys.len()` < q.
+    pub fn from_parts(base: u64, keys: Vec<(RingElement<C>, RingElement<C>)>) -> Self {
+        assert!(!keys.is_empty(), "At least one digit is required");
+        assert!(
+            power_reaches(base, keys.len(), &C::MODULUS),
+            "Gadget base^levels must be at least the modulus"
+        );
+        Self { base, keys }
+    }
+
+    /// Returns the gadget base B.
+    pub fn base(&self) -> u64 {
+        self.base
+    }
+
+    /// Returns the number of digits ℓ.
+    pub fn levels(&self) -> usize {
+        self.keys.len()
+    }
+
+    /// Returns the components (bⱼ, aⱼ).
+    pub fn keys(&self) -> &[(RingElement<C>, RingElement<C>)] {
+        &self.keys
+    }
+}
+
+/// Switches a ciphertext (c₀, c₁) with phase c₀ + c₁·s to one with phase
+/// c₀' + c₁'·s' under the target secret of `ksk`.
+///
+/// The result is in NTT form.
+pub fn key_switch<C: FieldConfig>(
+    ct: (&RingElement<C>, &RingElement<C>),
+    ksk: &KeySwitchingKey<C>,
+) -> (RingElement<C>, RingElement<C>) {
+    let (c0, c1) = ct;
+    let digits = c1.gadget_decompose(ksk.base, ksk.levels());
+    let mut out0 = c0.clone_to_ntt();
+    let mut out1 = RingElement::zero(c0.context().clone()).into_ntt();
+    for (d, (b, a)) in digits.into_iter().zip(&ksk.keys) {
+        let d = d.into_ntt();
+        out0 = &out0 + &(&d * b);
+        out1 = &out1 + &(&d * a);
+    }
+    (out0, out1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_div_rem_small() {
+        let x = U1024::from_u64(1_000_003).shl(100) + U1024::from_u64(17);
+        let (q, r) = div_rem_small(&x, 1 << 20);
+        assert_eq!(r, 17);
+        assert_eq!(q, U1024::from_u64(1_000_003).shl(80));
+    }
+
+    #[test]
+    fn test_power_reaches() {
+        let q = U1024::from_u64(7681);
+        assert!(power_reaches(2, 13, &q));
+        assert!(!power_reaches(2, 12, &q));
+        assert!(power_reaches(u64::MAX, 20, &q));
+        assert!(!power_reaches(3, 0, &q));
+    }
+}
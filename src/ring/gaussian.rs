@@ -0,0 +1,270 @@
+//! Constant-time discrete Gaussian sampling.
+//!
+//! The discrete Gaussian D_σ over Z gives x probability proportional to
+//! ρ_σ(x) = exp(−x² / 2σ²). [`DiscreteGaussian`] samples it truncated to
+//! |x| ≤ B = ⌈τσ⌉ for a tail cut τ, using a reverse cumulative distribution
+//! table of |x|:
+//!
+//! - **Table**: entry k holds the tail P(|X| > k) as a 64-bit fixed-point
+//!   fraction, for k = 0, …, B − 1; the distribution of |X| gives 0 weight
+//!   ρ(0) and every k ≥ 1 weight 2ρ(k), since ±k share it.
+//! - **Sampling**: a uniform 64-bit word u is compared against *every*
+//!   entry, and |x| is the number of entries that exceed u. A random sign
+//!   bit is then applied with a mask, which leaves 0 unchanged.
+//!
+//! The running time and memory accesses do not depend on the sampled value:
+//! the whole table is scanned, and the comparisons, the sign and the
+//! mapping into the field use arithmetic instead of branches or divisions.
+//!
+//! The tails are summed in `f64` from B downward, so small tails are added
+//! up first and each entry is accurate to about 2⁻⁵³ relative to its own
+//! size, however small. Entries are then rounded down to multiples of
+//! 2⁻⁶⁴, so each tail probability is off by less than 2⁻⁶⁴ in absolute
+//! terms, and only values whose tail mass is below 2⁻⁶⁴ are never sampled.
+//!
+//! # Example
+//!
+//! ```
+//! use std::sync::Arc;
+//! use rand::SeedableRng;
+//! use rand::rngs::StdRng;
+//! use lumen_math::{DefaultFieldConfig, NttContext};
+//! use lumen_math::ring::gaussian::DiscreteGaussian;
+//!
+//! let gaussian = DiscreteGaussian::new(3.2, 6.0).unwrap();
+//! assert_eq!(gaussian.bound(), 20);
+//!
+//! let mut rng = StdRng::seed_from_u64(1);
+//! let x = gaussian.sample(&mut rng);
+//! assert!(x.abs() <= 20);
+//!
+//! let ctx = Arc::new(NttContext::<DefaultFieldConfig>::new(8));
+//! let e = gaussian.sample_ring(&mut rng, ctx);
+//! assert!(e.centered_coefficients().iter().all(|c| c.magnitude().0[0] <= 20));
+//! ```
+
+use std::fmt;
+use std::sync::Arc;
+
+use rand::Rng;
+
+use super::element::RingElement;
+use crate::poly::ntt::NttContext;
+use crate::{FieldConfig, FieldElement};
+
+/// Largest supported bound ⌈τσ⌉, which keeps the table at 64 KiB.
+pub const MAX_GAUSSIAN_BOUND: u64 = 1 << 13;
+
+/// Error type for discrete Gaussian parameters.
+#[derive(Debug, Clone, PartialEq)]
+pub enum GaussianError {
+    /// σ is not a positive finite number.
+    InvalidSigma { sigma: f64 },
+    /// The tail cut τ is not a positive finite number.
+    InvalidTailCut { tail_cut: f64 },
+    /// The bound ⌈τσ⌉ exceeds [`MAX_GAUSSIAN_BOUND`].
+    BoundTooLarge { bound: f64 },
+}
+
+impl fmt::Display for GaussianError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GaussianError::InvalidSigma { sigma } => {
+                write!(
+                    f,
+                    "Standard deviation {} must be positive and finite",
+                    sigma
+                )
+            }
+            GaussianError::InvalidTailCut { tail_cut } => {
+                write!(f, "Tail cut {} must be positive and finite", tail_cut)
+            }
+            GaussianError::BoundTooLarge { bound } => {
+                write!(
+                    f,
+                    "Tail bound {} exceeds the maximum of {}",
+                    bound, MAX_GAUSSIAN_BOUND
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for GaussianError {}
+
+/// A discrete Gaussian distribution over Z with standard deviation σ,
+/// truncated to [−⌈τσ⌉, ⌈τσ⌉].
+#[derive(Clone, Debug, PartialEq)]
+pub struct DiscreteGaussian {
+    sigma: f64,
+    tail_cut: f64,
+    /// rcdt[k] = ⌊2^64 · P(|X| > k)⌋ for k < bound
+    rcdt: Vec<u64>,
+}
+
+impl DiscreteGaussian {
+    /// Creates a sampler with standard deviation `sigma` and tail cut
+    /// `tail_cut`, so samples lie in [−⌈`tail_cut`·`sigma`⌉, ⌈`tail_cut`·`sigma`⌉].
+    pub fn new(sigma: f64, tail_cut: f64) -> Result<Self, GaussianError> {
+        if !(sigma.is_finite() && sigma > 0.0) {
+            return Err(GaussianError::InvalidSigma { sigma });
+        }
+        if !(tail_cut.is_finite() && tail_cut > 0.0) {
+            return Err(GaussianError::InvalidTailCut { tail_cut });
+        }
+        let bound = (tail_cut * sigma).ceil();
+        if bound > MAX_GAUSSIAN_BOUND as f64 {
+            return Err(GaussianError::BoundTooLarge { bound });
+        }
+        let bound = bound as u64;
+
+        let two_var = 2.0 * sigma * sigma;
+        let weights: Vec<f64> = (0..=bound)
+            .map(|k| {
+                let rho = (-((k * k) as f64) / two_var).exp();
+                if k == 0 { rho } else { 2.0 * rho }
+            })
+            .collect();
+
+        // Suffix sums from the smallest weights up keep the relative
+        // precision of the far tail.
+        let mut tails = vec![0.0; bound as usize + 1];
+        for k in (0..bound as usize).rev() {
+            tails[k] = tails[k + 1] + weights[k + 1];
+        }
+        let total = tails[0] + weights[0];
+
+        let scale = 2f64.powi(64);
+        let rcdt = tails[..bound as usize]
+            .iter()
+            .map(|t| (t / total * scale) as u64)
+            .collect();
+
+        Ok(Self {
+            sigma,
+            tail_cut,
+            rcdt,
+        })
+    }
+
+    /// Returns the standard deviation σ.
+    pub fn sigma(&self) -> f64 {
+        self.sigma
+    }
+
+    /// Returns the tail cut τ.
+    pub fn tail_cut(&self) -> f64 {
+        self.tail_cut
+    }
+
+    /// Returns the bound B = ⌈τσ⌉ on the magnitude of every sample.
+    pub fn bound(&self) -> u64 {
+        self.rcdt.len() as u64
+    }
+
+    /// Samples an integer in [−B, B].
+    pub fn sample<R: Rng>(&self, rng: &mut R) -> i64 {
+        let u: u64 = rng.random();
+        let sign: u64 = rng.random::<u64>() & 1;
+        // |x| counts the tails above u: u < t exactly when u − t borrows.
+        let magnitude = self
+            .rcdt
+            .iter()
+            .map(|&t| u.overflowing_sub(t).1 as u64)
+            .sum::<u64>() as i64;
+        let mask = -(sign as i64);
+        (magnitude ^ mask) - mask
+    }
+
+    /// Samples the centered representative of an integer in [−B, B].
+    ///
+    /// |x| is mapped into the field by double-and-add over its bits, which
+    /// also reduces it modulo q without a variable-time division.
+    pub fn sample_field<C: FieldConfig, R: Rng>(&self, rng: &mut R) -> FieldElement<C> {
+        let x = self.sample(rng);
+        let bits = x.unsigned_abs();
+        let one = FieldElement::one();
+        let mut magnitude = FieldElement::zero();
+        for i in (0..=MAX_GAUSSIAN_BOUND.ilog2()).rev() {
+            magnitude = magnitude.double();
+            magnitude = FieldElement::conditional_select(
+                &(magnitude + one),
+                &magnitude,
+                bits >> i & 1 == 1,
+            );
+        }
+        FieldElement::conditional_select(&-magnitude, &magnitude, x < 0)
+    }
+
+    /// Samples a ring element with independent coefficients in [−B, B], in
+    /// coefficient form.
+    pub fn sample_ring<C: FieldConfig, R: Rng>(
+        &self,
+        rng: &mut R,
+        ctx: Arc<NttContext<C>>,
+    ) -> RingElement<C> {
+        let coeffs = (0..ctx.n).map(|_| self.sample_field(rng)).collect();
+        RingElement::new(coeffs, ctx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+
+    #[test]
+    fn test_rejects_invalid_parameters() {
+        assert_eq!(
+            DiscreteGaussian::new(0.0, 6.0),
+            Err(GaussianError::InvalidSigma { sigma: 0.0 })
+        );
+        assert!(matches!(
+            DiscreteGaussian::new(3.2, f64::NAN),
+            Err(GaussianError::InvalidTailCut { .. })
+        ));
+        assert_eq!(
+            DiscreteGaussian::new(1e4, 10.0),
+            Err(GaussianError::BoundTooLarge { bound: 1e5 })
+        );
+    }
+
+    #[test]
+    fn test_table_is_monotone() {
+        let g = DiscreteGaussian::new(2.5, 8.0).unwrap();
+        assert_eq!(g.bound(), 20);
+        assert!(g.rcdt.windows(2).all(|w| w[0] >= w[1]));
+        // P(X = 0) = 1 / Σ ρ(k) ≈ 1 / (σ√(2π))
+        let p0 = 1.0 - g.rcdt[0] as f64 / 2f64.powi(64);
+        assert!((p0 - 1.0 / (2.5 * (2.0 * std::f64::consts::PI).sqrt())).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_far_tail_keeps_its_precision() {
+        // For σ = 3.2, P(|X| > k) is below 2⁻⁵³ from k = 26 on, but stays
+        // above 2⁻⁶⁴ up to k = 28.
+        let (sigma, tail_cut) = (3.2f64, 12.0);
+        let g = DiscreteGaussian::new(sigma, tail_cut).unwrap();
+        let rho = |k: u64| (-((k * k) as f64) / (2.0 * sigma * sigma)).exp();
+        let total: f64 = rho(0) + (1..=g.bound()).map(|k| 2.0 * rho(k)).sum::<f64>();
+        for k in 26..=28u64 {
+            let tail: f64 = (k + 1..=g.bound()).map(|j| 2.0 * rho(j)).sum::<f64>() / total;
+            let entry = g.rcdt[k as usize] as f64 / 2f64.powi(64);
+            assert!(entry > 0.0, "k = {k}");
+            assert!(
+                (entry - tail).abs() <= 2f64.powi(-64) + tail * 1e-12,
+                "k = {k}"
+            );
+        }
+        assert_eq!(*g.rcdt.last().unwrap(), 0);
+    }
+
+    #[test]
+    fn test_tiny_tail_cut_gives_zero() {
+        let g = DiscreteGaussian::new(0.5, 1.0).unwrap();
+        assert_eq!(g.bound(), 1);
+        let mut rng = StdRng::seed_from_u64(0);
+        assert!((0..1000).all(|_| g.sample(&mut rng).abs() <= 1));
+    }
+}
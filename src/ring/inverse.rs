@@ -0,0 +1,147 @@
+//! Multiplicative inverses of ring elements.
+//!
+//! An element a of Zq[X]/(X^N + 1) is invertible exactly when it shares no
+//! factor with X^N + 1. When the NTT context is negacyclic, X^N + 1 splits
+//! into the linear factors X − ψ^(2j+1), so a is invertible iff none of its
+//! NTT slots is zero, and the inverse is computed slot by slot.
+//!
+//! For moduli without a primitive 2N-th root of unity, the inverse is found
+//! in the coefficient domain with the extended Euclidean algorithm on a and
+//! X^N + 1 over Zq[X], which requires q to be prime.
+//!
+//! # Example
+//!
+//! ```
+//! use std::sync::Arc;
+//! use lumen_math::{FieldConfig, FieldElement, NttContext, RingElement, U1024};
+//!
+//! /// q = 12289 with a primitive 1024th root of unity.
+//! #[derive(FieldConfig, Clone, Copy, Debug, Default, PartialEq, Eq)]
+//! #[modulus = "0x3001"]
+//! #[root = "0x283e"]
+//! struct Q;
+//!
+//! let ctx = Arc::new(NttContext::<Q>::new(512));
+//! let mut coeffs = vec![FieldElement::zero(); 512];
+//! coeffs[0] = FieldElement::new(U1024::from_u64(3));
+//! coeffs[1] = FieldElement::one();
+//! let a = RingElement::new(coeffs, ctx.clone());
+//!
+//! let inv = a.inverse().unwrap();
+//! assert_eq!(&a * &inv, RingElement::one(ctx));
+//! ```
+
+use super::element::{RingElement, RingElementState};
+use crate::poly::univariate::Polynomial;
+use crate::{FieldConfig, FieldElement};
+
+impl<C: FieldConfig> RingElement<C> {
+    /// Returns the multiplicative inverse, or `None` if the element is not
+    /// invertible.
+    ///
+    /// Negacyclic contexts invert the NTT slots and return NTT form; other
+    /// contexts fall back to the extended Euclidean algorithm and return
+    /// coefficient form.
+    pub fn inverse(&self) -> Option<Self> {
+        let ctx = self.context().clone();
+        if ctx.is_negacyclic() {
+            let slots = self.clone_to_ntt();
+            if slots.data().iter().any(|x| x.is_zero()) {
+                return None;
+            }
+            let inv = slots.data().iter().map(|x| x.inv()).collect();
+            Some(RingElement::from_ntt(inv, ctx))
+        } else {
+            let coeffs = match self.state() {
+                RingElementState::Coefficient => self.data().to_vec(),
+                RingElementState::Ntt => self.clone_to_coefficient().data().to_vec(),
+            };
+            inverse_euclid(coeffs).map(|inv| RingElement::new(inv, ctx))
+        }
+    }
+}
+
+/// Inverts a modulo X^n + 1 over a prime field with the extended Euclidean
+/// algorithm, tracking only the Bézout coefficient of a.
+fn inverse_euclid<C: FieldConfig>(a: Vec<FieldElement<C>>) -> Option<Vec<FieldElement<C>>> {
+    let n = a.len();
+    let mut modulus = vec![FieldElement::zero(); n + 1];
+    modulus[0] = FieldElement::one();
+    modulus[n] = FieldElement::one();
+
+    // Invariant: r_i ≡ t_i · a (mod X^n + 1).
+    let (mut r0, mut r1) = (Polynomial::new(modulus), Polynomial::new(a));
+    let (mut t0, mut t1) = (Polynomial::<C>::zero(), Polynomial::one());
+    while !r1.is_zero() {
+        let (quotient, remainder) = r0.divide_with_remainder(&r1);
+        let t2 = t0 - schoolbook_mul(&quotient, &t1);
+        (r0, r1) = (r1, remainder);
+        (t0, t1) = (t1, t2);
+    }
+
+    // gcd(a, X^n + 1) = r0 must be a nonzero constant.
+    if r0.degree() != Some(0) {
+        return None;
+    }
+    let scale = r0.coeffs[0].inv();
+    let mut inv: Vec<_> = t0.coeffs.iter().map(|c| *c * scale).collect();
+    inv.resize(n, FieldElement::zero());
+    Some(inv)
+}
+
+/// Multiplies in Zq[X] without the NTT, which needs roots of unity.
+fn schoolbook_mul<C: FieldConfig>(a: &Polynomial<C>, b: &Polynomial<C>) -> Polynomial<C> {
+    if a.is_zero() || b.is_zero() {
+        return Polynomial::zero();
+    }
+    let mut out = vec![FieldElement::zero(); a.coeffs.len() + b.coeffs.len() - 1];
+    for (i, x) in a.coeffs.iter().enumerate() {
+        for (j, y) in b.coeffs.iter().enumerate() {
+            out[i + j] = out[i + j] + *x * *y;
+        }
+    }
+    Polynomial::new(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DefaultFieldConfig, U1024};
+
+    fn field(v: u64) -> FieldElement<DefaultFieldConfig> {
+        FieldElement::new(U1024::from_u64(v))
+    }
+
+    /// Multiplies modulo X^n + 1 without the NTT.
+    fn negacyclic_mul(
+        a: &[FieldElement<DefaultFieldConfig>],
+        b: &[FieldElement<DefaultFieldConfig>],
+    ) -> Vec<FieldElement<DefaultFieldConfig>> {
+        let n = a.len();
+        let mut out = vec![FieldElement::zero(); n];
+        for (i, x) in a.iter().enumerate() {
+            for (j, y) in b.iter().enumerate() {
+                if i + j < n {
+                    out[i + j] = out[i + j] + *x * *y;
+                } else {
+                    out[i + j - n] = out[i + j - n] - *x * *y;
+                }
+            }
+        }
+        out
+    }
+
+    #[test]
+    fn test_inverse_euclid() {
+        let a: Vec<_> = (0..16).map(|i| field(i * i + 3)).collect();
+        let inv = inverse_euclid(a.clone()).unwrap();
+        let mut one = vec![FieldElement::zero(); 16];
+        one[0] = FieldElement::one();
+        assert_eq!(negacyclic_mul(&a, &inv), one);
+    }
+
+    #[test]
+    fn test_inverse_euclid_rejects_zero() {
+        assert!(inverse_euclid(vec![field(0); 4]).is_none());
+    }
+}
@@ -0,0 +1,647 @@
+//! Vectors and matrices of ring elements.
+//!
+//! Module-lattice schemes such as Kyber and Dilithium work over Rq^k and
+//! Rq^(k×l) rather than over a single polynomial. [`RingVector<C>`] and
+//! [`RingMatrix<C>`] hold ring elements that all share one
+//! `Arc<NttContext<C>>`.
+//!
+//! Products (inner products and matrix–vector products) are accumulated in
+//! NTT form: every operand is transformed at most once and the sum of
+//! pointwise products is taken directly on the NTT values, so the result is
+//! returned in NTT form. Keeping long-lived operands (e.g. a public matrix)
+//! in NTT form with [`RingMatrix::to_ntt`] avoids repeated transforms.
+//!
+//! # Example
+//!
+//! ```
+//! use std::sync::Arc;
+//! use lumen_math::{DefaultFieldConfig, NttContext, RingElement, RingMatrix, RingVector};
+//!
+//! let ctx = Arc::new(NttContext::<DefaultFieldConfig>::new(8));
+//!
+//! let a = RingMatrix::from_fn(2, 3, ctx.clone(), |_, _| RingElement::one(ctx.clone()));
+//! let s = RingVector::from_fn(3, ctx.clone(), |_| RingElement::one(ctx.clone()));
+//!
+//! let t = &a * &s;
+//! assert_eq!(t.len(), 2);
+//! assert_eq!(a.transpose().rows(), 3);
+//! ```
+
+use std::borrow::Cow;
+use std::fmt;
+use std::ops::{Add, Index, Mul, Neg, Sub};
+use std::sync::Arc;
+
+use super::element::{RingElement, RingElementState};
+use crate::poly::ntt::NttContext;
+use crate::{FieldConfig, FieldElement};
+
+fn assert_context<C: FieldConfig>(elem: &RingElement<C>, ctx: &Arc<NttContext<C>>) {
+    assert!(
+        Arc::ptr_eq(elem.context(), ctx),
+        "Ring elements must share the same NTT context"
+    );
+}
+
+fn ntt_form<C: FieldConfig>(elem: &RingElement<C>) -> Cow<'_, RingElement<C>> {
+    match elem.state() {
+        RingElementState::Ntt => Cow::Borrowed(elem),
+        RingElementState::Coefficient => Cow::Owned(elem.clone_to_ntt()),
+    }
+}
+
+/// Computes Σ aᵢ·bᵢ by accumulating pointwise products of NTT values.
+fn ntt_dot<'a, C: FieldConfig + 'a>(
+    pairs: impl Iterator<Item = (&'a RingElement<C>, &'a RingElement<C>)>,
+    ctx: &Arc<NttContext<C>>,
+) -> RingElement<C> {
+    let mut acc = vec![FieldElement::<C>::zero(); ctx.n];
+    for (a, b) in pairs {
+        let (a, b) = (ntt_form(a), ntt_form(b));
+        for ((s, x), y) in acc.iter_mut().zip(a.ntt_values()).zip(b.ntt_values()) {
+            *s = *s + *x * *y;
+        }
+    }
+    RingElement::from_ntt(acc, ctx.clone())
+}
+
+// =============================================================================
+// RingVector
+// =============================================================================
+
+/// A vector of ring elements in Rq^k.
+///
+/// All elements share the vector's NTT context.
+#[derive(Clone)]
+pub struct RingVector<C: FieldConfig> {
+    elements: Vec<RingElement<C>>,
+    ntt_ctx: Arc<NttContext<C>>,
+}
+
+impl<C: FieldConfig> RingVector<C> {
+    /// Creates a vector from its elements.
+    ///
+    /// # Panics
+    /// Panics if any element does not use `ctx`.
+    pub fn new(elements: Vec<RingElement<C>>, ctx: Arc<NttContext<C>>) -> Self {
+        for elem in &elements {
+            assert_context(elem, &ctx);
+        }
+        Self {
+            elements,
+            ntt_ctx: ctx,
+        }
+    }
+
+    /// Creates a vector of length `len` whose i-th element is `f(i)`.
+    ///
+    /// # Panics
+    /// Panics if any produced element does not use `ctx`.
+    pub fn from_fn<F>(len: usize, ctx: Arc<NttContext<C>>, f: F) -> Self
+    where
+        F: FnMut(usize) -> RingElement<C>,
+    {
+        Self::new((0..len).map(f).collect(), ctx)
+    }
+
+    /// Creates the zero vector of length `len`.
+    pub fn zero(len: usize, ctx: Arc<NttContext<C>>) -> Self {
+        Self {
+            elements: (0..len).map(|_| RingElement::zero(ctx.clone())).collect(),
+            ntt_ctx: ctx,
+        }
+    }
+
+    /// Returns the number of elements.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.elements.len()
+    }
+
+    /// Returns `true` if the vector has no elements.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.elements.is_empty()
+    }
+
+    /// Returns a reference to the shared NTT context.
+    #[inline]
+    pub fn context(&self) -> &Arc<NttContext<C>> {
+        &self.ntt_ctx
+    }
+
+    /// Returns the elements as a slice.
+    #[inline]
+    pub fn elements(&self) -> &[RingElement<C>] {
+        &self.elements
+    }
+
+    /// Consumes the vector and returns its elements.
+    pub fn into_elements(self) -> Vec<RingElement<C>> {
+        self.elements
+    }
+
+    /// Returns an iterator over the elements.
+    pub fn iter(&self) -> std::slice::Iter<'_, RingElement<C>> {
+        self.elements.iter()
+    }
+
+    /// Converts every element to NTT form in-place.
+    pub fn to_ntt(&mut self) {
+        self.elements.iter_mut().for_each(RingElement::to_ntt);
+    }
+
+    /// Converts every element to coefficient form in-place.
+    pub fn to_coefficient(&mut self) {
+        self.elements
+            .iter_mut()
+            .for_each(RingElement::to_coefficient);
+    }
+
+    /// Consumes self and returns the vector with every element in NTT form.
+    pub fn into_ntt(mut self) -> Self {
+        self.to_ntt();
+        self
+    }
+
+    /// Consumes self and returns the vector with every element in coefficient form.
+    pub fn into_coefficient(mut self) -> Self {
+        self.to_coefficient();
+        self
+    }
+
+    /// Computes the inner product ⟨self, other⟩ = Σ selfᵢ·otherᵢ.
+    ///
+    /// The result is in NTT form.
+    ///
+    /// # Panics
+    /// Panics if the lengths or NTT contexts differ.
+    pub fn inner_product(&self, other: &Self) -> RingElement<C> {
+        self.check_compatible(other);
+        ntt_dot(
+            self.elements.iter().zip(other.elements.iter()),
+            &self.ntt_ctx,
+        )
+    }
+
+    fn check_compatible(&self, other: &Self) {
+        assert!(
+            Arc::ptr_eq(&self.ntt_ctx, &other.ntt_ctx),
+            "Ring vectors must share the same NTT context"
+        );
+        assert_eq!(self.len(), other.len(), "Ring vector lengths must match");
+    }
+
+    fn zip_with<F>(&self, other: &Self, f: F) -> Self
+    where
+        F: Fn(&RingElement<C>, &RingElement<C>) -> RingElement<C>,
+    {
+        self.check_compatible(other);
+        Self {
+            elements: self
+                .elements
+                .iter()
+                .zip(other.elements.iter())
+                .map(|(a, b)| f(a, b))
+                .collect(),
+            ntt_ctx: self.ntt_ctx.clone(),
+        }
+    }
+}
+
+impl<C: FieldConfig> Index<usize> for RingVector<C> {
+    type Output = RingElement<C>;
+
+    fn index(&self, index: usize) -> &RingElement<C> {
+        &self.elements[index]
+    }
+}
+
+impl<'a, C: FieldConfig> IntoIterator for &'a RingVector<C> {
+    type Item = &'a RingElement<C>;
+    type IntoIter = std::slice::Iter<'a, RingElement<C>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.elements.iter()
+    }
+}
+
+impl<C: FieldConfig> Add for RingVector<C> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        &self + &rhs
+    }
+}
+
+impl<'a, C: FieldConfig> Add<&'a RingVector<C>> for &'a RingVector<C> {
+    type Output = RingVector<C>;
+
+    fn add(self, rhs: &'a RingVector<C>) -> RingVector<C> {
+        self.zip_with(rhs, |a, b| a + b)
+    }
+}
+
+impl<C: FieldConfig> Sub for RingVector<C> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        &self - &rhs
+    }
+}
+
+impl<'a, C: FieldConfig> Sub<&'a RingVector<C>> for &'a RingVector<C> {
+    type Output = RingVector<C>;
+
+    fn sub(self, rhs: &'a RingVector<C>) -> RingVector<C> {
+        self.zip_with(rhs, |a, b| a - b)
+    }
+}
+
+impl<C: FieldConfig> Neg for RingVector<C> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        RingVector {
+            elements: self.elements.into_iter().map(|e| -e).collect(),
+            ntt_ctx: self.ntt_ctx,
+        }
+    }
+}
+
+impl<C: FieldConfig> Neg for &RingVector<C> {
+    type Output = RingVector<C>;
+
+    fn neg(self) -> RingVector<C> {
+        RingVector {
+            elements: self.elements.iter().map(|e| -e).collect(),
+            ntt_ctx: self.ntt_ctx.clone(),
+        }
+    }
+}
+
+impl<C: FieldConfig> PartialEq for RingVector<C> {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.ntt_ctx, &other.ntt_ctx) && self.elements == other.elements
+    }
+}
+
+impl<C: FieldConfig> Eq for RingVector<C> {}
+
+impl<C: FieldConfig> fmt::Debug for RingVector<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "RingVector {{ len: {}, n: {} }}",
+            self.len(),
+            self.ntt_ctx.n
+        )
+    }
+}
+
+// =============================================================================
+// RingMatrix
+// =============================================================================
+
+/// A matrix of ring elements in Rq^(rows×cols), stored in row-major order.
+///
+/// All entries share the matrix's NTT context.
+#[derive(Clone)]
+pub struct RingMatrix<C: FieldConfig> {
+    entries: Vec<RingElement<C>>,
+    rows: usize,
+    cols: usize,
+    ntt_ctx: Arc<NttContext<C>>,
+}
+
+impl<C: FieldConfig> RingMatrix<C> {
+    /// Creates a matrix from its rows.
+    ///
+    /// # Panics
+    /// Panics if the rows have different lengths or any row does not use `ctx`.
+    pub fn from_rows(rows: Vec<RingVector<C>>, ctx: Arc<NttContext<C>>) -> Self {
+        let cols = rows.first().map_or(0, RingVector::len);
+        let num_rows = rows.len();
+        let mut entries = Vec::with_capacity(num_rows * cols);
+        for row in rows {
+            assert!(
+                Arc::ptr_eq(row.context(), &ctx),
+                "Ring elements must share the same NTT context"
+            );
+            assert_eq!(row.len(), cols, "All rows must have the same length");
+            entries.extend(row.into_elements());
+        }
+        Self {
+            entries,
+            rows: num_rows,
+            cols,
+            ntt_ctx: ctx,
+        }
+    }
+
+    /// Creates a `rows × cols` matrix whose (i, j) entry is `f(i, j)`.
+    ///
+    /// # Panics
+    /// Panics if any produced entry does not use `ctx`.
+    pub fn from_fn<F>(rows: usize, cols: usize, ctx: Arc<NttContext<C>>, mut f: F) -> Self
+    where
+        F: FnMut(usize, usize) -> RingElement<C>,
+    {
+        let mut entries = Vec::with_capacity(rows * cols);
+        for i in 0..rows {
+            for j in 0..cols {
+                let elem = f(i, j);
+                assert_context(&elem, &ctx);
+                entries.push(elem);
+            }
+        }
+        Self {
+            entries,
+            rows,
+            cols,
+            ntt_ctx: ctx,
+        }
+    }
+
+    /// Creates the `rows × cols` zero matrix.
+    pub fn zero(rows: usize, cols: usize, ctx: Arc<NttContext<C>>) -> Self {
+        Self {
+            entries: (0..rows * cols)
+                .map(|_| RingElement::zero(ctx.clone()))
+                .collect(),
+            rows,
+            cols,
+            ntt_ctx: ctx,
+        }
+    }
+
+    /// Returns the number of rows.
+    #[inline]
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Returns the number of columns.
+    #[inline]
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// Returns a reference to the shared NTT context.
+    #[inline]
+    pub fn context(&self) -> &Arc<NttContext<C>> {
+        &self.ntt_ctx
+    }
+
+    /// Returns the (i, j) entry.
+    ///
+    /// # Panics
+    /// Panics if the index is out of bounds.
+    pub fn get(&self, i: usize, j: usize) -> &RingElement<C> {
+        assert!(i < self.rows && j < self.cols, "Matrix index out of bounds");
+        &self.entries[i * self.cols + j]
+    }
+
+    /// Returns row `i` as a slice.
+    pub fn row(&self, i: usize) -> &[RingElement<C>] {
+        &self.entries[i * self.cols..(i + 1) * self.cols]
+    }
+
+    /// Returns the transpose.
+    pub fn transpose(&self) -> Self {
+        Self::from_fn(self.cols, self.rows, self.ntt_ctx.clone(), |i, j| {
+            self.get(j, i).clone()
+        })
+    }
+
+    /// Converts every entry to NTT form in-place.
+    pub fn to_ntt(&mut self) {
+        self.entries.iter_mut().for_each(RingElement::to_ntt);
+    }
+
+    /// Converts every entry to coefficient form in-place.
+    pub fn to_coefficient(&mut self) {
+        self.entries
+            .iter_mut()
+            .for_each(RingElement::to_coefficient);
+    }
+
+    /// Consumes self and returns the matrix with every entry in NTT form.
+    pub fn into_ntt(mut self) -> Self {
+        self.to_ntt();
+        self
+    }
+
+    /// Consumes self and returns the matrix with every entry in coefficient form.
+    pub fn into_coefficient(mut self) -> Self {
+        self.to_coefficient();
+        self
+    }
+
+    /// Computes the matrix–vector product self · v.
+    ///
+    /// `v` is transformed once and every row is accumulated in NTT form, so
+    /// the resulting elements are in NTT form.
+    ///
+    /// # Panics
+    /// Panics if `v.len() != self.cols()` or the NTT contexts differ.
+    pub fn mul_vector(&self, v: &RingVector<C>) -> RingVector<C> {
+        assert!(
+            Arc::ptr_eq(&self.ntt_ctx, v.context()),
+            "Ring matrix and vector must share the same NTT context"
+        );
+        assert_eq!(v.len(), self.cols, "Vector length must match column count");
+
+        let v_ntt: Vec<_> = v.iter().map(ntt_form).collect();
+        let elements = (0..self.rows)
+            .map(|i| {
+                ntt_dot(
+                    self.row(i).iter().zip(v_ntt.iter().map(|e| &**e)),
+                    &self.ntt_ctx,
+                )
+            })
+            .collect();
+
+        RingVector {
+            elements,
+            ntt_ctx: self.ntt_ctx.clone(),
+        }
+    }
+
+    fn zip_with<F>(&self, other: &Self, f: F) -> Self
+    where
+        F: Fn(&RingElement<C>, &RingElement<C>) -> RingElement<C>,
+    {
+        assert!(
+            Arc::ptr_eq(&self.ntt_ctx, &other.ntt_ctx),
+            "Ring matrices must share the same NTT context"
+        );
+        assert!(
+            self.rows == other.rows && self.cols == other.cols,
+            "Ring matrix dimensions must match"
+        );
+        Self {
+            entries: self
+                .entries
+                .iter()
+                .zip(other.entries.iter())
+                .map(|(a, b)| f(a, b))
+                .collect(),
+            rows: self.rows,
+            cols: self.cols,
+            ntt_ctx: self.ntt_ctx.clone(),
+        }
+    }
+}
+
+impl<C: FieldConfig> Index<(usize, usize)> for RingMatrix<C> {
+    type Output = RingElement<C>;
+
+    fn index(&self, (i, j): (usize, usize)) -> &RingElement<C> {
+        self.get(i, j)
+    }
+}
+
+impl<C: FieldConfig> Add for RingMatrix<C> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        &self + &rhs
+    }
+}
+
+impl<'a, C: FieldConfig> Add<&'a RingMatrix<C>> for &'a RingMatrix<C> {
+    type Output = RingMatrix<C>;
+
+    fn add(self, rhs: &'a RingMatrix<C>) -> RingMatrix<C> {
+        self.zip_with(rhs, |a, b| a + b)
+    }
+}
+
+impl<C: FieldConfig> Sub for RingMatrix<C> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        &self - &rhs
+    }
+}
+
+impl<'a, C: FieldConfig> Sub<&'a RingMatrix<C>> for &'a RingMatrix<C> {
+    type Output = RingMatrix<C>;
+
+    fn sub(self, rhs: &'a RingMatrix<C>) -> RingMatrix<C> {
+        self.zip_with(rhs, |a, b| a - b)
+    }
+}
+
+impl<C: FieldConfig> Mul<RingVector<C>> for RingMatrix<C> {
+    type Output = RingVector<C>;
+
+    fn mul(self, rhs: RingVector<C>) -> RingVector<C> {
+        self.mul_vector(&rhs)
+    }
+}
+
+impl<'a, C: FieldConfig> Mul<&'a RingVector<C>> for &'a RingMatrix<C> {
+    type Output = RingVector<C>;
+
+    fn mul(self, rhs: &'a RingVector<C>) -> RingVector<C> {
+        self.mul_vector(rhs)
+    }
+}
+
+impl<C: FieldConfig> PartialEq for RingMatrix<C> {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.ntt_ctx, &other.ntt_ctx)
+            && self.rows == other.rows
+            && self.cols == other.cols
+            && self.entries == other.entries
+    }
+}
+
+impl<C: FieldConfig> Eq for RingMatrix<C> {}
+
+impl<C: FieldConfig> fmt::Debug for RingMatrix<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "RingMatrix {{ rows: {}, cols: {}, n: {} }}",
+            self.rows, self.cols, self.ntt_ctx.n
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::field::config::DefaultFieldConfig;
+    use crate::fp;
+
+    fn make_ctx() -> Arc<NttContext<DefaultFieldConfig>> {
+        Arc::new(NttContext::new(8))
+    }
+
+    fn poly(
+        ctx: &Arc<NttContext<DefaultFieldConfig>>,
+        seed: u64,
+    ) -> RingElement<DefaultFieldConfig> {
+        let coeffs = (0..8).map(|i| fp!(seed * 31 + i * 7 + 1)).collect();
+        RingElement::new(coeffs, ctx.clone())
+    }
+
+    #[test]
+    fn test_inner_product_matches_elementwise() {
+        let ctx = make_ctx();
+        let a = RingVector::from_fn(3, ctx.clone(), |i| poly(&ctx, i as u64));
+        let b = RingVector::from_fn(3, ctx.clone(), |i| poly(&ctx, 10 + i as u64));
+
+        let expected = (0..3).fold(RingElement::zero(ctx.clone()), |acc, i| {
+            &acc + &(&a[i] * &b[i])
+        });
+        let ip = a.inner_product(&b);
+        assert_eq!(ip.state(), RingElementState::Ntt);
+        assert_eq!(ip, expected);
+    }
+
+    #[test]
+    fn test_empty_inner_product_is_zero() {
+        let ctx = make_ctx();
+        let a = RingVector::zero(0, ctx.clone());
+        assert!(a.is_empty());
+        assert!(a.inner_product(&a).is_zero());
+    }
+
+    #[test]
+    fn test_from_rows_shape() {
+        let ctx = make_ctx();
+        let rows = (0..2)
+            .map(|i| RingVector::from_fn(3, ctx.clone(), |j| poly(&ctx, (3 * i + j) as u64)))
+            .collect();
+        let m = RingMatrix::from_rows(rows, ctx.clone());
+        assert_eq!((m.rows(), m.cols()), (2, 3));
+        assert_eq!(m[(1, 2)], poly(&ctx, 5));
+        assert_eq!(m.row(1)[0], poly(&ctx, 3));
+    }
+
+    #[test]
+    #[should_panic(expected = "All rows must have the same length")]
+    fn test_from_rows_ragged_panics() {
+        let ctx = make_ctx();
+        let rows = vec![
+            RingVector::zero(2, ctx.clone()),
+            RingVector::zero(3, ctx.clone()),
+        ];
+        let _ = RingMatrix::from_rows(rows, ctx);
+    }
+
+    #[test]
+    #[should_panic(expected = "Ring elements must share the same NTT context")]
+    fn test_mixed_contexts_panic() {
+        let ctx = make_ctx();
+        let other = make_ctx();
+        let _ = RingVector::new(
+            vec![RingElement::one(ctx.clone()), RingElement::one(other)],
+            ctx,
+        );
+    }
+}
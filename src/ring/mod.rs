@@ -1,8 +1,29 @@
 //! Ring module for lattice-based cryptography.
 //!
 //! This module provides ring elements for working with polynomials in
-//! Rq = Zq[X]/(X^N + 1).
+//! Rq = Zq[X]/(X^N + 1), vectors and matrices of them for module lattices,
+//! gadget decomposition and key switching, and RNS representations over
+//! chains of word-sized primes.
 
+pub mod automorphism;
 pub mod element;
+pub mod encoding;
+pub mod gadget;
+pub mod gaussian;
+pub mod inverse;
+pub mod matrix;
+pub mod norm;
+pub mod rns;
+pub mod sampling;
 
 pub use element::{RingElement, RingElementState};
+pub use encoding::{RingEncodingError, packed_len};
+pub use gadget::{KeySwitchingKey, gadget_vector, key_switch};
+pub use gaussian::{DiscreteGaussian, GaussianError};
+pub use matrix::{RingMatrix, RingVector};
+pub use norm::{check_norm_bound_small, infinity_norm_small, l2_norm_squared_small};
+pub use rns::{RnsBasis, RnsError, RnsRingElement};
+pub use sampling::{
+    SmallField, sample_bounded, sample_bounded_small, sample_cbd, sample_cbd_small, sample_uniform,
+    sample_uniform_small,
+};
@@ -0,0 +1,148 @@
+//! Norms of centered coefficients.
+//!
+//! Lattice schemes measure the size of a polynomial through its coefficients'
+//! centered representatives in (-q/2, q/2]:
+//!
+//! - ‖a‖∞ = maxᵢ |aᵢ|, used by rejection sampling (e.g. ML-DSA's
+//!   ‖z‖∞ < γ1 - β check).
+//! - ‖a‖₂² = Σᵢ aᵢ², used in decryption-failure and Gaussian-width analysis.
+//!
+//! The norms are computed in constant time: every coefficient is visited,
+//! and centering, maxima and bound checks use masking and conditional
+//! selection instead of branches, so secret coefficients can be checked
+//! without leaking which one exceeds the bound.
+//!
+//! The same functions are available for `[KyberFieldElement]` and
+//! `[DilithiumFieldElement]` slices through the [`SmallField`] trait.
+//!
+//! # Example
+//!
+//! ```
+//! use lumen_math::DilithiumFieldElement;
+//! use lumen_math::ring::norm::{check_norm_bound_small, infinity_norm_small};
+//!
+//! let z = [DilithiumFieldElement::new(5), -DilithiumFieldElement::new(9)];
+//! assert_eq!(infinity_norm_small(&z), 9);
+//! assert!(check_norm_bound_small(&z, 10));
+//! assert!(!check_norm_bound_small(&z, 9));
+//! ```
+
+use super::element::RingElement;
+use super::sampling::SmallField;
+use crate::poly::ntt::small::centered_i64;
+use crate::{BigInt, FieldConfig, U1024};
+
+impl<C: FieldConfig> RingElement<C> {
+    /// Returns ‖self‖∞, the largest absolute centered coefficient.
+    pub fn infinity_norm(&self) -> U1024 {
+        let elem = self.clone_to_coefficient();
+        let mut max = U1024::ZERO;
+        for c in elem.coefficients() {
+            let (m, _) = c.centered_parts();
+            let (_, smaller) = max.borrowing_sub(&m);
+            max = U1024::conditional_select(&m, &max, smaller);
+        }
+        max
+    }
+
+    /// Returns ‖self‖₂², the sum of squared centered coefficients.
+    ///
+    /// # Panics
+    /// Panics if the sum can exceed 1024 bits, i.e. unless
+    /// n · (q/2)² < 2^1024. This depends only on the public parameters.
+    pub fn l2_norm_squared(&self) -> U1024 {
+        let q_bits = C::MODULUS.bits();
+        let n_bits = self.degree().next_power_of_two().trailing_zeros() as usize;
+        assert!(
+            2 * (q_bits - 1) + n_bits <= 1024,
+            "Squared L2 norm may overflow 1024 bits for this modulus and degree"
+        );
+
+        let elem = self.clone_to_coefficient();
+        elem.coefficients().iter().fold(U1024::ZERO, |acc, c| {
+            let (m, _) = c.centered_parts();
+            acc + m * m
+        })
+    }
+
+    /// Returns `true` if every centered coefficient satisfies |aᵢ| < `bound`,
+    /// i.e. ‖self‖∞ < `bound`.
+    pub fn check_norm_bound(&self, bound: U1024) -> bool {
+        let elem = self.clone_to_coefficient();
+        let mut within = true;
+        for c in elem.coefficients() {
+            let (m, _) = c.centered_parts();
+            let (_, below) = m.borrowing_sub(&bound);
+            within &= below;
+        }
+        within
+    }
+}
+
+/// |x| for the centered representative of x, without branching on x.
+#[inline]
+fn centered_abs<F: SmallField>(x: F) -> u32 {
+    let c = centered_i64(x.to_reduced(), F::MODULUS);
+    let sign = c >> 63;
+    ((c ^ sign) - sign) as u32
+}
+
+/// Returns ‖coeffs‖∞ over centered representatives.
+pub fn infinity_norm_small<F: SmallField>(coeffs: &[F]) -> u32 {
+    coeffs.iter().fold(0, |max, &c| {
+        let a = centered_abs(c);
+        // All ones when a > max.
+        let mask = ((max as i64 - a as i64) >> 63) as u32;
+        max ^ ((max ^ a) & mask)
+    })
+}
+
+/// Returns ‖coeffs‖₂² over centered representatives.
+pub fn l2_norm_squared_small<F: SmallField>(coeffs: &[F]) -> u64 {
+    coeffs
+        .iter()
+        .map(|&c| {
+            let a = centered_abs(c) as u64;
+            a * a
+        })
+        .sum()
+}
+
+/// Returns `true` if ‖coeffs‖∞ < `bound`.
+pub fn check_norm_bound_small<F: SmallField>(coeffs: &[F], bound: u32) -> bool {
+    let exceeded = coeffs.iter().fold(0u64, |acc, &c| {
+        // 1 when |c| ≥ bound.
+        acc | (((bound as i64 - 1 - centered_abs(c) as i64) >> 63) & 1) as u64
+    });
+    exceeded == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::poly::ntt::small::{DILITHIUM_Q, DilithiumFieldElement, KYBER_Q, KyberFieldElement};
+
+    #[test]
+    fn test_centered_abs_boundaries() {
+        let half = (KYBER_Q - 1) / 2;
+        assert_eq!(centered_abs(KyberFieldElement::new(half)), half);
+        assert_eq!(centered_abs(KyberFieldElement::new(half + 1)), half);
+        assert_eq!(centered_abs(KyberFieldElement::new(KYBER_Q - 1)), 1);
+        assert_eq!(centered_abs(DilithiumFieldElement::new(DILITHIUM_Q - 7)), 7);
+        assert_eq!(centered_abs(DilithiumFieldElement::zero()), 0);
+    }
+
+    #[test]
+    fn test_small_norms() {
+        let p = [
+            KyberFieldElement::new(3),
+            -KyberFieldElement::new(4),
+            KyberFieldElement::zero(),
+        ];
+        assert_eq!(infinity_norm_small(&p), 4);
+        assert_eq!(l2_norm_squared_small(&p), 25);
+        assert!(check_norm_bound_small(&p, 5));
+        assert!(!check_norm_bound_small(&p, 4));
+        assert!(check_norm_bound_small::<KyberFieldElement>(&[], 0));
+    }
+}
@@ -0,0 +1,1135 @@
+//! Residue Number System (RNS) ring elements.
+//!
+//! A large modulus Q = q₀ · q₁ ⋯ q_(L-1) is represented by its word-sized
+//! prime factors: an element of Z_Q[X]/(X^N + 1) is stored as one limb of
+//! `u64` residues per prime. Every prime satisfies qᵢ ≡ 1 (mod 2N), so each
+//! limb has its own negacyclic NTT and ring multiplication is a per-limb
+//! pointwise product on native integers, instead of 1024-bit Montgomery
+//! arithmetic on [`RingElement<DefaultFieldConfig>`](crate::DefaultFieldConfig).
+//!
+//! # Levels
+//!
+//! An [`RnsBasis`] fixes the degree and the full modulus chain. An element
+//! at *level* ℓ uses the first ℓ primes, i.e. it lives modulo
+//! Q_ℓ = q₀ ⋯ q_(ℓ-1). [`RnsRingElement::rescale`] divides by the last
+//! prime and drops it, which is the rescaling of CKKS and the modulus
+//! switching of BGV/BFV.
+//!
+//! # Conversions
+//!
+//! - CRT reconstruction to integers in [0, Q_ℓ) uses
+//!   [`CrtBasis`](crate::protocol::CrtBasis), precomputed once per level.
+//! - [`RnsRingElement::fast_base_conversion`] maps residues to another basis
+//!   without reconstruction, up to a small multiple of Q_ℓ.
+//! - Conversion to and from [`RingElement<C>`] goes through centered
+//!   representatives, so small signed coefficients are preserved in both
+//!   directions.
+//!
+//! # Example
+//!
+//! ```
+//! use std::sync::Arc;
+//! use lumen_math::ring::rns::{RnsBasis, RnsRingElement};
+//!
+//! // Three 50-bit primes ≡ 1 (mod 2 · 16)
+//! let basis = Arc::new(RnsBasis::generate(16, 50, 3).unwrap());
+//!
+//! let mut a = vec![0i64; 16];
+//! a[1] = 3; // 3X
+//! let mut b = vec![0i64; 16];
+//! b[15] = -2; // -2X^15
+//!
+//! let a = RnsRingElement::from_signed(&a, basis.clone(), 3);
+//! let b = RnsRingElement::from_signed(&b, basis, 3);
+//!
+//! // 3X · (-2X^15) = -6X^16 = 6 in Z_Q[X]/(X^16 + 1)
+//! let c = (&a * &b).to_centered_coefficients();
+//! assert_eq!(c[0], lumen_math::I1024::from(6i64));
+//! ```
+
+use std::fmt;
+use std::ops::{Add, Mul, Neg, Sub};
+use std::sync::Arc;
+
+use super::element::{RingElement, RingElementState};
+use crate::poly::ntt::NttContext;
+use crate::protocol::crt::{CrtBasis, CrtError};
+use crate::{FieldConfig, I1024, U1024};
+
+/// Largest supported prime size in bits, so that sums of two residues fit
+/// in a `u64` without overflow.
+pub const MAX_PRIME_BITS: u32 = 62;
+
+/// Error type for RNS basis construction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RnsError {
+    /// The ring degree is not a power of two.
+    InvalidDegree { n: usize },
+    /// No primes were given.
+    EmptyBasis,
+    /// A prime has more than [`MAX_PRIME_BITS`] bits.
+    PrimeTooLarge { prime: u64 },
+    /// A modulus is not prime.
+    NotPrime { prime: u64 },
+    /// A prime is not ≡ 1 (mod 2N), so it has no negacyclic NTT.
+    NotNttFriendly { prime: u64 },
+    /// A prime appears more than once.
+    DuplicatePrime { prime: u64 },
+    /// The product of the primes does not fit in 1024 bits.
+    ProductOverflow,
+    /// Fewer NTT-friendly primes of the requested size exist than requested.
+    NotEnoughPrimes { found: usize, requested: usize },
+    /// A requested prime size is zero or exceeds [`MAX_PRIME_BITS`].
+    InvalidPrimeBits { bits: u32 },
+}
+
+impl fmt::Display for RnsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RnsError::InvalidDegree { n } => {
+                write!(f, "Ring degree {} is not a power of two", n)
+            }
+            RnsError::EmptyBasis => write!(f, "RNS basis must contain at least one prime"),
+            RnsError::PrimeTooLarge { prime } => {
+                write!(f, "Prime {} exceeds {} bits", prime, MAX_PRIME_BITS)
+            }
+            RnsError::NotPrime { prime } => write!(f, "{} is not prime", prime),
+            RnsError::NotNttFriendly { prime } => {
+                write!(f, "Prime {} is not congruent to 1 modulo 2N", prime)
+            }
+            RnsError::DuplicatePrime { prime } => {
+                write!(f, "Prime {} appears more than once", prime)
+            }
+            RnsError::ProductOverflow => write!(f, "Product of primes overflows 1024 bits"),
+            RnsError::NotEnoughPrimes { found, requested } => {
+                write!(
+                    f,
+                    "Found {} suitable primes, {} requested",
+                    found, requested
+                )
+            }
+            RnsError::InvalidPrimeBits { bits } => {
+                write!(
+                    f,
+                    "Prime size of {} bits is outside 1..={}",
+                    bits, MAX_PRIME_BITS
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for RnsError {}
+
+// =============================================================================
+// Word-sized modular arithmetic
+// =============================================================================
+
+#[inline]
+fn add_mod(a: u64, b: u64, q: u64) -> u64 {
+    let s = a + b;
+    if s >= q { s - q } else { s }
+}
+
+#[inline]
+fn sub_mod(a: u64, b: u64, q: u64) -> u64 {
+    if a >= b { a - b } else { a + q - b }
+}
+
+#[inline]
+fn mul_mod(a: u64, b: u64, q: u64) -> u64 {
+    ((a as u128 * b as u128) % q as u128) as u64
+}
+
+fn pow_mod(mut base: u64, mut exp: u64, q: u64) -> u64 {
+    let mut result = 1 % q;
+    base %= q;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = mul_mod(result, base, q);
+        }
+        base = mul_mod(base, base, q);
+        exp >>= 1;
+    }
+    result
+}
+
+/// Inverse modulo a prime q, by Fermat's little theorem.
+fn inv_mod(a: u64, q: u64) -> u64 {
+    pow_mod(a, q - 2, q)
+}
+
+/// Reduces a `U1024` modulo a word-sized q.
+fn reduce_u1024(x: &U1024, q: u64) -> u64 {
+    x.0.iter().rev().fold(0u64, |r, &limb| {
+        ((((r as u128) << 64) | limb as u128) % q as u128) as u64
+    })
+}
+
+/// Deterministic Miller–Rabin test, exact for all 64-bit inputs.
+fn is_prime(n: u64) -> bool {
+    const BASES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+    if n < 2 {
+        return false;
+    }
+    for p in BASES {
+        if n.is_multiple_of(p) {
+            return n == p;
+        }
+    }
+
+    let s = (n - 1).trailing_zeros();
+    let d = (n - 1) >> s;
+    'witness: for a in BASES {
+        let mut x = pow_mod(a, d, n);
+        if x == 1 || x == n - 1 {
+            continue;
+        }
+        for _ in 1..s {
+            x = mul_mod(x, x, n);
+            if x == n - 1 {
+                continue 'witness;
+            }
+        }
+        return false;
+    }
+    true
+}
+
+fn bit_reverse(a: &mut [u64]) {
+    let n = a.len();
+    let mut j = 0;
+    for i in 1..n {
+        let mut bit = n >> 1;
+        while j & bit != 0 {
+            j ^= bit;
+            bit >>= 1;
+        }
+        j |= bit;
+        if i < j {
+            a.swap(i, j);
+        }
+    }
+}
+
+// =============================================================================
+// Per-prime NTT
+// =============================================================================
+
+/// Negacyclic NTT tables for one prime.
+///
+/// Uses the same slot order as [`NttContext`]: slot j holds a(ψ^(2j+1)).
+#[derive(Clone, Debug)]
+struct PrimeNtt {
+    q: u64,
+    /// ψ^i for i < n
+    psi_powers: Vec<u64>,
+    /// ψ^(-i) · n^(-1) for i < n
+    psi_inv_powers: Vec<u64>,
+    /// ω^k for k < n/2, with ω = ψ²
+    omega_powers: Vec<u64>,
+    /// ω^(-k) for k < n/2
+    omega_inv_powers: Vec<u64>,
+}
+
+impl PrimeNtt {
+    fn new(q: u64, n: usize) -> Self {
+        let two_n = 2 * n as u64;
+        // ψ = g^((q-1)/2n) is a primitive 2n-th root exactly when ψ^n = -1.
+        let psi = (2..q)
+            .map(|g| pow_mod(g, (q - 1) / two_n, q))
+            .find(|&psi| pow_mod(psi, n as u64, q) == q - 1)
+            .expect("Prime is NTT-friendly, so a primitive 2N-th root exists");
+        let psi_inv = inv_mod(psi, q);
+        let n_inv = inv_mod(n as u64 % q, q);
+        let omega = mul_mod(psi, psi, q);
+        let omega_inv = mul_mod(psi_inv, psi_inv, q);
+
+        let powers = |base: u64, count: usize, scale: u64| {
+            let mut out = Vec::with_capacity(count);
+            let mut cur = scale;
+            for _ in 0..count {
+                out.push(cur);
+                cur = mul_mod(cur, base, q);
+            }
+            out
+        };
+
+        Self {
+            q,
+            psi_powers: powers(psi, n, 1),
+            psi_inv_powers: powers(psi_inv, n, n_inv),
+            omega_powers: powers(omega, n / 2, 1),
+            omega_inv_powers: powers(omega_inv, n / 2, 1),
+        }
+    }
+
+    fn cyclic(&self, a: &mut [u64], twiddles: &[u64]) {
+        let n = a.len();
+        let q = self.q;
+        bit_reverse(a);
+        let mut len = 2;
+        while len <= n {
+            let half = len / 2;
+            let step = n / len;
+            for start in (0..n).step_by(len) {
+                for j in 0..half {
+                    let u = a[start + j];
+                    let v = mul_mod(a[start + j + half], twiddles[j * step], q);
+                    a[start + j] = add_mod(u, v, q);
+                    a[start + j + half] = sub_mod(u, v, q);
+                }
+            }
+            len <<= 1;
+        }
+    }
+
+    fn forward(&self, a: &mut [u64]) {
+        for (x, p) in a.iter_mut().zip(&self.psi_powers) {
+            *x = mul_mod(*x, *p, self.q);
+        }
+        self.cyclic(a, &self.omega_powers);
+    }
+
+    fn inverse(&self, a: &mut [u64]) {
+        self.cyclic(a, &self.omega_inv_powers);
+        for (x, p) in a.iter_mut().zip(&self.psi_inv_powers) {
+            *x = mul_mod(*x, *p, self.q);
+        }
+    }
+}
+
+// =============================================================================
+// RnsBasis
+// =============================================================================
+
+/// A chain of NTT-friendly primes for a fixed ring degree N.
+///
+/// Holds the NTT tables for every prime and the CRT data for every level.
+/// Share it between elements through an `Arc`.
+#[derive(Clone, Debug)]
+pub struct RnsBasis {
+    n: usize,
+    moduli: Vec<u64>,
+    ntts: Vec<PrimeNtt>,
+    /// `crt[l - 1]` reconstructs modulo the first `l` primes.
+    crt: Vec<CrtBasis>,
+}
+
+impl RnsBasis {
+    /// Creates a basis for degree `n` from the given primes, in chain order.
+    ///
+    /// # Errors
+    /// Returns an [`RnsError`] if `n` is not a power of two, or if a prime
+    /// is too large, not prime, not ≡ 1 (mod 2n) or repeated, or if the
+    /// product overflows 1024 bits.
+    pub fn new(n: usize, primes: &[u64]) -> Result<Self, RnsError> {
+        if !n.is_power_of_two() {
+            return Err(RnsError::InvalidDegree { n });
+        }
+        if primes.is_empty() {
+            return Err(RnsError::EmptyBasis);
+        }
+        for (i, &q) in primes.iter().enumerate() {
+            if 64 - q.leading_zeros() > MAX_PRIME_BITS {
+                return Err(RnsError::PrimeTooLarge { prime: q });
+            }
+            if !is_prime(q) {
+                return Err(RnsError::NotPrime { prime: q });
+            }
+            if q % (2 * n as u64) != 1 {
+                return Err(RnsError::NotNttFriendly { prime: q });
+            }
+            if primes[..i].contains(&q) {
+                return Err(RnsError::DuplicatePrime { prime: q });
+            }
+        }
+
+        let wide: Vec<U1024> = primes.iter().map(|&q| U1024::from_u64(q)).collect();
+        let crt = (1..=primes.len())
+            .map(|l| CrtBasis::new(&wide[..l]))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| match e {
+                CrtError::ProductOverflow => RnsError::ProductOverflow,
+                // Distinct primes are always coprime.
+                _ => unreachable!("distinct primes are pairwise coprime"),
+            })?;
+
+        Ok(Self {
+            n,
+            moduli: primes.to_vec(),
+            ntts: primes.iter().map(|&q| PrimeNtt::new(q, n)).collect(),
+            crt,
+        })
+    }
+
+    /// Creates a basis from the `count` largest primes below 2^`bits` that
+    /// are ≡ 1 (mod 2n), in decreasing order.
+    ///
+    /// # Errors
+    /// Returns [`RnsError::InvalidPrimeBits`] unless 1 ≤ `bits` ≤
+    /// [`MAX_PRIME_BITS`], [`RnsError::NotEnoughPrimes`] if there are fewer
+    /// such primes, and the errors of [`RnsBasis::new`] otherwise.
+    pub fn generate(n: usize, bits: u32, count: usize) -> Result<Self, RnsError> {
+        if !n.is_power_of_two() {
+            return Err(RnsError::InvalidDegree { n });
+        }
+        if bits == 0 || bits > MAX_PRIME_BITS {
+            return Err(RnsError::InvalidPrimeBits { bits });
+        }
+
+        let two_n = 2 * n as u64;
+        let top = 1u64 << bits;
+        let mut primes = Vec::with_capacity(count);
+        let mut q = (top - 1) / two_n * two_n + 1;
+        if q >= top {
+            q -= two_n;
+        }
+        while primes.len() < count && q > two_n {
+            if is_prime(q) {
+                primes.push(q);
+            }
+            q -= two_n;
+        }
+        if primes.len() < count {
+            return Err(RnsError::NotEnoughPrimes {
+                found: primes.len(),
+                requested: count,
+            });
+        }
+        Self::new(n, &primes)
+    }
+
+    /// Returns the ring degree N.
+    #[inline]
+    pub fn degree(&self) -> usize {
+        self.n
+    }
+
+    /// Returns the number of primes in the chain (the top level).
+    #[inline]
+    pub fn num_primes(&self) -> usize {
+        self.moduli.len()
+    }
+
+    /// Returns the primes in chain order.
+    #[inline]
+    pub fn moduli(&self) -> &[u64] {
+        &self.moduli
+    }
+
+    /// Returns Q_ℓ, the product of the first `level` primes.
+    ///
+    /// # Panics
+    /// Panics unless 1 ≤ `level` ≤ [`num_primes`](Self::num_primes).
+    pub fn modulus(&self, level: usize) -> U1024 {
+        self.check_level(level);
+        *self.crt[level - 1].product()
+    }
+
+    fn check_level(&self, level: usize) {
+        assert!(
+            level >= 1 && level <= self.moduli.len(),
+            "Level must be between 1 and the number of primes"
+        );
+    }
+}
+
+// =============================================================================
+// RnsRingElement
+// =============================================================================
+
+/// An element of Z_Q[X]/(X^N + 1) in RNS form, one limb per active prime.
+#[derive(Clone)]
+pub struct RnsRingElement {
+    /// `limbs[i][j]` is coefficient (or NTT slot) j modulo prime i.
+    limbs: Vec<Vec<u64>>,
+    state: RingElementState,
+    basis: Arc<RnsBasis>,
+}
+
+impl RnsRingElement {
+    /// Creates the zero element at `level`.
+    ///
+    /// # Panics
+    /// Panics unless 1 ≤ `level` ≤ number of primes.
+    pub fn zero(basis: Arc<RnsBasis>, level: usize) -> Self {
+        basis.check_level(level);
+        Self {
+            limbs: vec![vec![0; basis.n]; level],
+            state: RingElementState::Coefficient,
+            basis,
+        }
+    }
+
+    /// Creates an element from signed coefficients.
+    ///
+    /// # Panics
+    /// Panics if `coeffs.len() != N` or the level is out of range.
+    pub fn from_signed(coeffs: &[i64], basis: Arc<RnsBasis>, level: usize) -> Self {
+        Self::from_residue_fn(coeffs.len(), basis, level, |j, q| {
+            coeffs[j].rem_euclid(q as i64) as u64
+        })
+    }
+
+    /// Creates an element from non-negative coefficients, reducing each
+    /// modulo every prime.
+    ///
+    /// # Panics
+    /// Panics if `coeffs.len() != N` or the level is out of range.
+    pub fn from_u1024(coeffs: &[U1024], basis: Arc<RnsBasis>, level: usize) -> Self {
+        Self::from_residue_fn(coeffs.len(), basis, level, |j, q| {
+            reduce_u1024(&coeffs[j], q)
+        })
+    }
+
+    /// Creates an element from signed coefficients.
+    ///
+    /// # Panics
+    /// Panics if `coeffs.len() != N` or the level is out of range.
+    pub fn from_centered(coeffs: &[I1024], basis: Arc<RnsBasis>, level: usize) -> Self {
+        Self::from_residue_fn(coeffs.len(), basis, level, |j, q| {
+            let r = reduce_u1024(&coeffs[j].magnitude(), q);
+            if coeffs[j].is_negative() {
+                sub_mod(0, r, q)
+            } else {
+                r
+            }
+        })
+    }
+
+    fn from_residue_fn<F>(len: usize, basis: Arc<RnsBasis>, level: usize, f: F) -> Self
+    where
+        F: Fn(usize, u64) -> u64,
+    {
+        basis.check_level(level);
+        assert_eq!(len, basis.n, "Coefficient length must match ring degree");
+        let limbs = basis.moduli[..level]
+            .iter()
+            .map(|&q| (0..len).map(|j| f(j, q)).collect())
+            .collect();
+        Self {
+            limbs,
+            state: RingElementState::Coefficient,
+            basis,
+        }
+    }
+
+    /// Creates an element from raw limbs in the given state.
+    ///
+    /// # Panics
+    /// Panics if the number of limbs is not a valid level, a limb does not
+    /// have N entries, or a residue is not reduced.
+    pub fn from_limbs(limbs: Vec<Vec<u64>>, state: RingElementState, basis: Arc<RnsBasis>) -> Self {
+        basis.check_level(limbs.len());
+        for (limb, &q) in limbs.iter().zip(&basis.moduli) {
+            assert_eq!(limb.len(), basis.n, "Limb length must match ring degree");
+            assert!(limb.iter().all(|&x| x < q), "Residues must be reduced");
+        }
+        Self {
+            limbs,
+            state,
+            basis,
+        }
+    }
+
+    /// Converts a [`RingElement<C>`] through its centered coefficients.
+    ///
+    /// Coefficients in (-P/2, P/2] for the field modulus P are mapped to the
+    /// same signed integers modulo Q_ℓ.
+    ///
+    /// # Panics
+    /// Panics if the degrees differ or the level is out of range.
+    pub fn from_ring_element<C: FieldConfig>(
+        elem: &RingElement<C>,
+        basis: Arc<RnsBasis>,
+        level: usize,
+    ) -> Self {
+        Self::from_centered(&elem.centered_coefficients(), basis, level)
+    }
+
+    /// Converts to a [`RingElement<C>`] through the centered coefficients in
+    /// (-Q_ℓ/2, Q_ℓ/2], reduced modulo the field modulus.
+    ///
+    /// # Panics
+    /// Panics if `ctx.n` differs from the ring degree.
+    pub fn to_ring_element<C: FieldConfig>(&self, ctx: Arc<NttContext<C>>) -> RingElement<C> {
+        RingElement::from_centered_coefficients(&self.to_centered_coefficients(), ctx)
+    }
+
+    /// Returns the current state.
+    #[inline]
+    pub fn state(&self) -> RingElementState {
+        self.state
+    }
+
+    /// Returns the ring degree N.
+    #[inline]
+    pub fn degree(&self) -> usize {
+        self.basis.n
+    }
+
+    /// Returns the level, i.e. the number of active primes.
+    #[inline]
+    pub fn level(&self) -> usize {
+        self.limbs.len()
+    }
+
+    /// Returns the basis.
+    #[inline]
+    pub fn basis(&self) -> &Arc<RnsBasis> {
+        &self.basis
+    }
+
+    /// Returns the active primes.
+    #[inline]
+    pub fn moduli(&self) -> &[u64] {
+        &self.basis.moduli[..self.level()]
+    }
+
+    /// Returns Q_ℓ for the current level.
+    pub fn modulus(&self) -> U1024 {
+        self.basis.modulus(self.level())
+    }
+
+    /// Returns the limbs, one per active prime.
+    #[inline]
+    pub fn limbs(&self) -> &[Vec<u64>] {
+        &self.limbs
+    }
+
+    /// Converts every limb to NTT form in-place.
+    pub fn to_ntt(&mut self) {
+        if self.state == RingElementState::Coefficient {
+            for (limb, ntt) in self.limbs.iter_mut().zip(&self.basis.ntts) {
+                ntt.forward(limb);
+            }
+            self.state = RingElementState::Ntt;
+        }
+    }
+
+    /// Converts every limb to coefficient form in-place.
+    pub fn to_coefficient(&mut self) {
+        if self.state == RingElementState::Ntt {
+            for (limb, ntt) in self.limbs.iter_mut().zip(&self.basis.ntts) {
+                ntt.inverse(limb);
+            }
+            self.state = RingElementState::Coefficient;
+        }
+    }
+
+    /// Consumes self and returns the element in NTT form.
+    pub fn into_ntt(mut self) -> Self {
+        self.to_ntt();
+        self
+    }
+
+    /// Consumes self and returns the element in coefficient form.
+    pub fn into_coefficient(mut self) -> Self {
+        self.to_coefficient();
+        self
+    }
+
+    /// Clones and ensures the result is in NTT form.
+    pub fn clone_to_ntt(&self) -> Self {
+        self.clone().into_ntt()
+    }
+
+    /// Clones and ensures the result is in coefficient form.
+    pub fn clone_to_coefficient(&self) -> Self {
+        self.clone().into_coefficient()
+    }
+
+    /// Reconstructs the coefficients as integers in [0, Q_ℓ) with the CRT.
+    pub fn to_u1024_coefficients(&self) -> Vec<U1024> {
+        let elem = self.clone_to_coefficient();
+        let crt = &self.basis.crt[self.level() - 1];
+        let mut residues = vec![U1024::ZERO; self.level()];
+        (0..self.degree())
+            .map(|j| {
+                for (r, limb) in residues.iter_mut().zip(&elem.limbs) {
+                    *r = U1024::from_u64(limb[j]);
+                }
+                crt.reconstruct(&residues)
+            })
+            .collect()
+    }
+
+    /// Reconstructs the coefficients as centered integers in (-Q_ℓ/2, Q_ℓ/2].
+    pub fn to_centered_coefficients(&self) -> Vec<I1024> {
+        let q = self.modulus();
+        let half = q.shr(1);
+        self.to_u1024_coefficients()
+            .into_iter()
+            .map(|x| {
+                if x > half {
+                    I1024::new(q - x, false)
+                } else {
+                    I1024::from_unsigned(x)
+                }
+            })
+            .collect()
+    }
+
+    /// Applies the Galois automorphism σ_k: a(X) ↦ a(X^k) to every limb.
+    ///
+    /// Works in either state and preserves it, like
+    /// [`RingElement::automorphism`].
+    ///
+    /// # Panics
+    /// Panics if `k` is even.
+    pub fn automorphism(&self, k: usize) -> Self {
+        assert!(k % 2 == 1, "Automorphism index must be odd");
+        let n = self.degree();
+        let two_n = 2 * n;
+        let k = k % two_n;
+
+        let limbs = self
+            .limbs
+            .iter()
+            .zip(self.moduli())
+            .map(|(limb, &q)| match self.state {
+                RingElementState::Coefficient => {
+                    let mut out = vec![0; n];
+                    for (i, &c) in limb.iter().enumerate() {
+                        let e = (i * k) % two_n;
+                        if e < n {
+                            out[e] = c;
+                        } else {
+                            out[e - n] = sub_mod(0, c, q);
+                        }
+                    }
+                    out
+                }
+                RingElementState::Ntt => (0..n)
+                    .map(|j| limb[((2 * j + 1) * k % two_n - 1) / 2])
+                    .collect(),
+            })
+            .collect();
+        Self {
+            limbs,
+            state: self.state,
+            basis: self.basis.clone(),
+        }
+    }
+
+    /// Returns `true` if every residue is zero.
+    pub fn is_zero(&self) -> bool {
+        self.limbs.iter().flatten().all(|&x| x == 0)
+    }
+
+    /// Multiplies by an integer constant, reduced modulo each prime.
+    pub fn mul_scalar(&self, scalar: &U1024) -> Self {
+        let limbs = self
+            .limbs
+            .iter()
+            .zip(self.moduli())
+            .map(|(limb, &q)| {
+                let c = reduce_u1024(scalar, q);
+                limb.iter().map(|&x| mul_mod(x, c, q)).collect()
+            })
+            .collect();
+        Self {
+            limbs,
+            state: self.state,
+            basis: self.basis.clone(),
+        }
+    }
+
+    /// Fast base conversion to the full level of `target`.
+    ///
+    /// Computes Σᵢ [aᵢ · q̂ᵢ⁻¹]_(qᵢ) · q̂ᵢ modulo every target prime, where
+    /// q̂ᵢ = Q_ℓ / qᵢ. This avoids reconstructing the coefficients but is
+    /// only exact up to a multiple: the result is a + u · Q_ℓ for some
+    /// integer 0 ≤ u < ℓ per coefficient. The result is in coefficient form.
+    ///
+    /// # Panics
+    /// Panics if the two bases have different degrees.
+    pub fn fast_base_conversion(&self, target: &Arc<RnsBasis>) -> Self {
+        assert_eq!(
+            target.n, self.basis.n,
+            "Bases must have the same ring degree"
+        );
+        let elem = self.clone_to_coefficient();
+        let source = self.moduli();
+
+        // q̂ᵢ⁻¹ mod qᵢ
+        let q_hat_inv: Vec<u64> = source
+            .iter()
+            .enumerate()
+            .map(|(i, &qi)| {
+                let q_hat = source
+                    .iter()
+                    .enumerate()
+                    .filter(|&(k, _)| k != i)
+                    .fold(1, |acc, (_, &qk)| mul_mod(acc, qk % qi, qi));
+                inv_mod(q_hat, qi)
+            })
+            .collect();
+
+        // y[i][j] = [aᵢⱼ · q̂ᵢ⁻¹]_(qᵢ)
+        let y: Vec<Vec<u64>> = elem
+            .limbs
+            .iter()
+            .zip(source.iter().zip(&q_hat_inv))
+            .map(|(limb, (&qi, &inv))| limb.iter().map(|&x| mul_mod(x, inv, qi)).collect())
+            .collect();
+
+        let limbs = target
+            .moduli
+            .iter()
+            .map(|&p| {
+                // q̂ᵢ mod p
+                let q_hat_mod_p: Vec<u64> = (0..source.len())
+                    .map(|i| {
+                        source
+                            .iter()
+                            .enumerate()
+                            .filter(|&(k, _)| k != i)
+                            .fold(1, |acc, (_, &qk)| mul_mod(acc, qk % p, p))
+                    })
+                    .collect();
+                (0..self.degree())
+                    .map(|j| {
+                        y.iter().zip(&q_hat_mod_p).fold(0, |acc, (y_i, &h)| {
+                            add_mod(acc, mul_mod(y_i[j] % p, h, p), p)
+                        })
+                    })
+                    .collect()
+            })
+            .collect();
+
+        Self {
+            limbs,
+            state: RingElementState::Coefficient,
+            basis: target.clone(),
+        }
+    }
+
+    /// Divides by the last active prime with rounding and drops it:
+    /// a ↦ ⌊a / q_(ℓ-1)⌉ modulo Q_(ℓ-1).
+    ///
+    /// The state is preserved; in NTT form only the dropped limb is
+    /// transformed back and the correction is transformed into each
+    /// remaining prime.
+    ///
+    /// # Panics
+    /// Panics if the element is at level 1.
+    pub fn rescale(&self) -> Self {
+        assert!(self.level() >= 2, "Cannot rescale an element at level 1");
+        let level = self.level();
+        let q_last = self.basis.moduli[level - 1];
+        let half = q_last / 2;
+
+        // b = a + ⌊q_last/2⌋, so that ⌊b / q_last⌋ = ⌊a / q_last⌉.
+        let mut last = self.limbs[level - 1].clone();
+        if self.state == RingElementState::Ntt {
+            self.basis.ntts[level - 1].inverse(&mut last);
+        }
+        for x in last.iter_mut() {
+            *x = add_mod(*x, half, q_last);
+        }
+
+        let limbs = self.limbs[..level - 1]
+            .iter()
+            .zip(&self.basis.ntts)
+            .map(|(limb, ntt)| {
+                let qi = ntt.q;
+                // (bᵢ - (b mod q_last)) · q_last⁻¹, with bᵢ = aᵢ + half.
+                let mut correction: Vec<u64> = last
+                    .iter()
+                    .map(|&x| sub_mod(half % qi, x % qi, qi))
+                    .collect();
+                if self.state == RingElementState::Ntt {
+                    ntt.forward(&mut correction);
+                }
+                let inv = inv_mod(q_last % qi, qi);
+                limb.iter()
+                    .zip(&correction)
+                    .map(|(&a, &c)| mul_mod(add_mod(a, c, qi), inv, qi))
+                    .collect()
+            })
+            .collect();
+
+        Self {
+            limbs,
+            state: self.state,
+            basis: self.basis.clone(),
+        }
+    }
+
+    /// Switches to the smaller modulus Q_`level` by rescaling repeatedly,
+    /// i.e. a ↦ ≈ (Q_`level` / Q_ℓ) · a.
+    ///
+    /// Each step rounds, so the result may differ from a single rounding by
+    /// at most one per dropped prime.
+    ///
+    /// # Panics
+    /// Panics unless 1 ≤ `level` ≤ the current level.
+    pub fn mod_switch_to(&self, level: usize) -> Self {
+        assert!(
+            level >= 1 && level <= self.level(),
+            "Target level must be between 1 and the current level"
+        );
+        let mut result = self.clone();
+        while result.level() > level {
+            result = result.rescale();
+        }
+        result
+    }
+
+    /// Reduces modulo Q_`level` by dropping the limbs above `level`, without
+    /// scaling.
+    ///
+    /// # Panics
+    /// Panics unless 1 ≤ `level` ≤ the current level.
+    pub fn drop_to_level(&self, level: usize) -> Self {
+        assert!(
+            level >= 1 && level <= self.level(),
+            "Target level must be between 1 and the current level"
+        );
+        Self {
+            limbs: self.limbs[..level].to_vec(),
+            state: self.state,
+            basis: self.basis.clone(),
+        }
+    }
+
+    fn check_compatible(&self, other: &Self) {
+        assert!(
+            Arc::ptr_eq(&self.basis, &other.basis) && self.level() == other.level(),
+            "RNS elements must share the same basis and level"
+        );
+    }
+
+    fn zip_limbs<F>(&self, other: &Self, state: RingElementState, f: F) -> Self
+    where
+        F: Fn(u64, u64, u64) -> u64,
+    {
+        let limbs = self
+            .limbs
+            .iter()
+            .zip(&other.limbs)
+            .zip(self.moduli())
+            .map(|((a, b), &q)| a.iter().zip(b).map(|(&x, &y)| f(x, y, q)).collect())
+            .collect();
+        Self {
+            limbs,
+            state,
+            basis: self.basis.clone(),
+        }
+    }
+
+    /// Returns `other` in the state of `self`, cloning only if needed.
+    fn matching_state(&self, other: &Self) -> Self {
+        match self.state {
+            RingElementState::Coefficient => other.clone_to_coefficient(),
+            RingElementState::Ntt => other.clone_to_ntt(),
+        }
+    }
+}
+
+// =============================================================================
+// Arithmetic Operations
+// =============================================================================
+
+impl Add for RnsRingElement {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        &self + &rhs
+    }
+}
+
+impl<'a> Add<&'a RnsRingElement> for &'a RnsRingElement {
+    type Output = RnsRingElement;
+
+    fn add(self, rhs: &'a RnsRingElement) -> RnsRingElement {
+        self.check_compatible(rhs);
+        let rhs = self.matching_state(rhs);
+        self.zip_limbs(&rhs, self.state, add_mod)
+    }
+}
+
+impl Sub for RnsRingElement {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        &self - &rhs
+    }
+}
+
+impl<'a> Sub<&'a RnsRingElement> for &'a RnsRingElement {
+    type Output = RnsRingElement;
+
+    fn sub(self, rhs: &'a RnsRingElement) -> RnsRingElement {
+        self.check_compatible(rhs);
+        let rhs = self.matching_state(rhs);
+        self.zip_limbs(&rhs, self.state, sub_mod)
+    }
+}
+
+impl Mul for RnsRingElement {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        &self * &rhs
+    }
+}
+
+impl<'a> Mul<&'a RnsRingElement> for &'a RnsRingElement {
+    type Output = RnsRingElement;
+
+    /// Multiplies limb-wise in NTT form; the result is in NTT form.
+    fn mul(self, rhs: &'a RnsRingElement) -> RnsRingElement {
+        self.check_compatible(rhs);
+        let lhs = self.clone_to_ntt();
+        let rhs = rhs.clone_to_ntt();
+        lhs.zip_limbs(&rhs, RingElementState::Ntt, mul_mod)
+    }
+}
+
+impl Neg for RnsRingElement {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        -&self
+    }
+}
+
+impl Neg for &RnsRingElement {
+    type Output = RnsRingElement;
+
+    fn neg(self) -> RnsRingElement {
+        let limbs = self
+            .limbs
+            .iter()
+            .zip(self.moduli())
+            .map(|(limb, &q)| limb.iter().map(|&x| sub_mod(0, x, q)).collect())
+            .collect();
+        RnsRingElement {
+            limbs,
+            state: self.state,
+            basis: self.basis.clone(),
+        }
+    }
+}
+
+// =============================================================================
+// Trait Implementations
+// =============================================================================
+
+impl PartialEq for RnsRingElement {
+    fn eq(&self, other: &Self) -> bool {
+        if !Arc::ptr_eq(&self.basis, &other.basis) || self.level() != other.level() {
+            return false;
+        }
+        self.clone_to_coefficient().limbs == other.clone_to_coefficient().limbs
+    }
+}
+
+impl Eq for RnsRingElement {}
+
+impl fmt::Debug for RnsRingElement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "RnsRingElement {{ state: {:?}, n: {}, level: {} }}",
+            self.state,
+            self.degree(),
+            self.level()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_prime() {
+        let primes = [2u64, 3, 97, 7681, 8_380_417, (1 << 61) - 1];
+        for p in primes {
+            assert!(is_prime(p), "{p}");
+        }
+        // Carmichael numbers and a strong pseudoprime to base 2
+        for c in [0u64, 1, 561, 1_105, 2_047, 3_215_031_751, 7681 * 7681] {
+            assert!(!is_prime(c), "{c}");
+        }
+    }
+
+    #[test]
+    fn test_ntt_roundtrip_and_negacyclic_product() {
+        let n = 8;
+        let q = 7681;
+        let ntt = PrimeNtt::new(q, n);
+
+        let a: Vec<u64> = (1..=8).collect();
+        let mut x = a.clone();
+        ntt.forward(&mut x);
+        ntt.inverse(&mut x);
+        assert_eq!(x, a);
+
+        // X^7 · X = X^8 = -1
+        let mut b = vec![0u64; n];
+        b[7] = 1;
+        let mut c = vec![0u64; n];
+        c[1] = 1;
+        ntt.forward(&mut b);
+        ntt.forward(&mut c);
+        let mut prod: Vec<u64> = b.iter().zip(&c).map(|(&x, &y)| mul_mod(x, y, q)).collect();
+        ntt.inverse(&mut prod);
+        let mut expected = vec![0u64; n];
+        expected[0] = q - 1;
+        assert_eq!(prod, expected);
+    }
+
+    #[test]
+    fn test_generate_primes() {
+        let basis = RnsBasis::generate(1024, 40, 4).unwrap();
+        let moduli = basis.moduli();
+        assert_eq!(moduli.len(), 4);
+        assert!(moduli.windows(2).all(|w| w[0] > w[1]));
+        for &q in moduli {
+            assert!(q < 1 << 40 && q % 2048 == 1 && is_prime(q));
+        }
+    }
+
+    #[test]
+    fn test_basis_errors() {
+        assert_eq!(
+            RnsBasis::new(12, &[97]).unwrap_err(),
+            RnsError::InvalidDegree { n: 12 }
+        );
+        assert_eq!(RnsBasis::new(8, &[]).unwrap_err(), RnsError::EmptyBasis);
+        assert_eq!(
+            RnsBasis::new(8, &[7681 * 17]).unwrap_err(),
+            RnsError::NotPrime { prime: 7681 * 17 }
+        );
+        assert_eq!(
+            RnsBasis::new(8, &[101]).unwrap_err(),
+            RnsError::NotNttFriendly { prime: 101 }
+        );
+        assert_eq!(
+            RnsBasis::new(8, &[7681, 97, 7681]).unwrap_err(),
+            RnsError::DuplicatePrime { prime: 7681 }
+        );
+        assert_eq!(
+            RnsBasis::generate(8, 6, 5).unwrap_err(),
+            RnsError::NotEnoughPrimes {
+                found: 1,
+                requested: 5
+            }
+        );
+        assert_eq!(
+            RnsBasis::generate(8, 0, 1).unwrap_err(),
+            RnsError::InvalidPrimeBits { bits: 0 }
+        );
+        assert_eq!(
+            RnsBasis::generate(8, MAX_PRIME_BITS + 1, 1).unwrap_err(),
+            RnsError::InvalidPrimeBits {
+                bits: MAX_PRIME_BITS + 1
+            }
+        );
+    }
+}
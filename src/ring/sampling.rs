@@ -0,0 +1,312 @@
+//! Seed-driven samplers for ring elements.
+//!
+//! Every sampler expands `seed || nonce` with an extendable-output function,
+//! so the same inputs always give the same polynomial:
+//!
+//! - **Uniform** (SHAKE128): rejection sampling of values below q. The values
+//!   are interpreted directly as NTT-domain evaluations, which is how Kyber and
+//!   Dilithium sample their public matrices.
+//! - **Centered binomial** (SHAKE256): each coefficient is the difference of
+//!   two sums of η bits, giving values in [-η, η].
+//! - **Bounded uniform** (SHAKE256): rejection sampling of coefficients
+//!   uniform in [-γ, γ].
+//!
+//! The uniform sampler reads candidates in the layout of the standards: for
+//! moduli of at most 12 bits, each 3 bytes give two 12-bit candidates
+//! (FIPS 203, SampleNTT); for larger moduli, each candidate is a little-endian
+//! word of ⌈log2 q / 8⌉ bytes with the excess high bits cleared (FIPS 204,
+//! RejNTTPoly). With `nonce = [j, i]` the Kyber sampler reproduces ML-KEM's
+//! matrix entries and with `nonce = [s, r]` the Dilithium sampler reproduces
+//! ML-DSA's.
+//!
+//! The CBD sampler consumes 2η bits per coefficient from the stream, least
+//! significant bit first; with a one-byte nonce this matches ML-KEM's
+//! SamplePolyCBD(PRF(seed, nonce)).
+//!
+//! Both [`RingElement<C>`] and the small-field array types are supported; the
+//! latter go through the [`SmallField`] trait.
+//!
+//! # Examples
+//!
+//! ```
+//! use lumen_math::KyberFieldElement;
+//! use lumen_math::ring::sampling::{sample_cbd_small, sample_uniform_small};
+//!
+//! let a_hat: [KyberFieldElement; 256] = sample_uniform_small(b"public seed", &[0, 0]);
+//! let s: [KyberFieldElement; 256] = sample_cbd_small(b"secret seed", &[0], 2);
+//!
+//! assert!(s.iter().all(|c| c.value() <= 2 || c.value() >= 3327));
+//! # let _ = a_hat;
+//! ```
+
+use std::sync::Arc;
+
+use sha3::digest::{ExtendableOutput, Update, XofReader};
+use sha3::{Shake128, Shake256};
+
+use super::element::RingElement;
+use crate::poly::ntt::NttContext;
+use crate::poly::ntt::small::{DILITHIUM_Q, DilithiumFieldElement, KYBER_Q, KyberFieldElement};
+use crate::{FieldConfig, FieldElement, U1024};
+
+/// A small-modulus field element type that the samplers can produce and the
+/// norm helpers in [`super::norm`] can measure.
+pub trait SmallField: Copy {
+    /// The modulus q.
+    const MODULUS: u32;
+
+    /// Converts a value in [0, q) into a field element.
+    fn from_reduced(value: u32) -> Self;
+
+    /// Returns the value in [0, q).
+    fn to_reduced(self) -> u32;
+}
+
+impl SmallField for KyberFieldElement {
+    const MODULUS: u32 = KYBER_Q;
+
+    fn from_reduced(value: u32) -> Self {
+        KyberFieldElement::from_raw(value as u16)
+    }
+
+    fn to_reduced(self) -> u32 {
+        self.value() as u32
+    }
+}
+
+impl SmallField for DilithiumFieldElement {
+    const MODULUS: u32 = DILITHIUM_Q;
+
+    fn from_reduced(value: u32) -> Self {
+        DilithiumFieldElement::from_raw(value)
+    }
+
+    fn to_reduced(self) -> u32 {
+        self.value()
+    }
+}
+
+fn xof_reader<X: Default + Update + ExtendableOutput>(seed: &[u8], nonce: &[u8]) -> X::Reader {
+    let mut xof = X::default();
+    xof.update(seed);
+    xof.update(nonce);
+    xof.finalize_xof()
+}
+
+// ============================================================================
+// Shared sampling cores
+// ============================================================================
+
+/// Rejection-samples `n` values below a modulus of at most 24 bits.
+fn uniform_u32(seed: &[u8], nonce: &[u8], q: u32, n: usize) -> Vec<u32> {
+    let bits = 32 - (q - 1).leading_zeros();
+    let mut reader = xof_reader::<Shake128>(seed, nonce);
+    let mut out = Vec::with_capacity(n);
+    let push = |out: &mut Vec<u32>, candidate: u32| {
+        if candidate < q && out.len() < n {
+            out.push(candidate);
+        }
+    };
+
+    if bits <= 12 {
+        let mask = (1 << bits) - 1;
+        let mut buf = [0u8; 3];
+        while out.len() < n {
+            reader.read(&mut buf);
+            push(
+                &mut out,
+                (buf[0] as u32 | (buf[1] as u32 & 0x0f) << 8) & mask,
+            );
+            push(&mut out, (buf[1] as u32 >> 4 | (buf[2] as u32) << 4) & mask);
+        }
+    } else {
+        let width = (bits as usize).div_ceil(8);
+        let mut buf = [0u8; 4];
+        while out.len() < n {
+            reader.read(&mut buf[..width]);
+            push(&mut out, u32::from_le_bytes(buf) & ((1 << bits) - 1));
+        }
+    }
+    out
+}
+
+/// Samples `n` centered binomial values with parameter η.
+fn cbd_i32(seed: &[u8], nonce: &[u8], eta: usize, n: usize) -> Vec<i32> {
+    let mut bytes = vec![0u8; (2 * eta * n).div_ceil(8)];
+    xof_reader::<Shake256>(seed, nonce).read(&mut bytes);
+    let bit = |i: usize| ((bytes[i / 8] >> (i % 8)) & 1) as i32;
+
+    (0..n)
+        .map(|i| {
+            let x: i32 = (0..eta).map(|j| bit(2 * i * eta + j)).sum();
+            let y: i32 = (0..eta).map(|j| bit(2 * i * eta + eta + j)).sum();
+            x - y
+        })
+        .collect()
+}
+
+/// Rejection-samples `n` values uniform in [-γ, γ].
+fn bounded_i64(seed: &[u8], nonce: &[u8], gamma: u32, n: usize) -> Vec<i64> {
+    let range = 2 * gamma as u64;
+    let bits = 64 - range.leading_zeros();
+    let width = (bits as usize).div_ceil(8).max(1);
+    let mask = (1u64 << bits) - 1;
+
+    let mut reader = xof_reader::<Shake256>(seed, nonce);
+    let mut out = Vec::with_capacity(n);
+    let mut buf = [0u8; 8];
+    while out.len() < n {
+        reader.read(&mut buf[..width]);
+        let candidate = u64::from_le_bytes(buf) & mask;
+        if candidate <= range {
+            out.push(gamma as i64 - candidate as i64);
+        }
+    }
+    out
+}
+
+fn reduce_signed<F: SmallField>(v: i64) -> F {
+    F::from_reduced(v.rem_euclid(F::MODULUS as i64) as u32)
+}
+
+// ============================================================================
+// Small-field samplers
+// ============================================================================
+
+/// Samples 256 uniform values mod q from SHAKE128(seed || nonce), to be used
+/// as NTT-domain values.
+pub fn sample_uniform_small<F: SmallField>(seed: &[u8], nonce: &[u8]) -> [F; 256] {
+    let values = uniform_u32(seed, nonce, F::MODULUS, 256);
+    std::array::from_fn(|i| F::from_reduced(values[i]))
+}
+
+/// Samples 256 coefficients from the centered binomial distribution with
+/// parameter η, using SHAKE256(seed || nonce).
+pub fn sample_cbd_small<F: SmallField>(seed: &[u8], nonce: &[u8], eta: usize) -> [F; 256] {
+    let values = cbd_i32(seed, nonce, eta, 256);
+    std::array::from_fn(|i| reduce_signed(values[i] as i64))
+}
+
+/// Samples 256 coefficients uniform in [-γ, γ], using SHAKE256(seed || nonce).
+///
+/// # Panics
+/// Panics if 2γ ≥ q.
+pub fn sample_bounded_small<F: SmallField>(seed: &[u8], nonce: &[u8], gamma: u32) -> [F; 256] {
+    assert!(2 * gamma < F::MODULUS, "Bound must satisfy 2γ < q");
+    let values = bounded_i64(seed, nonce, gamma, 256);
+    std::array::from_fn(|i| reduce_signed(values[i]))
+}
+
+// ============================================================================
+// RingElement samplers
+// ============================================================================
+
+pub(crate) fn field_from_signed<C: FieldConfig>(v: i64) -> FieldElement<C> {
+    let magnitude = FieldElement::new(U1024::from_u64(v.unsigned_abs()));
+    if v < 0 { -magnitude } else { magnitude }
+}
+
+/// Samples a uniform ring element directly in NTT form from
+/// SHAKE128(seed || nonce).
+pub fn sample_uniform<C: FieldConfig>(
+    seed: &[u8],
+    nonce: &[u8],
+    ctx: Arc<NttContext<C>>,
+) -> RingElement<C> {
+    let n = ctx.n;
+    let bits = C::MODULUS.bits();
+
+    let values = if bits <= 24 {
+        uniform_u32(seed, nonce, C::MODULUS.0[0] as u32, n)
+            .into_iter()
+            .map(|v| FieldElement::new(U1024::from_u64(v as u64)))
+            .collect()
+    } else {
+        let width = bits.div_ceil(8);
+        let top_mask = if bits.is_multiple_of(8) {
+            0xff
+        } else {
+            (1u8 << (bits % 8)) - 1
+        };
+        let mut reader = xof_reader::<Shake128>(seed, nonce);
+        let mut buf = vec![0u8; width];
+        let mut values = Vec::with_capacity(n);
+        while values.len() < n {
+            reader.read(&mut buf);
+            buf[width - 1] &= top_mask;
+            buf.reverse();
+            let candidate = U1024::from_be_bytes(&buf);
+            if candidate < C::MODULUS {
+                values.push(FieldElement::new(candidate));
+            }
+        }
+        values
+    };
+
+    RingElement::from_ntt(values, ctx)
+}
+
+/// Samples a ring element with centered binomial coefficients (parameter η)
+/// from SHAKE256(seed || nonce).
+pub fn sample_cbd<C: FieldConfig>(
+    seed: &[u8],
+    nonce: &[u8],
+    eta: usize,
+    ctx: Arc<NttContext<C>>,
+) -> RingElement<C> {
+    let coeffs = cbd_i32(seed, nonce, eta, ctx.n)
+        .into_iter()
+        .map(|v| field_from_signed(v as i64))
+        .collect();
+    RingElement::new(coeffs, ctx)
+}
+
+/// Samples a ring element with coefficients uniform in [-γ, γ] from
+/// SHAKE256(seed || nonce).
+pub fn sample_bounded<C: FieldConfig>(
+    seed: &[u8],
+    nonce: &[u8],
+    gamma: u32,
+    ctx: Arc<NttContext<C>>,
+) -> RingElement<C> {
+    let coeffs = bounded_i64(seed, nonce, gamma, ctx.n)
+        .into_iter()
+        .map(field_from_signed)
+        .collect();
+    RingElement::new(coeffs, ctx)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_uniform_is_deterministic_and_nonce_separated() {
+        let a: [KyberFieldElement; 256] = sample_uniform_small(b"seed", &[0, 0]);
+        let b: [KyberFieldElement; 256] = sample_uniform_small(b"seed", &[0, 0]);
+        let c: [KyberFieldElement; 256] = sample_uniform_small(b"seed", &[1, 0]);
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+    }
+
+    #[test]
+    fn test_bounded_range() {
+        let gamma = 1000;
+        let p: [DilithiumFieldElement; 256] = sample_bounded_small(b"seed", &[3], gamma);
+        for c in p {
+            let v = c.value();
+            assert!(v <= gamma || v >= DILITHIUM_Q - gamma);
+        }
+    }
+
+    #[test]
+    fn test_cbd_range() {
+        for eta in [1, 2, 3, 4] {
+            let p: [DilithiumFieldElement; 256] = sample_cbd_small(b"seed", &[eta as u8], eta);
+            for c in p {
+                let v = c.value();
+                assert!(v <= eta as u32 || v >= DILITHIUM_Q - eta as u32);
+            }
+        }
+    }
+}
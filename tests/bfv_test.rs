@@ -0,0 +1,203 @@
+//! Tests for the BFV homomorphic encryption scheme.
+
+use lumen_math::fhe::bfv::{BfvContext, BfvParams};
+use proptest::prelude::*;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+const N: usize = 32;
+const T: u64 = 257;
+
+const PARAMS: BfvParams = BfvParams {
+    n: N,
+    prime_bits: 50,
+    num_primes: 3,
+    plaintext_modulus: T,
+    eta: 2,
+};
+
+fn context() -> BfvContext {
+    BfvContext::new(PARAMS).unwrap()
+}
+
+fn random_plaintext(rng: &mut StdRng) -> Vec<u64> {
+    (0..N).map(|_| rng.random_range(0..T)).collect()
+}
+
+fn negacyclic_mul_mod(a: &[u64], b: &[u64], t: u64) -> Vec<u64> {
+    let n = a.len();
+    let mut c = vec![0u64; n];
+    for (i, &x) in a.iter().enumerate() {
+        for (j, &y) in b.iter().enumerate() {
+            let p = x * y % t;
+            let k = (i + j) % n;
+            c[k] = if i + j < n {
+                (c[k] + p) % t
+            } else {
+                (c[k] + t - p) % t
+            };
+        }
+    }
+    c
+}
+
+#[test]
+fn test_encrypt_decrypt_roundtrip() {
+    let ctx = context();
+    let mut rng = StdRng::seed_from_u64(42);
+    let (sk, pk) = ctx.keygen(&mut rng);
+    for _ in 0..4 {
+        let m = random_plaintext(&mut rng);
+        let ct = ctx.encrypt(&pk, &m, &mut rng);
+        assert_eq!(ct.degree(), 1);
+        assert_eq!(ctx.decrypt(&sk, &ct), m);
+    }
+}
+
+#[test]
+fn test_seeded_rng_is_deterministic() {
+    let ctx = context();
+    let m: Vec<u64> = (0..N as u64).collect();
+    let run = |seed| {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let (_, pk) = ctx.keygen(&mut rng);
+        ctx.encrypt(&pk, &m, &mut rng)
+    };
+    assert_eq!(run(5).parts()[0].limbs(), run(5).parts()[0].limbs());
+    assert_ne!(run(5).parts()[0].limbs(), run(6).parts()[0].limbs());
+}
+
+#[test]
+fn test_homomorphic_add_sub_neg() {
+    let ctx = context();
+    let mut rng = StdRng::seed_from_u64(1);
+    let (sk, pk) = ctx.keygen(&mut rng);
+    let a = random_plaintext(&mut rng);
+    let b = random_plaintext(&mut rng);
+    let ca = ctx.encrypt(&pk, &a, &mut rng);
+    let cb = ctx.encrypt(&pk, &b, &mut rng);
+
+    let sum: Vec<u64> = a.iter().zip(&b).map(|(x, y)| (x + y) % T).collect();
+    let diff: Vec<u64> = a.iter().zip(&b).map(|(x, y)| (x + T - y) % T).collect();
+    let neg: Vec<u64> = a.iter().map(|x| (T - x) % T).collect();
+
+    assert_eq!(ctx.decrypt(&sk, &(&ca + &cb)), sum);
+    assert_eq!(ctx.decrypt(&sk, &(&ca - &cb)), diff);
+    assert_eq!(ctx.decrypt(&sk, &(-&ca)), neg);
+    assert_eq!(ctx.decrypt(&sk, &ctx.add_plain(&ca, &b)), sum);
+}
+
+#[test]
+fn test_plaintext_multiplication() {
+    let ctx = context();
+    let mut rng = StdRng::seed_from_u64(2);
+    let (sk, pk) = ctx.keygen(&mut rng);
+    let a = random_plaintext(&mut rng);
+    let b = random_plaintext(&mut rng);
+    let ct = ctx.mul_plain(&ctx.encrypt(&pk, &a, &mut rng), &b);
+    assert_eq!(ctx.decrypt(&sk, &ct), negacyclic_mul_mod(&a, &b, T));
+}
+
+#[test]
+fn test_multiplication_and_relinearisation() {
+    let ctx = context();
+    let mut rng = StdRng::seed_from_u64(3);
+    let (sk, pk) = ctx.keygen(&mut rng);
+    let rlk = ctx.relin_keygen(&sk, &mut rng);
+    let a = random_plaintext(&mut rng);
+    let b = random_plaintext(&mut rng);
+    let expected = negacyclic_mul_mod(&a, &b, T);
+
+    let prod = ctx.mul(
+        &ctx.encrypt(&pk, &a, &mut rng),
+        &ctx.encrypt(&pk, &b, &mut rng),
+    );
+    assert_eq!(prod.degree(), 2);
+    assert_eq!(ctx.decrypt(&sk, &prod), expected);
+
+    let relin = ctx.relinearize(&prod, &rlk);
+    assert_eq!(relin.degree(), 1);
+    assert_eq!(ctx.decrypt(&sk, &relin), expected);
+}
+
+#[test]
+fn test_noise_budget_decreases_with_depth() {
+    let ctx = context();
+    let mut rng = StdRng::seed_from_u64(4);
+    let (sk, pk) = ctx.keygen(&mut rng);
+    let rlk = ctx.relin_keygen(&sk, &mut rng);
+    let a = random_plaintext(&mut rng);
+    let b = random_plaintext(&mut rng);
+    let c = random_plaintext(&mut rng);
+
+    let ca = ctx.encrypt(&pk, &a, &mut rng);
+    let fresh = ctx.noise_budget(&sk, &ca);
+    // Q ≈ 2^150 with t = 257: the fresh noise leaves well over 100 bits.
+    assert!(fresh > 100, "fresh budget {fresh}");
+
+    let ab = ctx.relinearize(&ctx.mul(&ca, &ctx.encrypt(&pk, &b, &mut rng)), &rlk);
+    let depth1 = ctx.noise_budget(&sk, &ab);
+    assert!(depth1 < fresh && depth1 > 0, "{depth1} vs {fresh}");
+
+    let abc = ctx.relinearize(&ctx.mul(&ab, &ctx.encrypt(&pk, &c, &mut rng)), &rlk);
+    let depth2 = ctx.noise_budget(&sk, &abc);
+    assert!(depth2 < depth1 && depth2 > 0, "{depth2} vs {depth1}");
+    assert_eq!(
+        ctx.decrypt(&sk, &abc),
+        negacyclic_mul_mod(&negacyclic_mul_mod(&a, &b, T), &c, T)
+    );
+}
+
+#[test]
+fn test_small_modulus_exhausts_budget() {
+    // One 30-bit prime leaves too little room for a product with t = 2^16 + 1.
+    let ctx = BfvContext::new(BfvParams {
+        n: 16,
+        prime_bits: 30,
+        num_primes: 1,
+        plaintext_modulus: 65_537,
+        eta: 2,
+    })
+    .unwrap();
+    let mut rng = StdRng::seed_from_u64(5);
+    let (sk, pk) = ctx.keygen(&mut rng);
+    let m: Vec<u64> = (0..16).map(|i| 1000 + i).collect();
+    let ct = ctx.encrypt(&pk, &m, &mut rng);
+    assert!(ctx.noise_budget(&sk, &ct) > 0);
+    let sq = ctx.mul(&ct, &ct);
+    assert_eq!(ctx.noise_budget(&sk, &sq), 0);
+}
+
+#[test]
+#[should_panic(expected = "Ciphertexts must be relinearized before multiplication")]
+fn test_mul_of_degree_two_panics() {
+    let ctx = context();
+    let mut rng = StdRng::seed_from_u64(6);
+    let (_, pk) = ctx.keygen(&mut rng);
+    let ct = ctx.encrypt(&pk, &[0; N], &mut rng);
+    let sq = ctx.mul(&ct, &ct);
+    let _ = ctx.mul(&sq, &ct);
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(8))]
+
+    #[test]
+    fn prop_add_then_mul_matches_plaintext(seed in any::<u64>()) {
+        let ctx = context();
+        let mut rng = StdRng::seed_from_u64(seed);
+        let (sk, pk) = ctx.keygen(&mut rng);
+        let rlk = ctx.relin_keygen(&sk, &mut rng);
+        let a = random_plaintext(&mut rng);
+        let b = random_plaintext(&mut rng);
+        let c = random_plaintext(&mut rng);
+
+        let ca = ctx.encrypt(&pk, &a, &mut rng);
+        let cb = ctx.encrypt(&pk, &b, &mut rng);
+        let cc = ctx.encrypt(&pk, &c, &mut rng);
+        let ct = ctx.relinearize(&ctx.mul(&(&ca + &cb), &cc), &rlk);
+
+        let ab: Vec<u64> = a.iter().zip(&b).map(|(x, y)| (x + y) % T).collect();
+        prop_assert_eq!(ctx.decrypt(&sk, &ct), negacyclic_mul_mod(&ab, &c, T));
+    }
+}
@@ -0,0 +1,101 @@
+use lumen_math::FieldConfig;
+use lumen_math::U1024;
+use lumen_math::curve::bn254::{
+    Bn254FrConfig, Fq12, G1Affine, G1Projective, G2Affine, G2Projective, multi_pairing, pairing,
+};
+
+fn order() -> U1024 {
+    Bn254FrConfig::MODULUS
+}
+
+#[test]
+fn test_generators_on_curve() {
+    assert!(G1Affine::generator().is_on_curve());
+    assert!(G2Affine::generator().is_on_curve());
+}
+
+#[test]
+fn test_generators_have_order_r() {
+    assert!(G1Projective::generator().mul_scalar(&order()).is_identity());
+    assert!(G2Projective::generator().mul_scalar(&order()).is_identity());
+}
+
+#[test]
+fn test_g1_group_law() {
+    let g = G1Projective::generator();
+    let a = g.mul_scalar(&U1024::from_u64(1234));
+    let b = g.mul_scalar(&U1024::from_u64(5678));
+    assert_eq!(a + b, g.mul_scalar(&U1024::from_u64(6912)));
+    assert_eq!(a + a, a.double());
+    assert!((a - a).is_identity());
+    assert_eq!(a + G1Projective::identity(), a);
+
+    let affine = (a + b).to_affine();
+    assert!(affine.is_on_curve());
+    assert_eq!(affine.to_projective(), a + b);
+}
+
+#[test]
+fn test_g2_group_law() {
+    let g = G2Projective::generator();
+    let a = g.mul_scalar(&U1024::from_u64(99));
+    let b = g.mul_scalar(&U1024::from_u64(101));
+    assert_eq!(a + b, g.mul_scalar(&U1024::from_u64(200)));
+    assert!((a + b).to_affine().is_on_curve());
+}
+
+#[test]
+fn test_pairing_bilinear() {
+    let p = G1Affine::generator();
+    let q = G2Affine::generator();
+    let a = U1024::from_u64(7);
+    let b = U1024::from_u64(11);
+
+    let e = pairing(&p, &q);
+    let lhs = pairing(&p.mul_scalar(&a).to_affine(), &q.mul_scalar(&b).to_affine());
+    assert_eq!(lhs, e.pow(&U1024::from_u64(77)));
+}
+
+#[test]
+fn test_pairing_non_degenerate() {
+    let e = pairing(&G1Affine::generator(), &G2Affine::generator());
+    assert!(!e.is_one());
+    assert!(e.pow(&order()).is_one());
+}
+
+#[test]
+fn test_pairing_with_identity() {
+    let e = pairing(&G1Affine::identity(), &G2Affine::generator());
+    assert_eq!(e, Fq12::one());
+}
+
+#[test]
+fn test_multi_pairing_cancels() {
+    let p = G1Affine::generator()
+        .mul_scalar(&U1024::from_u64(5))
+        .to_affine();
+    let q = G2Affine::generator();
+    let q5 = q.mul_scalar(&U1024::from_u64(5)).to_affine();
+    // e(5P, Q) · e(-P, 5Q) = 1
+    assert!(multi_pairing(&[(p, q), (-G1Affine::generator(), q5)]).is_one());
+}
+
+#[test]
+fn test_scalar_field_ntt_multiplication() {
+    use lumen_math::Polynomial;
+    use lumen_math::curve::bn254::Fr;
+
+    // Fr has two-adicity 28, so the NTT must not assume a 2^32-th root.
+    let a = Polynomial::new(
+        (0..40u64)
+            .map(|i| Fr::new(U1024::from_u64(i + 1)))
+            .collect(),
+    );
+    let b = Polynomial::new(
+        (0..40u64)
+            .map(|i| Fr::new(U1024::from_u64(3 * i + 2)))
+            .collect(),
+    );
+    let x = Fr::new(U1024::from_u64(17));
+    assert_eq!(a.mul_ntt(&b).evaluate(&x), a.evaluate(&x) * b.evaluate(&x));
+}
@@ -0,0 +1,185 @@
+//! Precision tests for the CKKS homomorphic encryption scheme.
+
+use lumen_math::fhe::ckks::{CkksCiphertext, CkksContext, CkksParams, Complex};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+const N: usize = 32;
+const SLOTS: usize = N / 2;
+
+const PARAMS: CkksParams = CkksParams {
+    n: N,
+    first_prime_bits: 60,
+    scale_bits: 40,
+    levels: 2,
+    eta: 2,
+};
+
+fn context() -> CkksContext {
+    CkksContext::new(PARAMS).unwrap()
+}
+
+fn random_values(rng: &mut StdRng) -> Vec<Complex> {
+    (0..SLOTS)
+        .map(|_| Complex::new(rng.random_range(-1.0..1.0), rng.random_range(-1.0..1.0)))
+        .collect()
+}
+
+fn max_error(got: &[Complex], expected: &[Complex]) -> f64 {
+    assert_eq!(got.len(), expected.len());
+    got.iter()
+        .zip(expected)
+        .map(|(a, b)| (*a - *b).abs())
+        .fold(0.0, f64::max)
+}
+
+#[test]
+fn test_encode_decode_precision() {
+    let ctx = context();
+    let mut rng = StdRng::seed_from_u64(1);
+    let z = random_values(&mut rng);
+    let pt = ctx.encode(&z, ctx.default_scale(), ctx.max_level());
+    assert!(max_error(&ctx.decode(&pt), &z) < 1e-9);
+}
+
+#[test]
+fn test_encode_real_pads_with_zeros() {
+    let ctx = context();
+    let pt = ctx.encode_real(&[3.25, -1.5], ctx.default_scale(), 1);
+    let out = ctx.decode(&pt);
+    let mut expected = vec![Complex::default(); SLOTS];
+    expected[0] = Complex::from(3.25);
+    expected[1] = Complex::from(-1.5);
+    assert!(max_error(&out, &expected) < 1e-9);
+}
+
+#[test]
+fn test_encrypt_decrypt_precision() {
+    let ctx = context();
+    let mut rng = StdRng::seed_from_u64(2);
+    let (sk, pk) = ctx.keygen(&mut rng);
+    let z = random_values(&mut rng);
+    let ct = ctx.encrypt(
+        &pk,
+        &ctx.encode(&z, ctx.default_scale(), ctx.max_level()),
+        &mut rng,
+    );
+    assert_eq!(ct.level(), 3);
+    assert!(max_error(&ctx.decode(&ctx.decrypt(&sk, &ct)), &z) < 1e-7);
+}
+
+#[test]
+fn test_addition_and_plaintext_operations() {
+    let ctx = context();
+    let mut rng = StdRng::seed_from_u64(3);
+    let (sk, pk) = ctx.keygen(&mut rng);
+    let scale = ctx.default_scale();
+    let level = ctx.max_level();
+    let a = random_values(&mut rng);
+    let b = random_values(&mut rng);
+    let pa = ctx.encode(&a, scale, level);
+    let pb = ctx.encode(&b, scale, level);
+    let ca = ctx.encrypt(&pk, &pa, &mut rng);
+    let cb = ctx.encrypt(&pk, &pb, &mut rng);
+
+    let sum: Vec<Complex> = a.iter().zip(&b).map(|(x, y)| *x + *y).collect();
+    let diff: Vec<Complex> = a.iter().zip(&b).map(|(x, y)| *x - *y).collect();
+    let prod: Vec<Complex> = a.iter().zip(&b).map(|(x, y)| *x * *y).collect();
+    let neg: Vec<Complex> = a.iter().map(|x| -*x).collect();
+
+    let decrypt = |ct: &CkksCiphertext| ctx.decode(&ctx.decrypt(&sk, ct));
+    assert!(max_error(&decrypt(&(&ca + &cb)), &sum) < 1e-7);
+    assert!(max_error(&decrypt(&(&ca - &cb)), &diff) < 1e-7);
+    assert!(max_error(&decrypt(&(-&ca)), &neg) < 1e-7);
+    assert!(max_error(&decrypt(&ctx.add_plain(&ca, &pb)), &sum) < 1e-7);
+
+    let cp = ctx.mul_plain(&ca, &pb).rescale();
+    assert_eq!(cp.level(), level - 1);
+    assert!(max_error(&decrypt(&cp), &prod) < 1e-6);
+}
+
+#[test]
+fn test_multiplication_relinearisation_and_rescale() {
+    let ctx = context();
+    let mut rng = StdRng::seed_from_u64(4);
+    let (sk, pk) = ctx.keygen(&mut rng);
+    let rlk = ctx.relin_keygen(&sk, &mut rng);
+    let scale = ctx.default_scale();
+    let level = ctx.max_level();
+    let a = random_values(&mut rng);
+    let b = random_values(&mut rng);
+    let c = random_values(&mut rng);
+    let enc = |z: &[Complex], rng: &mut StdRng| ctx.encrypt(&pk, &ctx.encode(z, scale, level), rng);
+    let ca = enc(&a, &mut rng);
+    let cb = enc(&b, &mut rng);
+    let cc = enc(&c, &mut rng);
+
+    let ab = ctx.mul(&ca, &cb, &rlk);
+    assert!((ab.scale() / (scale * scale) - 1.0).abs() < 1e-12);
+    let ab = ab.rescale();
+    assert_eq!(ab.level(), level - 1);
+    assert!((ab.scale() / scale - 1.0).abs() < 1e-6);
+
+    let expected_ab: Vec<Complex> = a.iter().zip(&b).map(|(x, y)| *x * *y).collect();
+    let got = ctx.decode(&ctx.decrypt(&sk, &ab));
+    assert!(max_error(&got, &expected_ab) < 1e-6);
+
+    // Depth 2: (a · b) · c, with c dropped to the lower level automatically.
+    let abc = ctx.mul(&ab, &cc, &rlk).rescale();
+    assert_eq!(abc.level(), 1);
+    let expected_abc: Vec<Complex> = expected_ab.iter().zip(&c).map(|(x, y)| *x * *y).collect();
+    let got = ctx.decode(&ctx.decrypt(&sk, &abc));
+    assert!(max_error(&got, &expected_abc) < 1e-5);
+}
+
+#[test]
+fn test_rotation() {
+    let ctx = context();
+    let mut rng = StdRng::seed_from_u64(5);
+    let (sk, pk) = ctx.keygen(&mut rng);
+    let gk = ctx.rotation_keygen(&sk, &[1, 3, SLOTS + 5], &mut rng);
+    assert!(gk.supports(1) && gk.supports(3) && gk.supports(5));
+    assert!(!gk.supports(2));
+
+    let z = random_values(&mut rng);
+    let ct = ctx.encrypt(
+        &pk,
+        &ctx.encode(&z, ctx.default_scale(), ctx.max_level()),
+        &mut rng,
+    );
+    for r in [1, 3, 5] {
+        let rotated = ctx.rotate(&ct, r, &gk);
+        let expected: Vec<Complex> = (0..SLOTS).map(|i| z[(i + r) % SLOTS]).collect();
+        let got = ctx.decode(&ctx.decrypt(&sk, &rotated));
+        assert!(max_error(&got, &expected) < 1e-7, "rotation by {r}");
+    }
+
+    // Rotations compose and work at lower levels.
+    let low = ct.drop_to_level(1);
+    let twice = ctx.rotate(&ctx.rotate(&low, 1, &gk), 3, &gk);
+    let expected: Vec<Complex> = (0..SLOTS).map(|i| z[(i + 4) % SLOTS]).collect();
+    assert!(max_error(&ctx.decode(&ctx.decrypt(&sk, &twice)), &expected) < 1e-6);
+    assert_eq!(ctx.rotate(&ct, SLOTS, &gk), ct);
+}
+
+#[test]
+#[should_panic(expected = "No Galois key for rotation by 2")]
+fn test_rotation_without_key_panics() {
+    let ctx = context();
+    let mut rng = StdRng::seed_from_u64(6);
+    let (sk, pk) = ctx.keygen(&mut rng);
+    let gk = ctx.rotation_keygen(&sk, &[1], &mut rng);
+    let ct = ctx.encrypt(&pk, &ctx.encode_real(&[1.0], 1e6, 1), &mut rng);
+    let _ = ctx.rotate(&ct, 2, &gk);
+}
+
+#[test]
+#[should_panic(expected = "Ciphertext scales must match")]
+fn test_adding_different_scales_panics() {
+    let ctx = context();
+    let mut rng = StdRng::seed_from_u64(7);
+    let (_, pk) = ctx.keygen(&mut rng);
+    let a = ctx.encrypt(&pk, &ctx.encode_real(&[1.0], 2f64.powi(30), 3), &mut rng);
+    let b = ctx.encrypt(&pk, &ctx.encode_real(&[1.0], 2f64.powi(31), 3), &mut rng);
+    let _ = &a + &b;
+}
@@ -0,0 +1,25 @@
+//! Field configurations shared by the integration tests.
+//!
+//! Each test crate compiles this module separately and uses only some of
+//! the fixtures.
+#![allow(dead_code)]
+
+use lumen_math::FieldConfig;
+
+/// The ML-DSA modulus with the primitive 512th root of unity 1753, so
+/// `TWO_ADICITY` is 9 although 2^13 divides q − 1.
+#[derive(FieldConfig, Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[modulus = "0x7fe001"]
+#[root = "0x6d9"]
+pub struct Q8380417;
+
+/// 7681 = 2^9 · 3 · 5 + 1 with the primitive 512th root of unity
+/// 7146 = 17^15, so `TWO_ADICITY` is 9; radix-2, 3 and 5 transforms all
+/// exist.
+#[derive(FieldConfig, Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[modulus = "0x1e01"]
+#[root = "0x1bea"]
+pub struct Q7681;
+
+// The derive computes TWO_ADICITY from the root; cyclic transforms rely on it.
+const _: () = assert!(Q8380417::TWO_ADICITY == 9 && Q7681::TWO_ADICITY == 9);
@@ -0,0 +1,116 @@
+use std::sync::Arc;
+use std::thread;
+
+use lumen_math::poly::ntt::{CyclicNttContext, intt, ntt};
+use lumen_math::{DefaultFieldConfig, FieldConfig, FieldElement, Polynomial, U1024};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+type F = FieldElement<DefaultFieldConfig>;
+
+/// A config used only by the cache tests, so no other test grows its
+/// shared context concurrently.
+#[derive(FieldConfig, Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[modulus = "0x1e01"]
+#[root = "0x1bea"]
+struct CacheTestConfig;
+
+fn random_coeffs(rng: &mut StdRng, n: usize) -> Vec<F> {
+    (0..n)
+        .map(|_| FieldElement::new(U1024::from_u64(rng.random())))
+        .collect()
+}
+
+fn schoolbook(a: &[F], b: &[F]) -> Vec<F> {
+    let mut out = vec![FieldElement::zero(); a.len() + b.len() - 1];
+    for (i, x) in a.iter().enumerate() {
+        for (j, y) in b.iter().enumerate() {
+            out[i + j] = out[i + j] + *x * *y;
+        }
+    }
+    out
+}
+
+#[test]
+fn test_matches_free_ntt_at_every_size() {
+    let mut rng = StdRng::seed_from_u64(1);
+    let ctx = CyclicNttContext::<DefaultFieldConfig>::new(512);
+    for log_n in 0..=9 {
+        let coeffs = random_coeffs(&mut rng, 1 << log_n);
+        let mut expected = coeffs.clone();
+        ntt(&mut expected);
+        let mut actual = coeffs.clone();
+        ctx.ntt(&mut actual);
+        assert_eq!(actual, expected, "n = 2^{log_n}");
+
+        intt(&mut expected);
+        ctx.intt(&mut actual);
+        assert_eq!(actual, coeffs);
+        assert_eq!(expected, coeffs);
+    }
+}
+
+#[test]
+fn test_cyclic_mul() {
+    let mut rng = StdRng::seed_from_u64(2);
+    let ctx = CyclicNttContext::<DefaultFieldConfig>::new(64);
+    let n = 32;
+    let a = random_coeffs(&mut rng, n);
+    let b = random_coeffs(&mut rng, n);
+
+    // Reduce the linear product modulo X^n − 1.
+    let mut expected = vec![FieldElement::zero(); n];
+    for (i, c) in schoolbook(&a, &b).into_iter().enumerate() {
+        expected[i % n] = expected[i % n] + c;
+    }
+    assert_eq!(ctx.mul(&a, &b), expected);
+}
+
+#[test]
+fn test_mul_ntt_matches_schoolbook() {
+    let mut rng = StdRng::seed_from_u64(3);
+    for (m, n) in [(1, 1), (3, 70), (100, 100), (200, 57)] {
+        let a = random_coeffs(&mut rng, m);
+        let b = random_coeffs(&mut rng, n);
+        let product = Polynomial::new(a.clone()).mul_ntt(&Polynomial::new(b.clone()));
+        assert_eq!(product, Polynomial::new(schoolbook(&a, &b)), "{m} x {n}");
+    }
+}
+
+#[test]
+fn test_shared_context_grows_and_is_reused() {
+    let small = CyclicNttContext::<CacheTestConfig>::shared(20);
+    assert!(small.max_size() >= 32);
+    let large = CyclicNttContext::<CacheTestConfig>::shared(300);
+    assert!(large.max_size() >= 512);
+    // A smaller request is served by the cached larger context.
+    assert!(Arc::ptr_eq(
+        &large,
+        &CyclicNttContext::<CacheTestConfig>::shared(64)
+    ));
+}
+
+#[test]
+#[should_panic(expected = "NTT size exceeds the field's two-adicity")]
+fn test_shared_context_rejects_sizes_beyond_two_adicity() {
+    CyclicNttContext::<DefaultFieldConfig>::shared(1 << 33);
+}
+
+#[test]
+fn test_shared_context_across_threads() {
+    let handles: Vec<_> = (0..8)
+        .map(|t| {
+            thread::spawn(move || {
+                let mut rng = StdRng::seed_from_u64(10 + t);
+                let n = 16 << (t % 4);
+                let a = random_coeffs(&mut rng, n);
+                let b = random_coeffs(&mut rng, n);
+                let product = Polynomial::new(a.clone()).mul_ntt(&Polynomial::new(b.clone()));
+                assert_eq!(product, Polynomial::new(schoolbook(&a, &b)));
+            })
+        })
+        .collect();
+    for handle in handles {
+        handle.join().unwrap();
+    }
+}
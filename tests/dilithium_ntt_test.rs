@@ -0,0 +1,120 @@
+//! Tests for the FIPS 204 NTT on `DilithiumFieldElement` arrays.
+//!
+//! `tests/data/ml_dsa_ntt.txt` holds A·s1 for an OpenSSL 3.5.6 ML-DSA-44
+//! key, recovered from its t1, t0 and s2; see the header of that file for
+//! the derivation. The table prefix is copied from Appendix B of the
+//! standard.
+
+use lumen_math::{
+    DILITHIUM_Q, DILITHIUM_ZETAS, DilithiumFieldElement, dilithium_intt, dilithium_ntt,
+    dilithium_pointwise_mul,
+};
+use rand::Rng;
+
+type Poly = [DilithiumFieldElement; 256];
+
+fn random_poly(rng: &mut impl Rng) -> Poly {
+    std::array::from_fn(|_| DilithiumFieldElement::new(rng.random_range(0..DILITHIUM_Q)))
+}
+
+fn values(p: &[DilithiumFieldElement]) -> Vec<u32> {
+    p.iter().map(|c| c.value()).collect()
+}
+
+fn multiply(a: &Poly, b: &Poly) -> Poly {
+    let (mut a_hat, mut b_hat) = (*a, *b);
+    dilithium_ntt(&mut a_hat);
+    dilithium_ntt(&mut b_hat);
+    let mut c = dilithium_pointwise_mul(&a_hat, &b_hat);
+    dilithium_intt(&mut c);
+    c
+}
+
+#[test]
+fn test_zetas_match_fips204_appendix() {
+    assert_eq!(
+        DILITHIUM_ZETAS[..8],
+        [
+            1, 4808194, 3765607, 3761513, 5178923, 5496691, 5234739, 5178987
+        ]
+    );
+    assert_eq!(DILITHIUM_ZETAS[255], 7648983);
+}
+
+const OPENSSL_VECTORS: &str = include_str!("data/ml_dsa_ntt.txt");
+
+fn decode_hex_poly(hex: &str) -> Poly {
+    let bytes: Vec<u8> = (0..hex.len() / 2)
+        .map(|i| u8::from_str_radix(&hex[2 * i..2 * i + 2], 16).unwrap())
+        .collect();
+    std::array::from_fn(|i| {
+        let b = &bytes[3 * i..3 * i + 3];
+        DilithiumFieldElement::new(b[0] as u32 | (b[1] as u32) << 8 | (b[2] as u32) << 16)
+    })
+}
+
+#[test]
+fn test_matrix_vector_product_matches_openssl() {
+    let mut s1_hat = Vec::new();
+    let mut row = Vec::new();
+    let mut rows_checked = 0;
+    for line in OPENSSL_VECTORS.lines() {
+        let Some((key, value)) = line.split_once(" = ") else {
+            continue;
+        };
+        if key.starts_with("s1[") {
+            let coeffs: Vec<i64> = value.split(' ').map(|c| c.parse().unwrap()).collect();
+            let mut s: Poly = std::array::from_fn(|i| {
+                DilithiumFieldElement::new(coeffs[i].rem_euclid(DILITHIUM_Q as i64) as u32)
+            });
+            dilithium_ntt(&mut s);
+            s1_hat.push(s);
+        } else if key.starts_with("a_hat[") {
+            row.push(decode_hex_poly(value));
+        } else if key.starts_with("as1[") {
+            let mut acc = [DilithiumFieldElement::zero(); 256];
+            for (a, s) in row.drain(..).zip(&s1_hat) {
+                let product = dilithium_pointwise_mul(&a, s);
+                acc = std::array::from_fn(|i| acc[i] + product[i]);
+            }
+            dilithium_intt(&mut acc);
+            assert_eq!(values(&acc), values(&decode_hex_poly(value)), "{key}");
+            rows_checked += 1;
+        }
+    }
+    assert_eq!((s1_hat.len(), rows_checked), (4, 4));
+}
+
+#[test]
+fn test_ntt_intt_roundtrip() {
+    let mut rng = rand::rng();
+    for _ in 0..10 {
+        let original = random_poly(&mut rng);
+        let mut w = original;
+        dilithium_ntt(&mut w);
+        assert_ne!(w, original);
+        dilithium_intt(&mut w);
+        assert_eq!(w, original);
+    }
+}
+
+#[test]
+fn test_multiplication_matches_schoolbook() {
+    let mut rng = rand::rng();
+    let a = random_poly(&mut rng);
+    let b = random_poly(&mut rng);
+
+    let mut expected = [DilithiumFieldElement::zero(); 256];
+    for i in 0..256 {
+        for j in 0..256 {
+            let prod = a[i] * b[j];
+            if i + j < 256 {
+                expected[i + j] = expected[i + j] + prod;
+            } else {
+                expected[i + j - 256] = expected[i + j - 256] - prod;
+            }
+        }
+    }
+
+    assert_eq!(multiply(&a, &b), expected);
+}
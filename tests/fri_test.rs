@@ -0,0 +1,130 @@
+use lumen_math::protocol::{FriConfig, FriError, Transcript, prove_fri, verify_fri};
+use lumen_math::{DefaultFieldConfig, FieldConfig, FieldElement, Polynomial, U1024};
+
+type FE = FieldElement<DefaultFieldConfig>;
+
+#[derive(FieldConfig, Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[modulus = "0x11"] // 17
+#[root = "0x3"]
+struct SmallField;
+
+fn poly(num_coeffs: usize, seed: u64) -> Polynomial<DefaultFieldConfig> {
+    let coeffs = (0..num_coeffs as u64)
+        .map(|i| FE::new(U1024::from_u64(seed.wrapping_mul(i + 1) ^ (i * 977 + 13))))
+        .collect();
+    Polynomial::new(coeffs)
+}
+
+fn prove_and_verify(
+    config: &FriConfig,
+    p: &Polynomial<DefaultFieldConfig>,
+    degree_bound: usize,
+) -> Result<(), FriError> {
+    let proof = prove_fri(config, p, degree_bound, &mut Transcript::new(b"fri-test")).unwrap();
+    verify_fri(
+        config,
+        degree_bound,
+        &proof,
+        &mut Transcript::new(b"fri-test"),
+    )
+}
+
+#[test]
+fn test_honest_binary_folding() {
+    let config = FriConfig::new(2, 4, 16).unwrap();
+    assert!(prove_and_verify(&config, &poly(32, 5), 32).is_ok());
+}
+
+#[test]
+fn test_honest_higher_folding_factors() {
+    for f in [4, 8] {
+        let config = FriConfig::new(f, 2, 12).unwrap();
+        assert!(prove_and_verify(&config, &poly(64, 7), 64).is_ok());
+    }
+}
+
+#[test]
+fn test_honest_with_final_polynomial() {
+    let config = FriConfig::new(2, 4, 12).unwrap().with_final_degree_bound(4);
+    let p = poly(16, 11);
+    let proof = prove_fri(&config, &p, 16, &mut Transcript::new(b"fri-test")).unwrap();
+    assert_eq!(proof.layer_roots.len(), 2);
+    assert!(proof.final_polynomial.coeffs.len() <= 4);
+    assert!(verify_fri(&config, 16, &proof, &mut Transcript::new(b"fri-test")).is_ok());
+}
+
+#[test]
+fn test_lower_degree_polynomial_passes() {
+    let config = FriConfig::new(2, 4, 12).unwrap();
+    assert!(prove_and_verify(&config, &poly(5, 3), 32).is_ok());
+}
+
+#[test]
+fn test_high_degree_rejected() {
+    let config = FriConfig::new(2, 4, 16).unwrap();
+    // Degree 63 against a bound of 16 coefficients.
+    assert!(prove_and_verify(&config, &poly(64, 9), 16).is_err());
+
+    let config = FriConfig::new(4, 4, 16).unwrap();
+    assert!(prove_and_verify(&config, &poly(20, 9), 16).is_err());
+}
+
+#[test]
+fn test_degree_just_above_bound_rejected() {
+    let config = FriConfig::new(2, 8, 24).unwrap();
+    let mut p = poly(16, 21);
+    p = p + Polynomial::monomial(FE::one(), 16);
+    assert!(prove_and_verify(&config, &p, 16).is_err());
+}
+
+#[test]
+fn test_tampered_values_rejected() {
+    let config = FriConfig::new(2, 4, 8).unwrap();
+    let p = poly(16, 2);
+    let mut proof = prove_fri(&config, &p, 16, &mut Transcript::new(b"fri-test")).unwrap();
+    proof.queries[0][1].values[0] = proof.queries[0][1].values[0] + FE::one();
+    assert!(matches!(
+        verify_fri(&config, 16, &proof, &mut Transcript::new(b"fri-test")),
+        Err(FriError::InvalidOpening { layer: 1, .. }) | Err(FriError::FoldingMismatch { .. })
+    ));
+}
+
+#[test]
+fn test_tampered_final_polynomial_rejected() {
+    let config = FriConfig::new(2, 4, 8).unwrap();
+    let p = poly(16, 2);
+    let mut proof = prove_fri(&config, &p, 16, &mut Transcript::new(b"fri-test")).unwrap();
+    proof.final_polynomial = proof.final_polynomial + Polynomial::constant(FE::one());
+    assert!(verify_fri(&config, 16, &proof, &mut Transcript::new(b"fri-test")).is_err());
+}
+
+#[test]
+fn test_wrong_degree_bound_rejected() {
+    let config = FriConfig::new(2, 4, 8).unwrap();
+    let p = poly(16, 2);
+    let proof = prove_fri(&config, &p, 16, &mut Transcript::new(b"fri-test")).unwrap();
+    assert_eq!(
+        verify_fri(&config, 8, &proof, &mut Transcript::new(b"fri-test")),
+        Err(FriError::MalformedProof)
+    );
+}
+
+#[test]
+fn test_invalid_degree_bound() {
+    let config = FriConfig::default();
+    assert_eq!(
+        prove_fri(&config, &poly(4, 1), 12, &mut Transcript::new(b"fri-test")),
+        Err(FriError::InvalidParameters)
+    );
+}
+
+#[test]
+fn test_insufficient_two_adicity() {
+    let config = FriConfig::new(2, 2, 4).unwrap();
+    let p = Polynomial::<SmallField>::constant(FieldElement::one());
+    // 3 has order 16 = 2^4 mod 17, so a domain of 16 · 2 points does not fit.
+    assert_eq!(
+        prove_fri(&config, &p, 16, &mut Transcript::new(b"fri-test")),
+        Err(FriError::InsufficientTwoAdicity)
+    );
+}
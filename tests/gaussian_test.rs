@@ -0,0 +1,124 @@
+//! Statistical tests for the discrete Gaussian sampler.
+
+use std::sync::Arc;
+
+use lumen_math::ring::gaussian::DiscreteGaussian;
+use lumen_math::{FieldElement, NttContext};
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+
+mod common;
+use common::{Q7681, Q8380417};
+
+const SAMPLES: usize = 200_000;
+
+fn samples(sigma: f64, tail_cut: f64, seed: u64) -> (DiscreteGaussian, Vec<i64>) {
+    let gaussian = DiscreteGaussian::new(sigma, tail_cut).unwrap();
+    let mut rng = StdRng::seed_from_u64(seed);
+    let xs = (0..SAMPLES).map(|_| gaussian.sample(&mut rng)).collect();
+    (gaussian, xs)
+}
+
+fn mean_and_variance(xs: &[i64]) -> (f64, f64) {
+    let n = xs.len() as f64;
+    let mean = xs.iter().map(|&x| x as f64).sum::<f64>() / n;
+    let var = xs.iter().map(|&x| (x as f64 - mean).powi(2)).sum::<f64>() / (n - 1.0);
+    (mean, var)
+}
+
+#[test]
+fn test_mean_and_variance() {
+    for (sigma, seed) in [(1.0, 1), (3.2, 2), (4.05, 3), (19.2, 4)] {
+        let (_, xs) = samples(sigma, 10.0, seed);
+        let (mean, var) = mean_and_variance(&xs);
+        // The standard error of the mean is σ/√n; allow five of them.
+        let mean_tol = 5.0 * sigma / (SAMPLES as f64).sqrt();
+        assert!(mean.abs() < mean_tol, "σ = {sigma}: mean {mean}");
+        // Var(s²) ≈ 2σ⁴/n, so the relative error is about √(2/n) ≈ 0.3%.
+        assert!(
+            (var / (sigma * sigma) - 1.0).abs() < 0.02,
+            "σ = {sigma}: variance {var}"
+        );
+    }
+}
+
+#[test]
+fn test_tail_bound() {
+    let (gaussian, xs) = samples(3.2, 2.0, 5);
+    assert_eq!(gaussian.bound(), 7);
+    assert!(xs.iter().all(|x| x.abs() <= 7));
+    // With τ = 2 the bound is actually reached.
+    assert!(xs.contains(&7) && xs.contains(&-7));
+
+    // P(|X| > 3σ) ≈ 0.27% for the untruncated distribution.
+    let (_, xs) = samples(3.2, 12.0, 6);
+    let beyond = xs.iter().filter(|x| x.abs() as f64 > 3.0 * 3.2).count() as f64;
+    let frac = beyond / SAMPLES as f64;
+    assert!(frac > 0.0015 && frac < 0.004, "tail fraction {frac}");
+}
+
+#[test]
+fn test_symmetry_and_histogram() {
+    let sigma = 2.0;
+    let (_, xs) = samples(sigma, 8.0, 7);
+    let weights: Vec<f64> = (-16..=16i64)
+        .map(|k| (-((k * k) as f64) / (2.0 * sigma * sigma)).exp())
+        .collect();
+    let total: f64 = weights.iter().sum();
+    for (k, w) in (-16..=16i64).zip(&weights) {
+        let expected = w / total * SAMPLES as f64;
+        let observed = xs.iter().filter(|&&x| x == k).count() as f64;
+        // Within five standard deviations of the binomial count.
+        let tol = 5.0 * expected.sqrt() + 1.0;
+        assert!(
+            (observed - expected).abs() < tol,
+            "k = {k}: {observed} vs {expected}"
+        );
+    }
+}
+
+#[test]
+fn test_field_samples_are_centered() {
+    let gaussian = DiscreteGaussian::new(3.2, 6.0).unwrap();
+    let mut rng = StdRng::seed_from_u64(8);
+    for _ in 0..1000 {
+        let x: FieldElement<Q8380417> = gaussian.sample_field(&mut rng);
+        assert!(x.to_centered_i64().unsigned_abs() <= gaussian.bound());
+    }
+}
+
+#[test]
+fn test_field_samples_wrap_small_moduli() {
+    // B = 8000 exceeds q = 7681, so the largest magnitudes wrap around.
+    let gaussian = DiscreteGaussian::new(2500.0, 3.2).unwrap();
+    let mut a = StdRng::seed_from_u64(10);
+    let mut b = StdRng::seed_from_u64(10);
+    let mut wrapped = false;
+    for _ in 0..10_000 {
+        let x = gaussian.sample(&mut a);
+        let y: FieldElement<Q7681> = gaussian.sample_field(&mut b);
+        assert_eq!(y.to_u1024().0[0], x.rem_euclid(7681) as u64);
+        wrapped |= x.abs() >= 7681;
+    }
+    assert!(wrapped);
+}
+
+#[test]
+fn test_ring_samples() {
+    let gaussian = DiscreteGaussian::new(3.2, 6.0).unwrap();
+    let ctx = Arc::new(NttContext::<Q8380417>::new(256));
+    let mut rng = StdRng::seed_from_u64(9);
+    let coeffs: Vec<i64> = (0..200)
+        .flat_map(|_| {
+            let e = gaussian.sample_ring(&mut rng, ctx.clone());
+            e.coefficients()
+                .iter()
+                .map(|c| c.to_centered_i64())
+                .collect::<Vec<_>>()
+        })
+        .collect();
+    assert!(coeffs.iter().all(|c| c.unsigned_abs() <= gaussian.bound()));
+    let (mean, var) = mean_and_variance(&coeffs);
+    assert!(mean.abs() < 0.1);
+    assert!((var / (3.2 * 3.2) - 1.0).abs() < 0.03);
+}
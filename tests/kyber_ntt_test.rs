@@ -0,0 +1,199 @@
+//! Tests for the FIPS 203 incomplete NTT on `KyberFieldElement` arrays.
+//!
+//! `tests/data/ml_kem_ntt.txt` holds NTT inputs and outputs taken from
+//! OpenSSL 3.5.6 ML-KEM decapsulation keys, which store NTT(s) for the
+//! secret vector s; see the header of that file for how they were extracted.
+//! The table prefixes are copied from Appendix A of the standard.
+
+use lumen_math::{
+    KYBER_GAMMAS, KYBER_Q, KYBER_ZETAS, KyberFieldElement, kyber_basemul, kyber_intt, kyber_ntt,
+};
+use rand::Rng;
+
+type Poly = [KyberFieldElement; 256];
+
+fn from_fn(f: impl Fn(u32) -> u32) -> Poly {
+    std::array::from_fn(|i| KyberFieldElement::new(f(i as u32)))
+}
+
+fn random_poly(rng: &mut impl Rng) -> Poly {
+    std::array::from_fn(|_| KyberFieldElement::new(rng.random_range(0..KYBER_Q)))
+}
+
+/// Schoolbook multiplication in Z_q[X]/(X^256 + 1).
+fn schoolbook(a: &Poly, b: &Poly) -> Poly {
+    let mut c = [KyberFieldElement::zero(); 256];
+    for i in 0..256 {
+        for j in 0..256 {
+            let prod = a[i] * b[j];
+            if i + j < 256 {
+                c[i + j] = c[i + j] + prod;
+            } else {
+                c[i + j - 256] = c[i + j - 256] - prod;
+            }
+        }
+    }
+    c
+}
+
+fn values(p: &[KyberFieldElement]) -> Vec<u16> {
+    p.iter().map(|c| c.value()).collect()
+}
+
+// =============================================================================
+// Tables
+// =============================================================================
+
+#[test]
+fn test_zetas_match_fips203_appendix() {
+    assert_eq!(
+        KYBER_ZETAS[..16],
+        [
+            1, 1729, 2580, 3289, 2642, 630, 1897, 848, 1062, 1919, 193, 797, 2786, 3260, 569, 1746
+        ]
+    );
+    assert_eq!(KYBER_ZETAS[127], 2154);
+}
+
+#[test]
+fn test_gammas_match_fips203_appendix() {
+    assert_eq!(
+        KYBER_GAMMAS[..8],
+        [17, 3312, 2761, 568, 583, 2746, 2649, 680]
+    );
+    // Consecutive gammas are negatives of each other.
+    for i in 0..64 {
+        assert_eq!(
+            (KYBER_GAMMAS[2 * i] as u32 + KYBER_GAMMAS[2 * i + 1] as u32) % KYBER_Q,
+            0
+        );
+    }
+}
+
+// =============================================================================
+// OpenSSL vectors
+// =============================================================================
+
+const OPENSSL_VECTORS: &str = include_str!("data/ml_kem_ntt.txt");
+
+/// Returns `(parameter set, s, NTT(s))` for every secret polynomial in the
+/// OpenSSL vector file.
+fn openssl_vectors() -> Vec<(String, Poly, Poly)> {
+    let mut vectors = Vec::new();
+    let mut set = String::new();
+    let mut input = None;
+    for line in OPENSSL_VECTORS.lines().map(str::trim) {
+        if let Some(name) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+            set = name.to_string();
+        } else if let Some((key, value)) = line.split_once(" = ") {
+            if key.starts_with("s[") {
+                let coeffs: Vec<i32> = value.split(' ').map(|c| c.parse().unwrap()).collect();
+                input = Some(from_fn(|i| {
+                    coeffs[i as usize].rem_euclid(KYBER_Q as i32) as u32
+                }));
+            } else if key.starts_with("s_hat[") {
+                let bytes: Vec<u8> = (0..value.len() / 2)
+                    .map(|i| u8::from_str_radix(&value[2 * i..2 * i + 2], 16).unwrap())
+                    .collect();
+                // ByteEncode_12: two 12-bit coefficients per three bytes.
+                let output = from_fn(|i| {
+                    let b = &bytes[3 * (i as usize / 2)..];
+                    let pair = b[0] as u32 | (b[1] as u32) << 8 | (b[2] as u32) << 16;
+                    (pair >> (12 * (i % 2))) & 0xfff
+                });
+                vectors.push((set.clone(), input.take().unwrap(), output));
+            }
+        }
+    }
+    vectors
+}
+
+#[test]
+fn test_openssl_vectors_cover_all_parameter_sets() {
+    let vectors = openssl_vectors();
+    assert_eq!(vectors.len(), 2 + 3 + 4);
+    for set in ["ML-KEM-512", "ML-KEM-768", "ML-KEM-1024"] {
+        assert!(vectors.iter().any(|(s, _, _)| s == set), "{set}");
+    }
+}
+
+#[test]
+fn test_ntt_matches_openssl() {
+    for (set, s, s_hat) in openssl_vectors() {
+        let mut f = s;
+        kyber_ntt(&mut f);
+        assert_eq!(values(&f), values(&s_hat), "{set}");
+    }
+}
+
+#[test]
+fn test_intt_matches_openssl() {
+    for (set, s, s_hat) in openssl_vectors() {
+        let mut f = s_hat;
+        kyber_intt(&mut f);
+        assert_eq!(values(&f), values(&s), "{set}");
+    }
+}
+
+// =============================================================================
+// Algebraic properties
+// =============================================================================
+
+#[test]
+fn test_ntt_intt_roundtrip() {
+    let mut rng = rand::rng();
+    for _ in 0..10 {
+        let original = random_poly(&mut rng);
+        let mut f = original;
+        kyber_ntt(&mut f);
+        assert_ne!(f, original);
+        kyber_intt(&mut f);
+        assert_eq!(f, original);
+    }
+}
+
+#[test]
+fn test_ntt_is_linear() {
+    let mut rng = rand::rng();
+    let a = random_poly(&mut rng);
+    let b = random_poly(&mut rng);
+    let mut sum: Poly = std::array::from_fn(|i| a[i] + b[i]);
+    let (mut a_hat, mut b_hat) = (a, b);
+    kyber_ntt(&mut a_hat);
+    kyber_ntt(&mut b_hat);
+    kyber_ntt(&mut sum);
+    for i in 0..256 {
+        assert_eq!(sum[i], a_hat[i] + b_hat[i]);
+    }
+}
+
+#[test]
+fn test_basemul_matches_schoolbook() {
+    let mut rng = rand::rng();
+    for _ in 0..5 {
+        let a = random_poly(&mut rng);
+        let b = random_poly(&mut rng);
+
+        let (mut a_hat, mut b_hat) = (a, b);
+        kyber_ntt(&mut a_hat);
+        kyber_ntt(&mut b_hat);
+        let mut c = kyber_basemul(&a_hat, &b_hat);
+        kyber_intt(&mut c);
+
+        assert_eq!(c, schoolbook(&a, &b));
+    }
+}
+
+#[test]
+fn test_basemul_negacyclic_wraparound() {
+    // X^128 · X^128 = X^256 = -1
+    let mut a = [KyberFieldElement::zero(); 256];
+    a[128] = KyberFieldElement::one();
+    kyber_ntt(&mut a);
+    let mut c = kyber_basemul(&a, &a);
+    kyber_intt(&mut c);
+
+    let mut expected = [KyberFieldElement::zero(); 256];
+    expected[0] = -KyberFieldElement::one();
+    assert_eq!(c, expected);
+}
@@ -0,0 +1,105 @@
+use lumen_math::commit::{KzgError, KzgParams};
+use lumen_math::curve::bn254::{Fr, G1Projective};
+use lumen_math::{Polynomial, U1024};
+
+fn fr(v: u64) -> Fr {
+    Fr::new(U1024::from_u64(v))
+}
+
+fn params() -> KzgParams {
+    KzgParams::setup_insecure(8, 3, &fr(0x5eed))
+}
+
+fn sample_poly() -> Polynomial<lumen_math::curve::bn254::Bn254FrConfig> {
+    Polynomial::new((1..=8).map(|i| fr(i * i + 3)).collect())
+}
+
+#[test]
+fn test_single_opening() {
+    let params = params();
+    let poly = sample_poly();
+    let commitment = params.commit(&poly).unwrap();
+
+    let opening = params.open(&poly, &fr(42)).unwrap();
+    assert_eq!(opening.value, poly.evaluate(&fr(42)));
+    assert!(params.verify(&commitment, &opening).is_ok());
+}
+
+#[test]
+fn test_wrong_value_rejected() {
+    let params = params();
+    let poly = sample_poly();
+    let commitment = params.commit(&poly).unwrap();
+
+    let mut opening = params.open(&poly, &fr(42)).unwrap();
+    opening.value = opening.value + Fr::one();
+    assert_eq!(
+        params.verify(&commitment, &opening),
+        Err(KzgError::VerificationFailed)
+    );
+}
+
+#[test]
+fn test_opening_for_other_commitment_rejected() {
+    let params = params();
+    let poly = sample_poly();
+    let other = params
+        .commit(&(poly.clone() + Polynomial::constant(fr(1))))
+        .unwrap();
+    let opening = params.open(&poly, &fr(9)).unwrap();
+    assert!(params.verify(&other, &opening).is_err());
+}
+
+#[test]
+fn test_commitment_is_homomorphic() {
+    let params = params();
+    let a = sample_poly();
+    let b = Polynomial::new(vec![fr(2), fr(7), fr(1)]);
+    let ca = params.commit(&a).unwrap().0.to_projective();
+    let cb = params.commit(&b).unwrap().0.to_projective();
+    let cab = params.commit(&(a + b)).unwrap().0.to_projective();
+    assert_eq!(ca + cb, cab);
+}
+
+#[test]
+fn test_batch_opening() {
+    let params = params();
+    let poly = sample_poly();
+    let commitment = params.commit(&poly).unwrap();
+    let points = [fr(1), fr(10), fr(100)];
+
+    let opening = params.open_batch(&poly, &points).unwrap();
+    for (z, v) in opening.points.iter().zip(opening.values.iter()) {
+        assert_eq!(poly.evaluate(z), *v);
+    }
+    assert!(params.verify_batch(&commitment, &opening).is_ok());
+
+    let mut tampered = opening.clone();
+    tampered.values[1] = tampered.values[1] + Fr::one();
+    assert_eq!(
+        params.verify_batch(&commitment, &tampered),
+        Err(KzgError::VerificationFailed)
+    );
+
+    let mut forged = opening;
+    forged.proof = (forged.proof.to_projective() + G1Projective::generator()).to_affine();
+    assert!(params.verify_batch(&commitment, &forged).is_err());
+}
+
+#[test]
+fn test_batch_limits() {
+    let params = params();
+    let poly = sample_poly();
+    assert_eq!(params.open_batch(&poly, &[]), Err(KzgError::EmptyBatch));
+    assert_eq!(
+        params.open_batch(&poly, &[fr(1), fr(1)]),
+        Err(KzgError::DuplicatePoints)
+    );
+    assert_eq!(
+        params.open_batch(&poly, &[fr(1), fr(2), fr(3), fr(4)]),
+        Err(KzgError::TooManyPoints {
+            count: 4,
+            max_points: 3
+        })
+    );
+}
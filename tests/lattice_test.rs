@@ -0,0 +1,167 @@
+//! Tests for LWE and Ring-LWE encryption and their noise tracking.
+
+use std::sync::Arc;
+
+use lumen_math::lattice::lwe::{LweContext, LweParams};
+use lumen_math::lattice::rlwe::RlweContext;
+use lumen_math::lattice::{LatticeError, noise_threshold};
+use lumen_math::{NttContext, U1024};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+const N: usize = 256;
+
+const LWE_PARAMS: LweParams = LweParams {
+    n: 32,
+    m: 64,
+    eta: 2,
+};
+
+mod common;
+use common::Q8380417;
+
+fn lwe() -> LweContext<Q8380417> {
+    LweContext::new(LWE_PARAMS).unwrap()
+}
+
+fn rlwe() -> RlweContext<Q8380417> {
+    RlweContext::new(Arc::new(NttContext::new(N)), 2).unwrap()
+}
+
+fn random_bits(rng: &mut StdRng, len: usize) -> Vec<bool> {
+    (0..len).map(|_| rng.random()).collect()
+}
+
+fn xor(a: &[bool], b: &[bool]) -> Vec<bool> {
+    a.iter().zip(b).map(|(x, y)| x ^ y).collect()
+}
+
+#[test]
+fn test_lwe_roundtrip() {
+    let ctx = lwe();
+    let mut rng = StdRng::seed_from_u64(1);
+    let (sk, pk) = ctx.keygen(&mut rng);
+    let bits = random_bits(&mut rng, 40);
+    let cts = ctx.encrypt_bits(&pk, &bits, &mut rng);
+    assert_eq!(ctx.decrypt_bits(&sk, &cts), bits);
+    for (ct, &bit) in cts.iter().zip(&bits) {
+        assert_eq!(ct.u().len(), LWE_PARAMS.n);
+        assert!(ctx.noise(&sk, ct, bit) <= U1024::from_u64(ct.noise_bound()));
+    }
+}
+
+#[test]
+fn test_lwe_homomorphic_addition() {
+    let ctx = lwe();
+    let mut rng = StdRng::seed_from_u64(2);
+    let (sk, pk) = ctx.keygen(&mut rng);
+    let a = random_bits(&mut rng, 16);
+    let b = random_bits(&mut rng, 16);
+    let ca = ctx.encrypt_bits(&pk, &a, &mut rng);
+    let cb = ctx.encrypt_bits(&pk, &b, &mut rng);
+    let sum: Vec<_> = ca.iter().zip(&cb).map(|(x, y)| x + y).collect();
+    let expected = xor(&a, &b);
+    assert_eq!(ctx.decrypt_bits(&sk, &sum), expected);
+    for (ct, &bit) in sum.iter().zip(&expected) {
+        assert!(ctx.noise(&sk, ct, bit) <= U1024::from_u64(ct.noise_bound()));
+    }
+}
+
+#[test]
+fn test_lwe_decryption_fails_beyond_quarter_modulus() {
+    let ctx = lwe();
+    let mut rng = StdRng::seed_from_u64(3);
+    let (sk, pk) = ctx.keygen(&mut rng);
+    let threshold = noise_threshold::<Q8380417>();
+
+    // Doubling an encryption of 0 keeps the message and doubles the error.
+    let mut ct = ctx.encrypt(&pk, false, &mut rng);
+    while ctx.noise(&sk, &ct, false) > U1024::ZERO {
+        let noise = ctx.noise(&sk, &ct, false);
+        assert!(noise <= U1024::from_u64(ct.noise_bound()));
+        if noise > threshold {
+            assert!(ctx.decrypt(&sk, &ct), "noise above q/4 must flip the bit");
+            return;
+        }
+        assert!(!ctx.decrypt(&sk, &ct));
+        ct = &ct + &ct;
+    }
+    panic!("fresh ciphertext had no error");
+}
+
+#[test]
+fn test_lwe_rejects_excessive_noise() {
+    let params = LweParams {
+        n: 4,
+        m: 1 << 20,
+        eta: 4,
+    };
+    assert_eq!(
+        LweContext::<Q8380417>::new(params).unwrap_err(),
+        LatticeError::NoiseTooLarge { bound: 1 << 22 }
+    );
+}
+
+#[test]
+fn test_rlwe_roundtrip_and_addition() {
+    let ctx = rlwe();
+    let mut rng = StdRng::seed_from_u64(4);
+    let (sk, pk) = ctx.keygen(&mut rng);
+    let a = random_bits(&mut rng, N);
+    let b = random_bits(&mut rng, N);
+    let ca = ctx.encrypt(&pk, &a, &mut rng);
+    let cb = ctx.encrypt(&pk, &b, &mut rng);
+    assert_eq!(ctx.decrypt(&sk, &ca), a);
+    assert!(ctx.noise(&sk, &ca, &a) <= U1024::from_u64(ca.noise_bound()));
+
+    let sum = &ca + &cb;
+    let expected = xor(&a, &b);
+    assert_eq!(ctx.decrypt(&sk, &sum), expected);
+    assert!(ctx.noise(&sk, &sum, &expected) <= U1024::from_u64(sum.noise_bound()));
+}
+
+#[test]
+fn test_rlwe_short_messages_are_zero_padded() {
+    let ctx = rlwe();
+    let mut rng = StdRng::seed_from_u64(5);
+    let (sk, pk) = ctx.keygen(&mut rng);
+    let ct = ctx.encrypt(&pk, &[true, true, false, true], &mut rng);
+    let out = ctx.decrypt(&sk, &ct);
+    assert_eq!(out.len(), N);
+    assert_eq!(&out[..4], &[true, true, false, true]);
+    assert!(out[4..].iter().all(|&bit| !bit));
+}
+
+#[test]
+fn test_rlwe_decryption_fails_beyond_quarter_modulus() {
+    let ctx = rlwe();
+    let mut rng = StdRng::seed_from_u64(6);
+    let (sk, pk) = ctx.keygen(&mut rng);
+    let threshold = noise_threshold::<Q8380417>();
+    let zeros = vec![false; N];
+
+    let mut ct = ctx.encrypt(&pk, &zeros, &mut rng);
+    loop {
+        let noise = ctx.noise(&sk, &ct, &zeros);
+        assert!(noise <= U1024::from_u64(ct.noise_bound()));
+        if noise > threshold {
+            assert_ne!(
+                ctx.decrypt(&sk, &ct),
+                zeros,
+                "noise above q/4 must flip a bit"
+            );
+            return;
+        }
+        assert_eq!(ctx.decrypt(&sk, &ct), zeros);
+        ct = &ct + &ct;
+    }
+}
+
+#[test]
+#[should_panic(expected = "At most 256 bits fit in one ciphertext")]
+fn test_rlwe_too_many_bits_panics() {
+    let ctx = rlwe();
+    let mut rng = StdRng::seed_from_u64(7);
+    let (_, pk) = ctx.keygen(&mut rng);
+    let _ = ctx.encrypt(&pk, &[false; N + 1], &mut rng);
+}
@@ -0,0 +1,150 @@
+use lumen_math::commit::{MerkleError, MerkleTree};
+use lumen_math::{DefaultFieldConfig, FieldElement, U1024};
+
+type FE = FieldElement<DefaultFieldConfig>;
+
+fn field_values(n: usize) -> Vec<FE> {
+    (0..n)
+        .map(|i| FE::new(U1024::from_u64((i as u64) * 7919 + 3)))
+        .collect()
+}
+
+fn byte_leaves(n: usize) -> Vec<Vec<u8>> {
+    (0..n).map(|i| format!("leaf-{}", i).into_bytes()).collect()
+}
+
+#[test]
+fn test_root_is_deterministic() {
+    let values = field_values(16);
+    assert_eq!(
+        MerkleTree::from_field_elements(&values).root(),
+        MerkleTree::from_field_elements(&values).root()
+    );
+}
+
+#[test]
+fn test_root_changes_with_any_leaf() {
+    let values = field_values(16);
+    let root = MerkleTree::from_field_elements(&values).root();
+    for i in 0..values.len() {
+        let mut modified = values.clone();
+        modified[i] = modified[i] + FE::one();
+        assert_ne!(MerkleTree::from_field_elements(&modified).root(), root);
+    }
+}
+
+#[test]
+fn test_field_element_openings() {
+    let values = field_values(16);
+    let tree = MerkleTree::from_field_elements(&values);
+    let root = tree.root();
+    for (i, v) in values.iter().enumerate() {
+        let proof = tree.open(i);
+        assert_eq!(proof.siblings.len(), 4);
+        assert!(proof.verify_field_element(&root, v).is_ok());
+    }
+}
+
+#[test]
+fn test_wrong_leaf_rejected() {
+    let values = field_values(8);
+    let tree = MerkleTree::from_field_elements(&values);
+    let proof = tree.open(3);
+    assert_eq!(
+        proof.verify_field_element(&tree.root(), &values[4]),
+        Err(MerkleError::RootMismatch)
+    );
+}
+
+#[test]
+fn test_wrong_index_rejected() {
+    let values = field_values(8);
+    let tree = MerkleTree::from_field_elements(&values);
+    let mut proof = tree.open(3);
+    proof.leaf_index = 2;
+    assert!(
+        proof
+            .verify_field_element(&tree.root(), &values[3])
+            .is_err()
+    );
+    proof.leaf_index = 8;
+    assert_eq!(
+        proof.verify_field_element(&tree.root(), &values[3]),
+        Err(MerkleError::IndexOutOfRange { index: 8 })
+    );
+}
+
+#[test]
+fn test_non_power_of_two_byte_leaves() {
+    let leaves = byte_leaves(11);
+    let tree = MerkleTree::new(&leaves);
+    assert_eq!(tree.num_leaves(), 11);
+    assert_eq!(tree.depth(), 4);
+    for (i, leaf) in leaves.iter().enumerate() {
+        assert!(tree.open(i).verify(&tree.root(), leaf).is_ok());
+    }
+}
+
+#[test]
+fn test_multi_opening() {
+    let values = field_values(32);
+    let tree = MerkleTree::from_field_elements(&values);
+    let proof = tree.open_multi(&[17, 3, 4, 5, 3, 30]);
+    assert_eq!(proof.indices(), &[3, 4, 5, 17, 30]);
+
+    let opened: Vec<FE> = proof.indices().iter().map(|&i| values[i]).collect();
+    assert!(proof.verify_field_elements(&tree.root(), &opened).is_ok());
+
+    let singles: usize = proof
+        .indices()
+        .iter()
+        .map(|&i| tree.open(i).siblings.len())
+        .sum();
+    assert!(proof.nodes.len() < singles);
+}
+
+#[test]
+fn test_multi_opening_all_leaves_needs_no_nodes() {
+    let leaves = byte_leaves(8);
+    let tree = MerkleTree::new(&leaves);
+    let proof = tree.open_multi(&(0..8).collect::<Vec<_>>());
+    assert!(proof.nodes.is_empty());
+    assert!(proof.verify(&tree.root(), &leaves).is_ok());
+}
+
+#[test]
+fn test_multi_opening_rejects_tampering() {
+    let values = field_values(16);
+    let tree = MerkleTree::from_field_elements(&values);
+    let proof = tree.open_multi(&[1, 6, 7, 12]);
+    let mut opened: Vec<FE> = proof.indices().iter().map(|&i| values[i]).collect();
+
+    opened[2] = opened[2] + FE::one();
+    assert_eq!(
+        proof.verify_field_elements(&tree.root(), &opened),
+        Err(MerkleError::RootMismatch)
+    );
+
+    assert_eq!(
+        proof.verify_field_elements(&tree.root(), &opened[..3]),
+        Err(MerkleError::LeafCountMismatch)
+    );
+
+    let mut truncated = proof.clone();
+    truncated.nodes.pop();
+    let opened: Vec<FE> = proof.indices().iter().map(|&i| values[i]).collect();
+    assert_eq!(
+        truncated.verify_field_elements(&tree.root(), &opened),
+        Err(MerkleError::MalformedProof)
+    );
+}
+
+#[test]
+fn test_chunked_leaves() {
+    let values = field_values(16);
+    let tree = MerkleTree::from_field_element_chunks(&values, 4);
+    assert_eq!(tree.num_leaves(), 4);
+    let proof = tree.open(2);
+    let bytes = lumen_math::commit::merkle::field_elements_to_bytes(&values[8..12]);
+    assert!(proof.verify(&tree.root(), &bytes).is_ok());
+}
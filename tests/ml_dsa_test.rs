@@ -0,0 +1,230 @@
+//! Tests for ML-DSA (FIPS 204).
+//!
+//! `tests/data/ml_dsa_kat.rsp` holds known-answer vectors for all three
+//! parameter sets produced with OpenSSL's ML-DSA implementation; see the
+//! header of that file for how they were generated.
+
+use std::collections::HashMap;
+
+use lumen_math::pqc::ml_dsa::{
+    ML_DSA_44, ML_DSA_65, ML_DSA_87, MlDsaError, MlDsaParams, SigningKey, VerifyingKey,
+};
+use sha3::{Digest, Sha3_256};
+
+const KAT: &str = include_str!("data/ml_dsa_kat.rsp");
+
+struct KatVector {
+    params: MlDsaParams,
+    xi: [u8; 32],
+    rnd: [u8; 32],
+    msg: Vec<u8>,
+    ctx: Vec<u8>,
+    pk_digest: Vec<u8>,
+    sk_digest: Vec<u8>,
+    sig_det_digest: Vec<u8>,
+    sig_hedged_digest: Vec<u8>,
+}
+
+fn hex(s: &str) -> Vec<u8> {
+    if s == "-" {
+        return Vec::new();
+    }
+    (0..s.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+        .collect()
+}
+
+fn sha3(data: &[u8]) -> Vec<u8> {
+    Sha3_256::digest(data).to_vec()
+}
+
+fn parse_kat() -> Vec<KatVector> {
+    let mut vectors = Vec::new();
+    let mut params = ML_DSA_44;
+    let mut fields: HashMap<String, String> = HashMap::new();
+
+    let mut flush = |params: MlDsaParams, fields: &mut HashMap<String, String>| {
+        if fields.is_empty() {
+            return;
+        }
+        let get = |k: &str| hex(&fields[k]);
+        vectors.push(KatVector {
+            params,
+            xi: get("xi").try_into().unwrap(),
+            rnd: get("rnd").try_into().unwrap(),
+            msg: get("msg"),
+            ctx: get("ctx"),
+            pk_digest: get("pk_sha3_256"),
+            sk_digest: get("sk_sha3_256"),
+            sig_det_digest: get("sig_det_sha3_256"),
+            sig_hedged_digest: get("sig_hedged_sha3_256"),
+        });
+        fields.clear();
+    };
+
+    for line in KAT.lines().map(str::trim) {
+        if line.starts_with('#') {
+            continue;
+        }
+        if line.is_empty() {
+            flush(params, &mut fields);
+        } else if let Some(name) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+            params = [ML_DSA_44, ML_DSA_65, ML_DSA_87]
+                .into_iter()
+                .find(|p| p.name == name)
+                .expect("unknown parameter set");
+        } else if let Some((key, value)) = line.split_once(" = ")
+            && key != "count"
+        {
+            fields.insert(key.to_string(), value.to_string());
+        }
+    }
+    flush(params, &mut fields);
+    vectors
+}
+
+/// Builds M' = 0 || |ctx| || ctx || M.
+fn formatted(msg: &[u8], ctx: &[u8]) -> Vec<u8> {
+    let mut m = vec![0, ctx.len() as u8];
+    m.extend_from_slice(ctx);
+    m.extend_from_slice(msg);
+    m
+}
+
+// =============================================================================
+// Known-answer tests
+// =============================================================================
+
+#[test]
+fn test_kat_file_covers_all_parameter_sets() {
+    let vectors = parse_kat();
+    assert_eq!(vectors.len(), 9);
+    for params in [ML_DSA_44, ML_DSA_65, ML_DSA_87] {
+        assert_eq!(vectors.iter().filter(|v| v.params == params).count(), 3);
+    }
+}
+
+#[test]
+fn test_kat_keygen() {
+    for v in parse_kat() {
+        let (vk, sk) = v.params.keygen_from_seed(&v.xi);
+        assert_eq!(sha3(vk.as_bytes()), v.pk_digest, "{} pk", v.params.name);
+        assert_eq!(sha3(sk.as_bytes()), v.sk_digest, "{} sk", v.params.name);
+    }
+}
+
+#[test]
+fn test_kat_deterministic_signatures() {
+    for v in parse_kat() {
+        let (vk, sk) = v.params.keygen_from_seed(&v.xi);
+        let sig = sk.sign_deterministic(&v.msg, &v.ctx).unwrap();
+        assert_eq!(sha3(&sig), v.sig_det_digest, "{} sig", v.params.name);
+        assert!(vk.verify(&v.msg, &sig, &v.ctx).is_ok());
+    }
+}
+
+#[test]
+fn test_kat_hedged_signatures() {
+    for v in parse_kat() {
+        let (vk, sk) = v.params.keygen_from_seed(&v.xi);
+        let sig = sk.sign_internal(&formatted(&v.msg, &v.ctx), &v.rnd);
+        assert_eq!(sha3(&sig), v.sig_hedged_digest, "{} sig", v.params.name);
+        assert!(vk.verify(&v.msg, &sig, &v.ctx).is_ok());
+    }
+}
+
+// =============================================================================
+// Verification failures and input validation
+// =============================================================================
+
+#[test]
+fn test_random_sign_verify_all_parameter_sets() {
+    let mut rng = rand::rng();
+    for params in [ML_DSA_44, ML_DSA_65, ML_DSA_87] {
+        let (vk, sk) = params.keygen(&mut rng);
+        let sig = sk.sign(b"lumen", b"ctx", &mut rng).unwrap();
+        assert_eq!(sig.len(), params.signature_len());
+        assert!(vk.verify(b"lumen", &sig, b"ctx").is_ok());
+        assert_eq!(
+            vk.verify(b"lumen", &sig, b"other ctx"),
+            Err(MlDsaError::VerificationFailed)
+        );
+    }
+}
+
+#[test]
+fn test_tampered_signature_rejected() {
+    let (vk, sk) = ML_DSA_65.keygen_from_seed(&[1; 32]);
+    let sig = sk.sign_deterministic(b"message", b"").unwrap();
+
+    let mut bad = sig.clone();
+    bad[0] ^= 1;
+    assert_eq!(
+        vk.verify(b"message", &bad, b""),
+        Err(MlDsaError::VerificationFailed)
+    );
+
+    let mut bad = sig.clone();
+    bad[100] ^= 0x10;
+    assert!(vk.verify(b"message", &bad, b"").is_err());
+}
+
+#[test]
+fn test_malformed_hint_rejected() {
+    let params = ML_DSA_44;
+    let (vk, sk) = params.keygen_from_seed(&[2; 32]);
+    let mut sig = sk.sign_deterministic(b"message", b"").unwrap();
+    // The last k bytes hold cumulative hint counts; a count above ω is invalid.
+    let last = sig.len() - 1;
+    sig[last] = (params.omega + 1) as u8;
+    assert_eq!(
+        vk.verify(b"message", &sig, b""),
+        Err(MlDsaError::MalformedSignature)
+    );
+}
+
+#[test]
+fn test_wrong_key_rejected() {
+    let (_, sk) = ML_DSA_44.keygen_from_seed(&[3; 32]);
+    let (other_vk, _) = ML_DSA_44.keygen_from_seed(&[4; 32]);
+    let sig = sk.sign_deterministic(b"message", b"").unwrap();
+    assert_eq!(
+        other_vk.verify(b"message", &sig, b""),
+        Err(MlDsaError::VerificationFailed)
+    );
+}
+
+#[test]
+fn test_context_too_long() {
+    let (vk, sk) = ML_DSA_44.keygen_from_seed(&[5; 32]);
+    let ctx = [0u8; 256];
+    assert_eq!(
+        sk.sign_deterministic(b"message", &ctx),
+        Err(MlDsaError::ContextTooLong { len: 256 })
+    );
+    assert_eq!(
+        vk.verify(b"message", &[0u8; 2420], &ctx),
+        Err(MlDsaError::ContextTooLong { len: 256 })
+    );
+}
+
+#[test]
+fn test_keys_roundtrip_through_bytes() {
+    let (vk, sk) = ML_DSA_87.keygen_from_seed(&[6; 32]);
+    assert_eq!(
+        VerifyingKey::from_bytes(ML_DSA_87, vk.as_bytes()).unwrap(),
+        vk
+    );
+    assert_eq!(
+        SigningKey::from_bytes(ML_DSA_87, sk.as_bytes()).unwrap(),
+        sk
+    );
+    assert_eq!(
+        VerifyingKey::from_bytes(ML_DSA_44, vk.as_bytes()),
+        Err(MlDsaError::InvalidLength {
+            expected: 1312,
+            actual: 2592
+        })
+    );
+}
@@ -0,0 +1,204 @@
+//! Tests for ML-KEM (FIPS 203).
+//!
+//! `tests/data/ml_kem_kat.rsp` holds known-answer vectors for all three
+//! parameter sets produced with OpenSSL's ML-KEM implementation; see the
+//! header of that file for how they were generated.
+
+use lumen_math::pqc::ml_kem::{
+    DecapsulationKey, EncapsulationKey, ML_KEM_512, ML_KEM_768, ML_KEM_1024, MlKemError,
+    MlKemParams,
+};
+use sha3::{Digest, Sha3_256};
+
+const KAT: &str = include_str!("data/ml_kem_kat.rsp");
+
+struct KatVector {
+    params: MlKemParams,
+    d: [u8; 32],
+    z: [u8; 32],
+    m: [u8; 32],
+    ek_digest: [u8; 32],
+    dk_digest: [u8; 32],
+    ct_digest: [u8; 32],
+    ss: [u8; 32],
+    ss_rejected: [u8; 32],
+}
+
+fn hex32(s: &str) -> [u8; 32] {
+    assert_eq!(s.len(), 64);
+    std::array::from_fn(|i| u8::from_str_radix(&s[2 * i..2 * i + 2], 16).unwrap())
+}
+
+fn sha3(data: &[u8]) -> [u8; 32] {
+    Sha3_256::digest(data).into()
+}
+
+fn parse_kat() -> Vec<KatVector> {
+    let mut vectors = Vec::new();
+    let mut params = ML_KEM_512;
+    let mut fields = std::collections::HashMap::new();
+
+    let mut flush = |params: MlKemParams,
+                     fields: &mut std::collections::HashMap<String, String>| {
+        if fields.is_empty() {
+            return;
+        }
+        let get = |k: &str| hex32(&fields[k]);
+        vectors.push(KatVector {
+            params,
+            d: get("d"),
+            z: get("z"),
+            m: get("m"),
+            ek_digest: get("ek_sha3_256"),
+            dk_digest: get("dk_sha3_256"),
+            ct_digest: get("ct_sha3_256"),
+            ss: get("ss"),
+            ss_rejected: get("ss_rejected"),
+        });
+        fields.clear();
+    };
+
+    for line in KAT.lines().map(str::trim) {
+        if line.starts_with('#') {
+            continue;
+        }
+        if line.is_empty() {
+            flush(params, &mut fields);
+        } else if let Some(name) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+            params = [ML_KEM_512, ML_KEM_768, ML_KEM_1024]
+                .into_iter()
+                .find(|p| p.name == name)
+                .expect("unknown parameter set");
+        } else if let Some((key, value)) = line.split_once(" = ")
+            && key != "count"
+        {
+            fields.insert(key.to_string(), value.to_string());
+        }
+    }
+    flush(params, &mut fields);
+    vectors
+}
+
+// =============================================================================
+// Known-answer tests
+// =============================================================================
+
+#[test]
+fn test_kat_file_covers_all_parameter_sets() {
+    let vectors = parse_kat();
+    assert_eq!(vectors.len(), 9);
+    for params in [ML_KEM_512, ML_KEM_768, ML_KEM_1024] {
+        assert_eq!(vectors.iter().filter(|v| v.params == params).count(), 3);
+    }
+}
+
+#[test]
+fn test_kat_keygen() {
+    for v in parse_kat() {
+        let (ek, dk) = v.params.keygen_from_seed(&v.d, &v.z);
+        assert_eq!(sha3(ek.as_bytes()), v.ek_digest, "{} ek", v.params.name);
+        assert_eq!(sha3(dk.as_bytes()), v.dk_digest, "{} dk", v.params.name);
+    }
+}
+
+#[test]
+fn test_kat_encapsulate() {
+    for v in parse_kat() {
+        let (ek, _) = v.params.keygen_from_seed(&v.d, &v.z);
+        let (ss, ct) = ek.encapsulate_deterministic(&v.m);
+        assert_eq!(sha3(&ct), v.ct_digest, "{} ct", v.params.name);
+        assert_eq!(ss, v.ss, "{} ss", v.params.name);
+    }
+}
+
+#[test]
+fn test_kat_decapsulate_and_implicit_rejection() {
+    for v in parse_kat() {
+        let (ek, dk) = v.params.keygen_from_seed(&v.d, &v.z);
+        let (_, mut ct) = ek.encapsulate_deterministic(&v.m);
+        assert_eq!(dk.decapsulate(&ct).unwrap(), v.ss, "{}", v.params.name);
+
+        ct[0] ^= 0x01;
+        assert_eq!(
+            dk.decapsulate(&ct).unwrap(),
+            v.ss_rejected,
+            "{} rejection",
+            v.params.name
+        );
+    }
+}
+
+// =============================================================================
+// Round trips and input validation
+// =============================================================================
+
+#[test]
+fn test_random_roundtrip_all_parameter_sets() {
+    let mut rng = rand::rng();
+    for params in [ML_KEM_512, ML_KEM_768, ML_KEM_1024] {
+        let (ek, dk) = params.keygen(&mut rng);
+        assert_eq!(ek.as_bytes().len(), params.encapsulation_key_len());
+        assert_eq!(dk.as_bytes().len(), params.decapsulation_key_len());
+        assert_eq!(dk.encapsulation_key(), ek);
+
+        let (ss, ct) = ek.encapsulate(&mut rng);
+        assert_eq!(ct.len(), params.ciphertext_len());
+        assert_eq!(dk.decapsulate(&ct).unwrap(), ss);
+    }
+}
+
+#[test]
+fn test_keys_roundtrip_through_bytes() {
+    let (ek, dk) = ML_KEM_768.keygen_from_seed(&[1; 32], &[2; 32]);
+    assert_eq!(
+        EncapsulationKey::from_bytes(ML_KEM_768, ek.as_bytes()).unwrap(),
+        ek
+    );
+    assert_eq!(
+        DecapsulationKey::from_bytes(ML_KEM_768, dk.as_bytes()).unwrap(),
+        dk
+    );
+}
+
+#[test]
+fn test_wrong_lengths_rejected() {
+    let (ek, dk) = ML_KEM_512.keygen_from_seed(&[3; 32], &[4; 32]);
+    assert_eq!(
+        EncapsulationKey::from_bytes(ML_KEM_768, ek.as_bytes()),
+        Err(MlKemError::InvalidLength {
+            expected: 1184,
+            actual: 800
+        })
+    );
+    assert_eq!(
+        dk.decapsulate(&[0u8; 10]),
+        Err(MlKemError::InvalidLength {
+            expected: 768,
+            actual: 10
+        })
+    );
+}
+
+#[test]
+fn test_modulus_check() {
+    let (ek, _) = ML_KEM_512.keygen_from_seed(&[5; 32], &[6; 32]);
+    let mut bytes = ek.as_bytes().to_vec();
+    // Set the first 12-bit coefficient to 0xfff ≥ q.
+    bytes[0] = 0xff;
+    bytes[1] |= 0x0f;
+    assert_eq!(
+        EncapsulationKey::from_bytes(ML_KEM_512, &bytes),
+        Err(MlKemError::InvalidEncapsulationKey)
+    );
+}
+
+#[test]
+fn test_hash_check() {
+    let (_, dk) = ML_KEM_512.keygen_from_seed(&[7; 32], &[8; 32]);
+    let mut bytes = dk.as_bytes().to_vec();
+    bytes[768 * 2 + 40] ^= 1;
+    assert_eq!(
+        DecapsulationKey::from_bytes(ML_KEM_512, &bytes),
+        Err(MlKemError::InvalidDecapsulationKey)
+    );
+}
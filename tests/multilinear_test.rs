@@ -0,0 +1,90 @@
+//! Integration tests for dense multilinear extensions.
+
+use lumen_math::{
+    BooleanHypercube, DefaultFieldConfig, DenseMultilinearExtension, FieldElement,
+    MultivariatePolynomial, fp,
+};
+
+type FE = FieldElement<DefaultFieldConfig>;
+type Mle = DenseMultilinearExtension<DefaultFieldConfig>;
+type MvPoly = MultivariatePolynomial<DefaultFieldConfig>;
+
+fn sample(num_vars: usize) -> Mle {
+    Mle::new(
+        num_vars,
+        (0..1u64 << num_vars).map(|i| fp!(i * i + 3)).collect(),
+    )
+}
+
+#[test]
+fn test_multivariate_roundtrip() {
+    let mle = sample(3);
+    let mv = mle.to_multivariate();
+
+    for point in BooleanHypercube::<DefaultFieldConfig>::new(3) {
+        assert_eq!(mv.evaluate(&point), mle.evaluate(&point));
+    }
+
+    // Both forms agree off the hypercube as well.
+    let point = [fp!(11u64), fp!(22u64), fp!(33u64)];
+    assert_eq!(mv.evaluate(&point), mle.evaluate(&point));
+
+    assert_eq!(Mle::from_multivariate(&mv), mle);
+}
+
+#[test]
+fn test_from_multivariate_multilinear() {
+    // p = 2 + 3*x0*x1 + x2
+    let mut p = MvPoly::new(3);
+    p.add_term(vec![0, 0, 0], fp!(2u64));
+    p.add_term(vec![1, 1, 0], fp!(3u64));
+    p.add_term(vec![0, 0, 1], fp!(1u64));
+
+    let mle = Mle::from_multivariate(&p);
+    let point = [fp!(4u64), fp!(5u64), fp!(6u64)];
+    assert_eq!(mle.evaluate(&point), p.evaluate(&point));
+    assert_eq!(mle.to_multivariate(), p);
+}
+
+#[test]
+fn test_eq_polynomial_lagrange_identity() {
+    // f(r) = Σ_b f(b) · eq(b, r)
+    let mle = sample(4);
+    let r = [fp!(7u64), fp!(9u64), fp!(2u64), fp!(13u64)];
+    let eq = Mle::eq_polynomial(&r);
+
+    let weighted = mle
+        .evaluations()
+        .iter()
+        .zip(eq.evaluations())
+        .fold(FE::zero(), |acc, (f, e)| acc + *f * *e);
+    assert_eq!(weighted, mle.evaluate(&r));
+    assert_eq!(eq.sum_over_hypercube(), FE::one());
+}
+
+#[test]
+fn test_arithmetic() {
+    let a = sample(3);
+    let b = Mle::constant(3, fp!(5u64));
+    let point = [fp!(3u64), fp!(1u64), fp!(8u64)];
+
+    let sum = &a + &b;
+    assert_eq!(sum.evaluate(&point), a.evaluate(&point) + fp!(5u64));
+
+    let diff = &a - &b;
+    assert_eq!(diff.evaluate(&point), a.evaluate(&point) - fp!(5u64));
+
+    let scaled = a.scale(&fp!(2u64));
+    assert_eq!(scaled.evaluate(&point), a.evaluate(&point).double());
+
+    assert!((a.clone() + (-a)).is_zero());
+}
+
+#[test]
+fn test_fix_all_variables() {
+    let mle = sample(3);
+    let point = [fp!(2u64), fp!(3u64), fp!(4u64)];
+    let fixed = mle.fixed_variables(&point);
+    assert_eq!(fixed.num_vars(), 0);
+    assert_eq!(fixed.evaluations()[0], mle.evaluate(&point));
+}
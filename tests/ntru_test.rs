@@ -0,0 +1,273 @@
+//! Tests for ring inversion and NTRUSolve.
+//!
+//! `tests/data/falcon512_keys.rsp` holds Falcon-512 key pairs encoded as in
+//! the reference falcon512-KAT.rsp. Every key is decoded, checked against its
+//! public key and the NTRU equation, and solved again with [`ntru_solve`].
+//! The bundled key is self-generated, so until entries from the reference
+//! file are added these tests only check the decoder and the solver for
+//! self-consistency.
+
+use std::sync::Arc;
+
+use lumen_math::lattice::ntru::{NtruError, ntru_solve};
+use lumen_math::{FieldConfig, FieldElement, I1024, NttContext, RingElement, U1024};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+mod common;
+use common::Q7681;
+
+/// Falcon's q = 12289 with the primitive 1024th root of unity 10302.
+#[derive(FieldConfig, Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[modulus = "0x3001"]
+#[root = "0x283e"]
+struct Q12289;
+
+const Q: u64 = 12289;
+const KEYS: &str = include_str!("data/falcon512_keys.rsp");
+
+struct NtruKey {
+    h: Vec<i64>,
+    f: Vec<i64>,
+    g: Vec<i64>,
+    big_f: Vec<i64>,
+}
+
+fn from_hex(s: &str) -> Vec<u8> {
+    (0..s.len() / 2)
+        .map(|i| u8::from_str_radix(&s[2 * i..2 * i + 2], 16).unwrap())
+        .collect()
+}
+
+/// Unpacks `n` fields of `bits` bits, most significant bit first, as in
+/// Falcon's `modq_decode` (unsigned) and `trim_i8_decode` (signed).
+fn unpack(bytes: &[u8], n: usize, bits: u32, signed: bool) -> Vec<i64> {
+    let (mut acc, mut acc_len, mut out) = (0u64, 0, Vec::with_capacity(n));
+    for &b in bytes {
+        acc = (acc << 8) | b as u64;
+        acc_len += 8;
+        while acc_len >= bits && out.len() < n {
+            acc_len -= bits;
+            let v = ((acc >> acc_len) & ((1 << bits) - 1)) as i64;
+            out.push(if signed && v >= 1 << (bits - 1) {
+                v - (1 << bits)
+            } else {
+                v
+            });
+        }
+    }
+    assert_eq!(out.len(), n, "encoding too short");
+    out
+}
+
+fn falcon_keys() -> Vec<NtruKey> {
+    let mut pk = None;
+    let mut keys = Vec::new();
+    for line in KEYS.lines() {
+        if let Some(hex) = line.strip_prefix("pk = ") {
+            pk = Some(from_hex(hex));
+        } else if let Some(hex) = line.strip_prefix("sk = ") {
+            let pk = pk.take().expect("sk without pk");
+            let sk = from_hex(hex);
+            assert_eq!((pk.len(), pk[0]), (897, 0x09), "Falcon-512 public key");
+            assert_eq!((sk.len(), sk[0]), (1281, 0x59), "Falcon-512 secret key");
+            keys.push(NtruKey {
+                h: unpack(&pk[1..], 512, 14, false),
+                f: unpack(&sk[1..385], 512, 6, true),
+                g: unpack(&sk[385..769], 512, 6, true),
+                big_f: unpack(&sk[769..], 512, 8, true),
+            });
+        }
+    }
+    assert!(!keys.is_empty());
+    keys
+}
+
+fn to_i1024(a: &[i64]) -> Vec<I1024> {
+    a.iter().map(|&c| I1024::from(c)).collect()
+}
+
+fn to_i64(a: &[I1024]) -> Vec<i64> {
+    a.iter()
+        .map(|c| {
+            let m = c.magnitude();
+            assert!(m.bits() < 63, "coefficient too large");
+            let v = m.0[0] as i64;
+            if c.is_negative() { -v } else { v }
+        })
+        .collect()
+}
+
+/// Multiplies in Z[X]/(X^n + 1).
+fn negacyclic_mul(a: &[i64], b: &[i64]) -> Vec<i128> {
+    let n = a.len();
+    let mut out = vec![0i128; n];
+    for (i, &x) in a.iter().enumerate() {
+        for (j, &y) in b.iter().enumerate() {
+            let p = x as i128 * y as i128;
+            if i + j < n {
+                out[i + j] += p;
+            } else {
+                out[i + j - n] -= p;
+            }
+        }
+    }
+    out
+}
+
+/// Checks f·G − g·F = q over the integers.
+fn satisfies_ntru_equation(f: &[i64], g: &[i64], big_f: &[i64], big_g: &[i64]) -> bool {
+    let fg = negacyclic_mul(f, big_g);
+    let gf = negacyclic_mul(g, big_f);
+    fg.iter()
+        .zip(&gf)
+        .enumerate()
+        .all(|(i, (a, b))| a - b == if i == 0 { Q as i128 } else { 0 })
+}
+
+fn ring<C: FieldConfig>(a: &[i64], ctx: &Arc<NttContext<C>>) -> RingElement<C> {
+    let coeffs = a
+        .iter()
+        .map(|&c| {
+            let m = FieldElement::new(U1024::from_u64(c.unsigned_abs()));
+            if c < 0 { -m } else { m }
+        })
+        .collect();
+    RingElement::new(coeffs, ctx.clone())
+}
+
+/// Maps coefficients mod q to (−q/2, q/2].
+fn centered(a: &RingElement<Q12289>) -> Vec<i64> {
+    a.clone_to_coefficient()
+        .coefficients()
+        .iter()
+        .map(|c| {
+            let v = c.to_u1024().0[0] as i64;
+            if v > Q as i64 / 2 { v - Q as i64 } else { v }
+        })
+        .collect()
+}
+
+#[test]
+fn test_falcon512_keys_satisfy_ntru_equation() {
+    // h = g·f⁻¹ mod q, and fG − gF = q gives G ≡ h·F (mod q).
+    let ctx = Arc::new(NttContext::<Q12289>::new(512));
+    assert!(ctx.is_negacyclic());
+    for (i, key) in falcon_keys().iter().enumerate() {
+        let f = ring(&key.f, &ctx);
+        let g = ring(&key.g, &ctx);
+        let f_inv = f.inverse().expect("Falcon f is invertible mod q");
+        let h = &g * &f_inv;
+        assert_eq!(h, ring(&key.h, &ctx), "key {i}");
+        assert_eq!(&h * &f, g, "key {i}");
+
+        let big_g = centered(&(&h * &ring(&key.big_f, &ctx)));
+        assert!(
+            satisfies_ntru_equation(&key.f, &key.g, &key.big_f, &big_g),
+            "key {i}"
+        );
+    }
+}
+
+#[test]
+fn test_ntru_solve_falcon512() {
+    for (i, key) in falcon_keys().iter().enumerate() {
+        let (big_f, big_g) = ntru_solve(&to_i1024(&key.f), &to_i1024(&key.g), Q).unwrap();
+        let big_f = to_i64(&big_f);
+        let big_g = to_i64(&big_g);
+        assert!(
+            satisfies_ntru_equation(&key.f, &key.g, &big_f, &big_g),
+            "key {i}"
+        );
+        // Falcon encodes F and G with 8-bit signed coefficients.
+        assert!(
+            big_f.iter().chain(&big_g).all(|c| c.abs() <= 127),
+            "key {i}"
+        );
+    }
+}
+
+#[test]
+fn test_ntru_solve_random_small_degrees() {
+    let mut rng = StdRng::seed_from_u64(1);
+    for n in [2, 8, 32, 64] {
+        let mut solved = 0;
+        while solved < 3 {
+            let f: Vec<i64> = (0..n).map(|_| rng.random_range(-4..=4)).collect();
+            let g: Vec<i64> = (0..n).map(|_| rng.random_range(-4..=4)).collect();
+            match ntru_solve(&to_i1024(&f), &to_i1024(&g), Q) {
+                Ok((big_f, big_g)) => {
+                    let (big_f, big_g) = (to_i64(&big_f), to_i64(&big_g));
+                    assert!(satisfies_ntru_equation(&f, &g, &big_f, &big_g), "n = {n}");
+                    solved += 1;
+                }
+                Err(e) => assert_eq!(e, NtruError::NotCoprime),
+            }
+        }
+    }
+}
+
+#[test]
+fn test_ntru_solve_rejects_bad_shapes() {
+    let a = to_i1024(&[1, 2, 3]);
+    assert_eq!(
+        ntru_solve(&a, &a, Q),
+        Err(NtruError::InvalidDegree { n: 3 })
+    );
+    let b = to_i1024(&[1, 2]);
+    let c = to_i1024(&[1, 2, 3, 4]);
+    assert_eq!(
+        ntru_solve(&b, &c, Q),
+        Err(NtruError::LengthMismatch { f: 2, g: 4 })
+    );
+}
+
+#[test]
+fn test_inverse_via_ntt() {
+    let ctx = Arc::new(NttContext::<Q12289>::new(512));
+    let mut rng = StdRng::seed_from_u64(2);
+    let a: Vec<i64> = (0..512).map(|_| rng.random_range(-100..100)).collect();
+    let a = ring(&a, &ctx);
+    let inv = a.inverse().unwrap();
+    assert_eq!(&a * &inv, RingElement::one(ctx.clone()));
+    assert_eq!(inv.inverse().unwrap(), a);
+
+    // A zero NTT slot means a shares a factor X − ψ^(2j+1) with X^N + 1.
+    let mut slots = a.clone_to_ntt().data().to_vec();
+    slots[17] = FieldElement::zero();
+    assert!(
+        RingElement::from_ntt(slots, ctx.clone())
+            .inverse()
+            .is_none()
+    );
+    assert!(RingElement::zero(ctx).inverse().is_none());
+}
+
+#[test]
+fn test_inverse_coefficient_fallback() {
+    // ψ has order 512, so the degree-8 context is not negacyclic.
+    let ctx = Arc::new(NttContext::<Q7681>::new(8));
+    assert!(!ctx.is_negacyclic());
+    let mut rng = StdRng::seed_from_u64(3);
+    for _ in 0..5 {
+        let a: Vec<i64> = (0..8).map(|_| rng.random_range(0..7681)).collect();
+        let inv = ring(&a, &ctx).inverse().unwrap();
+        let inv: Vec<i64> = inv
+            .coefficients()
+            .iter()
+            .map(|c| c.to_u1024().0[0] as i64)
+            .collect();
+        let prod = negacyclic_mul(&a, &inv);
+        for (i, c) in prod.iter().enumerate() {
+            assert_eq!(c.rem_euclid(7681), if i == 0 { 1 } else { 0 });
+        }
+    }
+
+    // r = ψ^32 has order 16, so r^8 = −1 and X − r divides X^8 + 1.
+    let psi = FieldElement::<Q7681>::new(U1024::from_u64(0x1bea));
+    let r = psi.pow(U1024::from_u64(32));
+    let mut coeffs = vec![FieldElement::zero(); 8];
+    coeffs[0] = -r;
+    coeffs[1] = FieldElement::one();
+    assert!(RingElement::new(coeffs, ctx).inverse().is_none());
+}
@@ -0,0 +1,253 @@
+//! Tests for the mixed-radix, Bluestein and truncated transforms and the
+//! strategy `Polynomial::mul_fast` picks among them.
+
+use lumen_math::poly::ntt::{
+    BluesteinNttContext, MixedRadixNttContext, MulStrategy, mul_truncated,
+};
+use lumen_math::{DefaultFieldConfig, FieldConfig, FieldElement, Polynomial, U1024};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+mod common;
+use common::Q7681;
+
+/// Q7681 with the default `TWO_ADICITY` of 32, although its root has order
+/// 2^9, as in a field implemented by hand without setting it.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+struct Q7681DefaultAdicity;
+
+impl FieldConfig for Q7681DefaultAdicity {
+    const MODULUS: U1024 = Q7681::MODULUS;
+    const MODULUS_BITS: u32 = Q7681::MODULUS_BITS;
+    const R2: U1024 = Q7681::R2;
+    const N_PRIME: U1024 = Q7681::N_PRIME;
+    const ROOT_OF_UNITY: U1024 = Q7681::ROOT_OF_UNITY;
+}
+
+fn random_coeffs<C: FieldConfig>(rng: &mut StdRng, n: usize) -> Vec<FieldElement<C>> {
+    (0..n)
+        .map(|_| FieldElement::new(U1024::from_u64(rng.random_range(0..7681))))
+        .collect()
+}
+
+/// Evaluates A_k = Σ a_j ω^(jk) directly.
+fn naive_dft<C: FieldConfig>(
+    a: &[FieldElement<C>],
+    omega: FieldElement<C>,
+) -> Vec<FieldElement<C>> {
+    let mut point = FieldElement::one();
+    (0..a.len())
+        .map(|_| {
+            let value = a
+                .iter()
+                .rev()
+                .fold(FieldElement::zero(), |acc, c| acc * point + *c);
+            point = point * omega;
+            value
+        })
+        .collect()
+}
+
+fn schoolbook<C: FieldConfig>(
+    a: &[FieldElement<C>],
+    b: &[FieldElement<C>],
+) -> Vec<FieldElement<C>> {
+    let mut out = vec![FieldElement::zero(); a.len() + b.len() - 1];
+    for (i, x) in a.iter().enumerate() {
+        for (j, y) in b.iter().enumerate() {
+            out[i + j] = out[i + j] + *x * *y;
+        }
+    }
+    out
+}
+
+#[test]
+fn test_mixed_radix_matches_naive_dft() {
+    let mut rng = StdRng::seed_from_u64(1);
+    for n in [1, 2, 3, 5, 6, 15, 30, 60, 96, 120, 240] {
+        let ctx = MixedRadixNttContext::<Q7681>::new(n).unwrap();
+        let one = FieldElement::one();
+        assert_eq!(ctx.root().pow(U1024::from_u64(n as u64)), one, "n = {n}");
+
+        let coeffs = random_coeffs::<Q7681>(&mut rng, n);
+        let mut values = coeffs.clone();
+        ctx.ntt(&mut values);
+        assert_eq!(values, naive_dft(&coeffs, ctx.root()), "n = {n}");
+        ctx.intt(&mut values);
+        assert_eq!(values, coeffs, "n = {n}");
+    }
+}
+
+#[test]
+fn test_mixed_radix_radices() {
+    let ctx = MixedRadixNttContext::<Q7681>::new(7680).unwrap();
+    assert_eq!(ctx.radices(), &[5, 3, 2, 2, 2, 2, 2, 2, 2, 2, 2]);
+    // 7 is not a radix, 9 = 3² does not divide 7680, 2^10 exceeds the 2-part.
+    assert!(MixedRadixNttContext::<Q7681>::new(7).is_none());
+    assert!(MixedRadixNttContext::<Q7681>::new(9).is_none());
+    assert!(MixedRadixNttContext::<Q7681>::new(1024).is_none());
+    assert!(MixedRadixNttContext::<Q7681>::new(0).is_none());
+}
+
+#[test]
+fn test_mixed_radix_cyclic_mul() {
+    let mut rng = StdRng::seed_from_u64(2);
+    let n = 60;
+    let ctx = MixedRadixNttContext::<Q7681>::shared(n).unwrap();
+    let a = random_coeffs::<Q7681>(&mut rng, n);
+    let b = random_coeffs::<Q7681>(&mut rng, n);
+    let mut expected = vec![FieldElement::zero(); n];
+    for (i, c) in schoolbook(&a, &b).into_iter().enumerate() {
+        expected[i % n] = expected[i % n] + c;
+    }
+    assert_eq!(ctx.mul(&a, &b), expected);
+}
+
+#[test]
+fn test_bluestein_matches_naive_dft() {
+    let mut rng = StdRng::seed_from_u64(3);
+    // q − 1 = 2^32 · 5 · 281 · …, so 281 and 562 need Bluestein.
+    for n in [1, 2, 5, 40, 281, 562] {
+        let ctx = BluesteinNttContext::<DefaultFieldConfig>::new(n).unwrap();
+        let coeffs = random_coeffs::<DefaultFieldConfig>(&mut rng, n);
+        let mut values = coeffs.clone();
+        ctx.ntt(&mut values);
+        assert_eq!(values, naive_dft(&coeffs, ctx.root()), "n = {n}");
+        ctx.intt(&mut values);
+        assert_eq!(values, coeffs, "n = {n}");
+    }
+    assert!(BluesteinNttContext::<DefaultFieldConfig>::new(3).is_none());
+}
+
+#[test]
+fn test_bluestein_agrees_with_mixed_radix() {
+    let mut rng = StdRng::seed_from_u64(4);
+    let n = 80;
+    let mixed = MixedRadixNttContext::<DefaultFieldConfig>::new(n).unwrap();
+    let bluestein = BluesteinNttContext::<DefaultFieldConfig>::new(n).unwrap();
+    assert_eq!(mixed.root(), bluestein.root());
+
+    let coeffs = random_coeffs::<DefaultFieldConfig>(&mut rng, n);
+    let mut x = coeffs.clone();
+    let mut y = coeffs;
+    mixed.ntt(&mut x);
+    bluestein.ntt(&mut y);
+    assert_eq!(x, y);
+}
+
+#[test]
+fn test_mul_truncated_matches_schoolbook() {
+    let mut rng = StdRng::seed_from_u64(5);
+    for (m, n) in [
+        (1, 1),
+        (1, 40),
+        (100, 101),
+        (257, 1),
+        (150, 215),
+        (300, 301),
+    ] {
+        let a = random_coeffs::<DefaultFieldConfig>(&mut rng, m);
+        let b = random_coeffs::<DefaultFieldConfig>(&mut rng, n);
+        assert_eq!(mul_truncated(&a, &b), schoolbook(&a, &b), "{m} x {n}");
+    }
+    assert!(mul_truncated::<DefaultFieldConfig>(&[], &[FieldElement::one()]).is_empty());
+}
+
+#[test]
+fn test_strategies_agree() {
+    let mut rng = StdRng::seed_from_u64(6);
+    let a = random_coeffs::<DefaultFieldConfig>(&mut rng, 70);
+    let b = random_coeffs::<DefaultFieldConfig>(&mut rng, 61);
+    let expected = schoolbook(&a, &b);
+    for strategy in [
+        MulStrategy::PowerOfTwo { size: 256 },
+        MulStrategy::MixedRadix { size: 160 },
+        MulStrategy::Truncated { len: 130 },
+        MulStrategy::Bluestein { size: 281 },
+    ] {
+        assert_eq!(strategy.mul(&a, &b), expected, "{strategy:?}");
+    }
+
+    let a = random_coeffs::<Q7681>(&mut rng, 50);
+    let b = random_coeffs::<Q7681>(&mut rng, 47);
+    assert_eq!(
+        MulStrategy::MixedRadix { size: 96 }.mul(&a, &b),
+        schoolbook(&a, &b)
+    );
+}
+
+#[test]
+fn test_select() {
+    assert_eq!(
+        MulStrategy::select::<DefaultFieldConfig>(256),
+        MulStrategy::PowerOfTwo { size: 256 }
+    );
+    assert_eq!(
+        MulStrategy::select::<DefaultFieldConfig>(513),
+        MulStrategy::Truncated { len: 513 }
+    );
+    // 768 = 2^8 · 3 fits exactly when 3 divides q − 1.
+    assert_eq!(
+        MulStrategy::select::<Q7681>(700),
+        MulStrategy::MixedRadix { size: 768 }
+    );
+
+    // Q7681 has no power-of-two size above 2^9, whatever TWO_ADICITY says.
+    for len in [513, 1000, 1024] {
+        assert!(matches!(
+            MulStrategy::select::<Q7681>(len),
+            MulStrategy::MixedRadix { .. }
+        ));
+        assert!(matches!(
+            MulStrategy::select::<Q7681DefaultAdicity>(len),
+            MulStrategy::MixedRadix { .. }
+        ));
+    }
+    assert_eq!(
+        MulStrategy::select::<Q7681DefaultAdicity>(128),
+        MulStrategy::MixedRadix { size: 160 }
+    );
+}
+
+fn check_mul_fast<C: FieldConfig>(seed: u64) {
+    let mut rng = StdRng::seed_from_u64(seed);
+    // Product lengths 5, 20, 100, 300, 513, 1000 and 1024.
+    for (m, n) in [
+        (3, 3),
+        (10, 11),
+        (50, 51),
+        (150, 151),
+        (257, 257),
+        (500, 501),
+        (512, 513),
+    ] {
+        let a = random_coeffs::<C>(&mut rng, m);
+        let b = random_coeffs::<C>(&mut rng, n);
+        let product = Polynomial::new(a.clone()).mul_fast(&Polynomial::new(b.clone()));
+        assert_eq!(product, Polynomial::new(schoolbook(&a, &b)), "{m} x {n}");
+    }
+}
+
+#[test]
+fn test_mul_fast_small_two_adicity() {
+    check_mul_fast::<Q7681>(8);
+    check_mul_fast::<Q7681DefaultAdicity>(9);
+}
+
+#[test]
+fn test_mul_fast_matches_schoolbook() {
+    let mut rng = StdRng::seed_from_u64(7);
+    for (m, n) in [(1, 1), (2, 3), (33, 32), (64, 65), (129, 128), (200, 313)] {
+        let a = random_coeffs::<DefaultFieldConfig>(&mut rng, m);
+        let b = random_coeffs::<DefaultFieldConfig>(&mut rng, n);
+        let product = Polynomial::new(a.clone()).mul_fast(&Polynomial::new(b.clone()));
+        assert_eq!(product, Polynomial::new(schoolbook(&a, &b)), "{m} x {n}");
+        assert_eq!(
+            product,
+            Polynomial::new(a).mul_ntt(&Polynomial::new(b)),
+            "{m} x {n}"
+        );
+    }
+    let zero = Polynomial::<DefaultFieldConfig>::zero();
+    assert!(zero.mul_fast(&Polynomial::one()).is_zero());
+}
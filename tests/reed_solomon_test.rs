@@ -0,0 +1,97 @@
+//! Integration tests for Reed–Solomon encoding and decoding.
+
+use lumen_math::codes::{Encoding, ReedSolomon, ReedSolomonError};
+use lumen_math::{DefaultFieldConfig, FieldElement, fp};
+
+type FE = FieldElement<DefaultFieldConfig>;
+type RS = ReedSolomon<DefaultFieldConfig>;
+
+fn message(k: usize) -> Vec<FE> {
+    (0..k).map(|i| fp!((i * i + 11) as u64)).collect()
+}
+
+#[test]
+fn test_correct_up_to_capacity() {
+    for encoding in [Encoding::NonSystematic, Encoding::Systematic] {
+        let rs = RS::with_encoding(16, 8, encoding).unwrap();
+        let msg = message(8);
+        let codeword = rs.encode(&msg).unwrap();
+
+        // (16 - 8) / 2 = 4 correctable errors
+        let positions = [1, 6, 9, 15];
+        let mut received = codeword.clone();
+        for &p in &positions {
+            received[p] = received[p] + fp!(12345u64);
+        }
+
+        let decoded = rs.decode(&received).unwrap();
+        assert_eq!(decoded.message, msg);
+        assert_eq!(decoded.codeword, codeword);
+        assert_eq!(decoded.error_positions, positions.to_vec());
+    }
+}
+
+#[test]
+fn test_too_many_errors_rejected() {
+    let rs = RS::new(16, 8).unwrap();
+    let codeword = rs.encode(&message(8)).unwrap();
+
+    let mut received = codeword;
+    for p in [0, 3, 5, 10, 12] {
+        received[p] = received[p] + FE::one();
+    }
+
+    assert_eq!(rs.decode(&received), Err(ReedSolomonError::TooManyErrors));
+}
+
+#[test]
+fn test_erasure_decoding() {
+    let rs = RS::new_systematic(16, 4).unwrap();
+    let msg = message(4);
+    let codeword = rs.encode(&msg).unwrap();
+
+    // Keep only 4 of 16 symbols, none of them systematic positions.
+    let received: Vec<Option<FE>> = codeword
+        .iter()
+        .enumerate()
+        .map(|(i, s)| {
+            if [1, 7, 10, 13].contains(&i) {
+                Some(*s)
+            } else {
+                None
+            }
+        })
+        .collect();
+
+    assert_eq!(rs.decode_erasures(&received).unwrap(), msg);
+}
+
+#[test]
+fn test_erasure_decoding_failures() {
+    let rs = RS::new(8, 4).unwrap();
+    let codeword = rs.encode(&message(4)).unwrap();
+
+    let mut received: Vec<Option<FE>> = codeword.iter().map(|s| Some(*s)).collect();
+    for slot in received.iter_mut().take(5) {
+        *slot = None;
+    }
+    assert_eq!(
+        rs.decode_erasures(&received),
+        Err(ReedSolomonError::TooManyErasures)
+    );
+
+    let mut received: Vec<Option<FE>> = codeword.iter().map(|s| Some(*s)).collect();
+    received[7] = Some(fp!(0u64) - FE::one());
+    assert_eq!(
+        rs.decode_erasures(&received),
+        Err(ReedSolomonError::InconsistentSymbols)
+    );
+}
+
+#[test]
+fn test_zero_codeword() {
+    let rs = RS::new(8, 2).unwrap();
+    let decoded = rs.decode(&[FE::zero(); 8]).unwrap();
+    assert_eq!(decoded.message, vec![FE::zero(); 2]);
+    assert!(decoded.error_positions.is_empty());
+}
@@ -0,0 +1,142 @@
+//! Tests for Galois automorphisms and traces of ring elements.
+
+use std::sync::Arc;
+
+use lumen_math::ring::sampling::sample_uniform;
+use lumen_math::{FieldElement, NttContext, RingElement, RingElementState, U1024};
+
+mod common;
+use common::Q7681;
+
+const N: usize = 256;
+
+type Ctx = Arc<NttContext<Q7681>>;
+
+fn make_ctx() -> Ctx {
+    Arc::new(NttContext::new(N))
+}
+
+fn random(ctx: &Ctx, nonce: u8) -> RingElement<Q7681> {
+    sample_uniform(b"automorphism", &[nonce], ctx.clone()).into_coefficient()
+}
+
+const INDICES: [usize; 6] = [1, 3, 5, 25, 2 * N - 1, 2 * N + 5];
+
+#[test]
+fn test_automorphism_is_multiplicative() {
+    let ctx = make_ctx();
+    let a = random(&ctx, 0);
+    let b = random(&ctx, 1);
+    for k in INDICES {
+        let lhs = (&a * &b).automorphism(k);
+        let rhs = &a.automorphism(k) * &b.automorphism(k);
+        assert_eq!(lhs, rhs, "k = {k}");
+    }
+}
+
+#[test]
+fn test_automorphism_is_additive() {
+    let ctx = make_ctx();
+    let a = random(&ctx, 0);
+    let b = random(&ctx, 1);
+    for k in INDICES {
+        assert_eq!(
+            (&a + &b).automorphism(k),
+            &a.automorphism(k) + &b.automorphism(k)
+        );
+    }
+}
+
+#[test]
+fn test_ntt_and_coefficient_forms_agree() {
+    let ctx = make_ctx();
+    let a = random(&ctx, 2);
+    let a_ntt = a.clone_to_ntt();
+    for k in INDICES {
+        let coeff = a.automorphism(k);
+        let ntt = a_ntt.automorphism(k);
+        assert_eq!(coeff.state(), RingElementState::Coefficient);
+        assert_eq!(ntt.state(), RingElementState::Ntt);
+        assert_eq!(coeff, ntt, "k = {k}");
+    }
+}
+
+#[test]
+fn test_automorphisms_compose() {
+    let ctx = make_ctx();
+    let a = random(&ctx, 3).into_ntt();
+    for (k, l) in [(3, 5), (5, 5), (2 * N - 1, 7)] {
+        assert_eq!(a.automorphism(k).automorphism(l), a.automorphism(k * l));
+    }
+    // σ_(2N-1) is an involution.
+    assert_eq!(a.automorphism(2 * N - 1).automorphism(2 * N - 1), a);
+}
+
+#[test]
+fn test_conjugation_of_monomial() {
+    let ctx = make_ctx();
+    let mut coeffs = vec![FieldElement::zero(); N];
+    coeffs[1] = FieldElement::one();
+    let x = RingElement::new(coeffs, ctx);
+
+    // σ_(2N-1)(X) = X^(-1) = -X^(N-1)
+    let conj = x.automorphism(2 * N - 1);
+    let expected: Vec<_> = (0..N)
+        .map(|i| {
+            if i == N - 1 {
+                -FieldElement::<Q7681>::one()
+            } else {
+                FieldElement::zero()
+            }
+        })
+        .collect();
+    assert_eq!(conj.coefficients().len(), N);
+    assert!(
+        conj.coefficients()
+            .iter()
+            .zip(expected.iter())
+            .all(|(a, b)| a.to_u1024() == b.to_u1024())
+    );
+}
+
+#[test]
+fn test_trace_projects_onto_subring() {
+    let ctx = make_ctx();
+    let a = random(&ctx, 4);
+    for m in [1, 2, 16, 128, N] {
+        let step = N / m;
+        let tr = a.trace(m);
+        let scale = FieldElement::<Q7681>::new(U1024::from_u64(step as u64));
+        for (i, (t, c)) in tr.coefficients().iter().zip(a.coefficients()).enumerate() {
+            let expected = if i % step == 0 {
+                *c * scale
+            } else {
+                FieldElement::zero()
+            };
+            assert_eq!(t.to_u1024(), expected.to_u1024(), "m = {m}, i = {i}");
+        }
+    }
+}
+
+#[test]
+fn test_trace_is_fixed_by_subgroup_and_works_in_ntt_form() {
+    let ctx = make_ctx();
+    let a = random(&ctx, 5);
+    let m = 32;
+    let tr = a.clone_to_ntt().trace(m);
+    assert_eq!(tr.state(), RingElementState::Ntt);
+    assert_eq!(tr, a.trace(m));
+    for t in 0..4 {
+        let k = 1 + 2 * m * (2 * t + 1);
+        assert_eq!(tr.automorphism(k), tr, "k = {k}");
+    }
+}
+
+#[test]
+fn test_trace_of_subring_element() {
+    // On the subring of degree 64, Tr_(N→8) acts as [N : 64] · Tr_(64→8).
+    let ctx = make_ctx();
+    let a = random(&ctx, 6);
+    let four = FieldElement::new(U1024::from_u64(4));
+    assert_eq!(a.trace(64).trace(8), a.trace(8).scale(&four));
+}
@@ -72,9 +72,9 @@ fn test_addition_preserves_state() {
 
     // Verify addition
     let coeffs = sum.coefficients();
-    for i in 0..8 {
+    for (i, c) in coeffs.iter().enumerate() {
         let expected = (i + (i + 10)) as u64;
-        assert_eq!(coeffs[i].to_u1024().0[0], expected);
+        assert_eq!(c.to_u1024().0[0], expected);
     }
 }
 
@@ -148,9 +148,9 @@ fn test_scale() {
     let scaled = a.scale(&scalar);
     let coeffs = scaled.coefficients();
 
-    for i in 0..8 {
+    for (i, c) in coeffs.iter().enumerate() {
         let expected = (i * 3) as u64;
-        assert_eq!(coeffs[i].to_u1024().0[0], expected);
+        assert_eq!(c.to_u1024().0[0], expected);
     }
 }
 
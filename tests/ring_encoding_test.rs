@@ -0,0 +1,179 @@
+//! Tests for RingElement compression, rounding and bit-packing.
+
+use std::sync::Arc;
+
+use lumen_math::pqc::ml_dsa::power2round;
+use lumen_math::pqc::ml_kem::ML_KEM_768;
+use lumen_math::ring::{RingEncodingError, packed_len};
+use lumen_math::{
+    DilithiumFieldElement, FieldConfig, FieldElement, I1024, NttContext, RingElement, U1024,
+};
+use proptest::prelude::*;
+
+mod common;
+use common::Q8380417;
+
+/// The ML-KEM modulus. Z_3329 only has 256th roots of unity, so this context
+/// is only used for coefficient-form operations; its NTT is never applied.
+#[derive(FieldConfig, Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[modulus = "0xd01"]
+#[root = "0x11"]
+struct Q3329;
+
+const N: usize = 256;
+
+fn kyber_element(values: &[u16]) -> RingElement<Q3329> {
+    let coeffs = values
+        .iter()
+        .map(|&v| FieldElement::new(U1024::from_u64(v as u64)))
+        .collect();
+    RingElement::new(coeffs, Arc::new(NttContext::new(N)))
+}
+
+fn values<C: FieldConfig>(elem: &RingElement<C>) -> Vec<u64> {
+    elem.coefficients()
+        .iter()
+        .map(|c| c.to_u1024().0[0])
+        .collect()
+}
+
+fn kyber_coeffs() -> impl Strategy<Value = Vec<u16>> {
+    prop::collection::vec(0u16..3329, N)
+}
+
+#[test]
+fn test_pack_matches_ml_kem_encodings() {
+    let (ek, _) = ML_KEM_768.keygen_from_seed(&[3; 32], &[4; 32]);
+    let (_, ct) = ek.encapsulate_deterministic(&[5; 32]);
+    let ctx = Arc::new(NttContext::<Q3329>::new(N));
+
+    // The encapsulation key starts with ByteEncode_12(t̂) and the ciphertext
+    // with ByteEncode_10(Compress_10(u)), one 256-coefficient block per row.
+    let (t_bytes, _) = ek.as_bytes().split_at(3 * 384);
+    for block in t_bytes.chunks(384) {
+        let t = RingElement::unpack(block, 12, ctx.clone()).unwrap();
+        assert_eq!(t.pack(12), block);
+    }
+    let (u_bytes, _) = ct.split_at(3 * 320);
+    for block in u_bytes.chunks(320) {
+        let u = RingElement::unpack(block, 10, ctx.clone()).unwrap();
+        assert_eq!(u.pack(10), block);
+        assert_eq!(u.decompress(10).compress(10), u);
+    }
+}
+
+#[test]
+fn test_power2round_matches_ml_dsa() {
+    let ctx = Arc::new(NttContext::<Q8380417>::new(N));
+    let raw: Vec<u32> = (0..N as u32)
+        .map(|i| (i.wrapping_mul(2_654_435_761)) % 8_380_417)
+        .collect();
+    let coeffs = raw
+        .iter()
+        .map(|&v| FieldElement::new(U1024::from_u64(v as u64)))
+        .collect();
+    let a = RingElement::new(coeffs, ctx);
+    let (r1, r0) = a.power2round(13);
+
+    let r0 = r0.centered_coefficients();
+    for (i, &v) in raw.iter().enumerate() {
+        let (e1, e0) = power2round(DilithiumFieldElement::new(v));
+        assert_eq!(values(&r1)[i], e1 as u64);
+        assert_eq!(r0[i], I1024::from(e0 as i64));
+    }
+}
+
+#[test]
+fn test_unpack_rejects_values_above_modulus() {
+    let mut a = vec![0u16; N];
+    a[5] = 3328;
+    let mut bytes = kyber_element(&a).pack(12);
+    // 3328 → 4095 in the sixth 12-bit field
+    bytes[7] |= 0xf0;
+    bytes[8] = 0xff;
+    let ctx = Arc::new(NttContext::<Q3329>::new(N));
+    assert_eq!(
+        RingElement::unpack(&bytes, 12, ctx),
+        Err(RingEncodingError::CoefficientOutOfRange { index: 5 })
+    );
+}
+
+#[test]
+#[should_panic(expected = "Coefficient does not fit in 4 bits")]
+fn test_pack_panics_on_wide_coefficient() {
+    let _ = kyber_element(&[16; N]).pack(4);
+}
+
+#[test]
+fn test_ntt_form_is_packed_as_coefficients() {
+    let ctx = Arc::new(NttContext::<Q8380417>::new(N));
+    let coeffs = (0..N as u64)
+        .map(|i| FieldElement::new(U1024::from_u64(i)))
+        .collect();
+    let a = RingElement::new(coeffs, ctx.clone());
+    let bytes = a.clone_to_ntt().pack(23);
+    assert_eq!(bytes, a.pack(23));
+    assert_eq!(RingElement::unpack(&bytes, 23, ctx).unwrap(), a);
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(64))]
+
+    #[test]
+    fn prop_pack_unpack_roundtrip(raw in kyber_coeffs(), bits in 12u32..20) {
+        let a = kyber_element(&raw);
+        let bytes = a.pack(bits);
+        prop_assert_eq!(bytes.len(), packed_len(N, bits));
+        let b = RingElement::unpack(&bytes, bits, a.context().clone()).unwrap();
+        prop_assert_eq!(b, a);
+    }
+
+    #[test]
+    fn prop_compressed_pack_roundtrip(raw in kyber_coeffs(), d in 1u32..12) {
+        let c = kyber_element(&raw).compress(d);
+        let b = RingElement::unpack(&c.pack(d), d, c.context().clone()).unwrap();
+        prop_assert_eq!(b, c);
+    }
+
+    #[test]
+    fn prop_decompress_compress_error_bound(raw in kyber_coeffs(), d in 1u32..12) {
+        // |x - Decompress_d(Compress_d(x))| mod± q ≤ ⌈q / 2^(d+1)⌋
+        let a = kyber_element(&raw);
+        let err = (&a - &a.compress(d).decompress(d)).centered_coefficients();
+        let bound = (3329 + (1 << d)) >> (d + 1);
+        for e in err {
+            prop_assert!(e.magnitude() <= U1024::from_u64(bound));
+        }
+    }
+
+    #[test]
+    fn prop_compress_decompress_is_identity(raw in prop::collection::vec(0u16..1024, N)) {
+        // Compress_d(Decompress_d(y)) = y for d < log2 q
+        let y = kyber_element(&raw);
+        prop_assert_eq!(y.decompress(10).compress(10), y);
+    }
+
+    #[test]
+    fn prop_centered_roundtrip(raw in prop::collection::vec(-1664i64..=1664, N)) {
+        let ctx = Arc::new(NttContext::<Q3329>::new(N));
+        let signed: Vec<I1024> = raw.iter().map(|&v| I1024::from(v)).collect();
+        let a = RingElement::from_centered_coefficients(&signed, ctx);
+        prop_assert_eq!(a.centered_coefficients(), signed);
+    }
+
+    #[test]
+    fn prop_power2round_recombines(raw in prop::collection::vec(0u64..8_380_417, N), d in 1u32..23) {
+        let ctx = Arc::new(NttContext::<Q8380417>::new(N));
+        let coeffs = raw.iter().map(|&v| FieldElement::new(U1024::from_u64(v))).collect();
+        let a = RingElement::new(coeffs, ctx);
+        let (r1, r0) = a.power2round(d);
+        let two_d = FieldElement::new(U1024::from_u64(1 << d));
+        prop_assert_eq!(&r1.scale(&two_d) + &r0, a);
+        let half = 1i64 << (d - 1);
+        for c in r0.centered_coefficients() {
+            let m = c.magnitude().0[0] as i64;
+            let v = if c.is_negative() { -m } else { m };
+            prop_assert!(-half < v && v <= half);
+        }
+    }
+}
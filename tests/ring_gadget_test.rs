@@ -0,0 +1,138 @@
+//! Tests for gadget decomposition and key switching of ring elements.
+
+use std::sync::Arc;
+
+use lumen_math::ring::gadget::{KeySwitchingKey, gadget_vector, key_switch};
+use lumen_math::ring::sampling::{sample_cbd, sample_uniform};
+use lumen_math::{FieldElement, NttContext, RingElement, U1024};
+use proptest::prelude::*;
+
+mod common;
+use common::Q8380417;
+
+const N: usize = 256;
+const Q: u64 = 8_380_417;
+
+type Ctx = Arc<NttContext<Q8380417>>;
+
+fn make_ctx() -> Ctx {
+    Arc::new(NttContext::new(N))
+}
+
+fn random(ctx: &Ctx, seed: u64) -> RingElement<Q8380417> {
+    sample_uniform(&seed.to_le_bytes(), &[0], ctx.clone()).into_coefficient()
+}
+
+/// Returns the smallest ℓ with base^ℓ ≥ q.
+fn min_levels(base: u64) -> usize {
+    let mut levels = 0;
+    let mut power = 1u128;
+    while power < Q as u128 {
+        power *= base as u128;
+        levels += 1;
+    }
+    levels
+}
+
+fn max_abs_digit(digits: &[RingElement<Q8380417>]) -> u64 {
+    digits.iter().map(|d| d.infinity_norm().0[0]).max().unwrap()
+}
+
+#[test]
+fn test_gadget_vector_powers() {
+    let g = gadget_vector::<Q8380417>(1 << 8, 3);
+    let expected: Vec<_> = [1u64, 1 << 8, 1 << 16]
+        .iter()
+        .map(|&v| FieldElement::<Q8380417>::new(U1024::from_u64(v)))
+        .collect();
+    assert_eq!(g, expected);
+}
+
+#[test]
+fn test_binary_decomposition() {
+    let ctx = make_ctx();
+    let a = random(&ctx, 1);
+    let digits = a.gadget_decompose(2, min_levels(2));
+    assert_eq!(digits.len(), 23);
+    assert!(max_abs_digit(&digits) <= 1);
+    assert_eq!(RingElement::gadget_recompose(&digits, 2), a);
+}
+
+#[test]
+fn test_decomposition_accepts_ntt_form() {
+    let ctx = make_ctx();
+    let a = random(&ctx, 2);
+    let digits = a.clone_to_ntt().gadget_decompose(1 << 6, 4);
+    assert_eq!(RingElement::gadget_recompose(&digits, 1 << 6), a);
+}
+
+#[test]
+#[should_panic(expected = "Gadget base^levels must be at least the modulus")]
+fn test_too_few_levels_panics() {
+    let ctx = make_ctx();
+    let _ = random(&ctx, 3).gadget_decompose(1 << 8, 2);
+}
+
+#[test]
+fn test_key_switch_identity_secret() {
+    // Switching from s to s keeps the phase up to the key-switching noise.
+    let ctx = make_ctx();
+    let s = sample_cbd(b"secret", &[0], 2, ctx.clone());
+    let ksk = KeySwitchingKey::generate(&s, &s, 1 << 12, 2, b"ksk", 2);
+    assert_eq!(ksk.levels(), 2);
+    assert_eq!(ksk.base(), 1 << 12);
+
+    let c0 = random(&ctx, 4);
+    let c1 = random(&ctx, 5);
+    let (d0, d1) = key_switch((&c0, &c1), &ksk);
+    let before = &c0 + &(&c1 * &s);
+    let after = &d0 + &(&d1 * &s);
+    // |Σ dⱼ·eⱼ| ≤ ℓ · n · (B/2) · η
+    let bound = 2 * N as u64 * (1 << 11) * 2;
+    assert!((&after - &before).infinity_norm() <= U1024::from_u64(bound));
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(16))]
+
+    #[test]
+    fn prop_recompose_inverts_decompose(seed in any::<u64>(), log_base in 1u32..24) {
+        let ctx = make_ctx();
+        let a = random(&ctx, seed);
+        let base = 1u64 << log_base;
+        let digits = a.gadget_decompose(base, min_levels(base));
+        prop_assert_eq!(RingElement::gadget_recompose(&digits, base), a);
+    }
+
+    #[test]
+    fn prop_digits_are_balanced(seed in any::<u64>(), base in 2u64..5000) {
+        let ctx = make_ctx();
+        let a = random(&ctx, seed);
+        let levels = min_levels(base);
+        let digits = a.gadget_decompose(base, levels);
+        prop_assert_eq!(digits.len(), levels);
+        prop_assert!(max_abs_digit(&digits) <= base / 2);
+        prop_assert_eq!(RingElement::gadget_recompose(&digits, base), a);
+    }
+
+    #[test]
+    fn prop_key_switch_preserves_phase(seed in any::<u64>(), log_base in 2u32..12) {
+        let ctx = make_ctx();
+        // The key-switching seed must not be reused for the secrets.
+        let secret_seed = [&seed.to_le_bytes()[..], b"secrets"].concat();
+        let ksk_seed = [&seed.to_le_bytes()[..], b"ksk"].concat();
+        let s_from = sample_cbd(&secret_seed, &[0], 2, ctx.clone());
+        let s_to = sample_cbd(&secret_seed, &[1], 2, ctx.clone());
+        let base = 1u64 << log_base;
+        let levels = min_levels(base);
+        let ksk = KeySwitchingKey::generate(&s_from, &s_to, base, levels, &ksk_seed, 2);
+
+        let c0 = random(&ctx, seed ^ 1);
+        let c1 = random(&ctx, seed ^ 2);
+        let (d0, d1) = key_switch((&c0, &c1), &ksk);
+        let before = &c0 + &(&c1 * &s_from);
+        let after = &d0 + &(&d1 * &s_to);
+        let bound = levels as u64 * N as u64 * (base / 2) * 2;
+        prop_assert!((&after - &before).infinity_norm() <= U1024::from_u64(bound));
+    }
+}
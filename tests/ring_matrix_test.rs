@@ -0,0 +1,194 @@
+//! Integration tests for RingVector and RingMatrix.
+
+use std::sync::Arc;
+
+use lumen_math::ring::sampling::{sample_cbd, sample_uniform};
+use lumen_math::{NttContext, RingElement, RingElementState, RingMatrix, RingVector};
+
+mod common;
+use common::Q7681;
+
+const Q: i64 = 7681;
+const N: usize = 256;
+
+type Ctx = Arc<NttContext<Q7681>>;
+
+fn make_ctx() -> Ctx {
+    Arc::new(NttContext::new(N))
+}
+
+fn to_i64(elem: &RingElement<Q7681>) -> Vec<i64> {
+    elem.clone_to_coefficient()
+        .coefficients()
+        .iter()
+        .map(|c| c.to_u1024().0[0] as i64)
+        .collect()
+}
+
+/// Schoolbook multiplication in Z_q[X]/(X^N + 1).
+fn schoolbook(a: &[i64], b: &[i64]) -> Vec<i64> {
+    let mut out = vec![0i64; N];
+    for i in 0..N {
+        for j in 0..N {
+            let prod = a[i] * b[j] % Q;
+            if i + j < N {
+                out[i + j] = (out[i + j] + prod) % Q;
+            } else {
+                out[i + j - N] = (out[i + j - N] - prod).rem_euclid(Q);
+            }
+        }
+    }
+    out
+}
+
+fn public_matrix(rows: usize, cols: usize, ctx: &Ctx) -> RingMatrix<Q7681> {
+    RingMatrix::from_fn(rows, cols, ctx.clone(), |i, j| {
+        sample_uniform(b"matrix seed", &[j as u8, i as u8], ctx.clone())
+    })
+}
+
+fn small_vector(len: usize, offset: u8, ctx: &Ctx) -> RingVector<Q7681> {
+    RingVector::from_fn(len, ctx.clone(), |i| {
+        sample_cbd(b"secret seed", &[offset + i as u8], 2, ctx.clone())
+    })
+}
+
+#[test]
+fn test_matrix_vector_matches_schoolbook() {
+    let ctx = make_ctx();
+    let a = public_matrix(3, 2, &ctx);
+    let s = small_vector(2, 0, &ctx);
+
+    let t = &a * &s;
+    assert_eq!(t.len(), 3);
+    assert!(t.iter().all(|e| e.state() == RingElementState::Ntt));
+
+    for i in 0..3 {
+        let mut expected = vec![0i64; N];
+        for j in 0..2 {
+            let prod = schoolbook(&to_i64(a.get(i, j)), &to_i64(&s[j]));
+            for (e, p) in expected.iter_mut().zip(prod) {
+                *e = (*e + p) % Q;
+            }
+        }
+        assert_eq!(to_i64(&t[i]), expected, "row {i}");
+    }
+}
+
+#[test]
+fn test_inner_product_matches_schoolbook() {
+    let ctx = make_ctx();
+    let u = small_vector(3, 0, &ctx);
+    let v = small_vector(3, 10, &ctx);
+
+    let mut expected = vec![0i64; N];
+    for i in 0..3 {
+        let prod = schoolbook(&to_i64(&u[i]), &to_i64(&v[i]));
+        for (e, p) in expected.iter_mut().zip(prod) {
+            *e = (*e + p) % Q;
+        }
+    }
+    assert_eq!(to_i64(&u.inner_product(&v)), expected);
+    assert_eq!(u.inner_product(&v), v.inner_product(&u));
+}
+
+#[test]
+fn test_transpose_adjoint_identity() {
+    // ⟨Aᵀu, v⟩ = ⟨u, Av⟩
+    let ctx = make_ctx();
+    let a = public_matrix(2, 3, &ctx);
+    let u = small_vector(2, 0, &ctx);
+    let v = small_vector(3, 10, &ctx);
+
+    let at = a.transpose();
+    assert_eq!((at.rows(), at.cols()), (3, 2));
+    assert_eq!(at[(2, 1)], a[(1, 2)]);
+    assert_eq!(at.transpose(), a);
+
+    assert_eq!(
+        at.mul_vector(&u).inner_product(&v),
+        u.inner_product(&a.mul_vector(&v))
+    );
+}
+
+#[test]
+fn test_product_is_independent_of_operand_state() {
+    let ctx = make_ctx();
+    let a = public_matrix(2, 2, &ctx);
+    let s = small_vector(2, 0, &ctx);
+
+    let coeff_a = a.clone().into_coefficient();
+    let ntt_s = s.clone().into_ntt();
+    assert_eq!(&coeff_a * &s, &a * &ntt_s);
+    assert_eq!(coeff_a.clone() * s.clone(), &a * &s);
+}
+
+#[test]
+fn test_bulk_conversions() {
+    let ctx = make_ctx();
+    let mut a = public_matrix(2, 3, &ctx);
+    let original = a.clone();
+
+    a.to_coefficient();
+    assert!((0..2).all(|i| {
+        a.row(i)
+            .iter()
+            .all(|e| e.state() == RingElementState::Coefficient)
+    }));
+    a.to_ntt();
+    assert!((0..2).all(|i| a.row(i).iter().all(|e| e.state() == RingElementState::Ntt)));
+    assert_eq!(a, original);
+
+    let mut v = small_vector(4, 0, &ctx);
+    let original = v.clone();
+    v.to_ntt();
+    assert!(v.iter().all(|e| e.state() == RingElementState::Ntt));
+    v.to_coefficient();
+    assert!(v.iter().all(|e| e.state() == RingElementState::Coefficient));
+    assert_eq!(v, original);
+}
+
+#[test]
+fn test_vector_addition_and_subtraction() {
+    let ctx = make_ctx();
+    let u = small_vector(3, 0, &ctx);
+    let v = small_vector(3, 10, &ctx).into_ntt();
+
+    let sum = &u + &v;
+    for i in 0..3 {
+        assert_eq!(sum[i], &u[i] + &v[i]);
+    }
+    assert_eq!(&sum - &v, u);
+    assert_eq!(u.clone() + (-u.clone()), RingVector::zero(3, ctx.clone()));
+    assert_eq!(&u - &u, RingVector::zero(3, ctx));
+}
+
+#[test]
+fn test_matrix_addition_distributes_over_product() {
+    let ctx = make_ctx();
+    let a = public_matrix(2, 2, &ctx);
+    let b = RingMatrix::from_fn(2, 2, ctx.clone(), |i, j| {
+        sample_uniform(b"other seed", &[j as u8, i as u8], ctx.clone())
+    });
+    let s = small_vector(2, 0, &ctx);
+
+    assert_eq!(&(&a + &b) * &s, &(&a * &s) + &(&b * &s));
+    assert_eq!(&(&a - &b) * &s, &(&a * &s) - &(&b * &s));
+    assert_eq!(&a - &a, RingMatrix::zero(2, 2, ctx));
+}
+
+#[test]
+#[should_panic(expected = "Vector length must match column count")]
+fn test_dimension_mismatch_panics() {
+    let ctx = make_ctx();
+    let a = RingMatrix::zero(2, 3, ctx.clone());
+    let _ = a.mul_vector(&RingVector::zero(2, ctx));
+}
+
+#[test]
+#[should_panic(expected = "Ring vectors must share the same NTT context")]
+fn test_context_mismatch_panics() {
+    let u = RingVector::zero(2, make_ctx());
+    let v = RingVector::zero(2, make_ctx());
+    let _ = u.inner_product(&v);
+}
@@ -0,0 +1,167 @@
+//! Tests for centered representatives and coefficient norms.
+
+use std::sync::Arc;
+
+use lumen_math::ring::{check_norm_bound_small, infinity_norm_small, l2_norm_squared_small};
+use lumen_math::{
+    DILITHIUM_Q, DefaultFieldConfig, DilithiumFieldElement, FieldConfig, FieldElement, I1024,
+    KYBER_Q, KyberFieldElement, NttContext, RingElement, U1024,
+};
+use proptest::prelude::*;
+
+mod common;
+use common::Q8380417;
+
+const N: usize = 256;
+
+fn ring_element(raw: &[u32]) -> RingElement<Q8380417> {
+    let coeffs = raw
+        .iter()
+        .map(|&v| FieldElement::new(U1024::from_u64(v as u64)))
+        .collect();
+    RingElement::new(coeffs, Arc::new(NttContext::new(N)))
+}
+
+fn small_element(raw: &[u32]) -> Vec<DilithiumFieldElement> {
+    raw.iter().map(|&v| DilithiumFieldElement::new(v)).collect()
+}
+
+fn reference_centered(v: u32, q: u32) -> i64 {
+    if v > (q - 1) / 2 {
+        v as i64 - q as i64
+    } else {
+        v as i64
+    }
+}
+
+// =============================================================================
+// Centered representatives
+// =============================================================================
+
+#[test]
+fn test_field_element_centered_i64() {
+    let half = (DILITHIUM_Q - 1) / 2;
+    for v in [0, 1, half - 1, half, half + 1, DILITHIUM_Q - 1] {
+        let x = FieldElement::<Q8380417>::new(U1024::from_u64(v as u64));
+        let expected = reference_centered(v, DILITHIUM_Q);
+        assert_eq!(x.to_centered_i64(), expected, "v = {v}");
+        assert_eq!(x.to_centered_i1024(), I1024::from(expected));
+        assert_eq!(
+            DilithiumFieldElement::new(v).to_centered_i64(),
+            expected,
+            "v = {v}"
+        );
+    }
+}
+
+#[test]
+fn test_kyber_centered_i64() {
+    for v in 0..KYBER_Q {
+        assert_eq!(
+            KyberFieldElement::new(v).to_centered_i64(),
+            reference_centered(v, KYBER_Q)
+        );
+    }
+}
+
+#[test]
+fn test_centered_i1024_for_large_modulus() {
+    let q = DefaultFieldConfig::MODULUS;
+    let minus_one = -FieldElement::<DefaultFieldConfig>::one();
+    assert_eq!(minus_one.to_centered_i1024(), I1024::NEG_ONE);
+
+    let half = FieldElement::<DefaultFieldConfig>::new(q.shr(1));
+    assert_eq!(half.to_centered_i1024(), I1024::from_unsigned(q.shr(1)));
+    let above = half + FieldElement::one();
+    assert_eq!(above.to_centered_i1024(), I1024::new(q.shr(1), false));
+}
+
+#[test]
+#[should_panic(expected = "Centered representative only fits in i64")]
+fn test_centered_i64_panics_for_large_modulus() {
+    let _ = FieldElement::<DefaultFieldConfig>::one().to_centered_i64();
+}
+
+// =============================================================================
+// Norms
+// =============================================================================
+
+#[test]
+fn test_ring_norms_small_example() {
+    let mut raw = vec![0u32; N];
+    raw[0] = 3;
+    raw[1] = DILITHIUM_Q - 4;
+    raw[200] = 2;
+    let a = ring_element(&raw);
+
+    assert_eq!(a.infinity_norm(), U1024::from_u64(4));
+    assert_eq!(a.l2_norm_squared(), U1024::from_u64(29));
+    assert!(a.check_norm_bound(U1024::from_u64(5)));
+    assert!(!a.check_norm_bound(U1024::from_u64(4)));
+
+    let zero = RingElement::zero(a.context().clone());
+    assert_eq!(zero.infinity_norm(), U1024::ZERO);
+    assert!(!zero.check_norm_bound(U1024::ZERO));
+}
+
+#[test]
+fn test_norms_are_independent_of_state() {
+    let raw: Vec<u32> = (0..N as u32).map(|i| (i * 40_503) % DILITHIUM_Q).collect();
+    let a = ring_element(&raw);
+    let a_ntt = a.clone_to_ntt();
+    assert_eq!(a_ntt.infinity_norm(), a.infinity_norm());
+    assert_eq!(a_ntt.l2_norm_squared(), a.l2_norm_squared());
+}
+
+#[test]
+#[should_panic(expected = "Squared L2 norm may overflow")]
+fn test_l2_norm_panics_when_it_may_overflow() {
+    let ctx = Arc::new(NttContext::<DefaultFieldConfig>::new(8));
+    let _ = RingElement::one(ctx).l2_norm_squared();
+}
+
+#[test]
+fn test_infinity_norm_for_large_modulus() {
+    let ctx = Arc::new(NttContext::<DefaultFieldConfig>::new(8));
+    let q = DefaultFieldConfig::MODULUS;
+    let mut a = RingElement::zero(ctx);
+    a.modify_data(|d| {
+        d[3] = FieldElement::new(q.shr(1) + U1024::ONE);
+        d[5] = FieldElement::new(U1024::from_u64(7));
+    });
+    assert_eq!(a.infinity_norm(), q.shr(1));
+    assert!(a.check_norm_bound(q.shr(1) + U1024::ONE));
+    assert!(!a.check_norm_bound(q.shr(1)));
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(64))]
+
+    #[test]
+    fn prop_ring_norms_match_reference(raw in prop::collection::vec(0u32..DILITHIUM_Q, N)) {
+        let centered: Vec<i64> = raw.iter().map(|&v| reference_centered(v, DILITHIUM_Q)).collect();
+        let inf = centered.iter().map(|c| c.unsigned_abs()).max().unwrap();
+        let l2: u64 = centered.iter().map(|c| (c * c) as u64).sum();
+
+        let a = ring_element(&raw);
+        prop_assert_eq!(a.infinity_norm(), U1024::from_u64(inf));
+        prop_assert_eq!(a.l2_norm_squared(), U1024::from_u64(l2));
+        prop_assert!(a.check_norm_bound(U1024::from_u64(inf + 1)));
+        prop_assert!(!a.check_norm_bound(U1024::from_u64(inf)));
+
+        let s = small_element(&raw);
+        prop_assert_eq!(infinity_norm_small(&s) as u64, inf);
+        prop_assert_eq!(l2_norm_squared_small(&s), l2);
+        prop_assert!(check_norm_bound_small(&s, inf as u32 + 1));
+        prop_assert!(!check_norm_bound_small(&s, inf as u32));
+    }
+
+    #[test]
+    fn prop_small_bound_check_matches_norm(
+        raw in prop::collection::vec(0u32..KYBER_Q, 1..64),
+        bound in 0u32..2000,
+    ) {
+        let p: Vec<KyberFieldElement> = raw.iter().map(|&v| KyberFieldElement::new(v)).collect();
+        prop_assert_eq!(check_norm_bound_small(&p, bound), infinity_norm_small(&p) < bound);
+    }
+}
@@ -0,0 +1,242 @@
+//! Tests for RNS ring elements.
+
+use std::sync::Arc;
+
+use lumen_math::ring::RingElementState;
+use lumen_math::{
+    DefaultFieldConfig, I1024, NttContext, RingElement, RnsBasis, RnsRingElement, U1024,
+};
+use proptest::prelude::*;
+
+const N: usize = 16;
+
+fn basis(bits: u32, count: usize) -> Arc<RnsBasis> {
+    Arc::new(RnsBasis::generate(N, bits, count).unwrap())
+}
+
+fn negacyclic_schoolbook(a: &[i64], b: &[i64]) -> Vec<i128> {
+    let n = a.len();
+    let mut c = vec![0i128; n];
+    for i in 0..n {
+        for j in 0..n {
+            let p = a[i] as i128 * b[j] as i128;
+            if i + j < n {
+                c[i + j] += p;
+            } else {
+                c[i + j - n] -= p;
+            }
+        }
+    }
+    c
+}
+
+fn to_i128(x: &I1024) -> i128 {
+    let m = x.magnitude();
+    assert!(m.bits() < 127);
+    let v = m.0[0] as i128 | ((m.0[1] as i128) << 64);
+    if x.is_negative() { -v } else { v }
+}
+
+fn pseudo_random(seed: u64, len: usize, bound: i64) -> Vec<i64> {
+    let mut s = seed;
+    (0..len)
+        .map(|_| {
+            s = s
+                .wrapping_mul(6_364_136_223_846_793_005)
+                .wrapping_add(1_442_695_040_888_963_407);
+            ((s >> 33) as i64 % (2 * bound + 1)) - bound
+        })
+        .collect()
+}
+
+#[test]
+fn test_product_matches_integer_schoolbook() {
+    let basis = basis(50, 3);
+    let a = pseudo_random(1, N, 1 << 40);
+    let b = pseudo_random(2, N, 1 << 40);
+    let expected = negacyclic_schoolbook(&a, &b);
+
+    let ra = RnsRingElement::from_signed(&a, basis.clone(), 3);
+    let rb = RnsRingElement::from_signed(&b, basis, 3);
+    let c = &ra * &rb;
+    assert_eq!(c.state(), RingElementState::Ntt);
+
+    let got: Vec<i128> = c.to_centered_coefficients().iter().map(to_i128).collect();
+    assert_eq!(got, expected);
+}
+
+#[test]
+fn test_add_sub_neg_across_states() {
+    let basis = basis(40, 2);
+    let a = RnsRingElement::from_signed(&pseudo_random(3, N, 1000), basis.clone(), 2);
+    let b = RnsRingElement::from_signed(&pseudo_random(4, N, 1000), basis.clone(), 2);
+
+    let sum = &a.clone_to_ntt() + &b;
+    assert_eq!(sum.state(), RingElementState::Ntt);
+    assert_eq!(&sum - &b, a);
+    assert!((&a + &(-&a)).is_zero());
+    assert_eq!(a.clone_to_ntt().into_coefficient().limbs(), a.limbs());
+}
+
+#[test]
+fn test_ring_element_roundtrip() {
+    let basis = basis(50, 4);
+    let ctx = Arc::new(NttContext::<DefaultFieldConfig>::new(N));
+    let a_raw = pseudo_random(5, N, 1 << 28);
+    let b_raw = pseudo_random(6, N, 1 << 28);
+    let signed = |v: &[i64]| v.iter().map(|&x| I1024::from(x)).collect::<Vec<_>>();
+
+    let a = RingElement::from_centered_coefficients(&signed(&a_raw), ctx.clone());
+    let b = RingElement::from_centered_coefficients(&signed(&b_raw), ctx.clone());
+
+    let ra = RnsRingElement::from_ring_element(&a, basis.clone(), 4);
+    assert_eq!(ra, RnsRingElement::from_signed(&a_raw, basis.clone(), 4));
+    assert_eq!(ra.to_ring_element(ctx.clone()), a);
+
+    // Products stay far below both moduli, so they come back exactly.
+    let rb = RnsRingElement::from_ring_element(&b, basis, 4);
+    let expected: Vec<I1024> = negacyclic_schoolbook(&a_raw, &b_raw)
+        .into_iter()
+        .map(|c| I1024::from(c as i64))
+        .collect();
+    let product = (&ra * &rb).to_ring_element(ctx);
+    assert_eq!(product.centered_coefficients(), expected);
+}
+
+#[test]
+fn test_u1024_reconstruction() {
+    let basis = basis(60, 3);
+    let q = basis.modulus(3);
+    let coeffs: Vec<U1024> = (0..N as u64)
+        .map(|i| (q - U1024::ONE).shr(i as usize))
+        .collect();
+    let a = RnsRingElement::from_u1024(&coeffs, basis, 3);
+    assert_eq!(a.to_u1024_coefficients(), coeffs);
+    assert_eq!(a.clone_to_ntt().to_u1024_coefficients(), coeffs);
+}
+
+#[test]
+fn test_fast_base_conversion_bound() {
+    let source = basis(30, 3);
+    let target = basis(55, 4);
+    let q = source.modulus(3);
+    let coeffs: Vec<U1024> = (0..N as u64)
+        .map(|i| (q - U1024::from_u64(i * 7 + 1)).shr(i as usize % 5))
+        .collect();
+
+    let a = RnsRingElement::from_u1024(&coeffs, source, 3);
+    let b = a.clone_to_ntt().fast_base_conversion(&target);
+    assert_eq!(b.level(), 4);
+
+    for (x, y) in coeffs.iter().zip(b.to_u1024_coefficients()) {
+        // y = x + u·Q with 0 ≤ u < 3
+        assert!(y >= *x);
+        let diff = y - *x;
+        assert_eq!(diff % q, U1024::ZERO);
+        assert!(diff / q < U1024::from_u64(3));
+    }
+}
+
+#[test]
+fn test_rescale_rounds_to_nearest() {
+    let basis = basis(40, 3);
+    let q = basis.modulus(3);
+    let q_last = U1024::from_u64(basis.moduli()[2]);
+    let half = q_last.shr(1);
+    let coeffs: Vec<U1024> = (0..N as u64)
+        .map(|i| (q - U1024::from_u64(i * 1_000_003 + 5)).shr(i as usize))
+        .collect();
+    // ⌊x / q_last⌉ can reach Q / q_last itself, which reduces to zero.
+    let q_prev = basis.modulus(2);
+    let expected: Vec<U1024> = coeffs
+        .iter()
+        .map(|&x| (x + half) / q_last % q_prev)
+        .collect();
+
+    let a = RnsRingElement::from_u1024(&coeffs, basis, 3);
+    let r = a.rescale();
+    assert_eq!(r.level(), 2);
+    assert_eq!(r.to_u1024_coefficients(), expected);
+
+    let r_ntt = a.clone_to_ntt().rescale();
+    assert_eq!(r_ntt.state(), RingElementState::Ntt);
+    assert_eq!(r_ntt, r);
+}
+
+#[test]
+fn test_rescale_of_scaled_message() {
+    // Rescaling Δ·m + e by q_last ≈ Δ recovers m up to the rounding.
+    let basis = basis(45, 3);
+    let delta = U1024::from_u64(basis.moduli()[2]);
+    let m = pseudo_random(7, N, 1 << 20);
+    let a = RnsRingElement::from_signed(&m, basis.clone(), 3).mul_scalar(&delta);
+    let e = RnsRingElement::from_signed(&pseudo_random(8, N, 100), basis, 3);
+
+    let r = (&a + &e).rescale();
+    let got: Vec<i128> = r.to_centered_coefficients().iter().map(to_i128).collect();
+    assert_eq!(got, m.iter().map(|&x| x as i128).collect::<Vec<_>>());
+}
+
+#[test]
+fn test_mod_switch_and_drop() {
+    let basis = basis(40, 4);
+    let m = pseudo_random(9, N, 1 << 20);
+    let a = RnsRingElement::from_signed(&m, basis.clone(), 4);
+
+    let dropped = a.drop_to_level(2);
+    assert_eq!(dropped.level(), 2);
+    assert_eq!(dropped.limbs(), &a.limbs()[..2]);
+    assert_eq!(dropped.modulus(), basis.modulus(2));
+
+    // Small coefficients shrink to zero after dividing by ~2^80.
+    let switched = a.clone_to_ntt().mod_switch_to(2);
+    assert_eq!(switched.level(), 2);
+    assert!(switched.is_zero());
+
+    let q_top = U1024::from_u64(basis.moduli()[3]) * U1024::from_u64(basis.moduli()[2]);
+    let scaled = a.mul_scalar(&q_top).mod_switch_to(2);
+    assert_eq!(scaled, dropped);
+}
+
+#[test]
+#[should_panic(expected = "RNS elements must share the same basis and level")]
+fn test_level_mismatch_panics() {
+    let basis = basis(40, 3);
+    let a = RnsRingElement::zero(basis.clone(), 3);
+    let b = RnsRingElement::zero(basis, 2);
+    let _ = &a + &b;
+}
+
+#[test]
+#[should_panic(expected = "Cannot rescale an element at level 1")]
+fn test_rescale_at_level_one_panics() {
+    let _ = RnsRingElement::zero(basis(40, 2), 1).rescale();
+}
+
+#[test]
+fn test_basis_accessors() {
+    let basis = basis(50, 3);
+    assert_eq!(basis.degree(), N);
+    assert_eq!(basis.num_primes(), 3);
+    let product = basis
+        .moduli()
+        .iter()
+        .fold(U1024::ONE, |acc, &q| acc * U1024::from_u64(q));
+    assert_eq!(basis.modulus(3), product);
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(32))]
+
+    #[test]
+    fn prop_product_matches_schoolbook(
+        a in prop::collection::vec(-(1i64 << 35)..(1 << 35), N),
+        b in prop::collection::vec(-(1i64 << 35)..(1 << 35), N),
+    ) {
+        let basis = basis(45, 2);
+        let ra = RnsRingElement::from_signed(&a, basis.clone(), 2);
+        let rb = RnsRingElement::from_signed(&b, basis, 2);
+        let got: Vec<i128> = (&ra * &rb).to_centered_coefficients().iter().map(to_i128).collect();
+        prop_assert_eq!(got, negacyclic_schoolbook(&a, &b));
+    }
+}
@@ -0,0 +1,217 @@
+//! Tests for the seed-driven ring element samplers.
+
+use std::sync::Arc;
+
+use lumen_math::pqc::ml_dsa::ML_DSA_44;
+use lumen_math::pqc::ml_kem::sample_ntt;
+use lumen_math::ring::sampling::{
+    sample_bounded, sample_bounded_small, sample_cbd, sample_cbd_small, sample_uniform,
+    sample_uniform_small,
+};
+use lumen_math::{
+    DILITHIUM_Q, DefaultFieldConfig, DilithiumFieldElement, FieldConfig, FieldElement, KYBER_Q,
+    KyberFieldElement, NttContext, RingElementState, U1024,
+};
+use sha3::Shake256;
+use sha3::digest::{ExtendableOutput, Update, XofReader};
+
+mod common;
+use common::Q7681;
+
+/// Maps a field element to its representative in (-q/2, q/2] as i64.
+fn centered<C: FieldConfig>(x: &FieldElement<C>, q: u64) -> i64 {
+    let v = x.to_u1024().0[0];
+    if v > q / 2 {
+        v as i64 - q as i64
+    } else {
+        v as i64
+    }
+}
+
+// =============================================================================
+// Compatibility with the FIPS 203 / FIPS 204 samplers
+// =============================================================================
+
+#[test]
+fn test_kyber_uniform_matches_ml_kem_sample_ntt() {
+    let rho = [0x5a; 32];
+    for (i, j) in [(0u8, 0u8), (1, 2), (2, 1)] {
+        let a: [KyberFieldElement; 256] = sample_uniform_small(&rho, &[j, i]);
+        assert_eq!(a, sample_ntt(&rho, j, i));
+    }
+}
+
+#[test]
+fn test_kyber_cbd_matches_ml_kem_prf() {
+    let seed = [0x17; 32];
+    for eta in [2, 3] {
+        let mut bytes = vec![0u8; 64 * eta];
+        let mut xof = Shake256::default();
+        xof.update(&seed);
+        xof.update(&[4]);
+        xof.finalize_xof().read(&mut bytes);
+
+        // SamplePolyCBD_η (FIPS 203, Algorithm 8) on PRF_η(seed, 4).
+        let bit = |i: usize| ((bytes[i / 8] >> (i % 8)) & 1) as u32;
+        let expected: [KyberFieldElement; 256] = std::array::from_fn(|i| {
+            let x: u32 = (0..eta).map(|j| bit(2 * i * eta + j)).sum();
+            let y: u32 = (0..eta).map(|j| bit(2 * i * eta + eta + j)).sum();
+            KyberFieldElement::new(x + KYBER_Q - y)
+        });
+
+        let s: [KyberFieldElement; 256] = sample_cbd_small(&seed, &[4], eta);
+        assert_eq!(s, expected);
+    }
+}
+
+#[test]
+fn test_dilithium_uniform_matches_ml_dsa_expand_a() {
+    let rho = [0xa5; 32];
+    let a_hat = ML_DSA_44.expand_a(&rho);
+    for (r, row) in a_hat.iter().enumerate() {
+        for (s, entry) in row.iter().enumerate() {
+            let sampled: [DilithiumFieldElement; 256] =
+                sample_uniform_small(&rho, &[s as u8, r as u8]);
+            assert_eq!(&sampled, entry);
+        }
+    }
+}
+
+// =============================================================================
+// Small-field distributions
+// =============================================================================
+
+#[test]
+fn test_small_uniform_covers_range() {
+    let mut max = 0;
+    let mut min = u32::MAX;
+    for nonce in 0..8u8 {
+        let a: [DilithiumFieldElement; 256] = sample_uniform_small(b"range", &[nonce]);
+        for c in a {
+            max = max.max(c.value());
+            min = min.min(c.value());
+        }
+    }
+    assert!(min < DILITHIUM_Q / 100);
+    assert!(max > DILITHIUM_Q - DILITHIUM_Q / 100);
+}
+
+#[test]
+fn test_small_bounded_hits_both_ends() {
+    let gamma = 3;
+    let mut seen = [false; 7];
+    for nonce in 0..4u8 {
+        let p: [KyberFieldElement; 256] = sample_bounded_small(b"bounded", &[nonce], gamma);
+        for c in p {
+            let v = c.value() as i32;
+            let v = if v > (KYBER_Q / 2) as i32 {
+                v - KYBER_Q as i32
+            } else {
+                v
+            };
+            assert!(v.abs() <= gamma as i32);
+            seen[(v + gamma as i32) as usize] = true;
+        }
+    }
+    assert!(seen.iter().all(|s| *s));
+}
+
+#[test]
+fn test_small_cbd_mean_and_variance() {
+    let eta = 3;
+    let mut sum = 0i64;
+    let mut sum_sq = 0i64;
+    let samples = 16 * 256;
+    for nonce in 0..16u8 {
+        let p: [DilithiumFieldElement; 256] = sample_cbd_small(b"cbd", &[nonce], eta);
+        for c in p {
+            let v = c.value() as i64;
+            let v = if v > (DILITHIUM_Q / 2) as i64 {
+                v - DILITHIUM_Q as i64
+            } else {
+                v
+            };
+            sum += v;
+            sum_sq += v * v;
+        }
+    }
+    // E[X] = 0 and Var[X] = η / 2.
+    let mean = sum as f64 / samples as f64;
+    let variance = sum_sq as f64 / samples as f64 - mean * mean;
+    assert!(mean.abs() < 0.1, "mean {}", mean);
+    assert!(
+        (variance - eta as f64 / 2.0).abs() < 0.15,
+        "variance {}",
+        variance
+    );
+}
+
+// =============================================================================
+// RingElement samplers
+// =============================================================================
+
+#[test]
+fn test_ring_uniform_small_modulus() {
+    let ctx = Arc::new(NttContext::<Q7681>::new(256));
+    let a = sample_uniform(b"seed", &[0], ctx.clone());
+    assert_eq!(a.state(), RingElementState::Ntt);
+    assert_eq!(a.degree(), 256);
+    assert!(
+        a.data()
+            .iter()
+            .all(|c| c.to_u1024() < U1024::from_u64(7681))
+    );
+
+    let b = sample_uniform(b"seed", &[0], ctx.clone());
+    let c = sample_uniform(b"seed", &[1], ctx);
+    assert_eq!(a, b);
+    assert_ne!(a, c);
+}
+
+#[test]
+fn test_ring_uniform_large_modulus() {
+    let ctx = Arc::new(NttContext::<DefaultFieldConfig>::new(8));
+    let a = sample_uniform(b"seed", b"large", ctx);
+    assert_eq!(a.state(), RingElementState::Ntt);
+    let values: Vec<_> = a.data().iter().map(|c| c.to_u1024()).collect();
+    assert!(values.iter().all(|v| *v < DefaultFieldConfig::MODULUS));
+    // Top limbs are populated, so the values span the full modulus width.
+    assert!(values.iter().any(|v| v.0[15] != 0));
+}
+
+#[test]
+fn test_ring_cbd_and_bounded_ranges() {
+    let ctx = Arc::new(NttContext::<Q7681>::new(256));
+
+    let s = sample_cbd(b"seed", &[1], 2, ctx.clone());
+    assert_eq!(s.state(), RingElementState::Coefficient);
+    assert!(
+        s.coefficients()
+            .iter()
+            .all(|c| centered(c, 7681).abs() <= 2)
+    );
+
+    let y = sample_bounded(b"seed", &[2], 100, ctx);
+    assert!(
+        y.coefficients()
+            .iter()
+            .all(|c| centered(c, 7681).abs() <= 100)
+    );
+    assert!(y.coefficients().iter().any(|c| centered(c, 7681) < -50));
+}
+
+#[test]
+fn test_ring_samplers_agree_with_small_samplers() {
+    let ctx = Arc::new(NttContext::<Q7681>::new(256));
+    let ring = sample_cbd(b"agree", &[9], 2, ctx);
+    let small: [DilithiumFieldElement; 256] = sample_cbd_small(b"agree", &[9], 2);
+    for (r, s) in ring.coefficients().iter().zip(small.iter()) {
+        let v = s.value() as i64;
+        let v = if v > (DILITHIUM_Q / 2) as i64 {
+            v - DILITHIUM_Q as i64
+        } else {
+            v
+        };
+        assert_eq!(centered(r, 7681), v);
+    }
+}
@@ -0,0 +1,148 @@
+//! Soundness and completeness tests for the sum-check protocol.
+
+use lumen_math::protocol::{
+    SumcheckError, Transcript, prove_product_sumcheck, prove_sumcheck, verify_product_sumcheck,
+    verify_sumcheck, verify_sumcheck_rounds,
+};
+use lumen_math::{
+    DefaultFieldConfig, DenseMultilinearExtension, FieldElement, MultivariatePolynomial,
+    Polynomial, fp,
+};
+
+type FE = FieldElement<DefaultFieldConfig>;
+type Mle = DenseMultilinearExtension<DefaultFieldConfig>;
+type MvPoly = MultivariatePolynomial<DefaultFieldConfig>;
+
+fn mle(num_vars: usize, seed: u64) -> Mle {
+    Mle::new(
+        num_vars,
+        (0..1u64 << num_vars).map(|i| fp!(i * seed + 1)).collect(),
+    )
+}
+
+fn sample_poly() -> MvPoly {
+    // g = 4·x0·x1·x3 + x1^3 + 9·x2·x3 + 1
+    let mut g = MvPoly::new(4);
+    g.add_term(vec![1, 1, 0, 1], fp!(4u64));
+    g.add_term(vec![0, 3, 0, 0], fp!(1u64));
+    g.add_term(vec![0, 0, 1, 1], fp!(9u64));
+    g.add_term(vec![0, 0, 0, 0], fp!(1u64));
+    g
+}
+
+#[test]
+fn test_multivariate_completeness() {
+    let g = sample_poly();
+    let proof = prove_sumcheck(&g, &mut Transcript::new(b"test"));
+    assert!(verify_sumcheck(&g, &proof, &mut Transcript::new(b"test")).is_ok());
+
+    // Round polynomial degrees follow the per-variable degrees.
+    assert_eq!(proof.round_polynomials[1].degree(), Some(3));
+}
+
+#[test]
+fn test_tampered_round_polynomial_rejected() {
+    let g = sample_poly();
+    let mut proof = prove_sumcheck(&g, &mut Transcript::new(b"test"));
+
+    // Shift mass between g_1(0) and g_1(1): the round sum is preserved,
+    // so only the final evaluation check can catch it.
+    let tweak = Polynomial::new(vec![fp!(1u64), FE::zero() - fp!(2u64)]);
+    proof.round_polynomials[1] = proof.round_polynomials[1].clone() + tweak;
+
+    assert!(verify_sumcheck(&g, &proof, &mut Transcript::new(b"test")).is_err());
+}
+
+#[test]
+fn test_degree_bound_enforced() {
+    let g = sample_poly();
+    let mut proof = prove_sumcheck(&g, &mut Transcript::new(b"test"));
+    proof.round_polynomials[0] =
+        proof.round_polynomials[0].clone() + Polynomial::monomial(fp!(1u64), 5);
+
+    assert_eq!(
+        verify_sumcheck(&g, &proof, &mut Transcript::new(b"test")),
+        Err(SumcheckError::DegreeTooHigh { round: 0 })
+    );
+}
+
+#[test]
+fn test_proof_for_other_polynomial_rejected() {
+    let g = sample_poly();
+    let mut h = sample_poly();
+    h.add_term(vec![1, 0, 1, 0], fp!(3u64));
+
+    let proof = prove_sumcheck(&g, &mut Transcript::new(b"test"));
+    assert!(verify_sumcheck(&h, &proof, &mut Transcript::new(b"test")).is_err());
+}
+
+#[test]
+fn test_product_completeness() {
+    let factors = vec![mle(4, 3), mle(4, 5), mle(4, 7)];
+    let proof = prove_product_sumcheck(&factors, &mut Transcript::new(b"test"));
+
+    let expected = (0..16).fold(FE::zero(), |acc, b| {
+        acc + factors
+            .iter()
+            .fold(FE::one(), |p, f| p * f.evaluations()[b])
+    });
+    assert_eq!(proof.claimed_sum, expected);
+    assert!(verify_product_sumcheck(&factors, &proof, &mut Transcript::new(b"test")).is_ok());
+}
+
+#[test]
+fn test_product_wrong_claim_rejected() {
+    let factors = vec![mle(3, 2), mle(3, 11)];
+    let mut proof = prove_product_sumcheck(&factors, &mut Transcript::new(b"test"));
+    proof.claimed_sum = proof.claimed_sum + FE::one();
+
+    assert_eq!(
+        verify_product_sumcheck(&factors, &proof, &mut Transcript::new(b"test")),
+        Err(SumcheckError::RoundSumMismatch { round: 0 })
+    );
+}
+
+#[test]
+fn test_product_with_other_factors_rejected() {
+    let factors = vec![mle(3, 2), mle(3, 11)];
+    let proof = prove_product_sumcheck(&factors, &mut Transcript::new(b"test"));
+
+    let other = vec![mle(3, 2), mle(3, 13)];
+    assert!(verify_product_sumcheck(&other, &proof, &mut Transcript::new(b"test")).is_err());
+}
+
+#[test]
+fn test_product_with_mismatched_num_vars_rejected() {
+    let factors = vec![mle(3, 2), mle(3, 11)];
+    let proof = prove_product_sumcheck(&factors, &mut Transcript::new(b"test"));
+
+    let wider = vec![mle(3, 2), mle(4, 11)];
+    assert_eq!(
+        verify_product_sumcheck(&wider, &proof, &mut Transcript::new(b"test")),
+        Err(SumcheckError::NumVarsMismatch { factor: 1 })
+    );
+    let narrower = vec![mle(2, 2), mle(2, 11)];
+    assert_eq!(
+        verify_product_sumcheck(&narrower, &proof, &mut Transcript::new(b"test")),
+        Err(SumcheckError::NumVarsMismatch { factor: 0 })
+    );
+}
+
+#[test]
+fn test_subclaim_matches_polynomial() {
+    let factors = vec![mle(3, 4)];
+    let proof = prove_product_sumcheck(&factors, &mut Transcript::new(b"test"));
+    let subclaim =
+        verify_sumcheck_rounds(&[1, 1, 1], &proof, &mut Transcript::new(b"test")).unwrap();
+    assert_eq!(
+        factors[0].evaluate(&subclaim.point),
+        subclaim.expected_evaluation
+    );
+}
+
+#[test]
+fn test_transcript_mismatch_rejected() {
+    let g = sample_poly();
+    let proof = prove_sumcheck(&g, &mut Transcript::new(b"prover"));
+    assert!(verify_sumcheck(&g, &proof, &mut Transcript::new(b"verifier")).is_err());
+}
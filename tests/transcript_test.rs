@@ -0,0 +1,90 @@
+use lumen_math::protocol::Transcript;
+use lumen_math::{DefaultFieldConfig, FieldConfig, FieldElement, U1024, fp};
+
+type FE = FieldElement<DefaultFieldConfig>;
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+#[test]
+fn test_vector_message_then_challenge() {
+    let mut t = Transcript::new(b"lumen-math test");
+    t.append_message(b"msg", b"hello");
+    assert_eq!(
+        hex(&t.challenge_bytes(b"c", 16)),
+        "c22b3c75bb6bbec6c78c2dbcdca47485"
+    );
+}
+
+#[test]
+fn test_vector_empty_transcript() {
+    let mut t = Transcript::new(b"lumen-math test");
+    assert_eq!(
+        hex(&t.challenge_bytes(b"c", 32)),
+        "5f12356273b71d4f9a69d0992bad69a489a4dc2234439ddbba23371ee625a496"
+    );
+}
+
+#[test]
+fn test_vector_integers_and_u1024_challenge() {
+    let mut t = Transcript::new(b"lumen-math test");
+    t.append_u64(b"u64", 42);
+    t.append_u1024(b"u1024", &U1024::from_u64(7));
+    let expected = U1024::from_hex(
+        "313895938f058759fb77f834b943b72fd45d1825ba2c55f6148b7f393b1f810d\
+         6f88b58817d2ca69d3752d729a32477acfcf26389b4f5660fabe5dd090111f1f\
+         285630d845eb680549a24fa9f36d14d26e33e05f4f0289ce804f6e240f086077\
+         7daf7c98e281972bbdc051b9f5e135c57831f0403d316cdd454df694b17dc3a3",
+    );
+    assert_eq!(t.challenge_u1024(b"c"), expected);
+}
+
+#[test]
+fn test_field_element_encoding_is_canonical() {
+    let mut a = Transcript::new(b"test");
+    let mut b = Transcript::new(b"test");
+    a.append_field_element(b"x", &fp!(123u64));
+    b.append_u1024(b"x", &U1024::from_u64(123));
+    assert_eq!(a, b);
+}
+
+#[test]
+fn test_polynomial_and_slice_encoding_agree() {
+    let coeffs = vec![fp!(1u64), fp!(2u64), fp!(3u64)];
+    let mut a = Transcript::new(b"test");
+    let mut b = Transcript::new(b"test");
+    a.append_polynomial(b"p", &lumen_math::Polynomial::new(coeffs.clone()));
+    b.append_field_elements(b"p", &coeffs);
+    assert_eq!(a, b);
+}
+
+#[test]
+fn test_challenge_label_separates() {
+    let mut a = Transcript::new(b"test");
+    let mut b = a.clone();
+    assert_ne!(
+        a.challenge_bytes(b"alpha", 32),
+        b.challenge_bytes(b"beta", 32)
+    );
+}
+
+#[test]
+fn test_field_challenges_deterministic_and_reduced() {
+    let mut a = Transcript::new(b"test");
+    let mut b = Transcript::new(b"test");
+    let xs: Vec<FE> = a.challenge_field_elements(b"r", 4);
+    let ys: Vec<FE> = b.challenge_field_elements(b"r", 4);
+    assert_eq!(xs, ys);
+    for x in &xs {
+        assert!(x.to_u1024() < DefaultFieldConfig::MODULUS);
+    }
+    assert_ne!(xs[0], xs[1]);
+}
+
+#[test]
+#[should_panic(expected = "Challenge length exceeds")]
+fn test_challenge_length_limit() {
+    let mut t = Transcript::new(b"test");
+    t.challenge_bytes(b"c", 255 * 32 + 1);
+}